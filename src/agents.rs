@@ -3,6 +3,12 @@
 //! All per-agent metadata lives here. Adding a new agent means adding one
 //! `AgentDef` entry to `AGENTS` and writing a status detection function.
 
+use std::fmt;
+use std::path::Path;
+use std::str::FromStr;
+
+use serde::{Deserialize, Deserializer};
+
 use crate::session::Status;
 use crate::tmux::status_detection;
 
@@ -45,6 +51,9 @@ pub struct AgentDef {
     pub detect_status: fn(&str) -> Status,
     /// Environment variables always injected into the container for this agent.
     pub container_env: &'static [(&'static str, &'static str)],
+    /// Files or directories in a project that suggest this agent, used by
+    /// `detect_tool_from_dir`.
+    pub project_markers: &'static [&'static str],
 }
 
 pub const AGENTS: &[AgentDef] = &[
@@ -59,6 +68,7 @@ pub const AGENTS: &[AgentDef] = &[
         supports_host_launch: true,
         detect_status: status_detection::detect_claude_status,
         container_env: &[("CLAUDE_CONFIG_DIR", "/root/.claude")],
+        project_markers: &["CLAUDE.md", ".claude"],
     },
     AgentDef {
         name: "opencode",
@@ -71,6 +81,7 @@ pub const AGENTS: &[AgentDef] = &[
         supports_host_launch: false,
         detect_status: status_detection::detect_opencode_status,
         container_env: &[],
+        project_markers: &[".opencode", "opencode.json"],
     },
     AgentDef {
         name: "vibe",
@@ -83,6 +94,7 @@ pub const AGENTS: &[AgentDef] = &[
         supports_host_launch: true,
         detect_status: status_detection::detect_vibe_status,
         container_env: &[],
+        project_markers: &[".vibe"],
     },
     AgentDef {
         name: "codex",
@@ -97,6 +109,7 @@ pub const AGENTS: &[AgentDef] = &[
         supports_host_launch: true,
         detect_status: status_detection::detect_codex_status,
         container_env: &[],
+        project_markers: &[".codex"],
     },
     AgentDef {
         name: "gemini",
@@ -109,6 +122,7 @@ pub const AGENTS: &[AgentDef] = &[
         supports_host_launch: true,
         detect_status: status_detection::detect_gemini_status,
         container_env: &[],
+        project_markers: &["GEMINI.md", ".gemini"],
     },
     AgentDef {
         name: "cursor",
@@ -121,6 +135,20 @@ pub const AGENTS: &[AgentDef] = &[
         supports_host_launch: true,
         detect_status: status_detection::detect_cursor_status,
         container_env: &[("CURSOR_CONFIG_DIR", "/root/.cursor")],
+        project_markers: &[".cursorrules", ".cursor"],
+    },
+    AgentDef {
+        name: "crush",
+        binary: "crush",
+        aliases: &[],
+        detection: DetectionMethod::Which("crush"),
+        yolo: Some(YoloMode::CliFlag("--yolo")),
+        instruction_flag: None,
+        set_default_command: false,
+        supports_host_launch: true,
+        detect_status: status_detection::detect_generic_status,
+        container_env: &[],
+        project_markers: &["CRUSH.md", ".crush"],
     },
 ];
 
@@ -134,6 +162,67 @@ pub fn agent_names() -> Vec<&'static str> {
     AGENTS.iter().map(|a| a.name).collect()
 }
 
+/// A registered agent, identified by its canonical name.
+///
+/// Parsing trims, ignores case and accepts aliases, so `"Claude"` and
+/// `"open-code"` resolve to `claude` and `opencode`. Unlike
+/// `resolve_tool_name`, the whole input must name an agent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Tool(&'static str);
+
+impl Tool {
+    pub fn as_str(&self) -> &'static str {
+        self.0
+    }
+
+    pub fn def(&self) -> &'static AgentDef {
+        get_agent(self.0).expect("Tool is only built from registered agents")
+    }
+}
+
+impl fmt::Display for Tool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("Unknown tool: {0} (supported: {supported})", supported = agent_names().join(", "))]
+pub struct UnknownTool(pub String);
+
+impl FromStr for Tool {
+    type Err = UnknownTool;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = s.trim().to_lowercase();
+        AGENTS
+            .iter()
+            .find(|a| a.name == name || a.aliases.contains(&name.as_str()))
+            .map(|a| Tool(a.name))
+            .ok_or_else(|| UnknownTool(s.to_string()))
+    }
+}
+
+/// Normalize a stored tool name to its canonical form. Unknown names are
+/// kept as-is so older or hand-edited files still load.
+pub fn normalize_tool_name(name: &str) -> String {
+    name.parse::<Tool>()
+        .map(|t| t.as_str().to_string())
+        .unwrap_or_else(|_| name.to_string())
+}
+
+/// serde `deserialize_with` helper for `tool` string fields.
+pub fn deserialize_tool_name<'de, D: Deserializer<'de>>(d: D) -> Result<String, D::Error> {
+    String::deserialize(d).map(|s| normalize_tool_name(&s))
+}
+
+/// serde `deserialize_with` helper for optional `tool` string fields.
+pub fn deserialize_optional_tool_name<'de, D: Deserializer<'de>>(
+    d: D,
+) -> Result<Option<String>, D::Error> {
+    Option::<String>::deserialize(d).map(|s| s.map(|s| normalize_tool_name(&s)))
+}
+
 /// Given a command string (e.g. `"claude --resume xyz"` or `"open-code"`),
 /// return the canonical agent name if one is recognised.
 pub fn resolve_tool_name(cmd: &str) -> Option<&'static str> {
@@ -154,6 +243,41 @@ pub fn resolve_tool_name(cmd: &str) -> Option<&'static str> {
     None
 }
 
+/// Guess the agent a project is set up for from marker files in `dir`
+/// (e.g. `CLAUDE.md`, `.opencode`, `GEMINI.md`). The first agent in
+/// registry order with a marker present wins.
+pub fn detect_tool_from_dir(dir: &Path) -> Option<Tool> {
+    AGENTS
+        .iter()
+        .find(|a| a.project_markers.iter().any(|m| dir.join(m).exists()))
+        .map(|a| Tool(a.name))
+}
+
+/// Resume ID of the agent's most recent conversation in `project_path`, for
+/// agents whose conversations can be located on disk. Claude stores each
+/// conversation as `<id>.jsonl` under `projects/<encoded project path>/` in
+/// its config directory; the newest file wins.
+pub fn latest_resume_id(tool: &str, project_path: &Path) -> Option<String> {
+    if tool != "claude" {
+        return None;
+    }
+    let config_dir = crate::session::get_claude_config_dir()
+        .or_else(|| dirs::home_dir().map(|h| h.join(".claude")))?;
+    let encoded: String = project_path
+        .to_string_lossy()
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+        .collect();
+    std::fs::read_dir(config_dir.join("projects").join(encoded))
+        .ok()?
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| p.extension().is_some_and(|ext| ext == "jsonl"))
+        .filter_map(|p| Some((p.metadata().ok()?.modified().ok()?, p)))
+        .max_by_key(|(modified, _)| *modified)
+        .and_then(|(_, p)| Some(p.file_stem()?.to_string_lossy().into_owned()))
+}
+
 /// Convert a tool name to a 1-based settings index (0 = Auto).
 pub fn settings_index_from_name(name: Option<&str>) -> usize {
     match name {
@@ -179,6 +303,28 @@ pub fn name_from_settings_index(index: usize) -> Option<&'static str> {
 mod tests {
     use super::*;
 
+    #[test]
+    #[serial_test::serial]
+    fn test_latest_resume_id_picks_newest_claude_conversation() {
+        let temp = tempfile::TempDir::new().unwrap();
+        crate::session::setup_test_home(temp.path());
+        let project = Path::new("/work/my.repo");
+        assert_eq!(latest_resume_id("claude", project), None);
+
+        let dir = temp.path().join(".claude/projects/-work-my-repo");
+        std::fs::create_dir_all(&dir).unwrap();
+        let old = std::fs::File::create(dir.join("older.jsonl")).unwrap();
+        old.set_modified(std::time::SystemTime::UNIX_EPOCH).unwrap();
+        std::fs::write(dir.join("newer.jsonl"), "").unwrap();
+        std::fs::write(dir.join("notes.txt"), "").unwrap();
+
+        assert_eq!(
+            latest_resume_id("claude", project).as_deref(),
+            Some("newer")
+        );
+        assert_eq!(latest_resume_id("opencode", project), None);
+    }
+
     #[test]
     fn test_get_agent_known() {
         assert_eq!(get_agent("claude").unwrap().binary, "claude");
@@ -187,6 +333,40 @@ mod tests {
         assert_eq!(get_agent("codex").unwrap().binary, "codex");
         assert_eq!(get_agent("gemini").unwrap().binary, "gemini");
         assert_eq!(get_agent("cursor").unwrap().binary, "agent");
+        assert_eq!(get_agent("crush").unwrap().binary, "crush");
+    }
+
+    #[test]
+    fn test_tool_from_str_normalizes_case_and_aliases() {
+        assert_eq!("claude".parse::<Tool>().unwrap().as_str(), "claude");
+        assert_eq!(" Claude ".parse::<Tool>().unwrap().as_str(), "claude");
+        assert_eq!("open-code".parse::<Tool>().unwrap().as_str(), "opencode");
+        assert_eq!("OpenCode".parse::<Tool>().unwrap().as_str(), "opencode");
+        assert_eq!("mistral-vibe".parse::<Tool>().unwrap().as_str(), "vibe");
+        assert_eq!("agent".parse::<Tool>().unwrap().as_str(), "cursor");
+        assert_eq!("cursor".parse::<Tool>().unwrap().def().binary, "agent");
+    }
+
+    #[test]
+    fn test_tool_from_str_rejects_unknown() {
+        let err = "claud".parse::<Tool>().unwrap_err();
+        assert!(err.to_string().contains("Unknown tool: claud"));
+        assert!(err.to_string().contains("opencode"));
+        assert!("".parse::<Tool>().is_err());
+        assert!("claude --resume".parse::<Tool>().is_err());
+    }
+
+    #[test]
+    fn test_deserialize_normalizes_known_tools() {
+        #[derive(Deserialize)]
+        struct Stored {
+            #[serde(deserialize_with = "deserialize_tool_name")]
+            tool: String,
+        }
+        let stored: Stored = serde_json::from_str(r#"{"tool":"Open-Code"}"#).unwrap();
+        assert_eq!(stored.tool, "opencode");
+        let stored: Stored = serde_json::from_str(r#"{"tool":"my-agent"}"#).unwrap();
+        assert_eq!(stored.tool, "my-agent");
     }
 
     #[test]
@@ -199,7 +379,7 @@ mod tests {
         let names = agent_names();
         assert_eq!(
             names,
-            vec!["claude", "opencode", "vibe", "codex", "gemini", "cursor"]
+            vec!["claude", "opencode", "vibe", "codex", "gemini", "cursor", "crush"]
         );
     }
 
@@ -213,6 +393,7 @@ mod tests {
         assert_eq!(resolve_tool_name("cursor"), Some("cursor"));
         assert_eq!(resolve_tool_name(""), Some("claude"));
         assert_eq!(resolve_tool_name("agent"), Some("cursor"));
+        assert_eq!(resolve_tool_name("crush --yolo"), Some("crush"));
         assert_eq!(resolve_tool_name("unknown-tool"), None);
     }
 
@@ -222,11 +403,13 @@ mod tests {
         assert_eq!(settings_index_from_name(Some("claude")), 1);
         assert_eq!(settings_index_from_name(Some("gemini")), 5);
         assert_eq!(settings_index_from_name(Some("cursor")), 6);
+        assert_eq!(settings_index_from_name(Some("crush")), 7);
 
         assert_eq!(name_from_settings_index(0), None);
         assert_eq!(name_from_settings_index(1), Some("claude"));
         assert_eq!(name_from_settings_index(5), Some("gemini"));
         assert_eq!(name_from_settings_index(6), Some("cursor"));
+        assert_eq!(name_from_settings_index(7), Some("crush"));
         assert_eq!(name_from_settings_index(99), None);
     }
 
@@ -240,4 +423,33 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_detect_tool_from_dir_markers() {
+        let cases = [
+            ("CLAUDE.md", "claude"),
+            (".opencode", "opencode"),
+            ("GEMINI.md", "gemini"),
+            (".gemini", "gemini"),
+            (".cursorrules", "cursor"),
+            ("CRUSH.md", "crush"),
+        ];
+        for (marker, expected) in cases {
+            let dir = tempfile::tempdir().unwrap();
+            std::fs::write(dir.path().join(marker), "").unwrap();
+            assert_eq!(
+                detect_tool_from_dir(dir.path()).map(|t| t.as_str()),
+                Some(expected),
+                "marker {}",
+                marker
+            );
+        }
+    }
+
+    #[test]
+    fn test_detect_tool_from_dir_without_markers() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("README.md"), "").unwrap();
+        assert_eq!(detect_tool_from_dir(dir.path()), None);
+    }
 }
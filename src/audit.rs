@@ -0,0 +1,164 @@
+//! Append-only audit trail of session actions.
+//!
+//! Each event is one JSON object per line in `audit.jsonl` under the app
+//! directory:
+//!   - Linux: ~/.config/agent-of-empires/audit.jsonl
+//!   - macOS: ~/.agent-of-empires/audit.jsonl
+//!
+//! Once the log would grow past `MAX_AUDIT_BYTES` it is renamed to
+//! `audit.jsonl.1`, replacing any earlier rotation, and a new log is started.
+//!
+//! Recording is best effort: failures are logged and never interrupt the
+//! action being recorded.
+
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::session::{get_app_dir, Instance, Status};
+
+const AUDIT_FILE: &str = "audit.jsonl";
+const MAX_AUDIT_BYTES: u64 = 5 * 1024 * 1024;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum AuditAction {
+    Created,
+    Removed,
+    Started,
+    Stopped,
+    Restarted,
+    StatusChanged { from: Status, to: Status },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditEvent {
+    pub timestamp: DateTime<Utc>,
+    pub profile: String,
+    pub session_id: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub title: String,
+    #[serde(flatten)]
+    pub action: AuditAction,
+}
+
+impl AuditEvent {
+    pub fn new(profile: &str, instance: &Instance, action: AuditAction) -> Self {
+        Self {
+            timestamp: Utc::now(),
+            profile: profile.to_string(),
+            session_id: instance.id.clone(),
+            title: instance.title.clone(),
+            action,
+        }
+    }
+}
+
+pub fn audit_log_path() -> Result<PathBuf> {
+    Ok(get_app_dir()?.join(AUDIT_FILE))
+}
+
+/// Append an event to the audit log.
+pub fn record(event: AuditEvent) {
+    if let Err(e) = append(&event) {
+        tracing::warn!("Failed to write audit event: {}", e);
+    }
+}
+
+fn append(event: &AuditEvent) -> Result<()> {
+    let mut line = serde_json::to_string(event)?;
+    line.push('\n');
+    append_line(&audit_log_path()?, &line, MAX_AUDIT_BYTES)
+}
+
+fn append_line(path: &Path, line: &str, max_bytes: u64) -> Result<()> {
+    if fs::metadata(path).is_ok_and(|m| m.len() + line.len() as u64 > max_bytes) {
+        let mut rotated = path.as_os_str().to_owned();
+        rotated.push(".1");
+        fs::rename(path, rotated)?;
+    }
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    file.write_all(line.as_bytes())?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::session::{setup_test_home, SessionManager};
+    use serial_test::serial;
+
+    fn read_events() -> Vec<AuditEvent> {
+        std::fs::read_to_string(audit_log_path().unwrap())
+            .unwrap_or_default()
+            .lines()
+            .map(|l| serde_json::from_str(l).expect("audit line should parse"))
+            .collect()
+    }
+
+    #[test]
+    #[serial]
+    fn test_create_and_remove_append_two_lines() {
+        let temp = tempfile::tempdir().unwrap();
+        setup_test_home(temp.path());
+
+        let manager = SessionManager::new("audit-test").unwrap();
+        let added = manager
+            .add(Instance::new("audited", "/tmp/audited"))
+            .unwrap();
+        manager.remove(&added.id).unwrap();
+
+        let events = read_events();
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].action, AuditAction::Created);
+        assert_eq!(events[1].action, AuditAction::Removed);
+        for event in &events {
+            assert_eq!(event.profile, "audit-test");
+            assert_eq!(event.session_id, added.id);
+        }
+    }
+
+    #[test]
+    fn test_log_rotates_past_size_limit() {
+        let temp = tempfile::tempdir().unwrap();
+        let path = temp.path().join(AUDIT_FILE);
+        let line = "x".repeat(39) + "\n";
+
+        for _ in 0..2 {
+            append_line(&path, &line, 100).unwrap();
+        }
+        assert!(!temp.path().join("audit.jsonl.1").exists());
+        assert_eq!(fs::read_to_string(&path).unwrap().len(), 80);
+
+        append_line(&path, &line, 100).unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), line);
+        assert_eq!(
+            fs::read_to_string(temp.path().join("audit.jsonl.1"))
+                .unwrap()
+                .len(),
+            80
+        );
+    }
+
+    #[test]
+    fn test_status_change_serializes_flat() {
+        let inst = Instance::new("t", "/tmp/t");
+        let event = AuditEvent::new(
+            "default",
+            &inst,
+            AuditAction::StatusChanged {
+                from: Status::Running,
+                to: Status::Waiting,
+            },
+        );
+        let json: serde_json::Value = serde_json::to_value(&event).unwrap();
+        assert_eq!(json["action"], "status_changed");
+        assert_eq!(json["from"], "running");
+        assert_eq!(json["to"], "waiting");
+        assert_eq!(json["session_id"], inst.id);
+    }
+}
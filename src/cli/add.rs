@@ -1,14 +1,24 @@
 //! `agent-of-empires add` command implementation
 
-use anyhow::{bail, Result};
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, Utc};
 use clap::Args;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
+use std::sync::mpsc;
 
+use crate::audit::{self, AuditAction, AuditEvent};
+use crate::cli::output::{errln, infoln, ProgressIndicator};
+use crate::containers::error::DockerError;
 use crate::containers::{self, ContainerRuntimeInterface};
-use crate::session::repo_config;
-use crate::session::{civilizations, Config, GroupTree, Instance, SandboxInfo, Storage};
+use crate::session::repo_config::{self, HookProgress};
+use crate::session::{
+    civilizations, normalize_group_path, Config, GroupTree, Instance, SandboxInfo, SessionError,
+    SessionTemplate, Storage,
+};
 
-#[derive(Args)]
+#[derive(Args, Clone)]
 pub struct AddArgs {
     /// Project directory (defaults to current directory)
     #[arg(default_value = ".")]
@@ -22,8 +32,9 @@ pub struct AddArgs {
     #[arg(short = 'g', long)]
     group: Option<String>,
 
-    /// Command to run (e.g., 'claude', 'opencode', 'vibe', 'codex', 'gemini', 'cursor')
-    #[arg(short = 'c', long = "cmd")]
+    /// Command to run (e.g., 'claude', 'opencode', 'vibe', 'codex', 'gemini', 'cursor', 'crush'),
+    /// or 'auto' to pick the agent from marker files such as CLAUDE.md or .opencode
+    #[arg(short = 'c', long = "cmd", visible_alias = "tool")]
     command: Option<String>,
 
     /// Parent session (creates sub-session, inherits group)
@@ -46,10 +57,32 @@ pub struct AddArgs {
     #[arg(short = 's', long)]
     sandbox: bool,
 
+    /// Run directly on the host even if sandboxing is enabled by default in config
+    #[arg(long = "no-sandbox", conflicts_with_all = ["sandbox", "sandbox_image"])]
+    no_sandbox: bool,
+
     /// Custom Docker image for sandbox (implies --sandbox)
     #[arg(long = "sandbox-image")]
     sandbox_image: Option<String>,
 
+    /// CPU limit for the sandbox container (e.g. 2, 1.5)
+    #[arg(long, value_parser = parse_cpu_limit)]
+    cpus: Option<String>,
+
+    /// Memory limit for the sandbox container (e.g. 512m, 4g)
+    #[arg(long, value_parser = parse_memory_limit)]
+    memory: Option<String>,
+
+    /// Directory the agent runs in inside the container, relative to the
+    /// project mount (e.g. "frontend") or absolute. Defaults to the mount.
+    #[arg(long = "sandbox-workdir", value_name = "DIR")]
+    sandbox_workdir: Option<String>,
+
+    /// Keep build directories detected from the project type (node_modules,
+    /// target, .venv, ...) off the bind mount as anonymous volumes
+    #[arg(long = "auto-anon-volumes")]
+    auto_anon_volumes: bool,
+
     /// Enable YOLO mode (skip permission prompts)
     #[arg(short = 'y', long)]
     yolo: bool,
@@ -57,9 +90,498 @@ pub struct AddArgs {
     /// Automatically trust repository hooks without prompting
     #[arg(long = "trust-hooks")]
     trust_hooks: bool,
+
+    /// Command run once in the session's directory (or container) after
+    /// creation, before the agent starts, e.g. "npm install"
+    #[arg(long = "setup", value_name = "CMD")]
+    setup_command: Option<String>,
+
+    /// tmux option to set on the session after creation (repeatable)
+    #[arg(long = "tmux-option", value_name = "NAME=VALUE", value_parser = parse_tmux_option)]
+    tmux_options: Vec<(String, String)>,
+
+    /// Position when the group is started with `session start --group`
+    /// (lower starts first)
+    #[arg(long = "start-order", value_name = "N", allow_negative_numbers = true)]
+    start_order: Option<i32>,
+
+    /// Copy these variables from the current shell into the session's
+    /// environment (comma-separated, e.g. AWS_PROFILE,API_URL)
+    #[arg(long = "inherit-env", value_name = "VARS", value_delimiter = ',')]
+    inherit_env: Vec<String>,
+
+    /// Start from the defaults of a `[templates.<NAME>]` config entry; flags
+    /// given here take precedence
+    #[arg(long, value_name = "NAME")]
+    template: Option<String>,
+
+    /// Create every session defined in a TOML file instead of a single one
+    #[arg(
+        long,
+        value_name = "FILE",
+        conflicts_with_all = ["title", "group", "command", "parent", "launch", "worktree_branch", "create_branch", "template"]
+    )]
+    from: Option<PathBuf>,
+
+    /// Print creation and hook progress as JSON lines on stdout instead of
+    /// human-readable text. Untrusted repository hooks are an error unless
+    /// --trust-hooks is given
+    #[arg(long = "progress-json", conflicts_with = "from")]
+    progress_json: bool,
+}
+
+/// Steps of session creation, reported as text or with `--progress-json`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "event", content = "label", rename_all = "snake_case")]
+enum CreationProgress {
+    /// A step is starting
+    Step(String),
+    /// A step finished successfully
+    Done(String),
+    /// The session was not created; the label says why
+    Skipped(String),
+    /// The session was saved; the label is its ID
+    Created(String),
+}
+
+/// How `aoe add` reports progress.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Progress {
+    Human,
+    Json,
+}
+
+impl Progress {
+    fn creation(self, event: CreationProgress) {
+        match self {
+            Progress::Human => match &event {
+                CreationProgress::Step(label) | CreationProgress::Skipped(label) => {
+                    infoln!("{}", label)
+                }
+                CreationProgress::Done(label) => infoln!("✓ {}", label),
+                CreationProgress::Created(_) => {}
+            },
+            Progress::Json => println!("{}", progress_json_line("creation", &event, Utc::now())),
+        }
+    }
+
+    fn hook(self, event: &HookProgress) {
+        match self {
+            Progress::Human => {
+                if let HookProgress::Output(line) = event {
+                    infoln!("  {}", line);
+                }
+            }
+            Progress::Json => println!("{}", progress_json_line("hook", event, Utc::now())),
+        }
+    }
+
+    /// Run `f` with a sender whose hook events are reported as they arrive.
+    fn stream_hooks(self, f: impl FnOnce(&mpsc::Sender<HookProgress>) -> Result<()>) -> Result<()> {
+        let (tx, rx) = mpsc::channel();
+        let printer = std::thread::spawn(move || {
+            for event in rx {
+                self.hook(&event);
+            }
+        });
+        let result = f(&tx);
+        drop(tx);
+        let _ = printer.join();
+        result
+    }
+}
+
+/// One `--progress-json` line: the event's fields plus where it came from
+/// and when.
+fn progress_json_line(source: &str, event: &impl Serialize, timestamp: DateTime<Utc>) -> String {
+    #[derive(Serialize)]
+    struct Line<'a, E: Serialize> {
+        source: &'a str,
+        #[serde(flatten)]
+        event: &'a E,
+        timestamp: DateTime<Utc>,
+    }
+    serde_json::to_string(&Line {
+        source,
+        event,
+        timestamp,
+    })
+    .expect("progress events serialize")
+}
+
+/// One `[[session]]` entry in a file passed to `aoe add --from`.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SessionSpec {
+    pub path: PathBuf,
+    #[serde(default)]
+    pub title: Option<String>,
+    #[serde(default)]
+    pub group: Option<String>,
+    #[serde(default)]
+    pub tool: Option<String>,
+    /// Overrides `sandbox.enabled_by_default` when set.
+    #[serde(default)]
+    pub sandbox: Option<bool>,
+    #[serde(default)]
+    pub sandbox_image: Option<String>,
+    #[serde(default)]
+    pub sandbox_workdir: Option<String>,
+    #[serde(default)]
+    pub cpus: Option<String>,
+    #[serde(default)]
+    pub memory: Option<String>,
+    #[serde(default)]
+    pub yolo: bool,
+    #[serde(default)]
+    pub tmux_options: HashMap<String, String>,
+    #[serde(default)]
+    pub setup_command: Option<String>,
+    #[serde(default)]
+    pub start_order: Option<i32>,
+    #[serde(default)]
+    pub inherit_env: Vec<String>,
+    #[serde(default)]
+    pub auto_anon_volumes: bool,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SessionFile {
+    #[serde(default)]
+    session: Vec<SessionSpec>,
+}
+
+/// Parse a sessions file. Relative paths are resolved against `base_dir` and a
+/// leading `~/` against the home directory.
+pub fn parse_session_specs(content: &str, base_dir: &Path) -> Result<Vec<SessionSpec>> {
+    let file: SessionFile = toml::from_str(content)?;
+    Ok(file
+        .session
+        .into_iter()
+        .map(|mut spec| {
+            spec.path = resolve_spec_path(&spec.path, base_dir);
+            spec
+        })
+        .collect())
+}
+
+fn resolve_spec_path(path: &Path, base_dir: &Path) -> PathBuf {
+    if let Ok(rest) = path.strip_prefix("~") {
+        if let Some(home) = dirs::home_dir() {
+            return home.join(rest);
+        }
+    }
+    if path.is_relative() {
+        base_dir.join(path)
+    } else {
+        path.to_path_buf()
+    }
+}
+
+impl AddArgs {
+    /// Fill in options not given on the command line from `template`.
+    fn with_template(&self, template: &SessionTemplate) -> AddArgs {
+        let mut args = self.clone();
+        if args.command.is_none() {
+            args.command = template.tool.clone();
+        }
+        if args.group.is_none() && args.parent.is_none() {
+            args.group = template.group.clone();
+        }
+        if !args.sandbox && !args.no_sandbox && args.sandbox_image.is_none() {
+            match template.sandbox {
+                Some(true) => args.sandbox = true,
+                Some(false) => args.no_sandbox = true,
+                None => {}
+            }
+        }
+        if args.sandbox_image.is_none() && !args.no_sandbox {
+            args.sandbox_image = template.sandbox_image.clone();
+        }
+        args
+    }
+
+    /// Reject options `aoe create` cannot honor: it makes one session, always
+    /// launches it and never attaches.
+    pub(crate) fn check_headless(&self) -> Result<()> {
+        let unsupported = [
+            (self.from.is_some(), "--from"),
+            (self.launch, "--launch"),
+            (self.progress_json, "--progress-json"),
+        ];
+        if let Some((_, flag)) = unsupported.iter().find(|(set, _)| *set) {
+            return Err(
+                SessionError::Invalid(format!("{} is not supported by aoe create", flag)).into(),
+            );
+        }
+        Ok(())
+    }
+}
+
+impl SessionSpec {
+    fn label(&self) -> String {
+        self.title
+            .clone()
+            .unwrap_or_else(|| self.path.display().to_string())
+    }
+
+    fn to_args(&self, trust_hooks: bool) -> Result<AddArgs> {
+        let cpus = self
+            .cpus
+            .as_deref()
+            .map(parse_cpu_limit)
+            .transpose()
+            .map_err(anyhow::Error::msg)?;
+        let memory = self
+            .memory
+            .as_deref()
+            .map(parse_memory_limit)
+            .transpose()
+            .map_err(anyhow::Error::msg)?;
+        Ok(AddArgs {
+            path: self.path.clone(),
+            title: self.title.clone(),
+            group: self.group.clone(),
+            command: self.tool.clone(),
+            parent: None,
+            launch: false,
+            worktree_branch: None,
+            create_branch: false,
+            sandbox: self.sandbox == Some(true),
+            no_sandbox: self.sandbox == Some(false),
+            sandbox_image: self.sandbox_image.clone(),
+            cpus,
+            memory,
+            sandbox_workdir: self.sandbox_workdir.clone(),
+            auto_anon_volumes: self.auto_anon_volumes,
+            yolo: self.yolo,
+            trust_hooks,
+            setup_command: self.setup_command.clone(),
+            tmux_options: self.tmux_options.clone().into_iter().collect(),
+            start_order: self.start_order,
+            inherit_env: self.inherit_env.clone(),
+            template: None,
+            from: None,
+            progress_json: false,
+        })
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum BatchOutcome {
+    Added(String),
+    Skipped,
+    Failed(String),
+}
+
+/// Create each spec with `create`, collecting per-entry outcomes. A failing
+/// entry never stops the rest of the batch.
+fn run_batch(
+    specs: &[SessionSpec],
+    trust_hooks: bool,
+    mut create: impl FnMut(&AddArgs) -> Result<Option<Instance>>,
+) -> Vec<(String, BatchOutcome)> {
+    specs
+        .iter()
+        .map(|spec| {
+            let outcome = match spec.to_args(trust_hooks).and_then(|a| create(&a)) {
+                Ok(Some(instance)) => BatchOutcome::Added(instance.id),
+                Ok(None) => BatchOutcome::Skipped,
+                Err(e) => BatchOutcome::Failed(format!("{:#}", e)),
+            };
+            (spec.label(), outcome)
+        })
+        .collect()
+}
+
+fn run_from_file(profile: &str, file: &Path, args: &AddArgs) -> Result<()> {
+    let content = std::fs::read_to_string(file)
+        .with_context(|| format!("Failed to read {}", file.display()))?;
+    let base_dir = file
+        .parent()
+        .filter(|p| !p.as_os_str().is_empty())
+        .unwrap_or(Path::new("."));
+    let specs = parse_session_specs(&content, base_dir)
+        .with_context(|| format!("Invalid sessions file {}", file.display()))?;
+    if specs.is_empty() {
+        bail!("No [[session]] entries found in {}", file.display());
+    }
+
+    let results = run_batch(&specs, args.trust_hooks, |a| create_session(profile, a));
+
+    infoln!();
+    let mut added = 0;
+    let mut failed = 0;
+    for (label, outcome) in &results {
+        match outcome {
+            BatchOutcome::Added(id) => {
+                added += 1;
+                infoln!("✓ {} ({})", label, super::truncate_id(id, 8));
+            }
+            BatchOutcome::Skipped => infoln!("• {}: already exists, skipped", label),
+            BatchOutcome::Failed(e) => {
+                failed += 1;
+                errln!("✗ {}: {}", label, e);
+            }
+        }
+    }
+    infoln!("Added {} of {} sessions", added, results.len());
+    if failed > 0 {
+        bail!("{} session(s) failed", failed);
+    }
+    Ok(())
+}
+
+/// Whether this session should be sandboxed. `--no-sandbox` always wins, then
+/// an explicit `--sandbox`/`--sandbox-image`, then the config default.
+fn wants_sandbox(args: &AddArgs, enabled_by_default: bool) -> bool {
+    if args.no_sandbox {
+        return false;
+    }
+    args.sandbox || args.sandbox_image.is_some() || enabled_by_default
+}
+
+fn parse_cpu_limit(value: &str) -> std::result::Result<String, String> {
+    match value.trim().parse::<f64>() {
+        Ok(cpus) if cpus > 0.0 && cpus.is_finite() => Ok(value.trim().to_string()),
+        _ => Err("CPU limit must be a positive number (e.g. 2, 1.5)".to_string()),
+    }
+}
+
+/// Env values for a sandbox, which interpolates them: template values may
+/// refer to `$VAR` on purpose, but values captured from the shell are kept
+/// literal by escaping `$` as `$$`.
+fn sandbox_env_values(
+    template_env: &HashMap<String, String>,
+    inherited: &HashMap<String, String>,
+) -> HashMap<String, String> {
+    let mut values = template_env.clone();
+    values.extend(
+        inherited
+            .iter()
+            .map(|(key, val)| (key.clone(), val.replace('$', "$$"))),
+    );
+    values
+}
+
+/// Values of `keys` from `lookup`, plus the keys that were not set.
+fn capture_env(
+    keys: &[String],
+    lookup: impl Fn(&str) -> Option<String>,
+) -> (HashMap<String, String>, Vec<String>) {
+    let mut captured = HashMap::new();
+    let mut missing = Vec::new();
+    for key in keys.iter().map(|k| k.trim()).filter(|k| !k.is_empty()) {
+        match lookup(key) {
+            Some(val) => {
+                captured.insert(key.to_string(), val);
+            }
+            None => missing.push(key.to_string()),
+        }
+    }
+    (captured, missing)
+}
+
+fn parse_tmux_option(value: &str) -> std::result::Result<(String, String), String> {
+    match value.split_once('=') {
+        Some((name, v)) if !name.trim().is_empty() => Ok((name.trim().to_string(), v.to_string())),
+        _ => Err("tmux option must be NAME=VALUE (e.g. history-limit=50000)".to_string()),
+    }
+}
+
+fn parse_memory_limit(value: &str) -> std::result::Result<String, String> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Err("Memory limit cannot be empty".to_string());
+    }
+    crate::session::validate_memory_limit(value)?;
+    Ok(value.to_string())
 }
 
 pub async fn run(profile: &str, args: AddArgs) -> Result<()> {
+    if let Some(file) = &args.from {
+        return run_from_file(profile, file, &args);
+    }
+
+    let Some(instance) = create_session(profile, &args)? else {
+        return Ok(());
+    };
+    if args.progress_json {
+        Progress::Json.creation(CreationProgress::Created(instance.id.clone()));
+        return Ok(());
+    }
+
+    infoln!("✓ Added session: {}", instance.title);
+    infoln!("  Profile: {}", instance.profile);
+    infoln!("  Path:    {}", instance.project_path);
+    infoln!("  Group:   {}", instance.group_path);
+    infoln!("  ID:      {}", instance.id);
+    if let Some(cmd) = &args.command {
+        infoln!("  Cmd:     {}", cmd);
+    }
+    if let Some(parent) = &args.parent {
+        infoln!("  Parent:  {}", parent);
+    }
+    if let Some(sandbox) = &instance.sandbox_info {
+        infoln!("  Sandbox: enabled");
+        if let Some(ignores) = &sandbox.volume_ignores {
+            infoln!("  Volumes: {} (anonymous)", ignores.join(", "));
+        }
+    }
+    if instance.yolo_mode {
+        infoln!("  YOLO:    enabled");
+    }
+
+    if args.launch {
+        let storage = Storage::new(profile)?;
+        let (mut instances, groups) = storage.load_with_groups()?;
+        let idx = instances
+            .iter()
+            .position(|i| i.id == instance.id)
+            .expect("just added instance");
+        super::wait_until_sandbox_ready(&mut instances[idx])?;
+        instances[idx].start_with_size(crate::terminal::get_size())?;
+        let group_tree = GroupTree::new_with_groups(&instances, &groups);
+        storage.save_with_groups(&instances, &group_tree)?;
+        audit::record(AuditEvent::new(
+            storage.profile(),
+            &instances[idx],
+            AuditAction::Started,
+        ));
+
+        let tmux_session = instance.tmux_session()?;
+        tmux_session.attach(false)?;
+    } else {
+        infoln!();
+        infoln!("Next steps:");
+        infoln!(
+            "  agent-of-empires session start {}   # Start the session",
+            instance.title
+        );
+        infoln!("  agent-of-empires                         # Open TUI and press Enter to attach");
+    }
+
+    Ok(())
+}
+
+/// Create and persist one session from `args`. Returns None when a session
+/// with the same title and path already exists.
+pub(crate) fn create_session(profile: &str, args: &AddArgs) -> Result<Option<Instance>> {
+    let storage = Storage::new(profile)?;
+    let template = match &args.template {
+        Some(name) => resolve_template(storage.profile(), name)?,
+        None => SessionTemplate::default(),
+    };
+    let templated = args.with_template(&template);
+    let args = &templated;
+
+    let progress = if args.progress_json {
+        Progress::Json
+    } else {
+        Progress::Human
+    };
+    let _indicator = (progress == Progress::Human).then(ProgressIndicator::start);
     let mut path = if args.path.as_os_str() == "." {
         std::env::current_dir()?
     } else {
@@ -67,7 +589,9 @@ pub async fn run(profile: &str, args: AddArgs) -> Result<()> {
     };
 
     if !path.is_dir() {
-        bail!("Path is not a directory: {}", path.display());
+        return Err(
+            SessionError::Invalid(format!("Path is not a directory: {}", path.display())).into(),
+        );
     }
 
     let mut worktree_info_opt = None;
@@ -80,7 +604,11 @@ pub async fn run(profile: &str, args: AddArgs) -> Result<()> {
         let branch = branch_raw.trim();
 
         if !GitWorktree::is_git_repo(&path) {
-            bail!("Path is not in a git repository\nTip: Navigate to a git repository first");
+            return Err(SessionError::Invalid(
+                "Path is not in a git repository\nTip: Navigate to a git repository first"
+                    .to_string(),
+            )
+            .into());
         }
 
         let config = Config::load()?;
@@ -89,7 +617,7 @@ pub async fn run(profile: &str, args: AddArgs) -> Result<()> {
         let git_wt = GitWorktree::new(main_repo_path.clone())?;
 
         let session_id = uuid::Uuid::new_v4().to_string();
-        let session_id_short = &session_id[..8];
+        let session_id_short = super::truncate_id(&session_id, 8);
 
         // Choose appropriate template based on repo type (bare vs regular)
         // Use main_repo_path (not path) to correctly detect bare repos when running from a worktree
@@ -108,7 +636,10 @@ pub async fn run(profile: &str, args: AddArgs) -> Result<()> {
             );
         }
 
-        println!("Creating worktree at: {}", worktree_path.display());
+        progress.creation(CreationProgress::Step(format!(
+            "Creating worktree at: {}",
+            worktree_path.display()
+        )));
         git_wt.create_worktree(branch, &worktree_path, args.create_branch)?;
 
         path = worktree_path;
@@ -121,10 +652,11 @@ pub async fn run(profile: &str, args: AddArgs) -> Result<()> {
             cleanup_on_delete: true,
         });
 
-        println!("✓ Worktree created successfully");
+        progress.creation(CreationProgress::Done(
+            "Worktree created successfully".to_string(),
+        ));
     }
 
-    let storage = Storage::new(profile)?;
     let (mut instances, groups) = storage.load_with_groups()?;
 
     // Resolve parent session if specified
@@ -132,7 +664,10 @@ pub async fn run(profile: &str, args: AddArgs) -> Result<()> {
     let parent_id = if let Some(parent_ref) = &args.parent {
         let parent = super::resolve_session(parent_ref, &instances)?;
         if parent.is_sub_session() {
-            bail!("Cannot create sub-session of a sub-session (single level only)");
+            return Err(SessionError::Invalid(
+                "Cannot create sub-session of a sub-session (single level only)".to_string(),
+            )
+            .into());
         }
         group_path = Some(parent.group_path.clone());
         Some(parent.id.clone())
@@ -144,11 +679,11 @@ pub async fn run(profile: &str, args: AddArgs) -> Result<()> {
     let final_title = if let Some(title) = &args.title {
         let trimmed_title = title.trim();
         if is_duplicate_session(&instances, trimmed_title, path.to_str().unwrap_or("")) {
-            println!(
+            progress.creation(CreationProgress::Skipped(format!(
                 "Session already exists with same title and path: {}",
                 trimmed_title
-            );
-            return Ok(());
+            )));
+            return Ok(None);
         }
         trimmed_title.to_string()
     } else {
@@ -159,14 +694,23 @@ pub async fn run(profile: &str, args: AddArgs) -> Result<()> {
     let mut instance = Instance::new(&final_title, path.to_str().unwrap_or(""));
 
     if let Some(group) = &group_path {
-        instance.group_path = group.trim().to_string();
+        instance.group_path = normalize_group_path(group);
     }
 
     if let Some(parent) = parent_id {
         instance.parent_session_id = Some(parent);
     }
 
-    if let Some(cmd) = &args.command {
+    if args.command.as_deref().is_some_and(is_auto_tool) {
+        let default_tool = crate::session::resolve_config(storage.profile())
+            .ok()
+            .and_then(|c| c.session.default_tool);
+        instance.tool = auto_tool(&path, default_tool.as_deref());
+        progress.creation(CreationProgress::Done(format!(
+            "Detected tool: {}",
+            instance.tool
+        )));
+    } else if let Some(cmd) = &args.command {
         let tool_name = detect_tool(cmd)?;
         instance.tool = tool_name;
         // Only store a custom command when the user passed extra args
@@ -182,29 +726,71 @@ pub async fn run(profile: &str, args: AddArgs) -> Result<()> {
     }
 
     instance.yolo_mode = args.yolo;
+    instance.tmux_options = args.tmux_options.iter().cloned().collect();
+    instance.start_order = args.start_order;
+    instance.setup_command = args
+        .setup_command
+        .as_deref()
+        .map(str::trim)
+        .filter(|c| !c.is_empty())
+        .map(String::from);
+    instance.profile = storage.profile().to_string();
+
+    let (inherited, missing) = capture_env(&args.inherit_env, |key| std::env::var(key).ok());
+    if !missing.is_empty() {
+        errln!(
+            "Warning: --inherit-env: not set in this shell, skipping: {}",
+            missing.join(", ")
+        );
+    }
+    instance.env = template.env.clone();
+    instance.env.extend(inherited.clone());
 
     // Handle sandbox setup
     let use_sandbox = args.sandbox || args.sandbox_image.is_some();
     let config = Config::load()?;
 
     let runtime = containers::get_container_runtime();
-    if use_sandbox || config.sandbox.enabled_by_default {
+    if wants_sandbox(args, config.sandbox.enabled_by_default) {
         if !runtime.is_available() {
             if use_sandbox {
-                bail!(
+                return Err(DockerError::RuntimeUnavailable(
                     "Container runtime is not installed or not accessible.\n\
                      Install Docker: https://docs.docker.com/get-docker/\n\
                      Or on macOS: Apple Container\n\
                      Tip: Use 'aoe add' without --sandbox to run directly on host"
-                );
+                        .to_string(),
+                )
+                .into());
             }
         } else {
-            let container_name = containers::DockerContainer::generate_name(&instance.id);
-            let image = args
-                .sandbox_image
-                .as_ref()
-                .map(|s| s.trim().to_string())
-                .unwrap_or_else(|| runtime.effective_default_image());
+            let scheme = crate::session::resolve_config(storage.profile())
+                .map(|c| c.sandbox.container_name_scheme)
+                .unwrap_or_default();
+            let container_name = containers::container_name_for(
+                scheme,
+                storage.profile(),
+                &instance.id,
+                &instance.title,
+            );
+            let image = match &args.sandbox_image {
+                Some(image) => image.trim().to_string(),
+                None => crate::session::resolve_config(storage.profile())
+                    .map(|c| c.sandbox.default_image_for_tool(&instance.tool).to_string())
+                    .unwrap_or_else(|_| runtime.effective_default_image()),
+            };
+            let volume_ignores = if args.auto_anon_volumes {
+                let configured = crate::session::resolve_config(storage.profile())
+                    .map(|c| c.sandbox.volume_ignores)
+                    .unwrap_or_default();
+                let suggested: Vec<String> = crate::session::suggest_volume_ignores(&path)
+                    .into_iter()
+                    .filter(|p| !configured.contains(p))
+                    .collect();
+                (!suggested.is_empty()).then_some(suggested)
+            } else {
+                None
+            };
             instance.sandbox_info = Some(SandboxInfo {
                 enabled: true,
                 container_id: None,
@@ -216,28 +802,65 @@ pub async fn run(profile: &str, args: AddArgs) -> Result<()> {
                 custom_instruction: Config::load()
                     .ok()
                     .and_then(|c| c.sandbox.custom_instruction),
+                cpu_limit: args.cpus.clone(),
+                memory_limit: args.memory.clone(),
+                config_hash: None,
+                workdir: args
+                    .sandbox_workdir
+                    .as_deref()
+                    .map(str::trim)
+                    .filter(|w| !w.is_empty())
+                    .map(String::from),
+                volume_ignores,
+                extra_volumes: (!template.volumes.is_empty()).then(|| template.volumes.clone()),
             });
         }
     }
 
+    if let Some(sandbox) = instance.sandbox_info.as_mut() {
+        if !instance.env.is_empty() {
+            instance.env.clear();
+            sandbox.extra_env_values = Some(sandbox_env_values(&template.env, &inherited));
+        }
+    }
+
+    if instance.sandbox_info.is_none() && (args.cpus.is_some() || args.memory.is_some()) {
+        errln!("Warning: --cpus and --memory only apply to sandboxed sessions; ignoring");
+    }
+    if instance.sandbox_info.is_none() && args.sandbox_workdir.is_some() {
+        errln!("Warning: --sandbox-workdir only applies to sandboxed sessions; ignoring");
+    }
+    if instance.sandbox_info.is_none() && args.auto_anon_volumes {
+        errln!("Warning: --auto-anon-volumes only applies to sandboxed sessions; ignoring");
+    }
+    if instance.sandbox_info.is_none() && !template.volumes.is_empty() {
+        errln!("Warning: template volumes only apply to sandboxed sessions; ignoring");
+    }
+
     // Check for repository hooks
     let hook_result: Result<()> = (|| {
         match repo_config::check_hook_trust(&path) {
             Ok(repo_config::HookTrustStatus::NeedsTrust { hooks, hooks_hash }) => {
                 let should_trust = if args.trust_hooks {
                     true
+                } else if progress == Progress::Json {
+                    // Stdout carries only JSON lines, so there is nowhere to ask
+                    bail!(
+                        "Repository hooks in .aoe/config.toml need approval; \
+                         pass --trust-hooks to run them with --progress-json"
+                    );
                 } else {
-                    println!("\nRepository hooks detected in .aoe/config.toml:");
+                    infoln!("\nRepository hooks detected in .aoe/config.toml:");
                     if !hooks.on_create.is_empty() {
-                        println!("  on_create:");
+                        infoln!("  on_create:");
                         for cmd in &hooks.on_create {
-                            println!("    {}", cmd);
+                            infoln!("    {}", cmd);
                         }
                     }
                     if !hooks.on_launch.is_empty() {
-                        println!("  on_launch:");
+                        infoln!("  on_launch:");
                         for cmd in &hooks.on_launch {
-                            println!("    {}", cmd);
+                            infoln!("    {}", cmd);
                         }
                     }
                     print!("\nTrust and run these hooks? [y/N] ");
@@ -249,17 +872,19 @@ pub async fn run(profile: &str, args: AddArgs) -> Result<()> {
                 };
 
                 if should_trust {
-                    trust_and_run_on_create(&path, &hooks_hash, &hooks)?;
+                    repo_config::trust_repo(&path, &hooks_hash)?;
+                    progress.creation(CreationProgress::Done(
+                        "Repository hooks trusted".to_string(),
+                    ));
+                    run_on_create(&hooks, &path, progress)?;
                 } else {
-                    println!("Hooks skipped (session created without running hooks)");
+                    progress.creation(CreationProgress::Skipped(
+                        "Hooks skipped (session created without running hooks)".to_string(),
+                    ));
                 }
             }
             Ok(repo_config::HookTrustStatus::Trusted(hooks)) => {
-                if !hooks.on_create.is_empty() {
-                    println!("Running on_create hooks...");
-                    repo_config::execute_hooks(&hooks.on_create, &path)?;
-                    println!("✓ on_create hooks completed");
-                }
+                run_on_create(&hooks, &path, progress)?;
             }
             Ok(repo_config::HookTrustStatus::NoHooks) => {}
             Err(e) => {
@@ -269,7 +894,15 @@ pub async fn run(profile: &str, args: AddArgs) -> Result<()> {
         Ok(())
     })();
 
+    let hook_result = hook_result.and_then(|()| run_setup_command(&mut instance, progress));
+
     if let Err(e) = hook_result {
+        if instance.is_sandboxed() {
+            let container = instance.container();
+            if container.exists().unwrap_or(false) {
+                let _ = container.remove(true);
+            }
+        }
         // Clean up worktree if we created one
         if let Some(ref wt_info) = instance.worktree_info {
             if wt_info.managed_by_aoe {
@@ -292,46 +925,13 @@ pub async fn run(profile: &str, args: AddArgs) -> Result<()> {
     }
 
     storage.save_with_groups(&instances, &group_tree)?;
+    audit::record(AuditEvent::new(
+        storage.profile(),
+        &instance,
+        AuditAction::Created,
+    ));
 
-    println!("✓ Added session: {}", final_title);
-    println!("  Profile: {}", storage.profile());
-    println!("  Path:    {}", path.display());
-    println!("  Group:   {}", instance.group_path);
-    println!("  ID:      {}", instance.id);
-    if let Some(cmd) = &args.command {
-        println!("  Cmd:     {}", cmd);
-    }
-    if let Some(parent) = &args.parent {
-        println!("  Parent:  {}", parent);
-    }
-    if instance.sandbox_info.is_some() {
-        println!("  Sandbox: enabled");
-    }
-    if instance.yolo_mode {
-        println!("  YOLO:    enabled");
-    }
-
-    if args.launch {
-        let idx = instances
-            .iter()
-            .position(|i| i.id == instance.id)
-            .expect("just added instance");
-        instances[idx].start_with_size(crate::terminal::get_size())?;
-        storage.save_with_groups(&instances, &group_tree)?;
-
-        let tmux_session = crate::tmux::Session::new(&instance.id, &instance.title)?;
-        tmux_session.attach()?;
-    } else {
-        println!();
-        println!("Next steps:");
-        println!(
-            "  agent-of-empires session start {}   # Start the session",
-            final_title
-        );
-        println!("  agent-of-empires                         # Open TUI and press Enter to attach");
-    }
-
-    Ok(())
+    Ok(Some(instance))
 }
 
 pub fn is_duplicate_session(instances: &[Instance], title: &str, path: &str) -> bool {
@@ -342,31 +942,480 @@ pub fn is_duplicate_session(instances: &[Instance], title: &str, path: &str) ->
     })
 }
 
-fn trust_and_run_on_create(
-    project_path: &Path,
-    hooks_hash: &str,
+fn run_on_create(
     hooks: &crate::session::HooksConfig,
+    project_path: &Path,
+    progress: Progress,
 ) -> Result<()> {
-    repo_config::trust_repo(project_path, hooks_hash)?;
-    println!("✓ Repository hooks trusted");
-    if !hooks.on_create.is_empty() {
-        println!("Running on_create hooks...");
-        repo_config::execute_hooks(&hooks.on_create, project_path)?;
-        println!("✓ on_create hooks completed");
+    if hooks.on_create.is_empty() {
+        return Ok(());
+    }
+    progress.creation(CreationProgress::Step(
+        "Running on_create hooks...".to_string(),
+    ));
+    match progress {
+        Progress::Human => repo_config::execute_hooks(&hooks.on_create, project_path)?,
+        Progress::Json => progress.stream_hooks(|tx| {
+            repo_config::execute_hooks_streamed(&hooks.on_create, project_path, tx)
+        })?,
     }
+    progress.creation(CreationProgress::Done(
+        "on_create hooks completed".to_string(),
+    ));
     Ok(())
 }
 
+fn run_setup_command(instance: &mut Instance, progress: Progress) -> Result<()> {
+    let Some(cmd) = instance.setup_command.clone() else {
+        return Ok(());
+    };
+    progress.creation(CreationProgress::Step(format!(
+        "Running setup command: {}",
+        cmd
+    )));
+    progress.stream_hooks(|tx| instance.run_setup_command(tx))?;
+    progress.creation(CreationProgress::Done(
+        "Setup command completed".to_string(),
+    ));
+    Ok(())
+}
+
+fn is_auto_tool(cmd: &str) -> bool {
+    cmd.trim().eq_ignore_ascii_case("auto")
+}
+
+/// Tool for `--cmd auto`: the agent the project's marker files point to,
+/// else the configured default, else claude.
+fn auto_tool(dir: &Path, default_tool: Option<&str>) -> String {
+    crate::agents::detect_tool_from_dir(dir)
+        .map(|t| t.as_str())
+        .or(default_tool)
+        .unwrap_or("claude")
+        .to_string()
+}
+
+/// Look up a `[templates.<name>]` entry in the profile's effective config.
+fn resolve_template(profile: &str, name: &str) -> Result<SessionTemplate> {
+    let mut templates = crate::session::resolve_config(profile)?.templates;
+    templates.remove(name).ok_or_else(|| {
+        let mut known: Vec<String> = templates.into_keys().collect();
+        known.sort();
+        let known = if known.is_empty() {
+            "none defined".to_string()
+        } else {
+            known.join(", ")
+        };
+        SessionError::Invalid(format!(
+            "Unknown template: {}\nAvailable templates: {}",
+            name, known
+        ))
+        .into()
+    })
+}
+
 fn detect_tool(cmd: &str) -> Result<String> {
+    if let Ok(tool) = cmd.parse::<crate::agents::Tool>() {
+        return Ok(tool.as_str().to_string());
+    }
     crate::agents::resolve_tool_name(cmd)
         .map(|name| name.to_string())
         .ok_or_else(|| {
-            anyhow::anyhow!(
+            SessionError::Invalid(format!(
                 "Unknown tool in command: {}\n\
                  Supported tools: {}\n\
                  Tip: Command must contain one of the supported tool names",
                 cmd,
                 crate::agents::agent_names().join(", ")
-            )
+            ))
+            .into()
         })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::{Cli, Commands};
+    use clap::Parser;
+
+    fn parse_add(args: &[&str]) -> std::result::Result<AddArgs, clap::Error> {
+        let mut argv = vec!["aoe", "add"];
+        argv.extend_from_slice(args);
+        match Cli::try_parse_from(argv)?.command {
+            Some(Commands::Add(add)) => Ok(*add),
+            _ => panic!("expected add command"),
+        }
+    }
+
+    #[test]
+    fn test_resource_limit_flags() {
+        let args = parse_add(&[".", "--sandbox", "--cpus", "1.5", "--memory", "4g"]).unwrap();
+        assert_eq!(args.cpus.as_deref(), Some("1.5"));
+        assert_eq!(args.memory.as_deref(), Some("4g"));
+
+        let args = parse_add(&["."]).unwrap();
+        assert!(args.cpus.is_none());
+        assert!(args.memory.is_none());
+    }
+
+    #[test]
+    fn test_sandbox_env_escapes_only_inherited_values() {
+        let template_env = HashMap::from([
+            ("CACHE".to_string(), "$HOME/.cache".to_string()),
+            ("TOKEN".to_string(), "from-template".to_string()),
+        ]);
+        let inherited = HashMap::from([("TOKEN".to_string(), "pa$$word".to_string())]);
+
+        let values = sandbox_env_values(&template_env, &inherited);
+        assert_eq!(values["CACHE"], "$HOME/.cache");
+        assert_eq!(values["TOKEN"], "pa$$$$word");
+    }
+
+    #[test]
+    fn test_inherit_env_captures_and_reports_missing() {
+        let args = parse_add(&[".", "--inherit-env", "AWS_PROFILE,API_URL,UNSET_VAR"]).unwrap();
+        let lookup = |key: &str| match key {
+            "AWS_PROFILE" => Some("dev".to_string()),
+            "API_URL" => Some("http://localhost:8080".to_string()),
+            _ => None,
+        };
+
+        let (captured, missing) = capture_env(&args.inherit_env, lookup);
+        assert_eq!(captured.len(), 2);
+        assert_eq!(captured["AWS_PROFILE"], "dev");
+        assert_eq!(captured["API_URL"], "http://localhost:8080");
+        assert_eq!(missing, vec!["UNSET_VAR".to_string()]);
+    }
+
+    #[test]
+    fn test_no_sandbox_overrides_config_default() {
+        let args = parse_add(&[".", "--no-sandbox"]).unwrap();
+        assert!(!wants_sandbox(&args, true));
+
+        let args = parse_add(&["."]).unwrap();
+        assert!(wants_sandbox(&args, true));
+        assert!(!wants_sandbox(&args, false));
+
+        let args = parse_add(&[".", "--sandbox"]).unwrap();
+        assert!(wants_sandbox(&args, false));
+    }
+
+    #[test]
+    fn test_no_sandbox_conflicts_with_sandbox_flags() {
+        assert!(parse_add(&[".", "--no-sandbox", "--sandbox"]).is_err());
+        assert!(parse_add(&[".", "--no-sandbox", "--sandbox-image", "img"]).is_err());
+    }
+
+    #[test]
+    fn test_memory_flag_rejects_garbage() {
+        assert!(parse_add(&[".", "--memory", "lots"]).is_err());
+        assert!(parse_add(&[".", "--memory", "512mb"]).is_err());
+        assert!(parse_add(&[".", "--memory", ""]).is_err());
+        assert_eq!(parse_memory_limit("512m"), Ok("512m".to_string()));
+    }
+
+    #[test]
+    fn test_tmux_option_flags() {
+        let args = parse_add(&[
+            ".",
+            "--tmux-option",
+            "history-limit=50000",
+            "--tmux-option",
+            "mouse=on",
+        ])
+        .unwrap();
+        assert_eq!(
+            args.tmux_options,
+            [
+                ("history-limit".to_string(), "50000".to_string()),
+                ("mouse".to_string(), "on".to_string())
+            ]
+        );
+        assert!(parse_add(&[".", "--tmux-option", "mouse"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_session_specs() {
+        let content = r#"
+            [[session]]
+            title = "api"
+            path = "services/api"
+            group = "work/backend"
+            tool = "claude"
+
+            [[session]]
+            path = "/srv/web"
+            sandbox = true
+            sandbox_image = "node:20"
+            memory = "4g"
+            yolo = true
+        "#;
+        let specs = parse_session_specs(content, Path::new("/repo")).unwrap();
+        assert_eq!(specs.len(), 2);
+        assert_eq!(specs[0].title.as_deref(), Some("api"));
+        assert_eq!(specs[0].path, PathBuf::from("/repo/services/api"));
+        assert_eq!(specs[0].group.as_deref(), Some("work/backend"));
+        assert_eq!(specs[0].tool.as_deref(), Some("claude"));
+        assert_eq!(specs[0].sandbox, None);
+
+        assert_eq!(specs[1].path, PathBuf::from("/srv/web"));
+        assert_eq!(specs[1].sandbox, Some(true));
+        assert_eq!(specs[1].sandbox_image.as_deref(), Some("node:20"));
+        assert!(specs[1].yolo);
+    }
+
+    #[test]
+    fn test_parse_session_specs_rejects_unknown_fields() {
+        let content = "[[session]]\npath = \".\"\ntitel = \"typo\"\n";
+        assert!(parse_session_specs(content, Path::new("/")).is_err());
+    }
+
+    #[test]
+    fn test_spec_sandbox_maps_to_flags() {
+        let spec = SessionSpec {
+            path: PathBuf::from("/p"),
+            sandbox: Some(false),
+            ..Default::default()
+        };
+        let args = spec.to_args(false).unwrap();
+        assert!(args.no_sandbox);
+        assert!(!wants_sandbox(&args, true));
+
+        let spec = SessionSpec {
+            path: PathBuf::from("/p"),
+            ..Default::default()
+        };
+        assert!(wants_sandbox(&spec.to_args(false).unwrap(), true));
+    }
+
+    #[test]
+    fn test_batch_reports_partial_failure() {
+        let spec = |title: &str, path: &str| SessionSpec {
+            path: PathBuf::from(path),
+            title: Some(title.to_string()),
+            ..Default::default()
+        };
+        let specs = vec![
+            spec("ok", "/p/ok"),
+            spec("broken", "/p/missing"),
+            spec("dup", "/p/dup"),
+            SessionSpec {
+                memory: Some("lots".to_string()),
+                ..spec("bad-limit", "/p/ok2")
+            },
+            spec("after", "/p/after"),
+        ];
+
+        let mut attempted = Vec::new();
+        let results = run_batch(&specs, true, |args| {
+            attempted.push(args.path.clone());
+            assert!(args.trust_hooks);
+            match args.path.to_str().unwrap() {
+                "/p/missing" => bail!("Path is not a directory: /p/missing"),
+                "/p/dup" => Ok(None),
+                path => {
+                    let mut inst = Instance::new(args.title.as_deref().unwrap(), path);
+                    inst.id = format!("id-{}", inst.title);
+                    Ok(Some(inst))
+                }
+            }
+        });
+
+        // The invalid memory limit fails before creation; everything else is attempted
+        assert_eq!(attempted.len(), 4);
+        assert_eq!(
+            results,
+            vec![
+                ("ok".to_string(), BatchOutcome::Added("id-ok".to_string())),
+                (
+                    "broken".to_string(),
+                    BatchOutcome::Failed("Path is not a directory: /p/missing".to_string())
+                ),
+                ("dup".to_string(), BatchOutcome::Skipped),
+                (
+                    "bad-limit".to_string(),
+                    BatchOutcome::Failed(parse_memory_limit("lots").unwrap_err())
+                ),
+                (
+                    "after".to_string(),
+                    BatchOutcome::Added("id-after".to_string())
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_from_conflicts_with_single_session_flags() {
+        assert!(parse_add(&["--from", "s.toml"]).is_ok());
+        assert!(parse_add(&["--from", "s.toml", "--title", "x"]).is_err());
+        assert!(parse_add(&["--from", "s.toml", "--launch"]).is_err());
+    }
+
+    #[test]
+    fn test_cpus_flag_rejects_invalid_values() {
+        assert!(parse_add(&[".", "--cpus", "0"]).is_err());
+        assert!(parse_add(&[".", "--cpus", "-1"]).is_err());
+        assert!(parse_add(&[".", "--cpus", "many"]).is_err());
+        assert_eq!(parse_cpu_limit("2"), Ok("2".to_string()));
+    }
+
+    #[test]
+    fn test_progress_events_serialize_to_jsonl() {
+        let at: DateTime<Utc> = "2025-01-01T00:00:00Z".parse().unwrap();
+        let lines = [
+            progress_json_line(
+                "creation",
+                &CreationProgress::Step("Running setup command: make".into()),
+                at,
+            ),
+            progress_json_line("hook", &HookProgress::Started("make".into()), at),
+            progress_json_line("hook", &HookProgress::Output("built".into()), at),
+            progress_json_line(
+                "creation",
+                &CreationProgress::Created("0123456789abcdef".into()),
+                at,
+            ),
+        ];
+        assert_eq!(
+            lines.join("\n"),
+            [
+                r#"{"source":"creation","event":"step","label":"Running setup command: make","timestamp":"2025-01-01T00:00:00Z"}"#,
+                r#"{"source":"hook","event":"started","line":"make","timestamp":"2025-01-01T00:00:00Z"}"#,
+                r#"{"source":"hook","event":"output","line":"built","timestamp":"2025-01-01T00:00:00Z"}"#,
+                r#"{"source":"creation","event":"created","label":"0123456789abcdef","timestamp":"2025-01-01T00:00:00Z"}"#,
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn test_auto_tool_uses_markers_then_default() {
+        let dir = tempfile::tempdir().unwrap();
+        assert_eq!(auto_tool(dir.path(), None), "claude");
+        assert_eq!(auto_tool(dir.path(), Some("codex")), "codex");
+
+        std::fs::create_dir(dir.path().join(".opencode")).unwrap();
+        assert_eq!(auto_tool(dir.path(), Some("codex")), "opencode");
+        assert!(is_auto_tool(" Auto "));
+    }
+
+    fn backend_template() -> SessionTemplate {
+        SessionTemplate {
+            tool: Some("codex".to_string()),
+            group: Some("work/backend".to_string()),
+            sandbox: None,
+            sandbox_image: Some("rust:latest".to_string()),
+            env: HashMap::from([("RUST_LOG".to_string(), "debug".to_string())]),
+            volumes: vec!["/host/cache:/cache".to_string()],
+        }
+    }
+
+    #[test]
+    fn test_template_fills_unset_flags_only() {
+        let template = backend_template();
+
+        let args = parse_add(&[".", "--template", "backend"])
+            .unwrap()
+            .with_template(&template);
+        assert_eq!(args.command.as_deref(), Some("codex"));
+        assert_eq!(args.group.as_deref(), Some("work/backend"));
+        assert_eq!(args.sandbox_image.as_deref(), Some("rust:latest"));
+
+        let args = parse_add(&[
+            ".",
+            "--template",
+            "backend",
+            "--tool",
+            "claude",
+            "-g",
+            "personal",
+            "--sandbox-image",
+            "node:20",
+        ])
+        .unwrap()
+        .with_template(&template);
+        assert_eq!(args.command.as_deref(), Some("claude"));
+        assert_eq!(args.group.as_deref(), Some("personal"));
+        assert_eq!(args.sandbox_image.as_deref(), Some("node:20"));
+
+        let args = parse_add(&[".", "--template", "backend", "--no-sandbox"])
+            .unwrap()
+            .with_template(&template);
+        assert!(args.sandbox_image.is_none());
+        assert!(!wants_sandbox(&args, true));
+    }
+
+    #[test]
+    fn test_template_sandbox_setting() {
+        let off = SessionTemplate {
+            sandbox: Some(false),
+            sandbox_image: Some("rust:latest".to_string()),
+            ..Default::default()
+        };
+        let args = parse_add(&["."]).unwrap().with_template(&off);
+        assert!(!wants_sandbox(&args, true));
+        assert!(args.sandbox_image.is_none());
+
+        let args = parse_add(&[".", "--sandbox"]).unwrap().with_template(&off);
+        assert!(wants_sandbox(&args, false));
+
+        let on = SessionTemplate {
+            sandbox: Some(true),
+            ..Default::default()
+        };
+        let args = parse_add(&["."]).unwrap().with_template(&on);
+        assert!(wants_sandbox(&args, false));
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_create_session_applies_template() {
+        let home = tempfile::tempdir().unwrap();
+        std::env::set_var("HOME", home.path());
+        #[cfg(target_os = "linux")]
+        std::env::set_var("XDG_CONFIG_HOME", home.path().join(".config"));
+
+        let mut config = Config::default();
+        config.templates.insert(
+            "backend".to_string(),
+            SessionTemplate {
+                sandbox: Some(false),
+                sandbox_image: None,
+                volumes: Vec::new(),
+                ..backend_template()
+            },
+        );
+        crate::session::save_config(&config).unwrap();
+
+        let project = home.path().join("api");
+        std::fs::create_dir(&project).unwrap();
+        let path = project.to_str().unwrap();
+
+        let args = parse_add(&[path, "-t", "api", "--template", "backend"]).unwrap();
+        let instance = create_session("default", &args).unwrap().unwrap();
+        assert_eq!(instance.tool, "codex");
+        assert_eq!(instance.group_path, "work/backend");
+        assert!(instance.sandbox_info.is_none());
+        assert_eq!(instance.env["RUST_LOG"], "debug");
+
+        let args = parse_add(&[
+            path,
+            "-t",
+            "api-claude",
+            "--template",
+            "backend",
+            "--tool",
+            "claude",
+            "-g",
+            "personal",
+        ])
+        .unwrap();
+        let instance = create_session("default", &args).unwrap().unwrap();
+        assert_eq!(instance.tool, "claude");
+        assert_eq!(instance.group_path, "personal");
+
+        let args = parse_add(&[path, "--template", "frontend"]).unwrap();
+        let err = create_session("default", &args).unwrap_err().to_string();
+        assert!(err.contains("Unknown template: frontend"));
+        assert!(err.contains("backend"));
+    }
+}
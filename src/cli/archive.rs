@@ -0,0 +1,67 @@
+//! `agent-of-empires archive` / `unarchive` command implementation
+
+use anyhow::Result;
+use clap::Args;
+
+use crate::cli::output::infoln;
+use crate::session::{GroupTree, Storage};
+
+#[derive(Args)]
+pub struct ArchiveArgs {
+    /// Session ID or title
+    identifier: String,
+}
+
+pub async fn run(profile: &str, args: ArchiveArgs) -> Result<()> {
+    let storage = Storage::new(profile)?;
+    let (mut instances, groups) = storage.load_with_groups()?;
+
+    let id = super::resolve_session(&args.identifier, &instances)?
+        .id
+        .clone();
+    let inst = instances
+        .iter_mut()
+        .find(|i| i.id == id)
+        .expect("resolved session must exist");
+
+    if inst.archived {
+        infoln!("Session is already archived: {}", inst.title);
+        return Ok(());
+    }
+
+    inst.archive()?;
+    let title = inst.title.clone();
+
+    let group_tree = GroupTree::new_with_groups(&instances, &groups);
+    storage.save_with_groups(&instances, &group_tree)?;
+
+    infoln!("✓ Archived session: {}", title);
+    Ok(())
+}
+
+pub async fn run_unarchive(profile: &str, args: ArchiveArgs) -> Result<()> {
+    let storage = Storage::new(profile)?;
+    let (mut instances, groups) = storage.load_with_groups()?;
+
+    let id = super::resolve_session(&args.identifier, &instances)?
+        .id
+        .clone();
+    let inst = instances
+        .iter_mut()
+        .find(|i| i.id == id)
+        .expect("resolved session must exist");
+
+    if !inst.archived {
+        infoln!("Session is not archived: {}", inst.title);
+        return Ok(());
+    }
+
+    inst.unarchive();
+    let title = inst.title.clone();
+
+    let group_tree = GroupTree::new_with_groups(&instances, &groups);
+    storage.save_with_groups(&instances, &group_tree)?;
+
+    infoln!("✓ Unarchived session: {}", title);
+    Ok(())
+}
@@ -0,0 +1,69 @@
+//! `agent-of-empires config` subcommands implementation
+
+use anyhow::Result;
+use clap::Subcommand;
+
+use crate::session::{resolve_config_with_repo, Config};
+
+#[derive(Subcommand)]
+pub enum ConfigCommands {
+    /// Print the effective configuration (defaults, config file, profile and
+    /// repo overrides merged)
+    Show {
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+pub async fn run(profile: &str, command: ConfigCommands) -> Result<()> {
+    match command {
+        ConfigCommands::Show { json } => show(profile, json),
+    }
+}
+
+fn show(profile: &str, json: bool) -> Result<()> {
+    let cwd = std::env::current_dir()?;
+    let config = resolve_config_with_repo(profile, &cwd)?;
+    print!("{}", render(&config, json)?);
+    Ok(())
+}
+
+fn render(config: &Config, json: bool) -> Result<String> {
+    if json {
+        Ok(format!("{}\n", serde_json::to_string_pretty(config)?))
+    } else {
+        Ok(toml::to_string_pretty(config)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_show_json_round_trips() {
+        let mut config = Config::default();
+        config.session.auto_nudge_seconds = 90;
+        config.sandbox.read_only_root = true;
+        config.sandbox.cpu_limit = Some("2".to_string());
+
+        let rendered = render(&config, true).unwrap();
+        let parsed: Config = serde_json::from_str(&rendered).unwrap();
+
+        assert_eq!(
+            serde_json::to_value(&parsed).unwrap(),
+            serde_json::to_value(&config).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_show_toml_parses_as_config() {
+        let rendered = render(&Config::default(), false).unwrap();
+        let parsed: Config = toml::from_str(&rendered).unwrap();
+        assert_eq!(
+            serde_json::to_value(&parsed).unwrap(),
+            serde_json::to_value(Config::default()).unwrap()
+        );
+    }
+}
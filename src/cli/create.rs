@@ -0,0 +1,199 @@
+//! `agent-of-empires create` command implementation
+
+use anyhow::Result;
+
+use crate::audit::{self, AuditAction, AuditEvent};
+use crate::cli::add::{self, AddArgs};
+use crate::cli::output::infoln;
+use crate::session::{GroupTree, Instance, SessionError, Storage};
+
+/// Provisioning stages, run in this order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Stage {
+    Create,
+    Container,
+    Launch,
+}
+
+impl Stage {
+    fn label(self) -> &'static str {
+        match self {
+            Stage::Create => "Creating session",
+            Stage::Container => "Starting sandbox container",
+            Stage::Launch => "Launching agent",
+        }
+    }
+}
+
+/// The work behind each stage, split out so the ordering can be tested
+/// without tmux or a container runtime.
+trait Provision {
+    /// Create and persist the session, returning its ID.
+    fn create(&mut self) -> Result<String>;
+    fn sandboxed(&self) -> bool;
+    fn start_container(&mut self) -> Result<()>;
+    fn launch(&mut self) -> Result<()>;
+}
+
+/// Run every stage in order, stopping at the first failure. The container
+/// stage only runs for sandboxed sessions.
+fn provision(steps: &mut impl Provision, mut report: impl FnMut(Stage)) -> Result<String> {
+    report(Stage::Create);
+    let id = steps.create()?;
+    if steps.sandboxed() {
+        report(Stage::Container);
+        steps.start_container()?;
+    }
+    report(Stage::Launch);
+    steps.launch()?;
+    Ok(id)
+}
+
+struct Headless<'a> {
+    profile: &'a str,
+    args: &'a AddArgs,
+    instance: Option<Instance>,
+}
+
+impl Headless<'_> {
+    fn instance(&mut self) -> &mut Instance {
+        self.instance.as_mut().expect("session created")
+    }
+
+    fn save(&self) -> Result<()> {
+        let Some(instance) = &self.instance else {
+            return Ok(());
+        };
+        let storage = Storage::new(self.profile)?;
+        let (mut instances, groups) = storage.load_with_groups()?;
+        if let Some(slot) = instances.iter_mut().find(|i| i.id == instance.id) {
+            *slot = instance.clone();
+        }
+        let group_tree = GroupTree::new_with_groups(&instances, &groups);
+        storage.save_with_groups(&instances, &group_tree)
+    }
+}
+
+impl Provision for Headless<'_> {
+    fn create(&mut self) -> Result<String> {
+        let instance = add::create_session(self.profile, self.args)?.ok_or_else(|| {
+            SessionError::Invalid("A session with this title and path already exists".to_string())
+        })?;
+        let id = instance.id.clone();
+        self.instance = Some(instance);
+        Ok(id)
+    }
+
+    fn sandboxed(&self) -> bool {
+        self.instance.as_ref().is_some_and(|i| i.is_sandboxed())
+    }
+
+    fn start_container(&mut self) -> Result<()> {
+        self.instance().get_container_for_instance()?;
+        self.save()
+    }
+
+    fn launch(&mut self) -> Result<()> {
+        super::wait_until_sandbox_ready(self.instance())?;
+        self.instance()
+            .start_with_size(crate::terminal::get_size())?;
+        audit::record(AuditEvent::new(
+            self.profile,
+            self.instance(),
+            AuditAction::Started,
+        ));
+        self.save()
+    }
+}
+
+pub async fn run(profile: &str, args: AddArgs) -> Result<()> {
+    args.check_headless()?;
+
+    let mut steps = Headless {
+        profile,
+        args: &args,
+        instance: None,
+    };
+    let id = provision(&mut steps, |stage| infoln!("{}...", stage.label()))?;
+
+    let title = steps.instance().title.clone();
+    if crate::cli::output::is_quiet() {
+        println!("{}", id);
+    } else {
+        infoln!("✓ Session running: {}", title);
+        infoln!("  ID: {}", id);
+        infoln!("Attach with: aoe session attach {}", id);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default)]
+    struct Stub {
+        sandboxed: bool,
+        fail_container: bool,
+        calls: Vec<&'static str>,
+    }
+
+    impl Provision for Stub {
+        fn create(&mut self) -> Result<String> {
+            self.calls.push("create");
+            Ok("abc123".to_string())
+        }
+
+        fn sandboxed(&self) -> bool {
+            self.sandboxed
+        }
+
+        fn start_container(&mut self) -> Result<()> {
+            self.calls.push("container");
+            if self.fail_container {
+                anyhow::bail!("no runtime");
+            }
+            Ok(())
+        }
+
+        fn launch(&mut self) -> Result<()> {
+            self.calls.push("launch");
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_provision_runs_stages_in_order() {
+        let mut stub = Stub {
+            sandboxed: true,
+            ..Default::default()
+        };
+        let mut reported = Vec::new();
+        let id = provision(&mut stub, |stage| reported.push(stage)).unwrap();
+
+        assert_eq!(id, "abc123");
+        assert_eq!(stub.calls, vec!["create", "container", "launch"]);
+        assert_eq!(
+            reported,
+            vec![Stage::Create, Stage::Container, Stage::Launch]
+        );
+    }
+
+    #[test]
+    fn test_provision_skips_container_for_host_sessions() {
+        let mut stub = Stub::default();
+        provision(&mut stub, |_| {}).unwrap();
+        assert_eq!(stub.calls, vec!["create", "launch"]);
+    }
+
+    #[test]
+    fn test_provision_stops_at_first_failure() {
+        let mut stub = Stub {
+            sandboxed: true,
+            fail_container: true,
+            ..Default::default()
+        };
+        assert!(provision(&mut stub, |_| {}).is_err());
+        assert_eq!(stub.calls, vec!["create", "container"]);
+    }
+}
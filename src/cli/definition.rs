@@ -7,16 +7,26 @@ use clap::{Parser, Subcommand};
 use clap_complete::Shell;
 
 use super::add::AddArgs;
+use super::archive::ArchiveArgs;
+use super::config::ConfigCommands;
+use super::dump::DumpArgs;
 use super::group::GroupCommands;
+use super::import_tmux::ImportTmuxArgs;
 use super::init::InitArgs;
+use super::interrupt::InterruptArgs;
+use super::kill::KillArgs;
 use super::list::ListArgs;
 use super::profile::ProfileCommands;
+use super::prune::PruneArgs;
 use super::remove::RemoveArgs;
-use super::session::SessionCommands;
+#[cfg(feature = "serve")]
+use super::serve::ServeArgs;
+use super::session::{AttachArgs, SessionCommands, StartArgs};
 use super::sounds::SoundsCommands;
 use super::status::StatusArgs;
 use super::tmux::TmuxCommands;
 use super::uninstall::UninstallArgs;
+use super::watch::WatchArgs;
 use super::worktree::WorktreeCommands;
 
 const VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -30,11 +40,45 @@ const VERSION: &str = env!("CARGO_PKG_VERSION");
     you manage and monitor AI coding agents like Claude Code and OpenCode.\n\n\
     Run without arguments to launch the TUI dashboard."
 )]
+#[command(after_long_help = "Exit codes:\n  \
+    0  success\n  \
+    1  other error\n  \
+    2  session, group, profile or container not found\n  \
+    3  container runtime unavailable\n  \
+    4  tmux unavailable\n  \
+    5  invalid arguments or input")]
 pub struct Cli {
     /// Profile to use (separate workspace with its own sessions)
     #[arg(short = 'p', long, global = true, env = "AGENT_OF_EMPIRES_PROFILE")]
     pub profile: Option<String>,
 
+    /// Read and write this config file instead of the default location
+    #[arg(long, global = true, env = "AOE_CONFIG", value_name = "PATH")]
+    pub config: Option<std::path::PathBuf>,
+
+    /// Print only errors and requested data; `status` and `session current`
+    /// print just their value
+    #[arg(short = 'q', long, global = true)]
+    pub quiet: bool,
+
+    /// Disable colors and decorative glyphs in output (also honors NO_COLOR)
+    #[arg(long, global = true)]
+    pub no_color: bool,
+
+    /// On failure, print a single JSON object (`{"error", "kind", "code"}`)
+    /// to stderr instead of free-form text
+    #[arg(long, global = true)]
+    pub json_errors: bool,
+
+    /// Write diagnostic logs to this file (the TUI otherwise logs to aoe.log
+    /// in the app directory when logging is enabled)
+    #[arg(long, global = true, value_name = "PATH")]
+    pub log_file: Option<std::path::PathBuf>,
+
+    /// Log level for diagnostic logs (overrides RUST_LOG)
+    #[arg(long, global = true, value_parser = crate::cli::logging::LEVELS)]
+    pub log_level: Option<String>,
+
     #[command(subcommand)]
     pub command: Option<Commands>,
 }
@@ -42,11 +86,18 @@ pub struct Cli {
 #[derive(Subcommand)]
 pub enum Commands {
     /// Add a new session
-    Add(AddArgs),
+    Add(Box<AddArgs>),
+
+    /// Create a session and start it (sandbox container and agent) without
+    /// attaching; prints the session ID
+    Create(Box<AddArgs>),
 
     /// Initialize .aoe/config.toml in a repository
     Init(InitArgs),
 
+    /// Adopt existing tmux sessions as aoe sessions
+    ImportTmux(ImportTmuxArgs),
+
     /// List all sessions
     #[command(alias = "ls")]
     List(ListArgs),
@@ -55,9 +106,35 @@ pub enum Commands {
     #[command(alias = "rm")]
     Remove(RemoveArgs),
 
+    /// Archive a session (stops it but keeps the record)
+    Archive(ArchiveArgs),
+
+    /// Restore an archived session
+    Unarchive(ArchiveArgs),
+
     /// Show session status summary
     Status(StatusArgs),
 
+    /// Redraw a compact session status table until interrupted
+    Watch(WatchArgs),
+
+    /// Start a session, or a whole group with --group (same as `session start`)
+    Start(StartArgs),
+
+    /// Attach to a session (same as `session attach`)
+    Attach(AttachArgs),
+
+    /// Send Ctrl-C to a session without attaching
+    Interrupt(InterruptArgs),
+
+    /// Kill a session's tmux session but keep it for relaunching later. A
+    /// sandbox container is left running
+    Kill(KillArgs),
+
+    /// Save a session's pane contents, with a metadata header, to a file or
+    /// stdout
+    Dump(DumpArgs),
+
     /// Manage session lifecycle (start, stop, attach, etc.)
     Session {
         #[command(subcommand)]
@@ -76,6 +153,12 @@ pub enum Commands {
         command: Option<ProfileCommands>,
     },
 
+    /// Inspect the effective configuration
+    Config {
+        #[command(subcommand)]
+        command: ConfigCommands,
+    },
+
     /// Manage git worktrees for parallel development
     Worktree {
         #[command(subcommand)]
@@ -94,6 +177,17 @@ pub enum Commands {
         command: SoundsCommands,
     },
 
+    /// Check the environment for common setup problems
+    Doctor,
+
+    /// Remove sandbox containers that no session in any profile owns
+    PruneContainers(PruneArgs),
+
+    /// Serve a read-only JSON API of sessions over HTTP (localhost by default).
+    /// Only in builds with `--features serve`
+    #[cfg(feature = "serve")]
+    Serve(ServeArgs),
+
     /// Uninstall Agent of Empires
     Uninstall(UninstallArgs),
 
@@ -0,0 +1,272 @@
+//! `agent-of-empires doctor` command implementation
+
+use std::path::PathBuf;
+use std::process::Command;
+
+use anyhow::{bail, Result};
+
+use crate::cli::output::{infoln, outln};
+use crate::containers::{self, ContainerRuntimeInterface};
+use crate::session::get_app_dir;
+use crate::tmux::AvailableTools;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Outcome {
+    Pass,
+    Warn,
+    Fail,
+}
+
+#[derive(Debug)]
+struct CheckResult {
+    label: String,
+    outcome: Outcome,
+    detail: String,
+    hint: Option<String>,
+}
+
+impl CheckResult {
+    fn pass(label: impl Into<String>, detail: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            outcome: Outcome::Pass,
+            detail: detail.into(),
+            hint: None,
+        }
+    }
+
+    fn problem(
+        outcome: Outcome,
+        label: impl Into<String>,
+        detail: impl Into<String>,
+        hint: impl Into<String>,
+    ) -> Self {
+        Self {
+            label: label.into(),
+            outcome,
+            detail: detail.into(),
+            hint: Some(hint.into()),
+        }
+    }
+
+    fn line(&self) -> String {
+        let marker = match self.outcome {
+            Outcome::Pass => "✓",
+            Outcome::Warn => "⚠",
+            Outcome::Fail => "✗",
+        };
+        format!("{} {}: {}", marker, self.label, self.detail)
+    }
+}
+
+/// Raw environment facts gathered before evaluation, so the report logic can
+/// be tested without touching the host.
+struct Probes {
+    tmux_version: Option<String>,
+    runtime_binary: &'static str,
+    runtime: Option<containers::RuntimeInfo>,
+    daemon_running: bool,
+    config_dir: std::result::Result<PathBuf, String>,
+    aoe_on_path: bool,
+    agents: Vec<&'static str>,
+}
+
+impl Probes {
+    fn gather() -> Self {
+        let runtime = containers::runtime_info();
+        let daemon_running =
+            runtime.is_some() && containers::get_container_runtime().is_daemon_running();
+        Self {
+            tmux_version: tmux_version(),
+            runtime_binary: containers::runtime_binary(),
+            runtime,
+            daemon_running,
+            config_dir: check_config_dir(),
+            aoe_on_path: crate::terminal::binary_on_path("aoe"),
+            agents: AvailableTools::detect().available_list(),
+        }
+    }
+}
+
+fn tmux_version() -> Option<String> {
+    let output = Command::new("tmux").arg("-V").output().ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+fn check_config_dir() -> std::result::Result<PathBuf, String> {
+    let dir = get_app_dir().map_err(|e| e.to_string())?;
+    let probe = dir.join(".doctor-write-test");
+    std::fs::write(&probe, b"ok")
+        .map_err(|e| format!("{} is not writable: {}", dir.display(), e))?;
+    let _ = std::fs::remove_file(&probe);
+    Ok(dir)
+}
+
+fn evaluate(probes: &Probes) -> Vec<CheckResult> {
+    let mut results = Vec::new();
+
+    results.push(match &probes.tmux_version {
+        Some(version) => CheckResult::pass("tmux", version),
+        None => CheckResult::problem(
+            Outcome::Fail,
+            "tmux",
+            "not found",
+            "Install tmux (e.g. `brew install tmux` or `sudo apt install tmux`)",
+        ),
+    });
+
+    let runtime_label = format!("container runtime ({})", probes.runtime_binary);
+    results.push(match &probes.runtime {
+        Some(info) => CheckResult::pass(&runtime_label, info.to_string()),
+        None => CheckResult::problem(
+            Outcome::Warn,
+            &runtime_label,
+            "not found",
+            "Install Docker to use sandboxed sessions; everything else works without it",
+        ),
+    });
+
+    results.push(if probes.runtime.is_none() {
+        CheckResult::problem(
+            Outcome::Warn,
+            "container daemon",
+            "not checked",
+            "Install a container runtime first",
+        )
+    } else if probes.daemon_running {
+        CheckResult::pass("container daemon", "running")
+    } else {
+        CheckResult::problem(
+            Outcome::Warn,
+            "container daemon",
+            "not running",
+            format!(
+                "Start the {} daemon (e.g. open Docker Desktop)",
+                probes.runtime_binary
+            ),
+        )
+    });
+
+    results.push(match &probes.config_dir {
+        Ok(dir) => CheckResult::pass("config directory", format!("{} is writable", dir.display())),
+        Err(e) => CheckResult::problem(
+            Outcome::Fail,
+            "config directory",
+            e,
+            "Fix the directory's ownership or permissions so aoe can save sessions",
+        ),
+    });
+
+    results.push(if probes.aoe_on_path {
+        CheckResult::pass("PATH", "aoe is on PATH")
+    } else {
+        CheckResult::problem(
+            Outcome::Warn,
+            "PATH",
+            "aoe is not on PATH",
+            "Add the directory containing the aoe binary to PATH so tmux hooks can find it",
+        )
+    });
+
+    results.push(if probes.agents.is_empty() {
+        CheckResult::problem(
+            Outcome::Warn,
+            "coding agents",
+            "none found",
+            "Install a supported agent such as claude or opencode",
+        )
+    } else {
+        CheckResult::pass("coding agents", probes.agents.join(", "))
+    });
+
+    results
+}
+
+pub async fn run() -> Result<()> {
+    let results = evaluate(&Probes::gather());
+
+    for result in &results {
+        outln!("{}", result.line());
+        if let Some(hint) = &result.hint {
+            outln!("    → {}", hint);
+        }
+    }
+
+    let failures = results
+        .iter()
+        .filter(|r| r.outcome == Outcome::Fail)
+        .count();
+    infoln!();
+    if failures > 0 {
+        bail!("{} check(s) failed", failures);
+    }
+    infoln!("✓ All required checks passed");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn probes() -> Probes {
+        Probes {
+            tmux_version: Some("tmux 3.4".to_string()),
+            runtime_binary: "docker",
+            runtime: Some(containers::RuntimeInfo {
+                binary: "docker",
+                kind: containers::RuntimeKind::Docker,
+                version: Some("27.0.3".to_string()),
+            }),
+            daemon_running: true,
+            config_dir: Ok(PathBuf::from("/home/u/.config/agent-of-empires")),
+            aoe_on_path: true,
+            agents: vec!["claude"],
+        }
+    }
+
+    const LABELS: &[&str] = &[
+        "tmux",
+        "container runtime (docker)",
+        "container daemon",
+        "config directory",
+        "PATH",
+        "coding agents",
+    ];
+
+    #[test]
+    fn test_each_check_produces_labeled_line() {
+        let results = evaluate(&probes());
+        assert_eq!(results.len(), LABELS.len());
+        for (result, label) in results.iter().zip(LABELS) {
+            assert_eq!(result.outcome, Outcome::Pass);
+            assert!(result.line().starts_with(&format!("✓ {}: ", label)));
+            assert!(result.hint.is_none());
+        }
+    }
+
+    #[test]
+    fn test_failures_have_hints() {
+        let broken = Probes {
+            tmux_version: None,
+            runtime: None,
+            daemon_running: false,
+            config_dir: Err("permission denied".to_string()),
+            aoe_on_path: false,
+            agents: vec![],
+            ..probes()
+        };
+        let results = evaluate(&broken);
+        assert_eq!(results.len(), LABELS.len());
+        for (result, label) in results.iter().zip(LABELS) {
+            assert_ne!(result.outcome, Outcome::Pass);
+            assert!(result.line().contains(&format!(" {}: ", label)));
+            assert!(result.hint.is_some());
+        }
+        assert_eq!(results[0].outcome, Outcome::Fail);
+        assert_eq!(results[3].outcome, Outcome::Fail);
+        assert_eq!(results[1].outcome, Outcome::Warn);
+    }
+}
@@ -0,0 +1,104 @@
+//! `agent-of-empires dump` command implementation
+
+use std::path::PathBuf;
+
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, Utc};
+use clap::Args;
+
+use crate::cli::output::infoln;
+use crate::session::{resolve_session, Instance, Storage};
+
+/// Scrollback captured when `--lines` is not given
+const DEFAULT_DUMP_LINES: usize = 2000;
+
+#[derive(Args)]
+pub struct DumpArgs {
+    /// Session ID or title
+    identifier: String,
+
+    /// Write to this file instead of stdout
+    #[arg(short, long, value_name = "FILE")]
+    out: Option<PathBuf>,
+
+    /// Number of scrollback lines to capture
+    #[arg(short = 'n', long, default_value_t = DEFAULT_DUMP_LINES)]
+    lines: usize,
+}
+
+pub async fn run(profile: &str, args: DumpArgs) -> Result<()> {
+    let storage = Storage::new(profile)?;
+    let instances = storage.load()?;
+    let inst = resolve_session(&args.identifier, &instances)?;
+
+    let session = inst.tmux_session()?;
+    if !session.exists() {
+        bail!(
+            "Session is not running. Start it first with: agent-of-empires session start {}",
+            inst.title
+        );
+    }
+    let body = session.capture_pane(args.lines)?;
+    let dump = render_dump(inst, args.lines, &body, Utc::now());
+
+    match &args.out {
+        Some(path) => {
+            std::fs::write(path, dump)
+                .with_context(|| format!("Failed to write {}", path.display()))?;
+            infoln!("✓ Saved '{}' to {}", inst.title, path.display());
+        }
+        None => print!("{}", dump),
+    }
+    Ok(())
+}
+
+/// Session metadata header followed by the captured pane.
+fn render_dump(inst: &Instance, lines: usize, body: &str, now: DateTime<Utc>) -> String {
+    let mut out = String::new();
+    out.push_str(&format!("# aoe dump: {}\n", inst.title));
+    out.push_str(&format!("# id: {}\n", inst.id));
+    out.push_str(&format!("# tool: {}\n", inst.tool));
+    out.push_str(&format!("# path: {}\n", inst.project_path));
+    if !inst.group_path.is_empty() {
+        out.push_str(&format!("# group: {}\n", inst.group_path));
+    }
+    out.push_str(&format!("# status: {:?}\n", inst.status));
+    if let Some(code) = inst.last_exit_code {
+        out.push_str(&format!("# last exit code: {}\n", code));
+    }
+    out.push_str(&format!(
+        "# captured: {} (last {} lines)\n",
+        now.to_rfc3339(),
+        lines
+    ));
+    out.push_str(&format!("# aoe version: {}\n\n", env!("CARGO_PKG_VERSION")));
+    out.push_str(body);
+    if !body.ends_with('\n') {
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dump_includes_header_and_body() {
+        let mut inst = Instance::new("api", "/work/api");
+        inst.id = "abcdef0123456789".to_string();
+        inst.group_path = "work".to_string();
+        let now = DateTime::parse_from_rfc3339("2026-01-02T03:04:05Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        let dump = render_dump(&inst, 500, "$ cargo test\nok", now);
+
+        assert!(dump.starts_with("# aoe dump: api\n# id: abcdef0123456789\n"));
+        assert!(dump.contains("# tool: claude\n"));
+        assert!(dump.contains("# path: /work/api\n"));
+        assert!(dump.contains("# group: work\n"));
+        assert!(dump.contains("# captured: 2026-01-02T03:04:05+00:00 (last 500 lines)\n"));
+        assert!(dump.ends_with("\n\n$ cargo test\nok\n"));
+    }
+}
@@ -0,0 +1,142 @@
+//! Process exit codes for CLI commands.
+//!
+//! Errors are classified by walking the error chain for the typed errors
+//! raised by the session, container, tmux and git layers. Anything else
+//! exits with `FAILURE`.
+
+use crate::agents::UnknownTool;
+use crate::containers::error::DockerError;
+use crate::git::error::GitError;
+use crate::session::SessionError;
+use crate::tmux::TmuxUnavailable;
+
+pub const SUCCESS: i32 = 0;
+/// Any error without a more specific code
+pub const FAILURE: i32 = 1;
+/// The session, group, profile, container or branch does not exist
+pub const NOT_FOUND: i32 = 2;
+/// The container runtime is missing, stopped, or not accessible
+pub const CONTAINER_RUNTIME_UNAVAILABLE: i32 = 3;
+pub const TMUX_UNAVAILABLE: i32 = 4;
+/// Invalid arguments or input, including command-line usage errors
+pub const VALIDATION: i32 = 5;
+
+/// Exit code to report for an error returned by a command.
+pub fn for_error(err: &anyhow::Error) -> i32 {
+    err.chain().find_map(classify).unwrap_or(FAILURE)
+}
+
+fn classify(cause: &(dyn std::error::Error + 'static)) -> Option<i32> {
+    if let Some(e) = cause.downcast_ref::<SessionError>() {
+        return Some(match e {
+            SessionError::NotFound(_)
+            | SessionError::GroupNotFound(_)
+            | SessionError::ProfileNotFound(_)
+            | SessionError::MissingWorkingDir { .. } => NOT_FOUND,
+            SessionError::Ambiguous { .. } | SessionError::Invalid(_) => VALIDATION,
+        });
+    }
+    if let Some(e) = cause.downcast_ref::<DockerError>() {
+        return match e {
+            DockerError::NotInstalled
+            | DockerError::DaemonNotRunning
+            | DockerError::PermissionDenied
+            | DockerError::RuntimeUnavailable(_) => Some(CONTAINER_RUNTIME_UNAVAILABLE),
+            DockerError::ContainerNotFound(_) | DockerError::ImageNotFound(_) => Some(NOT_FOUND),
+            _ => None,
+        };
+    }
+    if let Some(e) = cause.downcast_ref::<GitError>() {
+        return match e {
+            GitError::NotAGitRepo => Some(VALIDATION),
+            GitError::BranchNotFound(_) | GitError::WorktreeNotFound(_) => Some(NOT_FOUND),
+            _ => None,
+        };
+    }
+    if cause.downcast_ref::<UnknownTool>().is_some() {
+        return Some(VALIDATION);
+    }
+    cause
+        .downcast_ref::<TmuxUnavailable>()
+        .map(|_| TMUX_UNAVAILABLE)
+}
+
+/// Stable machine-readable name for an exit code, used as the `kind` of
+/// JSON error output.
+pub fn kind_name(code: i32) -> &'static str {
+    match code {
+        NOT_FOUND => "not_found",
+        CONTAINER_RUNTIME_UNAVAILABLE => "container_runtime_unavailable",
+        TMUX_UNAVAILABLE => "tmux_unavailable",
+        VALIDATION => "validation",
+        _ => "failure",
+    }
+}
+
+/// Single-line JSON description of an error for `--json-errors`.
+pub fn error_json(err: &anyhow::Error) -> String {
+    let code = for_error(err);
+    serde_json::json!({
+        "error": format!("{:#}", err),
+        "kind": kind_name(code),
+        "code": code,
+    })
+    .to_string()
+}
+
+/// Exit code for a command-line parse error. Help and version output are
+/// reported through the same path and exit successfully.
+pub fn for_usage_error(err: &clap::Error) -> i32 {
+    if err.use_stderr() {
+        VALIDATION
+    } else {
+        SUCCESS
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Context;
+
+    #[test]
+    fn test_typed_errors_map_to_codes() {
+        let not_found = anyhow::Error::new(SessionError::NotFound("x".into()));
+        assert_eq!(for_error(&not_found), NOT_FOUND);
+
+        let invalid = anyhow::Error::new(SessionError::Invalid("bad".into()));
+        assert_eq!(for_error(&invalid), VALIDATION);
+
+        let docker = anyhow::Error::new(DockerError::DaemonNotRunning);
+        assert_eq!(for_error(&docker), CONTAINER_RUNTIME_UNAVAILABLE);
+
+        assert_eq!(
+            for_error(&anyhow::Error::new(TmuxUnavailable)),
+            TMUX_UNAVAILABLE
+        );
+        assert_eq!(for_error(&anyhow::anyhow!("boom")), FAILURE);
+    }
+
+    #[test]
+    fn test_code_found_under_context() {
+        let err = Err::<(), _>(SessionError::GroupNotFound("work".into()))
+            .context("renaming group")
+            .unwrap_err();
+        assert_eq!(for_error(&err), NOT_FOUND);
+    }
+
+    #[test]
+    fn test_not_found_error_json() {
+        let err = Err::<(), _>(SessionError::NotFound("ghost".into()))
+            .context("attaching")
+            .unwrap_err();
+        let value: serde_json::Value = serde_json::from_str(&error_json(&err)).unwrap();
+
+        assert_eq!(value["kind"], "not_found");
+        assert_eq!(value["code"], NOT_FOUND);
+        let message = value["error"].as_str().unwrap();
+        assert!(message.starts_with("attaching: "));
+        assert!(message.contains("ghost"));
+        assert_eq!(value.as_object().unwrap().len(), 3);
+    }
+}
@@ -4,7 +4,11 @@ use anyhow::{bail, Result};
 use clap::{Args, Subcommand};
 use serde::Serialize;
 
-use crate::session::{GroupTree, Storage};
+use crate::cli::output::{errln, infoln, outln};
+use crate::session::{
+    is_in_group, normalize_group_path, rename_group_sessions, ungroup_sessions, GroupTree,
+    RemoveOptions, SessionError, SessionManager, Storage,
+};
 
 #[derive(Subcommand)]
 pub enum GroupCommands {
@@ -14,9 +18,12 @@ pub enum GroupCommands {
     /// Create a new group
     Create(GroupCreateArgs),
 
-    /// Delete a group
+    /// Delete a group (member sessions move to the top level)
     Delete(GroupDeleteArgs),
 
+    /// Rename a group, keeping its sessions and subgroups
+    Rename(GroupRenameArgs),
+
     /// Move session to group
     Move(GroupMoveArgs),
 }
@@ -43,11 +50,24 @@ pub struct GroupDeleteArgs {
     /// Group name
     name: String,
 
-    /// Force delete by moving sessions to default group
-    #[arg(long)]
+    /// Remove member sessions instead of moving them to the top level
+    #[arg(long = "with-sessions")]
+    with_sessions: bool,
+
+    /// Accepted for compatibility; sessions are now ungrouped by default
+    #[arg(long, hide = true)]
     force: bool,
 }
 
+#[derive(Args)]
+pub struct GroupRenameArgs {
+    /// Current group path
+    old: String,
+
+    /// New group path
+    new: String,
+}
+
 #[derive(Args)]
 pub struct GroupMoveArgs {
     /// Session ID or title
@@ -70,6 +90,7 @@ pub async fn run(profile: &str, command: GroupCommands) -> Result<()> {
         GroupCommands::List(args) => list_groups(profile, args).await,
         GroupCommands::Create(args) => create_group(profile, args).await,
         GroupCommands::Delete(args) => delete_group(profile, args).await,
+        GroupCommands::Rename(args) => rename_group(profile, args).await,
         GroupCommands::Move(args) => move_session(profile, args).await,
     }
 }
@@ -98,26 +119,26 @@ async fn list_groups(profile: &str, args: GroupListArgs) -> Result<()> {
     } else {
         let all_groups = group_tree.get_all_groups();
         if all_groups.is_empty() {
-            println!("No groups found.");
-            println!("Create one with: agent-of-empires group create <name>");
+            infoln!("No groups found.");
+            infoln!("Create one with: agent-of-empires group create <name>");
             return Ok(());
         }
 
-        println!("Groups:\n");
+        infoln!("Groups:\n");
         for group in &all_groups {
             let session_count = instances
                 .iter()
                 .filter(|i| i.group_path == group.path)
                 .count();
             let indent = group.path.matches('/').count();
-            println!(
+            outln!(
                 "{}• {} ({} sessions)",
                 "  ".repeat(indent),
                 group.name,
                 session_count
             );
         }
-        println!("\nTotal: {} groups", all_groups.len());
+        infoln!("\nTotal: {} groups", all_groups.len());
     }
 
     Ok(())
@@ -127,12 +148,13 @@ async fn create_group(profile: &str, args: GroupCreateArgs) -> Result<()> {
     let storage = Storage::new(profile)?;
     let (instances, groups) = storage.load_with_groups()?;
 
-    let name = args.name.trim();
-    let group_path = if let Some(parent) = &args.parent {
-        format!("{}/{}", parent.trim(), name)
-    } else {
-        name.to_string()
+    let group_path = match &args.parent {
+        Some(parent) => normalize_group_path(&format!("{}/{}", parent, args.name)),
+        None => normalize_group_path(&args.name),
     };
+    if group_path.is_empty() {
+        bail!("Group name cannot be empty");
+    }
 
     let mut group_tree = GroupTree::new_with_groups(&instances, &groups);
 
@@ -143,7 +165,7 @@ async fn create_group(profile: &str, args: GroupCreateArgs) -> Result<()> {
     group_tree.create_group(&group_path);
     storage.save_with_groups(&instances, &group_tree)?;
 
-    println!("✓ Created group: {}", group_path);
+    infoln!("✓ Created group: {}", group_path);
 
     Ok(())
 }
@@ -152,42 +174,75 @@ async fn delete_group(profile: &str, args: GroupDeleteArgs) -> Result<()> {
     let storage = Storage::new(profile)?;
     let (mut instances, groups) = storage.load_with_groups()?;
 
-    let mut group_tree = GroupTree::new_with_groups(&instances, &groups);
-
     let name = args.name.trim();
-    if !group_tree.group_exists(name) {
-        bail!("Group not found: {}", name);
+    if !GroupTree::new_with_groups(&instances, &groups).group_exists(name) {
+        return Err(SessionError::GroupNotFound(name.to_string()).into());
     }
+    let _ = args.force;
 
-    // Check for sessions in this group
-    let session_count = instances
-        .iter()
-        .filter(|i| i.group_path == name || i.group_path.starts_with(&format!("{}/", name)))
-        .count();
-
-    if session_count > 0 {
-        if !args.force {
-            bail!(
-                "Group '{}' contains {} sessions. Use --force to move them to default group.",
-                name,
-                session_count
-            );
+    let affected = if args.with_sessions {
+        let members: Vec<String> = instances
+            .iter()
+            .filter(|i| is_in_group(&i.group_path, name))
+            .map(|i| i.id.clone())
+            .collect();
+        let manager = SessionManager::new(profile)?;
+        for id in &members {
+            let removal = manager.remove_with(id, RemoveOptions::default())?;
+            for warning in &removal.warnings {
+                errln!("Warning: {}: {}", removal.instance.title, warning);
+            }
         }
+        instances = storage.load()?;
+        members.len()
+    } else {
+        ungroup_sessions(&mut instances, name)
+    };
 
-        // Move sessions to default group
-        for inst in &mut instances {
-            if inst.group_path == name || inst.group_path.starts_with(&format!("{}/", name)) {
-                inst.group_path = String::new();
-            }
+    let mut group_tree = GroupTree::new_with_groups(&instances, &groups);
+    group_tree.delete_group(name);
+    storage.save_with_groups(&instances, &group_tree)?;
+
+    infoln!("✓ Deleted group: {}", name);
+    if affected > 0 {
+        if args.with_sessions {
+            infoln!("  Removed {} sessions (worktrees were kept)", affected);
+        } else {
+            infoln!("  Moved {} sessions to the top level", affected);
         }
     }
 
-    group_tree.delete_group(name);
+    Ok(())
+}
+
+async fn rename_group(profile: &str, args: GroupRenameArgs) -> Result<()> {
+    let storage = Storage::new(profile)?;
+    let (mut instances, groups) = storage.load_with_groups()?;
+
+    let old = args.old.trim().trim_matches('/');
+    let new = args.new.trim().trim_matches('/');
+    if new.is_empty() {
+        bail!("New group name cannot be empty");
+    }
+
+    let mut group_tree = GroupTree::new_with_groups(&instances, &groups);
+    if !group_tree.group_exists(old) {
+        return Err(SessionError::GroupNotFound(old.to_string()).into());
+    }
+    if group_tree.group_exists(new) {
+        bail!("Group already exists: {}", new);
+    }
+    if is_in_group(new, old) {
+        bail!("Cannot move group '{}' inside itself", old);
+    }
+
+    group_tree.rename_group(old, new);
+    let moved = rename_group_sessions(&mut instances, old, new);
     storage.save_with_groups(&instances, &group_tree)?;
 
-    println!("✓ Deleted group: {}", name);
-    if args.force && session_count > 0 {
-        println!("  Moved {} sessions to default group", session_count);
+    infoln!("✓ Renamed group: {} -> {}", old, new);
+    if moved > 0 {
+        infoln!("  Updated {} sessions", moved);
     }
 
     Ok(())
@@ -201,7 +256,7 @@ async fn move_session(profile: &str, args: GroupMoveArgs) -> Result<()> {
     let inst = instances
         .iter_mut()
         .find(|i| i.id == identifier || i.id.starts_with(identifier) || i.title == identifier)
-        .ok_or_else(|| anyhow::anyhow!("Session not found: {}", identifier))?;
+        .ok_or_else(|| SessionError::NotFound(identifier.to_string()))?;
 
     let group = args.group.trim();
     let old_group = inst.group_path.clone();
@@ -215,9 +270,9 @@ async fn move_session(profile: &str, args: GroupMoveArgs) -> Result<()> {
     storage.save_with_groups(&instances, &group_tree)?;
 
     if old_group.is_empty() {
-        println!("✓ Moved session to group: {}", group);
+        infoln!("✓ Moved session to group: {}", group);
     } else {
-        println!("✓ Moved session from '{}' to '{}'", old_group, group);
+        infoln!("✓ Moved session from '{}' to '{}'", old_group, group);
     }
 
     Ok(())
@@ -0,0 +1,195 @@
+//! `agent-of-empires import-tmux` command implementation
+
+use std::collections::HashSet;
+
+use anyhow::{Context, Result};
+use clap::Args;
+
+use crate::audit::{self, AuditAction, AuditEvent};
+use crate::cli::output::{errln, infoln};
+use crate::session::{list_profiles, GroupTree, Instance, Storage};
+use crate::tmux::{self, CONTAINER_TERMINAL_PREFIX, SESSION_PREFIX, TERMINAL_PREFIX};
+
+#[derive(Args)]
+pub struct ImportTmuxArgs {
+    /// Import every tmux session, not just ones named with the aoe prefix
+    #[arg(long)]
+    all: bool,
+
+    /// Group to put imported sessions in
+    #[arg(short, long)]
+    group: Option<String>,
+}
+
+/// tmux sessions that can be adopted: paired terminal sessions and ones
+/// already backing an instance in any profile are skipped, and without `all`
+/// only names with the aoe prefix are considered.
+fn importable<'a>(names: &'a [String], tracked: &[Instance], all: bool) -> Vec<&'a str> {
+    let tracked: HashSet<String> = tracked.iter().map(Instance::tmux_name).collect();
+    names
+        .iter()
+        .map(String::as_str)
+        .filter(|n| !n.starts_with(TERMINAL_PREFIX) && !n.starts_with(CONTAINER_TERMINAL_PREFIX))
+        .filter(|n| all || n.starts_with(SESSION_PREFIX))
+        .filter(|n| !tracked.contains(*n))
+        .collect()
+}
+
+/// Best-effort title for a session name. aoe names look like
+/// `aoe_<title>_<id8>`; anything else is used as-is.
+fn title_from_session_name(name: &str) -> &str {
+    let Some(rest) = name.strip_prefix(SESSION_PREFIX) else {
+        return name;
+    };
+    match rest.rsplit_once('_') {
+        Some((title, id))
+            if !title.is_empty() && id.len() == 8 && id.chars().all(|c| c.is_ascii_hexdigit()) =>
+        {
+            title
+        }
+        _ if !rest.is_empty() => rest,
+        _ => name,
+    }
+}
+
+pub async fn run(profile: &str, args: ImportTmuxArgs) -> Result<()> {
+    let storage = Storage::new(profile)?;
+    let (mut instances, groups) = storage.load_with_groups()?;
+
+    // Every profile must load: a session we fail to read would otherwise
+    // look untracked and be imported a second time.
+    let mut tracked = Vec::new();
+    for other in list_profiles()? {
+        let loaded = Storage::new(&other)?
+            .load()
+            .with_context(|| format!("Failed to load sessions for profile '{}'", other))?;
+        tracked.extend(loaded);
+    }
+
+    let names = tmux::list_session_names()?;
+    let candidates: Vec<String> = importable(&names, &tracked, args.all)
+        .into_iter()
+        .map(String::from)
+        .collect();
+    if candidates.is_empty() {
+        infoln!("No untracked tmux sessions to import.");
+        return Ok(());
+    }
+
+    let group = args
+        .group
+        .as_deref()
+        .map(crate::session::normalize_group_path);
+    let default_tool = crate::session::resolve_config(storage.profile())?
+        .session
+        .default_tool
+        .unwrap_or_else(|| "claude".to_string());
+    let mut imported = Vec::new();
+    for name in &candidates {
+        let (path, command) = tmux::active_pane_details(name).unwrap_or_default();
+        let path = if path.is_empty() {
+            std::env::current_dir()?.to_string_lossy().to_string()
+        } else {
+            path
+        };
+
+        let mut instance = Instance::new(title_from_session_name(name), &path);
+        instance.tool = match crate::agents::resolve_tool_name(&command) {
+            Some(tool) => tool.to_string(),
+            None => {
+                errln!(
+                    "Warning: {}: no known agent running ('{}'), using {}",
+                    name,
+                    command,
+                    default_tool
+                );
+                default_tool.clone()
+            }
+        };
+        if let Some(group) = &group {
+            instance.group_path = group.clone();
+        }
+        // Keep the existing name; renaming could break whatever else uses it
+        instance.tmux_name = Some(name.clone());
+
+        infoln!("✓ Imported {} as '{}' ({})", name, instance.title, path);
+        instances.push(instance.clone());
+        imported.push(instance);
+    }
+
+    if imported.is_empty() {
+        return Ok(());
+    }
+
+    let mut group_tree = GroupTree::new_with_groups(&instances, &groups);
+    if let Some(group) = group.filter(|g| !g.is_empty()) {
+        group_tree.create_group(&group);
+    }
+    storage.save_with_groups(&instances, &group_tree)?;
+    for instance in &imported {
+        audit::record(AuditEvent::new(
+            storage.profile(),
+            instance,
+            AuditAction::Created,
+        ));
+    }
+
+    infoln!("Imported {} session(s)", imported.len());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_importable_skips_tracked_and_terminals() {
+        let tracked = Instance::new("api", "/tmp/api");
+        let tracked_name = tracked.tmux_name();
+        let all = names(&[
+            &tracked_name,
+            "aoe_web_0a1b2c3d",
+            "aoe_term_web_0a1b2c3d",
+            "aoe_cterm_web_0a1b2c3d",
+            "scratch",
+        ]);
+
+        assert_eq!(
+            importable(&all, std::slice::from_ref(&tracked), false),
+            ["aoe_web_0a1b2c3d"]
+        );
+        assert_eq!(
+            importable(&all, std::slice::from_ref(&tracked), true),
+            ["aoe_web_0a1b2c3d", "scratch"]
+        );
+        assert_eq!(
+            importable(&all, &[], false),
+            [tracked_name.as_str(), "aoe_web_0a1b2c3d"]
+        );
+    }
+
+    #[test]
+    fn test_importable_skips_previously_imported_names() {
+        let mut imported = Instance::new("scratch", "/tmp/scratch");
+        imported.tmux_name = Some("scratch".to_string());
+        let all = names(&["scratch", "notes"]);
+
+        assert_eq!(
+            importable(&all, std::slice::from_ref(&imported), true),
+            ["notes"]
+        );
+    }
+
+    #[test]
+    fn test_title_from_session_name() {
+        assert_eq!(title_from_session_name("aoe_web_0a1b2c3d"), "web");
+        assert_eq!(title_from_session_name("aoe_my_app_0a1b2c3d"), "my_app");
+        assert_eq!(title_from_session_name("aoe_notes"), "notes");
+        assert_eq!(title_from_session_name("scratch"), "scratch");
+        assert_eq!(title_from_session_name("aoe_"), "aoe_");
+    }
+}
@@ -5,6 +5,7 @@ use clap::Args;
 use std::fs;
 use std::path::PathBuf;
 
+use crate::cli::output::infoln;
 use crate::session::repo_config::INIT_TEMPLATE;
 
 #[derive(Args)]
@@ -34,8 +35,8 @@ pub async fn run(args: InitArgs) -> Result<()> {
     fs::create_dir_all(&aoe_dir)?;
     fs::write(&config_path, INIT_TEMPLATE)?;
 
-    println!("Created .aoe/config.toml at {}", path.display());
-    println!("Edit the file to configure hooks and session defaults for this repo.");
+    infoln!("Created .aoe/config.toml at {}", path.display());
+    infoln!("Edit the file to configure hooks and session defaults for this repo.");
 
     Ok(())
 }
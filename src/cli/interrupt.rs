@@ -0,0 +1,32 @@
+//! `agent-of-empires interrupt` command implementation
+
+use anyhow::{bail, Result};
+use clap::Args;
+
+use crate::cli::output::infoln;
+use crate::session::{resolve_session, Storage};
+
+#[derive(Args)]
+pub struct InterruptArgs {
+    /// Session ID or title
+    identifier: String,
+
+    /// Send Ctrl-C twice (some agents only exit on the second one)
+    #[arg(long)]
+    double: bool,
+}
+
+pub async fn run(profile: &str, args: InterruptArgs) -> Result<()> {
+    let storage = Storage::new(profile)?;
+    let instances = storage.load()?;
+    let inst = resolve_session(&args.identifier, &instances)?;
+
+    let session = inst.tmux_session()?;
+    if !session.exists() {
+        bail!("Session is not running: {}", inst.title);
+    }
+    session.interrupt(args.double)?;
+
+    infoln!("✓ Sent Ctrl-C to session: {}", inst.title);
+    Ok(())
+}
@@ -0,0 +1,52 @@
+//! `agent-of-empires kill` command implementation
+
+use anyhow::Result;
+use clap::Args;
+
+use crate::audit::{self, AuditAction, AuditEvent};
+use crate::cli::output::infoln;
+use crate::session::{resolve_session, GroupTree, Status, Storage};
+
+#[derive(Args)]
+pub struct KillArgs {
+    /// Session ID or title
+    identifier: String,
+}
+
+/// Kill the session's tmux session and mark it stopped, keeping the record
+/// so it can be relaunched later. A sandbox container is left running.
+/// Returns the session title, or `None` if it was not running.
+pub fn kill_session(storage: &Storage, identifier: &str) -> Result<Option<String>> {
+    let (mut instances, groups) = storage.load_with_groups()?;
+    let inst = resolve_session(identifier, &instances)?;
+    let session_id = inst.id.clone();
+    let title = inst.title.clone();
+
+    if !inst.tmux_session()?.exists() {
+        return Ok(None);
+    }
+
+    inst.kill()?;
+    audit::record(AuditEvent::new(
+        storage.profile(),
+        inst,
+        AuditAction::Stopped,
+    ));
+
+    if let Some(stored) = instances.iter_mut().find(|i| i.id == session_id) {
+        stored.status = Status::Stopped;
+    }
+    let group_tree = GroupTree::new_with_groups(&instances, &groups);
+    storage.save_with_groups(&instances, &group_tree)?;
+
+    Ok(Some(title))
+}
+
+pub async fn run(profile: &str, args: KillArgs) -> Result<()> {
+    let storage = Storage::new(profile)?;
+    match kill_session(&storage, &args.identifier)? {
+        Some(title) => infoln!("✓ Killed session: {}", title),
+        None => infoln!("Session is not running: {}", args.identifier),
+    }
+    Ok(())
+}
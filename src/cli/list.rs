@@ -1,10 +1,12 @@
 //! `agent-of-empires list` command implementation
 
 use anyhow::Result;
+use chrono::{DateTime, Duration, Local, NaiveDate, TimeZone, Utc};
 use clap::Args;
 use serde::Serialize;
 
-use crate::session::{Instance, Storage};
+use crate::cli::output::{infoln, outln};
+use crate::session::{Instance, SessionManager, Storage};
 
 const TABLE_COL_TITLE: usize = 20;
 const TABLE_COL_GROUP: usize = 15;
@@ -17,9 +19,83 @@ pub struct ListArgs {
     #[arg(long)]
     json: bool,
 
-    /// List sessions from all profiles
+    /// List sessions from all profiles, including archived sessions
     #[arg(long)]
     all: bool,
+
+    /// Show the git branch, and container name, ID and image for sandboxed sessions
+    #[arg(short = 'v', long)]
+    verbose: bool,
+
+    /// Only sessions created within this window (30m, 24h, 7d, 2w) or since
+    /// a date (2026-03-01) or RFC 3339 timestamp
+    #[arg(long, value_name = "WHEN", value_parser = parse_since)]
+    since: Option<Since>,
+}
+
+/// Start of a `--since` window.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Since {
+    Ago(Duration),
+    At(DateTime<Utc>),
+}
+
+impl Since {
+    fn cutoff(self, now: DateTime<Utc>) -> DateTime<Utc> {
+        match self {
+            Since::Ago(duration) => now - duration,
+            Since::At(at) => at,
+        }
+    }
+}
+
+fn parse_since(value: &str) -> std::result::Result<Since, String> {
+    let value = value.trim();
+    if let Some(duration) = parse_duration(value) {
+        return Ok(Since::Ago(duration));
+    }
+    if let Ok(at) = DateTime::parse_from_rfc3339(value) {
+        return Ok(Since::At(at.with_timezone(&Utc)));
+    }
+    if let Ok(date) = NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+        // Midnight local time, which is what a bare date means to the user
+        let midnight = date.and_hms_opt(0, 0, 0).expect("midnight is valid");
+        if let Some(at) = Local.from_local_datetime(&midnight).earliest() {
+            return Ok(Since::At(at.with_timezone(&Utc)));
+        }
+    }
+    Err(format!(
+        "invalid --since '{}': use a duration like 30m, 24h, 7d or 2w, or a date like 2026-03-01",
+        value
+    ))
+}
+
+/// Parse `<number><unit>` with unit s, m, h, d or w.
+fn parse_duration(value: &str) -> Option<Duration> {
+    let unit = value.chars().last()?;
+    let amount: i64 = value[..value.len() - unit.len_utf8()].parse().ok()?;
+    if amount < 0 {
+        return None;
+    }
+    match unit {
+        's' => Duration::try_seconds(amount),
+        'm' => Duration::try_minutes(amount),
+        'h' => Duration::try_hours(amount),
+        'd' => Duration::try_days(amount),
+        'w' => Duration::try_weeks(amount),
+        _ => None,
+    }
+}
+
+/// Keep sessions created at or after `cutoff`.
+fn created_since(instances: Vec<Instance>, cutoff: Option<DateTime<Utc>>) -> Vec<Instance> {
+    match cutoff {
+        Some(cutoff) => instances
+            .into_iter()
+            .filter(|inst| inst.created_at >= cutoff)
+            .collect(),
+        None => instances,
+    }
 }
 
 #[derive(Serialize)]
@@ -32,11 +108,48 @@ struct SessionJson {
     #[serde(skip_serializing_if = "String::is_empty")]
     command: String,
     profile: String,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    archived: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    container: Option<ContainerJson>,
     created_at: chrono::DateTime<chrono::Utc>,
 }
 
+#[derive(Serialize)]
+struct ContainerJson {
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<String>,
+    image: String,
+}
+
+impl SessionJson {
+    fn new(inst: &Instance, profile: &str) -> Self {
+        Self {
+            id: inst.id.clone(),
+            title: inst.title.clone(),
+            path: inst.project_path.clone(),
+            group: inst.group_path.clone(),
+            tool: inst.tool.clone(),
+            command: inst.command.clone(),
+            profile: profile.to_string(),
+            archived: inst.archived,
+            container: inst
+                .sandbox_info
+                .as_ref()
+                .filter(|s| s.enabled)
+                .map(|s| ContainerJson {
+                    name: s.container_name.clone(),
+                    id: s.container_id.clone(),
+                    image: s.image.clone(),
+                }),
+            created_at: inst.created_at,
+        }
+    }
+}
+
 fn print_table_header() {
-    println!(
+    outln!(
         "{:<width_title$} {:<width_group$} {:<width_path$} ID",
         "TITLE",
         "GROUP",
@@ -45,18 +158,18 @@ fn print_table_header() {
         width_group = TABLE_COL_GROUP,
         width_path = TABLE_COL_PATH
     );
-    println!(
+    outln!(
         "{}",
         "-".repeat(TABLE_COL_TITLE + TABLE_COL_GROUP + TABLE_COL_PATH + TABLE_COL_ID_DISPLAY + 5)
     );
 }
 
-fn print_table_row(inst: &Instance) {
+fn print_table_row(inst: &Instance, verbose: bool) {
     let title = super::truncate(&inst.title, TABLE_COL_TITLE);
     let group = super::truncate(&inst.group_path, TABLE_COL_GROUP);
     let path = super::truncate(&inst.project_path, TABLE_COL_PATH);
     let id_display = super::truncate_id(&inst.id, TABLE_COL_ID_DISPLAY);
-    println!(
+    outln!(
         "{:<width_title$} {:<width_group$} {:<width_path$} {}",
         title,
         group,
@@ -66,56 +179,62 @@ fn print_table_row(inst: &Instance) {
         width_group = TABLE_COL_GROUP,
         width_path = TABLE_COL_PATH
     );
+
+    if verbose {
+        if let Some(branch) = crate::git::current_branch(std::path::Path::new(&inst.project_path)) {
+            outln!("  branch: {}", branch);
+        }
+        if let Some(sandbox) = inst.sandbox_info.as_ref().filter(|s| s.enabled) {
+            outln!(
+                "  container: {} (id: {}, image: {})",
+                sandbox.container_name,
+                sandbox.container_id.as_deref().unwrap_or("-"),
+                sandbox.image
+            );
+        }
+    }
 }
 
 pub async fn run(profile: &str, args: ListArgs) -> Result<()> {
+    let cutoff = args.since.map(|since| since.cutoff(Utc::now()));
     if args.all {
-        return run_all_profiles(args.json).await;
+        return run_all_profiles(args.json, args.verbose, cutoff).await;
     }
 
-    let storage = Storage::new(profile)?;
-    let (instances, _) = storage.load_with_groups()?;
+    let manager = SessionManager::new(profile)?;
+    let instances = created_since(manager.list()?, cutoff);
 
     if instances.is_empty() {
-        println!("No sessions found in profile '{}'.", storage.profile());
+        infoln!("No sessions found in profile '{}'.", manager.profile());
         return Ok(());
     }
 
     if args.json {
         let sessions: Vec<SessionJson> = instances
             .iter()
-            .map(|inst| SessionJson {
-                id: inst.id.clone(),
-                title: inst.title.clone(),
-                path: inst.project_path.clone(),
-                group: inst.group_path.clone(),
-                tool: inst.tool.clone(),
-                command: inst.command.clone(),
-                profile: storage.profile().to_string(),
-                created_at: inst.created_at,
-            })
+            .map(|inst| SessionJson::new(inst, manager.profile()))
             .collect();
         println!("{}", serde_json::to_string_pretty(&sessions)?);
         return Ok(());
     }
 
-    println!("Profile: {}\n", storage.profile());
+    infoln!("Profile: {}\n", manager.profile());
     print_table_header();
     for inst in &instances {
-        print_table_row(inst);
+        print_table_row(inst, args.verbose);
     }
-    println!("\nTotal: {} sessions", instances.len());
+    infoln!("\nTotal: {} sessions", instances.len());
 
     crate::update::print_update_notice().await;
 
     Ok(())
 }
 
-async fn run_all_profiles(json: bool) -> Result<()> {
+async fn run_all_profiles(json: bool, verbose: bool, cutoff: Option<DateTime<Utc>>) -> Result<()> {
     let profiles = crate::session::list_profiles()?;
 
     if profiles.is_empty() {
-        println!("No profiles found.");
+        infoln!("No profiles found.");
         return Ok(());
     }
 
@@ -124,17 +243,8 @@ async fn run_all_profiles(json: bool) -> Result<()> {
         for profile_name in &profiles {
             if let Ok(storage) = Storage::new(profile_name) {
                 if let Ok((instances, _)) = storage.load_with_groups() {
-                    for inst in instances {
-                        all_sessions.push(SessionJson {
-                            id: inst.id,
-                            title: inst.title,
-                            path: inst.project_path,
-                            group: inst.group_path,
-                            tool: inst.tool,
-                            command: inst.command,
-                            profile: profile_name.clone(),
-                            created_at: inst.created_at,
-                        });
+                    for inst in &created_since(instances, cutoff) {
+                        all_sessions.push(SessionJson::new(inst, profile_name));
                     }
                 }
             }
@@ -147,23 +257,24 @@ async fn run_all_profiles(json: bool) -> Result<()> {
     for profile_name in &profiles {
         if let Ok(storage) = Storage::new(profile_name) {
             if let Ok((instances, _)) = storage.load_with_groups() {
+                let instances = created_since(instances, cutoff);
                 if instances.is_empty() {
                     continue;
                 }
 
-                println!("\n═══ Profile: {} ═══\n", profile_name);
+                outln!("\n═══ Profile: {} ═══\n", profile_name);
                 print_table_header();
                 for inst in &instances {
-                    print_table_row(inst);
+                    print_table_row(inst, verbose);
                 }
-                println!("({} sessions)", instances.len());
+                infoln!("({} sessions)", instances.len());
                 total_sessions += instances.len();
             }
         }
     }
 
-    println!("\n═══════════════════════════════════════");
-    println!(
+    infoln!("\n═══════════════════════════════════════");
+    infoln!(
         "Total: {} sessions across {} profiles",
         total_sessions,
         profiles.len()
@@ -171,3 +282,57 @@ async fn run_all_profiles(json: bool) -> Result<()> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_since_durations() {
+        assert_eq!(parse_since("30m"), Ok(Since::Ago(Duration::minutes(30))));
+        assert_eq!(parse_since("24h"), Ok(Since::Ago(Duration::hours(24))));
+        assert_eq!(parse_since("7d"), Ok(Since::Ago(Duration::days(7))));
+        assert_eq!(parse_since("2w"), Ok(Since::Ago(Duration::weeks(2))));
+        assert_eq!(parse_since("45s"), Ok(Since::Ago(Duration::seconds(45))));
+        assert!(parse_since("h").is_err());
+        assert!(parse_since("-3d").is_err());
+        assert!(parse_since("3y").is_err());
+        assert!(parse_since("yesterday").is_err());
+    }
+
+    #[test]
+    fn test_parse_since_dates() {
+        let at = DateTime::parse_from_rfc3339("2026-03-01T12:00:00+02:00").unwrap();
+        assert_eq!(
+            parse_since("2026-03-01T12:00:00+02:00"),
+            Ok(Since::At(at.with_timezone(&Utc)))
+        );
+
+        let Ok(Since::At(midnight)) = parse_since("2026-03-01") else {
+            panic!("expected a date");
+        };
+        assert_eq!(
+            midnight
+                .with_timezone(&Local)
+                .format("%Y-%m-%d %H:%M")
+                .to_string(),
+            "2026-03-01 00:00"
+        );
+    }
+
+    #[test]
+    fn test_created_since_filters_by_cutoff() {
+        let now = Utc::now();
+        let mut old = Instance::new("old", "/tmp/old");
+        old.created_at = now - Duration::days(10);
+        let mut recent = Instance::new("recent", "/tmp/recent");
+        recent.created_at = now - Duration::hours(2);
+
+        let cutoff = Since::Ago(Duration::hours(24)).cutoff(now);
+        let kept = created_since(vec![old.clone(), recent.clone()], Some(cutoff));
+        assert_eq!(kept.len(), 1);
+        assert_eq!(kept[0].title, "recent");
+
+        assert_eq!(created_since(vec![old, recent], None).len(), 2);
+    }
+}
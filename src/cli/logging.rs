@@ -0,0 +1,129 @@
+//! Diagnostic logging set up from `--log-file` and `--log-level`
+
+use std::fs::{File, OpenOptions};
+use std::path::Path;
+use std::sync::Mutex;
+
+use anyhow::{Context, Result};
+use tracing_subscriber::EnvFilter;
+
+use crate::session::get_app_dir;
+
+pub const DEBUG_ENV: &str = "AGENT_OF_EMPIRES_DEBUG";
+
+/// Log file used by the TUI when logging is enabled without `--log-file`,
+/// so events never draw over the dashboard.
+pub const TUI_LOG_FILE: &str = "aoe.log";
+
+pub const LEVELS: [&str; 5] = ["error", "warn", "info", "debug", "trace"];
+
+/// Filter directive to log with, or `None` when logging is off. An explicit
+/// level wins, then `RUST_LOG`, then the debug env var. A log file on its own
+/// records info and above.
+fn filter_directive(
+    level: Option<&str>,
+    rust_log: Option<String>,
+    debug: bool,
+    has_file: bool,
+) -> Option<String> {
+    if let Some(level) = level {
+        return Some(format!("agent_of_empires={}", level));
+    }
+    if let Some(rust_log) = rust_log.filter(|s| !s.trim().is_empty()) {
+        return Some(rust_log);
+    }
+    if debug {
+        return Some("agent_of_empires=debug".to_string());
+    }
+    has_file.then(|| "agent_of_empires=info".to_string())
+}
+
+fn open_log(path: &Path) -> Result<File> {
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .with_context(|| format!("Failed to open log file {}", path.display()))
+}
+
+fn file_subscriber(directive: &str, file: File) -> impl tracing::Subscriber + Send + Sync {
+    tracing_subscriber::fmt()
+        .with_env_filter(EnvFilter::new(directive))
+        .with_writer(Mutex::new(file))
+        .with_ansi(false)
+        .finish()
+}
+
+/// Install the global subscriber. CLI commands log to stderr unless a file is
+/// given; the TUI always logs to a file.
+pub fn init(log_file: Option<&Path>, level: Option<&str>, tui: bool) -> Result<()> {
+    let Some(directive) = filter_directive(
+        level,
+        std::env::var("RUST_LOG").ok(),
+        std::env::var(DEBUG_ENV).is_ok(),
+        log_file.is_some(),
+    ) else {
+        return Ok(());
+    };
+
+    let path = match log_file {
+        Some(path) => Some(path.to_path_buf()),
+        None if tui => Some(get_app_dir()?.join(TUI_LOG_FILE)),
+        None => None,
+    };
+
+    match path {
+        Some(path) => {
+            tracing::subscriber::set_global_default(file_subscriber(&directive, open_log(&path)?))?
+        }
+        None => tracing_subscriber::fmt()
+            .with_env_filter(EnvFilter::new(directive))
+            .with_writer(std::io::stderr)
+            .init(),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filter_directive_precedence() {
+        assert_eq!(
+            filter_directive(Some("trace"), Some("info".into()), true, false).as_deref(),
+            Some("agent_of_empires=trace")
+        );
+        assert_eq!(
+            filter_directive(None, Some("hyper=debug".into()), true, false).as_deref(),
+            Some("hyper=debug")
+        );
+        assert_eq!(
+            filter_directive(None, None, true, false).as_deref(),
+            Some("agent_of_empires=debug")
+        );
+        assert_eq!(
+            filter_directive(None, None, false, true).as_deref(),
+            Some("agent_of_empires=info")
+        );
+        assert_eq!(filter_directive(None, None, false, false), None);
+    }
+
+    #[test]
+    fn test_warn_event_lands_in_log_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("aoe.log");
+        let subscriber = file_subscriber("agent_of_empires=warn", open_log(&path).unwrap());
+
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::info!("below the threshold");
+            tracing::warn!(session = "api", "container did not become ready");
+        });
+
+        let logged = std::fs::read_to_string(&path).unwrap();
+        assert!(logged.contains("WARN"));
+        assert!(logged.contains("container did not become ready"));
+        assert!(logged.contains("session=\"api\""));
+        assert!(!logged.contains("below the threshold"));
+    }
+}
@@ -1,46 +1,52 @@
 //! CLI command implementations
 
 pub mod add;
+pub mod archive;
+pub mod config;
+pub mod create;
 pub mod definition;
+pub mod doctor;
+pub mod dump;
+pub mod exit_code;
 pub mod group;
+pub mod import_tmux;
 pub mod init;
+pub mod interrupt;
+pub mod kill;
 pub mod list;
+pub mod logging;
+pub mod output;
 pub mod profile;
+pub mod prune;
 pub mod remove;
+#[cfg(feature = "serve")]
+pub mod serve;
 pub mod session;
 pub mod sounds;
 pub mod status;
 pub mod tmux;
 pub mod uninstall;
+pub mod watch;
 pub mod worktree;
 
 pub use definition::{Cli, Commands};
 
-use crate::session::Instance;
-use anyhow::{bail, Result};
-
-pub fn resolve_session<'a>(identifier: &str, instances: &'a [Instance]) -> Result<&'a Instance> {
-    // Try exact ID match
-    if let Some(inst) = instances.iter().find(|i| i.id == identifier) {
-        return Ok(inst);
-    }
-
-    // Try ID prefix match
-    if let Some(inst) = instances.iter().find(|i| i.id.starts_with(identifier)) {
-        return Ok(inst);
-    }
-
-    // Try exact title match
-    if let Some(inst) = instances.iter().find(|i| i.title == identifier) {
-        return Ok(inst);
-    }
+pub use crate::session::resolve_session;
 
-    // Try path match
-    if let Some(inst) = instances.iter().find(|i| i.project_path == identifier) {
-        return Ok(inst);
-    }
+use crate::session::Instance;
+use output::errln;
 
-    bail!("Session not found: {}", identifier)
+/// Wait for a sandboxed session's readiness probe before it is started,
+/// reporting each failed attempt so the command does not appear to hang.
+pub(crate) fn wait_until_sandbox_ready(inst: &mut Instance) -> anyhow::Result<()> {
+    let title = inst.title.clone();
+    inst.wait_until_sandbox_ready(|attempt| {
+        errln!(
+            "Waiting for '{}' to become ready (attempt {})...",
+            title,
+            attempt
+        )
+    })
 }
 
 pub fn truncate(s: &str, max: usize) -> String {
@@ -55,10 +61,30 @@ pub fn truncate(s: &str, max: usize) -> String {
     }
 }
 
+/// Shorten an id to at most `max_len` characters. Cuts on a char boundary, so
+/// hand-edited or imported ids with multibyte characters never panic.
 pub fn truncate_id(id: &str, max_len: usize) -> &str {
-    if id.len() > max_len {
-        &id[..max_len]
-    } else {
-        id
+    match id.char_indices().nth(max_len) {
+        Some((end, _)) => &id[..end],
+        None => id,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_truncate_id_ascii() {
+        assert_eq!(truncate_id("0123456789abcdef", 8), "01234567");
+        assert_eq!(truncate_id("abc", 8), "abc");
+        assert_eq!(truncate_id("", 8), "");
+    }
+
+    #[test]
+    fn test_truncate_id_multibyte() {
+        // Byte index 8 falls inside 'é', which a byte slice would panic on
+        assert_eq!(truncate_id("abcdefgé12", 8), "abcdefgé");
+        assert_eq!(truncate_id("日本語のセッションID", 4), "日本語の");
     }
 }
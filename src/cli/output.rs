@@ -0,0 +1,339 @@
+//! Plain-output support for CLI commands.
+//!
+//! Human-readable CLI output goes through `outln!`, `infoln!` and `errln!`.
+//! When color is disabled (via `--no-color` or a non-empty `NO_COLOR`, see
+//! <https://no-color.org>), ANSI escape sequences are stripped and decorative
+//! glyphs are replaced with ASCII markers so logs and non-TTY consumers get
+//! plain text. With `--quiet`, `infoln!` prints nothing so only errors and
+//! requested data (`outln!`, JSON, scripting values) reach the terminal.
+
+use std::borrow::Cow;
+use std::ffi::OsStr;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static PLAIN: AtomicBool = AtomicBool::new(false);
+static QUIET: AtomicBool = AtomicBool::new(false);
+
+/// Glyph replacements used when color is disabled.
+const ASCII_MARKERS: &[(&str, &str)] = &[
+    ("✓", "[ok]"),
+    ("✗", "[x]"),
+    ("❌", "[error]"),
+    ("⚠", "[!]"),
+    ("💡", "[tip]"),
+    ("📥 ", ""),
+    ("📂 ", ""),
+    ("🔊 ", ""),
+    ("🎮 ", ""),
+    ("⬆", "^"),
+    ("→", "->"),
+    ("•", "-"),
+    ("●", "*"),
+    ("◐", "~"),
+    ("○", "o"),
+    ("■", "#"),
+    ("✕", "x"),
+    ("═", "="),
+    ("║", "|"),
+    ("╔", "+"),
+    ("╗", "+"),
+    ("╚", "+"),
+    ("╝", "+"),
+];
+
+/// Whether color should be disabled given the `--no-color` flag and the
+/// `NO_COLOR` environment value. An empty `NO_COLOR` is ignored, per the spec.
+pub fn color_disabled(no_color_flag: bool, no_color_env: Option<&OsStr>) -> bool {
+    no_color_flag || no_color_env.is_some_and(|v| !v.is_empty())
+}
+
+/// Configure output styling for this process. Call once at startup.
+pub fn init(no_color_flag: bool) {
+    let env = std::env::var_os("NO_COLOR");
+    set_plain(color_disabled(no_color_flag, env.as_deref()));
+}
+
+pub fn set_plain(plain: bool) {
+    PLAIN.store(plain, Ordering::Relaxed);
+}
+
+pub fn is_plain() -> bool {
+    PLAIN.load(Ordering::Relaxed)
+}
+
+pub fn set_quiet(quiet: bool) {
+    QUIET.store(quiet, Ordering::Relaxed);
+}
+
+/// Whether informational output is suppressed (`--quiet`).
+pub fn is_quiet() -> bool {
+    QUIET.load(Ordering::Relaxed)
+}
+
+/// Apply the current output mode to a line of text.
+pub fn render(text: &str) -> Cow<'_, str> {
+    if is_plain() {
+        Cow::Owned(to_plain(text))
+    } else {
+        Cow::Borrowed(text)
+    }
+}
+
+/// Strip ANSI escape sequences and replace decorative glyphs with ASCII.
+pub fn to_plain(text: &str) -> String {
+    let mut out = strip_escapes(text);
+    for (glyph, ascii) in ASCII_MARKERS {
+        if out.contains(glyph) {
+            out = out.replace(glyph, ascii);
+        }
+    }
+    out
+}
+
+fn strip_escapes(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\x1b' {
+            out.push(c);
+            continue;
+        }
+        match chars.peek() {
+            // CSI: ESC [ params... final byte in @..~
+            Some('[') => {
+                chars.next();
+                for c in chars.by_ref() {
+                    if ('@'..='~').contains(&c) {
+                        break;
+                    }
+                }
+            }
+            // OSC: ESC ] ... terminated by BEL or ESC \
+            Some(']') => {
+                chars.next();
+                while let Some(c) = chars.next() {
+                    if c == '\x07' {
+                        break;
+                    }
+                    if c == '\x1b' && chars.peek() == Some(&'\\') {
+                        chars.next();
+                        break;
+                    }
+                }
+            }
+            _ => {
+                chars.next();
+            }
+        }
+    }
+    out
+}
+
+/// Taskbar/tab progress state, reported with the OSC 9;4 sequence understood
+/// by Windows Terminal, ConEmu, WezTerm, Ghostty and others.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TerminalProgress {
+    Clear,
+    Percent(u8),
+    Error,
+    Indeterminate,
+}
+
+/// Environment variable that forces terminal progress on (`1`) or off (`0`)
+/// regardless of the detected terminal.
+pub const TERMINAL_PROGRESS_ENV: &str = "AOE_TERMINAL_PROGRESS";
+
+/// Encode `progress` as an OSC 9;4 sequence.
+pub fn osc_progress(progress: TerminalProgress) -> String {
+    let (state, value) = match progress {
+        TerminalProgress::Clear => (0, 0),
+        TerminalProgress::Percent(p) => (1, p.min(100)),
+        TerminalProgress::Error => (2, 0),
+        TerminalProgress::Indeterminate => (3, 0),
+    };
+    format!("\x1b]9;4;{};{}\x07", state, value)
+}
+
+/// Wrap an escape sequence in tmux's DCS passthrough so it reaches the outer
+/// terminal (needs `allow-passthrough` in tmux 3.3+).
+fn tmux_passthrough(seq: &str) -> String {
+    format!("\x1bPtmux;{}\x1b\\", seq.replace('\x1b', "\x1b\x1b"))
+}
+
+/// Whether the terminal is known to handle OSC 9;4. `override_value` is the
+/// value of `AOE_TERMINAL_PROGRESS`, if set.
+fn terminal_supports_progress(
+    term_program: Option<&str>,
+    windows_terminal: bool,
+    override_value: Option<&str>,
+) -> bool {
+    match override_value {
+        Some("0") => false,
+        Some("1") => true,
+        _ => {
+            windows_terminal
+                || matches!(
+                    term_program,
+                    Some("WezTerm" | "ghostty" | "iTerm.app" | "ConEmu")
+                )
+        }
+    }
+}
+
+/// Report progress for a long-running operation to the terminal. Does
+/// nothing in plain or quiet mode, when stderr is not a terminal, or when the
+/// terminal is not known to support it.
+pub fn report_progress(progress: TerminalProgress) {
+    use std::io::{IsTerminal, Write};
+
+    if is_plain() || is_quiet() || !std::io::stderr().is_terminal() {
+        return;
+    }
+    let term_program = std::env::var("TERM_PROGRAM").ok();
+    let override_value = std::env::var(TERMINAL_PROGRESS_ENV).ok();
+    if !terminal_supports_progress(
+        term_program.as_deref(),
+        std::env::var_os("WT_SESSION").is_some(),
+        override_value.as_deref(),
+    ) {
+        return;
+    }
+    let mut seq = osc_progress(progress);
+    if std::env::var_os("TMUX").is_some() {
+        seq = tmux_passthrough(&seq);
+    }
+    let mut stderr = std::io::stderr();
+    let _ = stderr.write_all(seq.as_bytes());
+    let _ = stderr.flush();
+}
+
+/// Shows an indeterminate terminal progress indicator until dropped.
+pub struct ProgressIndicator;
+
+impl ProgressIndicator {
+    pub fn start() -> Self {
+        report_progress(TerminalProgress::Indeterminate);
+        Self
+    }
+}
+
+impl Drop for ProgressIndicator {
+    fn drop(&mut self) {
+        report_progress(TerminalProgress::Clear);
+    }
+}
+
+/// `println!` for output the command was asked for (listings, `show`
+/// details). Honors `--no-color` / `NO_COLOR` and still prints under `--quiet`.
+macro_rules! outln {
+    () => {
+        println!()
+    };
+    ($($arg:tt)*) => {
+        println!("{}", $crate::cli::output::render(&format!($($arg)*)))
+    };
+}
+
+/// `outln!` for progress, confirmations and decoration around the data.
+/// Prints nothing under `--quiet`.
+macro_rules! infoln {
+    () => {
+        if !$crate::cli::output::is_quiet() {
+            println!()
+        }
+    };
+    ($($arg:tt)*) => {
+        if !$crate::cli::output::is_quiet() {
+            println!("{}", $crate::cli::output::render(&format!($($arg)*)))
+        }
+    };
+}
+
+/// `eprintln!` that honors `--no-color` / `NO_COLOR`.
+macro_rules! errln {
+    () => {
+        eprintln!()
+    };
+    ($($arg:tt)*) => {
+        eprintln!("{}", $crate::cli::output::render(&format!($($arg)*)))
+    };
+}
+
+pub(crate) use errln;
+pub(crate) use infoln;
+pub(crate) use outln;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_color_disabled() {
+        assert!(!color_disabled(false, None));
+        assert!(color_disabled(true, None));
+        assert!(color_disabled(false, Some(OsStr::new("1"))));
+        assert!(!color_disabled(false, Some(OsStr::new(""))));
+    }
+
+    #[test]
+    fn test_plain_output_has_no_escape_sequences() {
+        let styled = "\x1b[32m✓ Added session\x1b[0m → \x1b]8;;https://x\x07link\x1b]8;;\x07 • ok";
+        let plain = to_plain(styled);
+        assert!(!plain.contains('\x1b'));
+        assert!(plain.is_ascii());
+        assert_eq!(plain, "[ok] Added session -> link - ok");
+    }
+
+    #[test]
+    fn test_osc_progress_encoding() {
+        assert_eq!(
+            osc_progress(TerminalProgress::Percent(0)),
+            "\x1b]9;4;1;0\x07"
+        );
+        assert_eq!(
+            osc_progress(TerminalProgress::Percent(50)),
+            "\x1b]9;4;1;50\x07"
+        );
+        assert_eq!(
+            osc_progress(TerminalProgress::Percent(100)),
+            "\x1b]9;4;1;100\x07"
+        );
+        assert_eq!(
+            osc_progress(TerminalProgress::Percent(250)),
+            "\x1b]9;4;1;100\x07"
+        );
+        assert_eq!(
+            osc_progress(TerminalProgress::Indeterminate),
+            "\x1b]9;4;3;0\x07"
+        );
+        assert_eq!(osc_progress(TerminalProgress::Clear), "\x1b]9;4;0;0\x07");
+        assert_eq!(
+            tmux_passthrough(&osc_progress(TerminalProgress::Clear)),
+            "\x1bPtmux;\x1b\x1b]9;4;0;0\x07\x1b\\"
+        );
+        assert_eq!(to_plain(&osc_progress(TerminalProgress::Percent(50))), "");
+    }
+
+    #[test]
+    fn test_terminal_supports_progress() {
+        assert!(terminal_supports_progress(Some("WezTerm"), false, None));
+        assert!(terminal_supports_progress(None, true, None));
+        assert!(!terminal_supports_progress(
+            Some("Apple_Terminal"),
+            false,
+            None
+        ));
+        assert!(terminal_supports_progress(None, false, Some("1")));
+        assert!(!terminal_supports_progress(
+            Some("ghostty"),
+            false,
+            Some("0")
+        ));
+    }
+
+    #[test]
+    fn test_plain_output_keeps_regular_text() {
+        assert_eq!(to_plain("Profile: default"), "Profile: default");
+        assert_eq!(to_plain("日本語"), "日本語");
+    }
+}
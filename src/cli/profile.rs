@@ -4,6 +4,7 @@ use anyhow::{bail, Result};
 use clap::Subcommand;
 use std::io::{self, Write};
 
+use crate::cli::output::{infoln, outln};
 use crate::session;
 
 #[derive(Subcommand)]
@@ -69,34 +70,34 @@ async fn list_profiles() -> Result<()> {
         .unwrap_or(session::DEFAULT_PROFILE);
 
     if profiles.is_empty() {
-        println!("No profiles found.");
-        println!("Run 'agent-of-empires' to create the default profile automatically.");
+        infoln!("No profiles found.");
+        infoln!("Run 'agent-of-empires' to create the default profile automatically.");
         return Ok(());
     }
 
-    println!("Profiles:");
+    infoln!("Profiles:");
     for p in &profiles {
         if p == default_profile {
-            println!("  * {} (default)", p);
+            outln!("  * {} (default)", p);
         } else {
-            println!("    {}", p);
+            outln!("    {}", p);
         }
     }
-    println!("\nTotal: {} profiles", profiles.len());
+    infoln!("\nTotal: {} profiles", profiles.len());
 
     Ok(())
 }
 
 async fn create_profile(name: &str) -> Result<()> {
     session::create_profile(name)?;
-    println!("✓ Created profile: {}", name);
-    println!("  Use with: agent-of-empires -p {}", name);
+    infoln!("✓ Created profile: {}", name);
+    infoln!("  Use with: agent-of-empires -p {}", name);
     Ok(())
 }
 
 async fn rename_profile(old_name: &str, new_name: &str) -> Result<()> {
     session::rename_profile(old_name, new_name)?;
-    println!("✓ Renamed profile: {} -> {}", old_name, new_name);
+    infoln!("✓ Renamed profile: {} -> {}", old_name, new_name);
     Ok(())
 }
 
@@ -111,12 +112,12 @@ async fn delete_profile(name: &str) -> Result<()> {
     io::stdin().read_line(&mut response)?;
 
     if response.trim().to_lowercase() != "y" {
-        println!("Cancelled.");
+        infoln!("Cancelled.");
         return Ok(());
     }
 
     session::delete_profile(name)?;
-    println!("✓ Deleted profile: {}", name);
+    infoln!("✓ Deleted profile: {}", name);
     Ok(())
 }
 
@@ -126,7 +127,7 @@ async fn show_default_profile() -> Result<()> {
         .as_ref()
         .map(|c| c.default_profile.as_str())
         .unwrap_or(session::DEFAULT_PROFILE);
-    println!("Default profile: {}", default_profile);
+    outln!("Default profile: {}", default_profile);
     Ok(())
 }
 
@@ -138,6 +139,6 @@ async fn set_default_profile(name: &str) -> Result<()> {
     }
 
     session::set_default_profile(name)?;
-    println!("✓ Default profile set to: {}", name);
+    infoln!("✓ Default profile set to: {}", name);
     Ok(())
 }
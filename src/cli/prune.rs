@@ -0,0 +1,130 @@
+//! `agent-of-empires prune-containers` command implementation
+
+use std::collections::HashSet;
+
+use anyhow::{Context, Result};
+use clap::Args;
+
+use crate::cli::output::{errln, infoln, outln};
+use crate::containers::{self, DockerContainer};
+use crate::session::{list_profiles, Instance, Storage};
+
+#[derive(Args)]
+pub struct PruneArgs {
+    /// List orphaned containers without removing them
+    #[arg(long)]
+    dry_run: bool,
+
+    /// Remove without asking for confirmation
+    #[arg(short, long)]
+    yes: bool,
+}
+
+/// Names a tracked session may own: the recorded container name and the
+/// default id-based name.
+fn owned_container_names(instances: &[Instance]) -> HashSet<String> {
+    let mut names = HashSet::new();
+    for inst in instances {
+        names.insert(DockerContainer::generate_name(&inst.id));
+        if let Some(sandbox) = &inst.sandbox_info {
+            names.insert(sandbox.container_name.clone());
+        }
+    }
+    names
+}
+
+/// Sandbox containers not owned by any tracked session, sorted by name.
+fn orphaned_containers<'a>(
+    existing: impl IntoIterator<Item = &'a String>,
+    owned: &HashSet<String>,
+) -> Vec<String> {
+    let mut orphans: Vec<String> = existing
+        .into_iter()
+        .filter(|name| !owned.contains(*name))
+        .cloned()
+        .collect();
+    orphans.sort();
+    orphans
+}
+
+pub async fn run(args: PruneArgs) -> Result<()> {
+    use std::io::{self, Write};
+
+    // Every profile must load: a session we fail to read would otherwise
+    // have its container treated as orphaned.
+    let mut instances = Vec::new();
+    for profile in list_profiles()? {
+        let storage = Storage::new(&profile)?;
+        let loaded = storage
+            .load()
+            .with_context(|| format!("Failed to load sessions for profile '{}'", profile))?;
+        instances.extend(loaded);
+    }
+
+    let existing = containers::batch_container_health().unwrap_or_default();
+    let orphans = orphaned_containers(existing.keys(), &owned_container_names(&instances));
+
+    if orphans.is_empty() {
+        infoln!("No orphaned sandbox containers.");
+        return Ok(());
+    }
+
+    infoln!("Sandbox containers with no matching session:");
+    for name in &orphans {
+        let state = if existing[name] { "running" } else { "stopped" };
+        outln!("  {} ({})", name, state);
+    }
+
+    if args.dry_run {
+        return Ok(());
+    }
+
+    if !args.yes {
+        print!("\nRemove {} container(s)? (y/N): ", orphans.len());
+        io::stdout().flush()?;
+        let mut response = String::new();
+        io::stdin().read_line(&mut response)?;
+        let response = response.trim().to_lowercase();
+        if response != "y" && response != "yes" {
+            infoln!("Cancelled");
+            return Ok(());
+        }
+    }
+
+    let mut failed = 0;
+    for name in &orphans {
+        match DockerContainer::from_name(name, "").remove(true) {
+            Ok(()) => infoln!("✓ Removed {}", name),
+            Err(e) => {
+                failed += 1;
+                errln!("Failed to remove {}: {}", name, e);
+            }
+        }
+    }
+    if failed > 0 {
+        anyhow::bail!("{} container(s) could not be removed", failed);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_orphaned_containers_skips_tracked_sessions() {
+        let mut live = Instance::new("live", "/tmp/live");
+        live.id = "abcdef0123456789".to_string();
+        let owned = owned_container_names(&[live]);
+
+        let existing = [
+            "aoe-sandbox-abcdef01".to_string(),
+            "aoe-sandbox-deadbeef".to_string(),
+        ];
+        assert_eq!(
+            orphaned_containers(&existing, &owned),
+            vec!["aoe-sandbox-deadbeef".to_string()]
+        );
+    }
+}
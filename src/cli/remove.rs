@@ -3,25 +3,67 @@
 use anyhow::{bail, Result};
 use clap::Args;
 
-use crate::containers;
-use crate::session::{Config, GroupTree, Instance, Storage};
+use crate::cli::output::{errln, infoln};
+use crate::session::{Config, Instance, RemoveOptions, SessionError, SessionManager};
 
 #[derive(Args)]
 pub struct RemoveArgs {
-    /// Session ID or title to remove
+    /// Session ID, ID prefix, title or path to remove
     identifier: String,
 
     /// Delete worktree directory (default: keep worktree)
     #[arg(long = "delete-worktree")]
     delete_worktree: bool,
 
-    /// Force worktree removal even with untracked/modified files
+    /// Force worktree removal even with untracked/modified files (also skips
+    /// the uncommitted-changes confirmation)
     #[arg(long)]
     force: bool,
 
     /// Keep container instead of deleting it (default: delete per config)
     #[arg(long = "keep-container")]
     keep_container: bool,
+
+    /// Skip the confirmation when the working directory has uncommitted changes
+    #[arg(short, long)]
+    yes: bool,
+}
+
+/// Ask before removing a session whose working directory has uncommitted
+/// changes. Returns true when removal should go ahead. Without a terminal to
+/// ask on, this fails unless `--yes` or `--force` was given.
+fn confirm_uncommitted_changes(inst: &Instance, args: &RemoveArgs) -> Result<bool> {
+    use std::io::{self, IsTerminal, Write};
+
+    if args.yes || args.force {
+        return Ok(true);
+    }
+    let changed = crate::git::uncommitted_changes(std::path::Path::new(&inst.project_path));
+    let Some(changed) = changed.filter(|&n| n > 0) else {
+        return Ok(true);
+    };
+    if !io::stdin().is_terminal() {
+        bail!(
+            "{} has uncommitted changes ({} modified file{}); pass --yes to remove the session without confirmation",
+            inst.project_path,
+            changed,
+            if changed == 1 { "" } else { "s" }
+        );
+    }
+
+    infoln!(
+        "⚠ {} has uncommitted changes ({} modified file{})",
+        inst.project_path,
+        changed,
+        if changed == 1 { "" } else { "s" }
+    );
+    print!("Remove session anyway? (y/N): ");
+    io::stdout().flush()?;
+
+    let mut response = String::new();
+    io::stdin().read_line(&mut response)?;
+    let response = response.trim().to_lowercase();
+    Ok(response == "y" || response == "yes")
 }
 
 fn needs_worktree_cleanup(inst: &Instance, args: &RemoveArgs) -> bool {
@@ -31,140 +73,127 @@ fn needs_worktree_cleanup(inst: &Instance, args: &RemoveArgs) -> bool {
 }
 
 pub async fn run(profile: &str, args: RemoveArgs) -> Result<()> {
-    let storage = Storage::new(profile)?;
-    let (instances, groups) = storage.load_with_groups()?;
-
-    let mut found = false;
-    let mut removed_title = String::new();
-    let mut new_instances = Vec::with_capacity(instances.len());
-
-    for inst in instances {
-        if inst.id == args.identifier
-            || inst.id.starts_with(&args.identifier)
-            || inst.title == args.identifier
-        {
-            found = true;
-            removed_title = inst.title.clone();
-
-            let will_cleanup_worktree = needs_worktree_cleanup(&inst, &args);
-
-            // Show warning and get confirmation for worktree deletion
-            let user_confirmed = if will_cleanup_worktree {
-                use std::io::{self, Write};
-
-                let wt_info = inst.worktree_info.as_ref().unwrap();
-                println!("\nThis will delete:");
-                println!(
-                    "  - Worktree: {} (branch: {})",
-                    inst.project_path, wt_info.branch
-                );
-                print!("\nProceed? (Y/n): ");
-                io::stdout().flush()?;
-
-                let mut response = String::new();
-                io::stdin().read_line(&mut response)?;
-                let response = response.trim().to_lowercase();
-
-                response.is_empty() || response == "y" || response == "yes"
-            } else {
-                true
-            };
-
-            // Handle worktree cleanup
-            if will_cleanup_worktree {
-                if user_confirmed {
-                    use crate::git::GitWorktree;
-                    use std::path::PathBuf;
-
-                    let wt_info = inst.worktree_info.as_ref().unwrap();
-                    let worktree_path = PathBuf::from(&inst.project_path);
-                    let main_repo = PathBuf::from(&wt_info.main_repo_path);
-
-                    match GitWorktree::new(main_repo) {
-                        Ok(git_wt) => {
-                            if let Err(e) = git_wt.remove_worktree(&worktree_path, args.force) {
-                                eprintln!("Warning: failed to remove worktree: {}", e);
-                                eprintln!(
-                                    "You may need to remove it manually with: git worktree remove {}",
-                                    inst.project_path
-                                );
-                            } else {
-                                println!("✓ Worktree removed");
-                            }
-                        }
-                        Err(e) => {
-                            eprintln!("Warning: failed to access git repository: {}", e);
-                        }
-                    }
-                } else {
-                    println!("Worktree preserved at: {}", inst.project_path);
-                }
-            } else if let Some(wt_info) = &inst.worktree_info {
-                // Worktree exists but not scheduled for deletion (user didn't use --delete-worktree)
-                if wt_info.managed_by_aoe {
-                    println!(
-                        "Worktree preserved at: {} (use --delete-worktree to remove)",
-                        inst.project_path
-                    );
-                }
+    let manager = SessionManager::new(profile)?;
+
+    let inst = match manager.get(&args.identifier) {
+        Ok(inst) => inst,
+        Err(e) => match e.downcast_ref::<SessionError>() {
+            Some(SessionError::NotFound(_)) => {
+                return Err(SessionError::NotFound(format!(
+                    "{} (profile '{}')",
+                    args.identifier,
+                    manager.profile()
+                ))
+                .into())
             }
+            _ => return Err(e),
+        },
+    };
 
-            // Kill tmux session if it exists
-            if let Ok(tmux_session) = crate::tmux::Session::new(&inst.id, &inst.title) {
-                if tmux_session.exists() {
-                    if let Err(e) = tmux_session.kill() {
-                        eprintln!("Warning: failed to kill tmux session: {}", e);
-                        eprintln!(
-                            "Session removed from Agent of Empires but may still be running in tmux"
-                        );
-                    }
-                }
-            }
+    if !confirm_uncommitted_changes(&inst, &args)? {
+        infoln!("Cancelled, session kept: {}", inst.title);
+        return Ok(());
+    }
 
-            // Container cleanup (if config allows and user didn't request --keep-container)
-            if let Some(sandbox) = &inst.sandbox_info {
-                if sandbox.enabled && !args.keep_container {
-                    let config = Config::load().ok().unwrap_or_default();
-                    if config.sandbox.auto_cleanup {
-                        let container = containers::DockerContainer::from_session_id(&inst.id);
-                        if container.exists().unwrap_or(false) {
-                            if let Err(e) = container.remove(true) {
-                                eprintln!("Warning: failed to remove container: {}", e);
-                            } else {
-                                println!("✓ Container removed");
-                            }
-                        }
-                    } else {
-                        println!(
-                            "Container preserved: {} (auto_cleanup disabled in config)",
-                            sandbox.container_name
+    let will_cleanup_worktree = needs_worktree_cleanup(&inst, &args);
+
+    // Show warning and get confirmation for worktree deletion
+    let user_confirmed = if will_cleanup_worktree {
+        use std::io::{self, Write};
+
+        let wt_info = inst.worktree_info.as_ref().unwrap();
+        infoln!("\nThis will delete:");
+        infoln!(
+            "  - Worktree: {} (branch: {})",
+            inst.project_path,
+            wt_info.branch
+        );
+        print!("\nProceed? (Y/n): ");
+        io::stdout().flush()?;
+
+        let mut response = String::new();
+        io::stdin().read_line(&mut response)?;
+        let response = response.trim().to_lowercase();
+
+        response.is_empty() || response == "y" || response == "yes"
+    } else {
+        true
+    };
+
+    // Kill tmux first, then remove the container (if config allows and
+    // user didn't request --keep-container)
+    let removal = manager.remove_with(
+        &inst.id,
+        RemoveOptions {
+            keep_container: args.keep_container,
+        },
+    )?;
+    let inst = &removal.instance;
+    for warning in &removal.warnings {
+        errln!("Warning: {}", warning);
+    }
+    if removal.container_removed {
+        infoln!("✓ Container removed");
+    }
+
+    if let Some(sandbox) = &inst.sandbox_info {
+        let auto_cleanup = Config::load().ok().unwrap_or_default().sandbox.auto_cleanup;
+        if args.keep_container {
+            infoln!(
+                "Container stopped and preserved: {}",
+                sandbox.container_name
+            );
+        } else if sandbox.enabled && !auto_cleanup {
+            infoln!(
+                "Container stopped and preserved: {} (auto_cleanup disabled in config)",
+                sandbox.container_name
+            );
+        }
+    }
+
+    // Handle worktree cleanup now that nothing runs in it
+    if will_cleanup_worktree {
+        if user_confirmed {
+            use crate::git::GitWorktree;
+            use std::path::PathBuf;
+
+            let wt_info = inst.worktree_info.as_ref().unwrap();
+            let worktree_path = PathBuf::from(&inst.project_path);
+            let main_repo = PathBuf::from(&wt_info.main_repo_path);
+
+            match GitWorktree::new(main_repo) {
+                Ok(git_wt) => {
+                    if let Err(e) = git_wt.remove_worktree(&worktree_path, args.force) {
+                        errln!("Warning: failed to remove worktree: {}", e);
+                        errln!(
+                            "You may need to remove it manually with: git worktree remove {}",
+                            inst.project_path
                         );
+                    } else {
+                        infoln!("✓ Worktree removed");
                     }
-                } else if args.keep_container {
-                    println!("Container preserved: {}", sandbox.container_name);
+                }
+                Err(e) => {
+                    errln!("Warning: failed to access git repository: {}", e);
                 }
             }
         } else {
-            new_instances.push(inst);
+            infoln!("Worktree preserved at: {}", inst.project_path);
+        }
+    } else if let Some(wt_info) = &inst.worktree_info {
+        // Worktree exists but not scheduled for deletion (user didn't use --delete-worktree)
+        if wt_info.managed_by_aoe {
+            infoln!(
+                "Worktree preserved at: {} (use --delete-worktree to remove)",
+                inst.project_path
+            );
         }
     }
 
-    if !found {
-        bail!(
-            "Session not found in profile '{}': {}",
-            storage.profile(),
-            args.identifier
-        );
-    }
-
-    // Rebuild group tree and save
-    let group_tree = GroupTree::new_with_groups(&new_instances, &groups);
-    storage.save_with_groups(&new_instances, &group_tree)?;
-
-    println!(
+    infoln!(
         "✓ Removed session: {} (from profile '{}')",
-        removed_title,
-        storage.profile()
+        inst.title,
+        manager.profile()
     );
 
     Ok(())
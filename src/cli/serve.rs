@@ -0,0 +1,273 @@
+//! `agent-of-empires serve` command implementation
+//!
+//! A minimal, read-only HTTP/1.1 JSON API for dashboards:
+//!   - `GET /api/sessions` lists the profile's sessions
+//!   - `GET /api/sessions/<id>` returns one session with its live status and
+//!     captured pane content
+//!
+//! Every connection handles a single request and is then closed.
+
+use std::net::SocketAddr;
+use std::time::Duration;
+
+use anyhow::Result;
+use clap::Args;
+use serde::Serialize;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+
+use crate::cli::output::infoln;
+use crate::session::{Instance, SessionManager, Status};
+
+const MAX_REQUEST_BYTES: usize = 8 * 1024;
+const PANE_LINES: usize = 200;
+/// How long a client gets to send its request, and to accept the response
+const IO_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Args)]
+pub struct ServeArgs {
+    /// Address to bind to. Defaults to localhost only.
+    #[arg(long, default_value = "127.0.0.1")]
+    host: String,
+
+    /// Port to listen on
+    #[arg(long, default_value_t = 7878)]
+    port: u16,
+}
+
+#[derive(Debug, Serialize)]
+struct SessionSummary {
+    id: String,
+    title: String,
+    path: String,
+    group: String,
+    tool: String,
+    status: Status,
+    sandboxed: bool,
+    created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<&Instance> for SessionSummary {
+    fn from(inst: &Instance) -> Self {
+        Self {
+            id: inst.id.clone(),
+            title: inst.title.clone(),
+            path: inst.project_path.clone(),
+            group: inst.group_path.clone(),
+            tool: inst.tool.clone(),
+            status: inst.status,
+            sandboxed: inst.is_sandboxed(),
+            created_at: inst.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct SessionDetail {
+    #[serde(flatten)]
+    summary: SessionSummary,
+    /// Captured pane content, or null when the tmux session is not running.
+    pane: Option<String>,
+}
+
+struct Response {
+    status: u16,
+    body: String,
+}
+
+impl Response {
+    fn json<T: Serialize>(value: &T) -> Self {
+        match serde_json::to_string(value) {
+            Ok(body) => Self { status: 200, body },
+            Err(e) => Self::error(500, &e.to_string()),
+        }
+    }
+
+    fn error(status: u16, message: &str) -> Self {
+        Self {
+            status,
+            body: serde_json::json!({ "error": message }).to_string(),
+        }
+    }
+
+    fn to_http(&self) -> String {
+        let reason = match self.status {
+            200 => "OK",
+            400 => "Bad Request",
+            404 => "Not Found",
+            405 => "Method Not Allowed",
+            _ => "Internal Server Error",
+        };
+        format!(
+            "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            self.status,
+            reason,
+            self.body.len(),
+            self.body
+        )
+    }
+}
+
+fn route(manager: &SessionManager, method: &str, path: &str) -> Response {
+    if method != "GET" {
+        return Response::error(405, "only GET is supported");
+    }
+    let path = path.split('?').next().unwrap_or(path).trim_end_matches('/');
+
+    if path == "/api/sessions" {
+        return match manager.list() {
+            Ok(sessions) => {
+                let summaries: Vec<SessionSummary> =
+                    sessions.iter().map(SessionSummary::from).collect();
+                Response::json(&summaries)
+            }
+            Err(e) => Response::error(500, &e.to_string()),
+        };
+    }
+
+    if let Some(id) = path.strip_prefix("/api/sessions/") {
+        let mut inst = match manager.get(id) {
+            Ok(inst) => inst,
+            Err(e) => return Response::error(404, &e.to_string()),
+        };
+        inst.update_status();
+        let pane = inst
+            .tmux_session()
+            .ok()
+            .filter(|s| s.exists())
+            .and_then(|s| s.capture_pane(PANE_LINES).ok());
+        return Response::json(&SessionDetail {
+            summary: SessionSummary::from(&inst),
+            pane,
+        });
+    }
+
+    Response::error(404, "not found")
+}
+
+/// Read the request head, giving up after `IO_TIMEOUT` so idle clients
+/// don't hold connections open.
+async fn read_request(stream: &mut TcpStream) -> Result<Vec<u8>> {
+    let mut buf = Vec::with_capacity(1024);
+    let mut chunk = [0u8; 1024];
+    tokio::time::timeout(IO_TIMEOUT, async {
+        while !buf.windows(4).any(|w| w == b"\r\n\r\n") {
+            let n = stream.read(&mut chunk).await?;
+            if n == 0 || buf.len() + n > MAX_REQUEST_BYTES {
+                break;
+            }
+            buf.extend_from_slice(&chunk[..n]);
+        }
+        Ok::<_, std::io::Error>(())
+    })
+    .await
+    .map_err(|_| anyhow::anyhow!("timed out reading request"))??;
+    Ok(buf)
+}
+
+async fn handle_connection(mut stream: TcpStream, profile: &str) -> Result<()> {
+    let buf = read_request(&mut stream).await?;
+
+    let request = String::from_utf8_lossy(&buf);
+    let mut parts = request.lines().next().unwrap_or("").split_whitespace();
+    let response = match (parts.next(), parts.next()) {
+        (Some(method), Some(path)) => {
+            let (method, path, profile) =
+                (method.to_string(), path.to_string(), profile.to_string());
+            // Storage and tmux calls block, so keep them off the async workers
+            tokio::task::spawn_blocking(move || match SessionManager::new(&profile) {
+                Ok(manager) => route(&manager, &method, &path),
+                Err(e) => Response::error(500, &e.to_string()),
+            })
+            .await?
+        }
+        _ => Response::error(400, "malformed request line"),
+    };
+
+    tokio::time::timeout(IO_TIMEOUT, async {
+        stream.write_all(response.to_http().as_bytes()).await?;
+        stream.shutdown().await
+    })
+    .await
+    .map_err(|_| anyhow::anyhow!("timed out writing response"))??;
+    Ok(())
+}
+
+/// Accept connections on `listener` until the task is dropped.
+async fn serve(listener: TcpListener, profile: String) -> Result<()> {
+    loop {
+        let (stream, peer) = listener.accept().await?;
+        let profile = profile.clone();
+        tokio::spawn(async move {
+            if let Err(e) = handle_connection(stream, &profile).await {
+                tracing::debug!("serve: request from {} failed: {}", peer, e);
+            }
+        });
+    }
+}
+
+pub async fn run(profile: &str, args: ServeArgs) -> Result<()> {
+    let addr: SocketAddr = format!("{}:{}", args.host, args.port)
+        .parse()
+        .map_err(|e| anyhow::anyhow!("Invalid address {}:{}: {}", args.host, args.port, e))?;
+    let listener = TcpListener::bind(addr).await?;
+    infoln!(
+        "Serving read-only session API on http://{}",
+        listener.local_addr()?
+    );
+    infoln!("  GET /api/sessions");
+    infoln!("  GET /api/sessions/<id>");
+    serve(listener, profile.to_string()).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::session::setup_test_home;
+    use serial_test::serial;
+
+    async fn get(addr: SocketAddr, path: &str) -> (String, serde_json::Value) {
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        stream
+            .write_all(format!("GET {} HTTP/1.1\r\nHost: localhost\r\n\r\n", path).as_bytes())
+            .await
+            .unwrap();
+        let mut raw = String::new();
+        stream.read_to_string(&mut raw).await.unwrap();
+        let (head, body) = raw.split_once("\r\n\r\n").unwrap();
+        let status_line = head.lines().next().unwrap().to_string();
+        (status_line, serde_json::from_str(body).unwrap())
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_list_endpoint_returns_profile_sessions() {
+        let temp = tempfile::tempdir().unwrap();
+        setup_test_home(temp.path());
+
+        let manager = SessionManager::new("serve-test").unwrap();
+        let mut inst = Instance::new("api", "/tmp/api");
+        inst.group_path = "work".to_string();
+        let added = manager.add(inst).unwrap();
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = tokio::spawn(serve(listener, "serve-test".to_string()));
+
+        let (status, body) = get(addr, "/api/sessions").await;
+        assert_eq!(status, "HTTP/1.1 200 OK");
+        let sessions = body.as_array().unwrap();
+        assert_eq!(sessions.len(), 1);
+        assert_eq!(sessions[0]["id"], added.id);
+        assert_eq!(sessions[0]["title"], "api");
+        assert_eq!(sessions[0]["group"], "work");
+        assert_eq!(sessions[0]["status"], "idle");
+        assert_eq!(sessions[0]["sandboxed"], false);
+
+        let (status, body) = get(addr, "/api/sessions/nope").await;
+        assert_eq!(status, "HTTP/1.1 404 Not Found");
+        assert!(body["error"].as_str().unwrap().contains("nope"));
+
+        server.abort();
+    }
+}
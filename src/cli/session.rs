@@ -1,15 +1,23 @@
 //! `agent-of-empires session` subcommands implementation
 
+use std::path::PathBuf;
+use std::time::Duration;
+
 use anyhow::{bail, Result};
 use clap::{Args, Subcommand};
 use serde::Serialize;
 
-use crate::session::{GroupTree, Storage};
+use crate::audit::{self, AuditAction, AuditEvent};
+use crate::cli::output::{errln, infoln, outln};
+use crate::session::{
+    group_start_order, normalize_group_path, resolve_config, GroupTree, Instance, RelaunchOnAttach,
+    SessionError, SessionManager, Storage,
+};
 
 #[derive(Subcommand)]
 pub enum SessionCommands {
-    /// Start a session's tmux process
-    Start(SessionIdArgs),
+    /// Start a session's tmux process, or every session in a group
+    Start(StartArgs),
 
     /// Stop session process
     Stop(SessionIdArgs),
@@ -18,7 +26,7 @@ pub enum SessionCommands {
     Restart(SessionIdArgs),
 
     /// Attach to session interactively
-    Attach(SessionIdArgs),
+    Attach(AttachArgs),
 
     /// Show session details
     Show(ShowArgs),
@@ -36,6 +44,36 @@ pub struct SessionIdArgs {
     identifier: String,
 }
 
+#[derive(Args)]
+pub struct StartArgs {
+    /// Session ID or title
+    #[arg(required_unless_present = "group")]
+    identifier: Option<String>,
+
+    /// Start every session in this group (and its subgroups) in ascending
+    /// `start_order`
+    #[arg(short, long, conflicts_with = "identifier")]
+    group: Option<String>,
+
+    /// Seconds to wait between starting group members
+    #[arg(long, value_name = "SECS", default_value_t = 0, requires = "group")]
+    delay: u64,
+}
+
+#[derive(Args)]
+pub struct AttachArgs {
+    /// Session ID or title
+    identifier: String,
+
+    /// Open the session in a new terminal window instead of this one
+    #[arg(long)]
+    window: bool,
+
+    /// Watch the session without sending keystrokes (tmux read-only client)
+    #[arg(long)]
+    readonly: bool,
+}
+
 #[derive(Args)]
 pub struct RenameArgs {
     /// Session ID or title (optional, auto-detects in tmux)
@@ -48,6 +86,11 @@ pub struct RenameArgs {
     /// New group for the session (empty string to ungroup)
     #[arg(short, long)]
     group: Option<String>,
+
+    /// New working directory, e.g. after the project was moved (takes effect
+    /// on the next start)
+    #[arg(long)]
+    path: Option<PathBuf>,
 }
 
 #[derive(Args)]
@@ -62,10 +105,6 @@ pub struct ShowArgs {
 
 #[derive(Args)]
 pub struct CurrentArgs {
-    /// Just session name (for scripting)
-    #[arg(short = 'q', long)]
-    quiet: bool,
-
     /// Output as JSON
     #[arg(long)]
     json: bool,
@@ -97,26 +136,94 @@ pub async fn run(profile: &str, command: SessionCommands) -> Result<()> {
     }
 }
 
-async fn start_session(profile: &str, args: SessionIdArgs) -> Result<()> {
+pub async fn start_session(profile: &str, args: StartArgs) -> Result<()> {
     let storage = Storage::new(profile)?;
     let (mut instances, groups) = storage.load_with_groups()?;
 
-    let idx = instances
-        .iter()
-        .position(|i| {
-            i.id == args.identifier
-                || i.id.starts_with(&args.identifier)
-                || i.title == args.identifier
-        })
-        .ok_or_else(|| anyhow::anyhow!("Session not found: {}", args.identifier))?;
+    if let Some(group) = &args.group {
+        return start_group(&storage, instances, &groups, group, args.delay).await;
+    }
+    let identifier = args.identifier.unwrap_or_default();
+    let idx = session_index(&identifier, &instances)?;
 
+    super::wait_until_sandbox_ready(&mut instances[idx])?;
     instances[idx].start_with_size(crate::terminal::get_size())?;
     let title = instances[idx].title.clone();
+    audit::record(AuditEvent::new(
+        storage.profile(),
+        &instances[idx],
+        AuditAction::Started,
+    ));
 
     let group_tree = GroupTree::new_with_groups(&instances, &groups);
     storage.save_with_groups(&instances, &group_tree)?;
 
-    println!("✓ Started session: {}", title);
+    infoln!("✓ Started session: {}", title);
+    Ok(())
+}
+
+/// Position of the session `identifier` resolves to.
+fn session_index(identifier: &str, instances: &[Instance]) -> Result<usize> {
+    let id = super::resolve_session(identifier, instances)?.id.clone();
+    Ok(instances
+        .iter()
+        .position(|i| i.id == id)
+        .expect("resolved session is in the list"))
+}
+
+async fn start_group(
+    storage: &Storage,
+    mut instances: Vec<Instance>,
+    groups: &[crate::session::Group],
+    group: &str,
+    delay: u64,
+) -> Result<()> {
+    let group = normalize_group_path(group);
+    let order = group_start_order(&instances, &group);
+    if order.is_empty() {
+        return Err(SessionError::GroupNotFound(group).into());
+    }
+
+    let size = crate::terminal::get_size();
+    let result = start_in_order(
+        &order,
+        Duration::from_secs(delay),
+        |idx| {
+            let inst = &mut instances[idx];
+            super::wait_until_sandbox_ready(inst)?;
+            inst.start_with_size(size)?;
+            audit::record(AuditEvent::new(
+                storage.profile(),
+                inst,
+                AuditAction::Started,
+            ));
+            infoln!("✓ Started session: {}", inst.title);
+            Ok(())
+        },
+        tokio::time::sleep,
+    )
+    .await;
+
+    let group_tree = GroupTree::new_with_groups(&instances, groups);
+    storage.save_with_groups(&instances, &group_tree)?;
+    result
+}
+
+/// Start `order` one after another, calling `sleep` with `delay` between
+/// consecutive starts. Stops at the first failure so later members never
+/// come up without the ones they follow.
+async fn start_in_order<F: std::future::Future<Output = ()>>(
+    order: &[usize],
+    delay: Duration,
+    mut start: impl FnMut(usize) -> Result<()>,
+    mut sleep: impl FnMut(Duration) -> F,
+) -> Result<()> {
+    for (n, &idx) in order.iter().enumerate() {
+        if n > 0 && !delay.is_zero() {
+            sleep(delay).await;
+        }
+        start(idx)?;
+    }
     Ok(())
 }
 
@@ -127,19 +234,21 @@ async fn stop_session(profile: &str, args: SessionIdArgs) -> Result<()> {
     let inst = super::resolve_session(&args.identifier, &instances)?;
     let session_id = inst.id.clone();
     let title = inst.title.clone();
-    let tmux_session = crate::tmux::Session::new(&inst.id, &inst.title)?;
+    let tmux_session = inst.tmux_session()?;
     let was_running = tmux_session.exists();
-    let had_container = inst.is_sandboxed()
-        && crate::containers::DockerContainer::from_session_id(&inst.id)
-            .is_running()
-            .unwrap_or(false);
+    let had_container = inst.is_sandboxed() && inst.container().is_running().unwrap_or(false);
 
     if !was_running && !had_container {
-        println!("Session is not running: {}", title);
+        infoln!("Session is not running: {}", title);
         return Ok(());
     }
 
     inst.stop()?;
+    audit::record(AuditEvent::new(
+        storage.profile(),
+        inst,
+        AuditAction::Stopped,
+    ));
 
     // Persist Stopped status to disk so it survives TUI restarts
     if let Some(stored) = instances.iter_mut().find(|i| i.id == session_id) {
@@ -149,9 +258,9 @@ async fn stop_session(profile: &str, args: SessionIdArgs) -> Result<()> {
     storage.save_with_groups(&instances, &group_tree)?;
 
     if had_container {
-        println!("✓ Stopped session and container: {}", title);
+        infoln!("✓ Stopped session and container: {}", title);
     } else {
-        println!("✓ Stopped session: {}", title);
+        infoln!("✓ Stopped session: {}", title);
     }
 
     Ok(())
@@ -161,40 +270,101 @@ async fn restart_session(profile: &str, args: SessionIdArgs) -> Result<()> {
     let storage = Storage::new(profile)?;
     let (mut instances, groups) = storage.load_with_groups()?;
 
-    let idx = instances
-        .iter()
-        .position(|i| {
-            i.id == args.identifier
-                || i.id.starts_with(&args.identifier)
-                || i.title == args.identifier
-        })
-        .ok_or_else(|| anyhow::anyhow!("Session not found: {}", args.identifier))?;
+    let idx = session_index(&args.identifier, &instances)?;
 
+    super::wait_until_sandbox_ready(&mut instances[idx])?;
     instances[idx].restart_with_size(crate::terminal::get_size())?;
     let title = instances[idx].title.clone();
+    audit::record(AuditEvent::new(
+        storage.profile(),
+        &instances[idx],
+        AuditAction::Restarted,
+    ));
 
     let group_tree = GroupTree::new_with_groups(&instances, &groups);
     storage.save_with_groups(&instances, &group_tree)?;
 
-    println!("✓ Restarted session: {}", title);
+    infoln!("✓ Restarted session: {}", title);
     Ok(())
 }
 
-async fn attach_session(profile: &str, args: SessionIdArgs) -> Result<()> {
-    let storage = Storage::new(profile)?;
-    let (instances, _) = storage.load_with_groups()?;
-
-    let inst = super::resolve_session(&args.identifier, &instances)?;
-    let tmux_session = crate::tmux::Session::new(&inst.id, &inst.title)?;
+pub async fn attach_session(profile: &str, args: AttachArgs) -> Result<()> {
+    let manager = SessionManager::new(profile)?;
+    let inst = manager.get(&args.identifier)?;
+    let tmux_session = inst.tmux_session()?;
 
     if !tmux_session.exists() {
+        inst.check_working_dir()?;
+        if !crate::tmux::is_server_running() {
+            bail!(
+                "No tmux server is running, so no sessions are active. Start this one with: agent-of-empires session start {}",
+                args.identifier
+            );
+        }
         bail!(
             "Session is not running. Start it first with: agent-of-empires session start {}",
             args.identifier
         );
     }
 
-    tmux_session.attach()?;
+    if !args.readonly && inst.agent_exited() && should_relaunch(profile, &inst)? {
+        relaunch_agent(profile, &inst.id)?;
+    }
+
+    if args.window {
+        let command = manager.attach_command(&inst.id, args.readonly)?;
+        crate::terminal::open_in_new_window(&command)?;
+        infoln!("✓ Opened {} in a new terminal window", inst.title);
+        return Ok(());
+    }
+
+    tmux_session.attach(args.readonly)?;
+    Ok(())
+}
+
+/// Decide, per `session.relaunch_on_attach`, whether to relaunch an agent
+/// that has exited. Prompt mode asks on an interactive terminal only.
+fn should_relaunch(profile: &str, inst: &Instance) -> Result<bool> {
+    use std::io::{self, IsTerminal, Write};
+
+    let mode = resolve_config(profile)
+        .map(|c| c.session.relaunch_on_attach)
+        .unwrap_or_default();
+    match mode {
+        RelaunchOnAttach::Off => Ok(false),
+        RelaunchOnAttach::Auto => Ok(true),
+        RelaunchOnAttach::Prompt => {
+            if !io::stdin().is_terminal() {
+                return Ok(false);
+            }
+            print!(
+                "The agent in '{}' has exited. Relaunch {}? (Y/n): ",
+                inst.title, inst.tool
+            );
+            io::stdout().flush()?;
+            let mut response = String::new();
+            io::stdin().read_line(&mut response)?;
+            let response = response.trim().to_lowercase();
+            Ok(response.is_empty() || response == "y" || response == "yes")
+        }
+    }
+}
+
+fn relaunch_agent(profile: &str, id: &str) -> Result<()> {
+    let storage = Storage::new(profile)?;
+    let (mut instances, groups) = storage.load_with_groups()?;
+    let Some(inst) = instances.iter_mut().find(|i| i.id == id) else {
+        return Ok(());
+    };
+    super::wait_until_sandbox_ready(inst)?;
+    inst.restart_with_size(crate::terminal::get_size())?;
+    audit::record(AuditEvent::new(
+        storage.profile(),
+        inst,
+        AuditAction::Restarted,
+    ));
+    let group_tree = GroupTree::new_with_groups(&instances, &groups);
+    storage.save_with_groups(&instances, &group_tree)?;
     Ok(())
 }
 
@@ -214,7 +384,7 @@ async fn show_session(profile: &str, args: ShowArgs) -> Result<()> {
             instances
                 .iter()
                 .find(|i| {
-                    let tmux_name = crate::tmux::Session::generate_name(&i.id, &i.title);
+                    let tmux_name = i.tmux_name();
                     tmux_name == session_name
                 })
                 .ok_or_else(|| {
@@ -239,16 +409,16 @@ async fn show_session(profile: &str, args: ShowArgs) -> Result<()> {
         };
         println!("{}", serde_json::to_string_pretty(&details)?);
     } else {
-        println!("Session: {}", inst.title);
-        println!("  ID:      {}", inst.id);
-        println!("  Path:    {}", inst.project_path);
-        println!("  Group:   {}", inst.group_path);
-        println!("  Tool:    {}", inst.tool);
-        println!("  Command: {}", inst.command);
-        println!("  Status:  {:?}", inst.status);
-        println!("  Profile: {}", storage.profile());
+        outln!("Session: {}", inst.title);
+        outln!("  ID:      {}", inst.id);
+        outln!("  Path:    {}", inst.project_path);
+        outln!("  Group:   {}", inst.group_path);
+        outln!("  Tool:    {}", inst.tool);
+        outln!("  Command: {}", inst.command);
+        outln!("  Status:  {:?}", inst.status);
+        outln!("  Profile: {}", storage.profile());
         if let Some(parent_id) = &inst.parent_session_id {
-            println!("  Parent:  {}", parent_id);
+            outln!("  Parent:  {}", parent_id);
         }
     }
 
@@ -256,10 +426,21 @@ async fn show_session(profile: &str, args: ShowArgs) -> Result<()> {
 }
 
 async fn rename_session(profile: &str, args: RenameArgs) -> Result<()> {
-    if args.title.is_none() && args.group.is_none() {
-        bail!("At least one of --title or --group must be specified");
+    if args.title.is_none() && args.group.is_none() && args.path.is_none() {
+        bail!("At least one of --title, --group or --path must be specified");
     }
 
+    let new_path = match &args.path {
+        Some(path) if !path.is_dir() => {
+            bail!(
+                "Path does not exist or is not a directory: {}",
+                path.display()
+            )
+        }
+        Some(path) => Some(path.canonicalize()?.to_string_lossy().into_owned()),
+        None => None,
+    };
+
     let storage = Storage::new(profile)?;
     let (mut instances, groups) = storage.load_with_groups()?;
 
@@ -275,7 +456,7 @@ async fn rename_session(profile: &str, args: RenameArgs) -> Result<()> {
             instances
                 .iter()
                 .find(|i| {
-                    let tmux_name = crate::tmux::Session::generate_name(&i.id, &i.title);
+                    let tmux_name = i.tmux_name();
                     tmux_name == session_name
                 })
                 .ok_or_else(|| {
@@ -295,18 +476,21 @@ async fn rename_session(profile: &str, args: RenameArgs) -> Result<()> {
     let idx = instances
         .iter()
         .position(|i| i.id == id)
-        .ok_or_else(|| anyhow::anyhow!("Session not found"))?;
+        .ok_or_else(|| SessionError::NotFound(id.to_string()))?;
 
     // Rename tmux session if title changed
     if instances[idx].title != effective_title {
-        let tmux_session = crate::tmux::Session::new(&id, &instances[idx].title)?;
+        let tmux_session = instances[idx].tmux_session()?;
         if tmux_session.exists() {
             let new_tmux_name = crate::tmux::Session::generate_name(&id, &effective_title);
             if let Err(e) = tmux_session.rename(&new_tmux_name) {
-                eprintln!("Warning: failed to rename tmux session: {}", e);
+                errln!("Warning: failed to rename tmux session: {}", e);
             } else {
+                instances[idx].tmux_name = Some(new_tmux_name);
                 crate::tmux::refresh_session_cache();
             }
+        } else {
+            instances[idx].tmux_name = None;
         }
     }
 
@@ -316,6 +500,10 @@ async fn rename_session(profile: &str, args: RenameArgs) -> Result<()> {
         instances[idx].group_path = group.trim().to_string();
     }
 
+    if let Some(path) = new_path {
+        instances[idx].project_path = path;
+    }
+
     let mut group_tree = GroupTree::new_with_groups(&instances, &groups);
     if !instances[idx].group_path.is_empty() {
         group_tree.create_group(&instances[idx].group_path);
@@ -323,9 +511,9 @@ async fn rename_session(profile: &str, args: RenameArgs) -> Result<()> {
     storage.save_with_groups(&instances, &group_tree)?;
 
     if old_title != effective_title {
-        println!("✓ Renamed session: {} → {}", old_title, effective_title);
+        infoln!("✓ Renamed session: {} → {}", old_title, effective_title);
     } else {
-        println!("✓ Updated session: {}", effective_title);
+        infoln!("✓ Updated session: {}", effective_title);
     }
 
     Ok(())
@@ -346,7 +534,7 @@ async fn current_session(args: CurrentArgs) -> Result<()> {
         if let Ok(storage) = Storage::new(profile_name) {
             if let Ok((instances, _)) = storage.load_with_groups() {
                 if let Some(inst) = instances.iter().find(|i| {
-                    let tmux_name = crate::tmux::Session::generate_name(&i.id, &i.title);
+                    let tmux_name = i.tmux_name();
                     tmux_name == session_name
                 }) {
                     if args.json {
@@ -362,12 +550,12 @@ async fn current_session(args: CurrentArgs) -> Result<()> {
                             id: inst.id.clone(),
                         };
                         println!("{}", serde_json::to_string_pretty(&info)?);
-                    } else if args.quiet {
+                    } else if crate::cli::output::is_quiet() {
                         println!("{}", inst.title);
                     } else {
-                        println!("Session: {}", inst.title);
-                        println!("Profile: {}", profile_name);
-                        println!("ID:      {}", inst.id);
+                        outln!("Session: {}", inst.title);
+                        outln!("Profile: {}", profile_name);
+                        outln!("ID:      {}", inst.id);
                     }
                     return Ok(());
                 }
@@ -377,3 +565,76 @@ async fn current_session(args: CurrentArgs) -> Result<()> {
 
     bail!("Current tmux session is not an Agent of Empires session")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::{Cli, Commands};
+    use clap::Parser;
+
+    #[test]
+    fn test_top_level_attach_accepts_readonly_and_window() {
+        let cli = Cli::try_parse_from(["aoe", "attach", "demo", "--readonly", "--window"]).unwrap();
+        let Some(Commands::Attach(args)) = cli.command else {
+            panic!("expected the attach command");
+        };
+        assert_eq!(args.identifier, "demo");
+        assert!(args.readonly);
+        assert!(args.window);
+    }
+
+    #[test]
+    fn test_top_level_start_accepts_group_and_delay() {
+        let cli = Cli::try_parse_from(["aoe", "start", "--group", "work", "--delay", "5"]).unwrap();
+        let Some(Commands::Start(args)) = cli.command else {
+            panic!("expected the start command");
+        };
+        assert_eq!(args.identifier, None);
+        assert_eq!(args.group.as_deref(), Some("work"));
+        assert_eq!(args.delay, 5);
+    }
+
+    #[tokio::test]
+    async fn test_start_in_order_waits_between_members() {
+        let events = std::cell::RefCell::new(Vec::new());
+        start_in_order(
+            &[2, 0, 1],
+            Duration::from_secs(3),
+            |idx| {
+                events.borrow_mut().push(format!("start {}", idx));
+                Ok(())
+            },
+            |d| {
+                events.borrow_mut().push(format!("sleep {}", d.as_secs()));
+                std::future::ready(())
+            },
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            events.into_inner(),
+            vec!["start 2", "sleep 3", "start 0", "sleep 3", "start 1"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_start_in_order_stops_at_first_failure() {
+        let mut started = Vec::new();
+        let result = start_in_order(
+            &[0, 1, 2],
+            Duration::ZERO,
+            |idx| {
+                if idx == 1 {
+                    bail!("boom");
+                }
+                started.push(idx);
+                Ok(())
+            },
+            |_| -> std::future::Ready<()> { panic!("no delay configured") },
+        )
+        .await;
+        assert!(result.is_err());
+        assert_eq!(started, vec![0]);
+    }
+}
@@ -3,6 +3,7 @@
 use anyhow::Result;
 use clap::Subcommand;
 
+use crate::cli::output::{errln, infoln, outln, ProgressIndicator};
 use crate::sound;
 
 #[derive(Subcommand)]
@@ -30,42 +31,46 @@ pub async fn run(command: SoundsCommands) -> Result<()> {
 }
 
 async fn install_bundled() -> Result<()> {
-    println!("📥 Downloading bundled CC0 sounds from GitHub...\n");
+    infoln!("📥 Downloading bundled CC0 sounds from GitHub...\n");
 
-    match sound::install_bundled_sounds().await {
+    let indicator = ProgressIndicator::start();
+    let result = sound::install_bundled_sounds().await;
+    drop(indicator);
+
+    match result {
         Ok(()) => {
             if let Some(sounds_dir) = sound::get_sounds_dir() {
-                println!("\n✓ Successfully installed bundled CC0 sounds to:");
-                println!("  {}\n", sounds_dir.display());
+                infoln!("\n✓ Successfully installed bundled CC0 sounds to:");
+                infoln!("  {}\n", sounds_dir.display());
 
                 let sounds = sound::list_available_sounds();
-                println!("📂 Installed {} sounds:", sounds.len());
+                infoln!("📂 Installed {} sounds:", sounds.len());
                 for sound_name in sounds {
-                    println!("  • {}", sound_name);
+                    infoln!("  • {}", sound_name);
                 }
 
-                println!("\n💡 Next steps:");
-                println!("  1. Launch the TUI: aoe");
-                println!("  2. Press 's' to open Settings");
-                println!("  3. Navigate to Sound category");
-                println!("  4. Enable sounds and configure transitions");
-
-                println!("\n🎮 Want Age of Empires II sounds instead?");
-                println!("   If you own AoE II, copy the taunt .wav files from:");
-                println!("   • (AoE II dir)/resources/_common/sound/taunt/");
-                println!("   • Or: (AoE II dir)/Sound/taunt/");
-                println!("   To: {}", sounds_dir.display());
-                println!("\n   Then configure which sounds to use in Settings!");
+                infoln!("\n💡 Next steps:");
+                infoln!("  1. Launch the TUI: aoe");
+                infoln!("  2. Press 's' to open Settings");
+                infoln!("  3. Navigate to Sound category");
+                infoln!("  4. Enable sounds and configure transitions");
+
+                infoln!("\n🎮 Want Age of Empires II sounds instead?");
+                infoln!("   If you own AoE II, copy the taunt .wav files from:");
+                infoln!("   • (AoE II dir)/resources/_common/sound/taunt/");
+                infoln!("   • Or: (AoE II dir)/Sound/taunt/");
+                infoln!("   To: {}", sounds_dir.display());
+                infoln!("\n   Then configure which sounds to use in Settings!");
             }
             Ok(())
         }
         Err(e) => {
-            eprintln!("\n❌ Failed to install sounds: {}", e);
-            eprintln!("\n💡 Troubleshooting:");
-            eprintln!("  • Check your internet connection");
-            eprintln!("  • Try again later if GitHub is unavailable");
-            eprintln!("  • You can manually download sounds from:");
-            eprintln!("    https://github.com/njbrake/agent-of-empires/tree/main/bundled_sounds");
+            errln!("\n❌ Failed to install sounds: {}", e);
+            errln!("\n💡 Troubleshooting:");
+            errln!("  • Check your internet connection");
+            errln!("  • Try again later if GitHub is unavailable");
+            errln!("  • You can manually download sounds from:");
+            errln!("    https://github.com/njbrake/agent-of-empires/tree/main/bundled_sounds");
             Err(e)
         }
     }
@@ -75,22 +80,22 @@ fn list_sounds() -> Result<()> {
     let sounds = sound::list_available_sounds();
 
     if sounds.is_empty() {
-        println!("No sounds installed yet.");
-        println!("\nRun 'aoe sounds install' to get started.");
+        infoln!("No sounds installed yet.");
+        infoln!("\nRun 'aoe sounds install' to get started.");
         return Ok(());
     }
 
-    println!("📂 Installed sounds:");
+    infoln!("📂 Installed sounds:");
     for sound_name in &sounds {
-        println!("  • {}", sound_name);
+        outln!("  • {}", sound_name);
     }
-    println!("\nTotal: {} sounds", sounds.len());
+    infoln!("\nTotal: {} sounds", sounds.len());
 
     if let Some(sounds_dir) = sound::get_sounds_dir() {
-        println!("\nLocation: {}", sounds_dir.display());
+        infoln!("\nLocation: {}", sounds_dir.display());
     }
 
-    println!("\n💡 Test a sound: aoe sounds test <name>");
+    infoln!("\n💡 Test a sound: aoe sounds test <name>");
 
     Ok(())
 }
@@ -99,10 +104,10 @@ fn test_sound(name: &str) -> Result<()> {
     let sounds = sound::list_available_sounds();
 
     if !sounds.contains(&name.to_string()) {
-        println!("❌ Sound '{}' not found.", name);
-        println!("\n📂 Available sounds:");
+        infoln!("❌ Sound '{}' not found.", name);
+        infoln!("\n📂 Available sounds:");
         for sound_name in sounds {
-            println!("  • {}", sound_name);
+            infoln!("  • {}", sound_name);
         }
         return Ok(());
     }
@@ -112,22 +117,22 @@ fn test_sound(name: &str) -> Result<()> {
 
     match sound::play_sound_blocking(name) {
         Ok(()) => {
-            println!("✓");
+            infoln!("✓");
             Ok(())
         }
         Err(e) => {
-            println!("✗");
-            eprintln!("\n❌ Failed to play sound: {}", e);
-            eprintln!("\n💡 Troubleshooting:");
+            infoln!("✗");
+            errln!("\n❌ Failed to play sound: {}", e);
+            errln!("\n💡 Troubleshooting:");
             if cfg!(target_os = "linux") {
-                eprintln!("  • Ensure audio tools are installed:");
-                eprintln!("    - Debian/Ubuntu: sudo apt install alsa-utils pulseaudio-utils");
-                eprintln!("    - Arch: sudo pacman -S alsa-utils pulseaudio");
-                eprintln!("  • Check that your audio device is working");
-                eprintln!("  • Note: Audio doesn't work over SSH sessions");
+                errln!("  • Ensure audio tools are installed:");
+                errln!("    - Debian/Ubuntu: sudo apt install alsa-utils pulseaudio-utils");
+                errln!("    - Arch: sudo pacman -S alsa-utils pulseaudio");
+                errln!("  • Check that your audio device is working");
+                errln!("  • Note: Audio doesn't work over SSH sessions");
             } else {
-                eprintln!("  • Check that your audio device is working");
-                eprintln!("  • Note: Audio doesn't work over SSH sessions");
+                errln!("  • Check that your audio device is working");
+                errln!("  • Note: Audio doesn't work over SSH sessions");
             }
             Err(e.into())
         }
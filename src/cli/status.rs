@@ -4,6 +4,7 @@ use anyhow::Result;
 use clap::Args;
 use serde::Serialize;
 
+use crate::cli::output::outln;
 use crate::session::{Status, Storage};
 
 #[derive(Args)]
@@ -12,10 +13,6 @@ pub struct StatusArgs {
     #[arg(short = 'v', long)]
     verbose: bool,
 
-    /// Only output waiting count (for scripts)
-    #[arg(short = 'q', long)]
-    quiet: bool,
-
     /// Output as JSON
     #[arg(long)]
     json: bool,
@@ -41,7 +38,9 @@ struct StatusJson {
     total: usize,
 }
 
+/// With the global `--quiet`, only the waiting count is printed.
 pub async fn run(profile: &str, args: StatusArgs) -> Result<()> {
+    let quiet = crate::cli::output::is_quiet();
     let storage = Storage::new(profile)?;
     let (mut instances, _) = storage.load_with_groups()?;
 
@@ -50,10 +49,10 @@ pub async fn run(profile: &str, args: StatusArgs) -> Result<()> {
             println!(
                 r#"{{"waiting": 0, "running": 0, "idle": 0, "stopped": 0, "error": 0, "total": 0}}"#
             );
-        } else if args.quiet {
+        } else if quiet {
             println!("0");
         } else {
-            println!("No sessions in profile '{}'.", storage.profile());
+            outln!("No sessions in profile '{}'.", storage.profile());
         }
         return Ok(());
     }
@@ -78,33 +77,39 @@ pub async fn run(profile: &str, args: StatusArgs) -> Result<()> {
             total: counts.total,
         };
         println!("{}", serde_json::to_string(&status_json)?);
-    } else if args.quiet {
+    } else if quiet {
         println!("{}", counts.waiting);
     } else if args.verbose {
-        print_status_group("WAITING", "◐", Status::Waiting, &instances);
-        print_status_group("RUNNING", "●", Status::Running, &instances);
-        print_status_group("IDLE", "○", Status::Idle, &instances);
-        print_status_group("STOPPED", "■", Status::Stopped, &instances);
-        print_status_group("ERROR", "✕", Status::Error, &instances);
-        println!(
+        print_status_group("WAITING", Status::Waiting, &instances);
+        print_status_group("RUNNING", Status::Running, &instances);
+        print_status_group("IDLE", Status::Idle, &instances);
+        print_status_group("STOPPED", Status::Stopped, &instances);
+        print_status_group("ERROR", Status::Error, &instances);
+        outln!(
             "Total: {} sessions in profile '{}'",
             counts.total,
             storage.profile()
         );
     } else if counts.stopped > 0 {
-        println!(
+        outln!(
             "{} waiting • {} running • {} idle • {} stopped",
-            counts.waiting, counts.running, counts.idle, counts.stopped
+            counts.waiting,
+            counts.running,
+            counts.idle,
+            counts.stopped
         );
     } else {
-        println!(
+        outln!(
             "{} waiting • {} running • {} idle",
-            counts.waiting, counts.running, counts.idle
+            counts.waiting,
+            counts.running,
+            counts.idle
         );
     }
 
-    // Show update notice if available (skip for JSON/quiet output)
-    if !args.json && !args.quiet {
+    // Show update notice if available (skip for JSON output; quiet is handled
+    // by the notice itself)
+    if !args.json {
         crate::update::print_update_notice().await;
     }
 
@@ -128,23 +133,24 @@ fn count_by_status(instances: &[crate::session::Instance]) -> StatusCounts {
     counts
 }
 
-fn print_status_group(
-    label: &str,
-    symbol: &str,
-    status: Status,
-    instances: &[crate::session::Instance],
-) {
+fn print_status_group(label: &str, status: Status, instances: &[crate::session::Instance]) {
     let matching: Vec<_> = instances.iter().filter(|i| i.status == status).collect();
     if matching.is_empty() {
         return;
     }
 
-    println!("{} ({}):", label, matching.len());
+    outln!("{} ({}):", label, matching.len());
     for inst in matching {
         let path = shorten_path(&inst.project_path);
-        println!("  {} {:<16} {:<10} {}", symbol, inst.title, inst.tool, path);
+        outln!(
+            "  {} {:<16} {:<10} {}",
+            status.icon(),
+            inst.title,
+            inst.tool,
+            path
+        );
     }
-    println!();
+    outln!();
 }
 
 fn shorten_path(path: &str) -> String {
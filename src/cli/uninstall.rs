@@ -1,5 +1,6 @@
 //! `agent-of-empires uninstall` command implementation
 
+use crate::cli::output::infoln;
 use anyhow::Result;
 use clap::Args;
 use std::fs;
@@ -32,14 +33,14 @@ struct FoundItem {
 }
 
 pub async fn run(args: UninstallArgs) -> Result<()> {
-    println!("╔════════════════════════════════════════╗");
-    println!("║     Agent of Empires Uninstaller       ║");
-    println!("╚════════════════════════════════════════╝");
-    println!();
+    infoln!("╔════════════════════════════════════════╗");
+    infoln!("║     Agent of Empires Uninstaller       ║");
+    infoln!("╚════════════════════════════════════════╝");
+    infoln!();
 
     if args.dry_run {
-        println!("DRY RUN MODE - Nothing will be removed");
-        println!();
+        infoln!("DRY RUN MODE - Nothing will be removed");
+        infoln!();
     }
 
     let home_dir = dirs::home_dir().ok_or_else(|| anyhow::anyhow!("Cannot find home directory"))?;
@@ -70,7 +71,7 @@ pub async fn run(args: UninstallArgs) -> Result<()> {
             item_type: "homebrew".to_string(),
             path: PathBuf::new(),
         });
-        println!("Found: Homebrew installation");
+        infoln!("Found: Homebrew installation");
     }
 
     // Check common binary locations for both "aoe" and "agent-of-empires"
@@ -98,7 +99,7 @@ pub async fn run(args: UninstallArgs) -> Result<()> {
                 item_type: "binary".to_string(),
                 path: loc.clone(),
             });
-            println!("Found: Binary at {}", loc.display());
+            infoln!("Found: Binary at {}", loc.display());
         }
     }
 
@@ -126,10 +127,11 @@ pub async fn run(args: UninstallArgs) -> Result<()> {
                 item_type: "data".to_string(),
                 path: data_dir.clone(),
             });
-            println!("Found: Data directory at {}", data_dir.display());
-            println!(
+            infoln!("Found: Data directory at {}", data_dir.display());
+            infoln!(
                 "       {} profiles, {} sessions",
-                profile_count, session_count
+                profile_count,
+                session_count
             );
         }
     }
@@ -142,45 +144,45 @@ pub async fn run(args: UninstallArgs) -> Result<()> {
                 item_type: "tmux".to_string(),
                 path: tmux_conf.clone(),
             });
-            println!("Found: tmux configuration in ~/.tmux.conf");
+            infoln!("Found: tmux configuration in ~/.tmux.conf");
         }
     }
 
-    println!();
+    infoln!();
 
     if found_items.is_empty() {
-        println!("Agent of Empires does not appear to be installed.");
+        infoln!("Agent of Empires does not appear to be installed.");
         return Ok(());
     }
 
     // Summary
-    println!("The following will be removed:");
-    println!();
+    infoln!("The following will be removed:");
+    infoln!();
 
     for item in &found_items {
         match item.item_type.as_str() {
-            "homebrew" => println!("  • Homebrew package: aoe"),
-            "binary" => println!("  • Binary: {}", item.path.display()),
+            "homebrew" => infoln!("  • Homebrew package: aoe"),
+            "binary" => infoln!("  • Binary: {}", item.path.display()),
             "data" => {
                 if args.keep_data {
-                    println!("  ○ Data directory: {} (keeping)", item.path.display());
+                    infoln!("  ○ Data directory: {} (keeping)", item.path.display());
                 } else {
-                    println!("  • Data directory: {}", item.path.display());
-                    println!("    Including: sessions, logs, config");
+                    infoln!("  • Data directory: {}", item.path.display());
+                    infoln!("    Including: sessions, logs, config");
                 }
             }
             "tmux" => {
                 if args.keep_tmux_config {
-                    println!("  ○ tmux config: ~/.tmux.conf (keeping)");
+                    infoln!("  ○ tmux config: ~/.tmux.conf (keeping)");
                 } else {
-                    println!("  • tmux config block in ~/.tmux.conf");
+                    infoln!("  • tmux config block in ~/.tmux.conf");
                 }
             }
             _ => {}
         }
     }
 
-    println!();
+    infoln!();
 
     // Confirm
     if !args.yes && !args.dry_run {
@@ -191,32 +193,32 @@ pub async fn run(args: UninstallArgs) -> Result<()> {
         io::stdin().read_line(&mut response)?;
 
         if response.trim().to_lowercase() != "y" {
-            println!("Uninstall cancelled.");
+            infoln!("Uninstall cancelled.");
             return Ok(());
         }
-        println!();
+        infoln!();
     }
 
     if args.dry_run {
-        println!("Dry run complete. No changes made.");
+        infoln!("Dry run complete. No changes made.");
         return Ok(());
     }
 
-    println!("Uninstalling...");
-    println!();
+    infoln!("Uninstalling...");
+    infoln!();
 
     // Perform uninstall
     for item in &found_items {
         match item.item_type.as_str() {
             "homebrew" => {
-                println!("Removing Homebrew package...");
+                infoln!("Removing Homebrew package...");
                 let _ = Command::new("brew").args(["uninstall", "aoe"]).status();
-                println!("✓ Homebrew package removed");
+                infoln!("✓ Homebrew package removed");
             }
             "binary" => {
-                println!("Removing binary at {}...", item.path.display());
+                infoln!("Removing binary at {}...", item.path.display());
                 if fs::remove_file(&item.path).is_ok() {
-                    println!("✓ Binary removed: {}", item.path.display());
+                    infoln!("✓ Binary removed: {}", item.path.display());
                 } else {
                     // Try with sudo
                     let _ = Command::new("sudo")
@@ -225,13 +227,13 @@ pub async fn run(args: UninstallArgs) -> Result<()> {
                 }
             }
             "data" if !args.keep_data => {
-                println!("Removing data directory...");
+                infoln!("Removing data directory...");
                 if fs::remove_dir_all(&item.path).is_ok() {
-                    println!("✓ Data directory removed: {}", item.path.display());
+                    infoln!("✓ Data directory removed: {}", item.path.display());
                 }
             }
             "tmux" if !args.keep_tmux_config => {
-                println!("Removing tmux configuration...");
+                infoln!("Removing tmux configuration...");
                 if let Ok(content) = fs::read_to_string(&item.path) {
                     // Backup
                     let backup_path = format!("{}.bak.aoe-uninstall", item.path.display());
@@ -251,7 +253,7 @@ pub async fn run(args: UninstallArgs) -> Result<()> {
                         }
                         new_content = new_content.trim_end().to_string() + "\n";
                         if fs::write(&item.path, new_content).is_ok() {
-                            println!("✓ tmux configuration removed (backup: {})", backup_path);
+                            infoln!("✓ tmux configuration removed (backup: {})", backup_path);
                         }
                     }
                 }
@@ -260,11 +262,11 @@ pub async fn run(args: UninstallArgs) -> Result<()> {
         }
     }
 
-    println!();
-    println!("╔════════════════════════════════════════╗");
-    println!("║     Uninstall complete!                ║");
-    println!("╚════════════════════════════════════════╝");
-    println!();
+    infoln!();
+    infoln!("╔════════════════════════════════════════╗");
+    infoln!("║     Uninstall complete!                ║");
+    infoln!("╚════════════════════════════════════════╝");
+    infoln!();
 
     if args.keep_data {
         let preserved: Vec<_> = found_items
@@ -272,17 +274,17 @@ pub async fn run(args: UninstallArgs) -> Result<()> {
             .filter(|i| i.item_type == "data")
             .collect();
         for item in preserved {
-            println!("Note: Data directory preserved at {}", item.path.display());
+            infoln!("Note: Data directory preserved at {}", item.path.display());
         }
     }
 
     if args.keep_tmux_config {
-        println!("Note: tmux config preserved in ~/.tmux.conf");
+        infoln!("Note: tmux config preserved in ~/.tmux.conf");
     }
 
-    println!();
-    println!("Thank you for using Agent of Empires!");
-    println!("Feedback: https://github.com/njbrake/agent-of-empires/issues");
+    infoln!();
+    infoln!("Thank you for using Agent of Empires!");
+    infoln!("Feedback: https://github.com/njbrake/agent-of-empires/issues");
 
     Ok(())
 }
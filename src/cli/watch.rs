@@ -0,0 +1,135 @@
+//! `agent-of-empires watch` command implementation
+
+use std::io::{IsTerminal, Write};
+use std::time::Duration;
+
+use anyhow::Result;
+use clap::Args;
+
+use crate::cli::output::{self, outln};
+use crate::session::{Instance, SessionManager};
+use crate::tui::status_poller::StatusPoller;
+
+const COL_STATUS: usize = 10;
+const COL_TITLE: usize = 24;
+const COL_TOOL: usize = 10;
+const COL_GROUP: usize = 16;
+/// How long to wait for the poller before drawing stale statuses
+const POLL_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Args)]
+pub struct WatchArgs {
+    /// Seconds between refreshes
+    #[arg(short = 'n', long, default_value_t = 2, value_parser = clap::value_parser!(u64).range(1..))]
+    interval: u64,
+
+    /// Print the table once and exit
+    #[arg(long)]
+    once: bool,
+}
+
+/// One line per session: status, title, tool, group and any status note.
+fn format_table(instances: &[Instance]) -> Vec<String> {
+    let mut lines = vec![format!(
+        "  {:<COL_STATUS$} {:<COL_TITLE$} {:<COL_TOOL$} {:<COL_GROUP$} NOTE",
+        "STATUS", "TITLE", "TOOL", "GROUP"
+    )];
+    for inst in instances {
+        let status = format!("{:?}", inst.status).to_lowercase();
+        let line = format!(
+            "{} {:<COL_STATUS$} {:<COL_TITLE$} {:<COL_TOOL$} {:<COL_GROUP$} {}",
+            inst.status.icon(),
+            status,
+            super::truncate(&inst.title, COL_TITLE),
+            super::truncate(&inst.tool, COL_TOOL),
+            super::truncate(&inst.group_path, COL_GROUP),
+            inst.status_note.as_deref().unwrap_or("")
+        );
+        lines.push(line.trim_end().to_string());
+    }
+    lines
+}
+
+/// Refresh statuses through the background poller, keeping the stored status
+/// for any session the poller does not report back in time.
+fn poll(poller: &StatusPoller, instances: &mut [Instance]) {
+    poller.request_refresh(instances.to_vec());
+    let Some(updates) = poller.recv_updates(POLL_TIMEOUT) else {
+        return;
+    };
+    for update in updates {
+        if let Some(inst) = instances.iter_mut().find(|i| i.id == update.id) {
+            inst.status = update.status;
+            inst.status_note = update.note;
+        }
+    }
+}
+
+pub async fn run(profile: &str, args: WatchArgs) -> Result<()> {
+    let manager = SessionManager::new(profile)?;
+    let poller = StatusPoller::new();
+    // Clear-and-redraw only on a real terminal; pipes get appended snapshots
+    let redraw = !args.once && std::io::stdout().is_terminal() && !output::is_plain();
+
+    loop {
+        let mut instances = manager.list()?;
+        poll(&poller, &mut instances);
+
+        if redraw {
+            print!("\x1b[2J\x1b[H");
+        }
+        outln!(
+            "Profile '{}' at {} (every {}s, Ctrl-C to stop)",
+            manager.profile(),
+            chrono::Local::now().format("%H:%M:%S"),
+            args.interval
+        );
+        if instances.is_empty() {
+            outln!("No sessions.");
+        } else {
+            for line in format_table(&instances) {
+                outln!("{}", line);
+            }
+        }
+        std::io::stdout().flush()?;
+
+        if args.once {
+            return Ok(());
+        }
+        if !redraw {
+            outln!();
+        }
+        tokio::time::sleep(Duration::from_secs(args.interval)).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::session::Status;
+
+    #[test]
+    fn test_format_table() {
+        let mut api = Instance::new("api", "/tmp/api");
+        api.status = Status::Running;
+        api.group_path = "work".to_string();
+        api.status_note = Some("compacting".to_string());
+        let mut web = Instance::new("a-very-long-session-title-here", "/tmp/web");
+        web.status = Status::Waiting;
+        web.tool = "opencode".to_string();
+
+        let lines = format_table(&[api, web]);
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].starts_with("  STATUS"));
+        assert!(lines[0].ends_with("NOTE"));
+        assert_eq!(
+            lines[1],
+            format!(
+                "● {:<10} {:<24} {:<10} {:<16} compacting",
+                "running", "api", "claude", "work"
+            )
+        );
+        assert!(lines[2].starts_with("◐ waiting    a-very-long-session-t... opencode"));
+        assert!(!lines[2].ends_with(' '));
+    }
+}
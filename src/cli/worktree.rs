@@ -4,6 +4,7 @@ use anyhow::{bail, Result};
 use clap::Subcommand;
 use std::path::{Path, PathBuf};
 
+use crate::cli::output::{errln, infoln, outln};
 use crate::git::GitWorktree;
 use crate::session::Storage;
 
@@ -46,9 +47,9 @@ async fn list_worktrees() -> Result<()> {
 
     let worktrees = git_wt.list_worktrees()?;
 
-    println!("Git Worktrees:\n");
-    println!("{:<40} {:<30} {:<10}", "PATH", "BRANCH", "TYPE");
-    println!("{}", "=".repeat(80));
+    infoln!("Git Worktrees:\n");
+    outln!("{:<40} {:<30} {:<10}", "PATH", "BRANCH", "TYPE");
+    outln!("{}", "=".repeat(80));
 
     for wt in &worktrees {
         let branch = wt.branch.clone().unwrap_or_else(|| {
@@ -67,10 +68,10 @@ async fn list_worktrees() -> Result<()> {
 
         let shortened_path = shorten_path(&wt.path);
 
-        println!("{:<40} {:<30} {:<10}", shortened_path, branch, wt_type);
+        outln!("{:<40} {:<30} {:<10}", shortened_path, branch, wt_type);
     }
 
-    println!("\nTotal: {} worktrees", worktrees.len());
+    infoln!("\nTotal: {} worktrees", worktrees.len());
 
     Ok(())
 }
@@ -82,16 +83,16 @@ async fn show_info(profile: &str, identifier: &str) -> Result<()> {
     let session = super::resolve_session(identifier, &instances)?;
 
     if let Some(wt_info) = &session.worktree_info {
-        println!("Worktree Information:\n");
-        println!("  Session:       {}", session.title);
-        println!("  Branch:        {}", wt_info.branch);
-        println!("  Worktree Path: {}", session.project_path);
-        println!("  Main Repo:     {}", wt_info.main_repo_path);
-        println!(
+        infoln!("Worktree Information:\n");
+        outln!("  Session:       {}", session.title);
+        outln!("  Branch:        {}", wt_info.branch);
+        outln!("  Worktree Path: {}", session.project_path);
+        outln!("  Main Repo:     {}", wt_info.main_repo_path);
+        outln!(
             "  Managed by aoe: {}",
             if wt_info.managed_by_aoe { "Yes" } else { "No" }
         );
-        println!(
+        outln!(
             "  Cleanup on delete: {}",
             if wt_info.cleanup_on_delete {
                 "Yes"
@@ -99,7 +100,7 @@ async fn show_info(profile: &str, identifier: &str) -> Result<()> {
                 "No"
             }
         );
-        println!(
+        outln!(
             "  Created at:    {}",
             wt_info.created_at.format("%Y-%m-%d %H:%M:%S")
         );
@@ -107,10 +108,10 @@ async fn show_info(profile: &str, identifier: &str) -> Result<()> {
         // Check if worktree still exists
         let worktree_path = PathBuf::from(&session.project_path);
         if worktree_path.exists() {
-            println!("\n  Status:        ✓ Worktree exists");
+            outln!("\n  Status:        ✓ Worktree exists");
         } else {
-            println!("\n  Status:        ✗ Worktree missing (orphaned session)");
-            println!("  Tip:           Run 'aoe worktree cleanup' to remove orphaned sessions");
+            outln!("\n  Status:        ✗ Worktree missing (orphaned session)");
+            infoln!("  Tip:           Run 'aoe worktree cleanup' to remove orphaned sessions");
         }
     } else {
         bail!(
@@ -164,37 +165,37 @@ async fn cleanup_orphaned(profile: &str, force: bool) -> Result<()> {
     }
 
     if orphaned_sessions.is_empty() && orphaned_worktrees.is_empty() {
-        println!("✓ No orphaned worktrees or sessions found");
+        infoln!("✓ No orphaned worktrees or sessions found");
         return Ok(());
     }
 
     // Report findings
     if !orphaned_sessions.is_empty() {
-        println!("Orphaned Sessions (worktree deleted but session remains):\n");
+        infoln!("Orphaned Sessions (worktree deleted but session remains):\n");
         for inst in &orphaned_sessions {
-            println!("  • {} ({})", inst.title, inst.id);
-            println!("    Missing worktree: {}", inst.project_path);
+            outln!("  • {} ({})", inst.title, inst.id);
+            outln!("    Missing worktree: {}", inst.project_path);
         }
-        println!();
+        infoln!();
     }
 
     if !orphaned_worktrees.is_empty() {
-        println!("Orphaned Worktrees (worktree exists but no session):\n");
+        infoln!("Orphaned Worktrees (worktree exists but no session):\n");
         for wt in &orphaned_worktrees {
             let unknown = "(unknown)".to_string();
             let branch = wt.branch.as_ref().unwrap_or(&unknown);
-            println!("  • {}", wt.path.display());
-            println!("    Branch: {}", branch);
+            outln!("  • {}", wt.path.display());
+            outln!("    Branch: {}", branch);
         }
-        println!();
+        infoln!();
     }
 
     if !force {
-        println!("This is a dry-run. Use --force to actually remove orphaned items.");
-        println!();
-        println!("What would be cleaned up:");
-        println!("  - {} orphaned sessions", orphaned_sessions.len());
-        println!("  - {} orphaned worktrees", orphaned_worktrees.len());
+        infoln!("This is a dry-run. Use --force to actually remove orphaned items.");
+        infoln!();
+        infoln!("What would be cleaned up:");
+        infoln!("  - {} orphaned sessions", orphaned_sessions.len());
+        infoln!("  - {} orphaned worktrees", orphaned_worktrees.len());
         return Ok(());
     }
 
@@ -202,8 +203,8 @@ async fn cleanup_orphaned(profile: &str, force: bool) -> Result<()> {
     use std::io::{self, Write};
 
     print!("\nProceed with cleanup? This will:\n");
-    println!("  - Remove {} sessions from aoe", orphaned_sessions.len());
-    println!(
+    infoln!("  - Remove {} sessions from aoe", orphaned_sessions.len());
+    infoln!(
         "  - Delete {} worktree directories",
         orphaned_worktrees.len()
     );
@@ -215,7 +216,7 @@ async fn cleanup_orphaned(profile: &str, force: bool) -> Result<()> {
     let response = response.trim().to_lowercase();
 
     if response != "y" && response != "yes" {
-        println!("Cleanup cancelled");
+        infoln!("Cleanup cancelled");
         return Ok(());
     }
 
@@ -230,7 +231,7 @@ async fn cleanup_orphaned(profile: &str, force: bool) -> Result<()> {
         storage.save_with_groups(&new_instances, &group_tree)?;
 
         removed_count += orphaned_sessions.len();
-        println!("✓ Removed {} orphaned sessions", orphaned_sessions.len());
+        infoln!("✓ Removed {} orphaned sessions", orphaned_sessions.len());
     }
 
     // Remove orphaned worktrees
@@ -242,17 +243,17 @@ async fn cleanup_orphaned(profile: &str, force: bool) -> Result<()> {
         for wt in &orphaned_worktrees {
             match git_wt.remove_worktree(&wt.path, true) {
                 Ok(_) => {
-                    println!("✓ Removed worktree: {}", wt.path.display());
+                    infoln!("✓ Removed worktree: {}", wt.path.display());
                     removed_count += 1;
                 }
                 Err(e) => {
-                    eprintln!("✗ Failed to remove {}: {}", wt.path.display(), e);
+                    errln!("✗ Failed to remove {}: {}", wt.path.display(), e);
                 }
             }
         }
     }
 
-    println!("\n✓ Cleanup complete: {} items removed", removed_count);
+    infoln!("\n✓ Cleanup complete: {} items removed", removed_count);
 
     Ok(())
 }
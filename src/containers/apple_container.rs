@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use super::container_interface::{ContainerConfig, ContainerRuntimeInterface};
+use super::container_interface::{ContainerConfig, ContainerInspect, ContainerRuntimeInterface};
 use super::error::{DockerError, Result};
 use super::runtime_base::RuntimeBase;
 use serde_json::Value;
@@ -38,6 +38,10 @@ impl ContainerRuntimeInterface for AppleContainer {
         self.base.ensure_image(image)
     }
 
+    fn ensure_image_with_progress(&self, image: &str, on_line: &mut dyn FnMut(&str)) -> Result<()> {
+        self.base.ensure_image_with_progress(image, on_line)
+    }
+
     fn default_sandbox_image(&self) -> &'static str {
         self.base.default_sandbox_image()
     }
@@ -110,8 +114,15 @@ impl ContainerRuntimeInterface for AppleContainer {
         self.base.exec(name, cmd)
     }
 
-    fn batch_running_states(&self, _prefix: &str) -> HashMap<String, bool> {
-        HashMap::new()
+    fn inspect(&self, _name: &str) -> Result<ContainerInspect> {
+        // Apple Container's inspect output uses its own schema
+        Err(DockerError::CommandFailed(
+            "container inspect is not supported with Apple Container".to_string(),
+        ))
+    }
+
+    fn batch_running_states(&self, _prefix: &str) -> Option<HashMap<String, bool>> {
+        None
     }
 }
 
@@ -1,7 +1,10 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
-use super::error::Result;
+use super::error::{DockerError, Result};
+use chrono::{DateTime, Utc};
 use enum_dispatch::enum_dispatch;
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
 
 pub struct VolumeMount {
     pub host_path: String,
@@ -16,6 +19,139 @@ pub struct ContainerConfig {
     pub environment: Vec<(String, String)>,
     pub cpu_limit: Option<String>,
     pub memory_limit: Option<String>,
+    pub labels: BTreeMap<String, String>,
+    pub read_only_root: bool,
+    /// Run an init process as PID 1 (`--init`)
+    pub init: bool,
+    /// `/etc/hosts` entries as `hostname:ip`
+    pub extra_hosts: Vec<String>,
+}
+
+impl ContainerConfig {
+    /// SHA-256 of everything that shapes a container created from this config
+    /// and `image`. Environment order does not affect the result.
+    pub fn content_hash(&self, image: &str) -> String {
+        let mut hasher = Sha256::new();
+        let mut field = |name: &str, value: &str| {
+            hasher.update(name.as_bytes());
+            hasher.update(b"=");
+            hasher.update(value.as_bytes());
+            hasher.update(b"\n");
+        };
+        field("image", image);
+        field("working_dir", &self.working_dir);
+        for v in &self.volumes {
+            let mode = if v.read_only { "ro" } else { "rw" };
+            field(
+                "volume",
+                &format!("{}:{}:{}", v.host_path, v.container_path, mode),
+            );
+        }
+        for v in &self.anonymous_volumes {
+            field("anonymous_volume", v);
+        }
+        let mut env: Vec<_> = self.environment.iter().collect();
+        env.sort();
+        for (k, v) in env {
+            field("env", &format!("{}={}", k, v));
+        }
+        field("cpu_limit", self.cpu_limit.as_deref().unwrap_or(""));
+        field("memory_limit", self.memory_limit.as_deref().unwrap_or(""));
+        for (k, v) in &self.labels {
+            field("label", &format!("{}={}", k, v));
+        }
+        if self.read_only_root {
+            field("read_only_root", "true");
+        }
+        if self.init {
+            field("init", "true");
+        }
+        for h in &self.extra_hosts {
+            field("extra_host", h);
+        }
+        format!("{:x}", hasher.finalize())
+    }
+}
+
+/// Whether an existing container was created from a different effective
+/// config than `config`/`image`. Containers without a recorded hash (created
+/// by older versions) are assumed current.
+pub fn needs_recreate(config: &ContainerConfig, image: &str, stored_hash: Option<&str>) -> bool {
+    stored_hash.is_some_and(|stored| stored != config.content_hash(image))
+}
+
+/// Container metadata from `docker container inspect`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ContainerInspect {
+    pub id: String,
+    /// Container name without Docker's leading `/`
+    #[serde(deserialize_with = "deserialize_container_name")]
+    pub name: String,
+    pub created: DateTime<Utc>,
+    pub state: ContainerState,
+    pub config: ContainerInspectConfig,
+    #[serde(default)]
+    pub mounts: Vec<ContainerMount>,
+}
+
+impl ContainerInspect {
+    /// Image reference the container was created from, e.g. `ubuntu:latest`.
+    pub fn image(&self) -> &str {
+        &self.config.image
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ContainerState {
+    /// `created`, `running`, `paused`, `exited`, ...
+    pub status: String,
+    pub running: bool,
+    #[serde(default)]
+    pub exit_code: i32,
+    pub started_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ContainerInspectConfig {
+    pub image: String,
+    #[serde(default)]
+    pub labels: Option<HashMap<String, String>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ContainerMount {
+    /// `bind`, `volume` or `tmpfs`
+    #[serde(rename = "Type")]
+    pub kind: String,
+    #[serde(default)]
+    pub source: String,
+    pub destination: String,
+    #[serde(rename = "RW")]
+    pub read_write: bool,
+}
+
+fn deserialize_container_name<'de, D>(deserializer: D) -> std::result::Result<String, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let name = String::deserialize(deserializer)?;
+    Ok(name.trim_start_matches('/').to_string())
+}
+
+/// Parse `inspect` output, a JSON array with one object per container.
+pub fn parse_inspect(json: &str) -> Result<ContainerInspect> {
+    let mut containers: Vec<ContainerInspect> = serde_json::from_str(json)
+        .map_err(|e| DockerError::CommandFailed(format!("unexpected inspect output: {}", e)))?;
+    if containers.is_empty() {
+        return Err(DockerError::CommandFailed(
+            "inspect returned no containers".to_string(),
+        ));
+    }
+    Ok(containers.swap_remove(0))
 }
 
 #[enum_dispatch]
@@ -33,6 +169,9 @@ pub trait ContainerRuntimeInterface {
 
     fn ensure_image(&self, image: &str) -> Result<()>;
 
+    /// Like `ensure_image`, passing each line of pull output to `on_line`
+    fn ensure_image_with_progress(&self, image: &str, on_line: &mut dyn FnMut(&str)) -> Result<()>;
+
     fn default_sandbox_image(&self) -> &'static str;
 
     fn effective_default_image(&self) -> String;
@@ -61,7 +200,105 @@ pub trait ContainerRuntimeInterface {
 
     fn exec(&self, name: &str, cmd: &[&str]) -> Result<std::process::Output>;
 
+    /// Structured metadata for the container `name`.
+    fn inspect(&self, name: &str) -> Result<ContainerInspect>;
+
     /// Check running state of all containers matching a name prefix in a single call.
-    /// Returns a map of container name -> is_running.
-    fn batch_running_states(&self, prefix: &str) -> HashMap<String, bool>;
+    /// Returns a map of container name -> is_running, or None when the runtime
+    /// cannot list containers.
+    fn batch_running_states(&self, prefix: &str) -> Option<HashMap<String, bool>>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DOCKER_INSPECT: &str = r#"[
+      {
+        "Id": "4f66ad9a0b2e",
+        "Created": "2026-03-02T09:15:27.123456789Z",
+        "Path": "sleep",
+        "State": {
+          "Status": "running",
+          "Running": true,
+          "Paused": false,
+          "ExitCode": 0,
+          "StartedAt": "2026-03-02T09:15:28.5Z"
+        },
+        "Image": "sha256:ab12",
+        "Name": "/aoe-sandbox-4f66ad9a",
+        "Config": {
+          "Image": "ghcr.io/njbrake/aoe-sandbox:latest",
+          "Labels": {"aoe.session": "4f66ad9a"}
+        },
+        "Mounts": [
+          {
+            "Type": "bind",
+            "Source": "/home/me/project",
+            "Destination": "/workspace/project",
+            "Mode": "",
+            "RW": true
+          },
+          {
+            "Type": "volume",
+            "Name": "e3b0c442",
+            "Source": "/var/lib/docker/volumes/e3b0c442/_data",
+            "Destination": "/workspace/project/node_modules",
+            "RW": true
+          }
+        ]
+      }
+    ]"#;
+
+    #[test]
+    fn test_parse_inspect_docker() {
+        let info = parse_inspect(DOCKER_INSPECT).unwrap();
+        assert_eq!(info.id, "4f66ad9a0b2e");
+        assert_eq!(info.name, "aoe-sandbox-4f66ad9a");
+        assert_eq!(info.image(), "ghcr.io/njbrake/aoe-sandbox:latest");
+        assert_eq!(
+            info.created.to_rfc3339(),
+            "2026-03-02T09:15:27.123456789+00:00"
+        );
+        assert_eq!(info.state.status, "running");
+        assert!(info.state.running);
+        assert_eq!(info.state.exit_code, 0);
+        assert_eq!(
+            info.config.labels.as_ref().unwrap()["aoe.session"],
+            "4f66ad9a"
+        );
+        assert_eq!(info.mounts.len(), 2);
+        assert_eq!(info.mounts[0].kind, "bind");
+        assert_eq!(info.mounts[0].source, "/home/me/project");
+        assert_eq!(
+            info.mounts[1].destination,
+            "/workspace/project/node_modules"
+        );
+        assert!(info.mounts[1].read_write);
+    }
+
+    #[test]
+    fn test_parse_inspect_podman_offsets_and_exited() {
+        let json = r#"[{
+          "Id": "9c1d",
+          "Created": "2026-03-02T10:15:27.5+01:00",
+          "State": {"Status": "exited", "Running": false, "ExitCode": 137,
+                    "StartedAt": "2026-03-02T10:15:28+01:00"},
+          "Name": "aoe-sandbox-9c1d",
+          "Config": {"Image": "docker.io/library/ubuntu:24.04", "Labels": null},
+          "Mounts": []
+        }]"#;
+        let info = parse_inspect(json).unwrap();
+        assert_eq!(info.name, "aoe-sandbox-9c1d");
+        assert_eq!(info.created.to_rfc3339(), "2026-03-02T09:15:27.500+00:00");
+        assert_eq!(info.state.status, "exited");
+        assert_eq!(info.state.exit_code, 137);
+        assert!(info.config.labels.is_none());
+    }
+
+    #[test]
+    fn test_parse_inspect_rejects_empty_and_garbage() {
+        assert!(parse_inspect("[]").is_err());
+        assert!(parse_inspect("Error: no such container").is_err());
+    }
 }
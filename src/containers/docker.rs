@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use super::container_interface::{ContainerConfig, ContainerRuntimeInterface};
+use super::container_interface::{ContainerConfig, ContainerInspect, ContainerRuntimeInterface};
 use super::error::{DockerError, Result};
 use super::runtime_base::RuntimeBase;
 
@@ -41,6 +41,10 @@ impl ContainerRuntimeInterface for Docker {
         self.base.ensure_image(image)
     }
 
+    fn ensure_image_with_progress(&self, image: &str, on_line: &mut dyn FnMut(&str)) -> Result<()> {
+        self.base.ensure_image_with_progress(image, on_line)
+    }
+
     fn default_sandbox_image(&self) -> &'static str {
         self.base.default_sandbox_image()
     }
@@ -109,7 +113,11 @@ impl ContainerRuntimeInterface for Docker {
         self.base.exec(name, cmd)
     }
 
-    fn batch_running_states(&self, prefix: &str) -> HashMap<String, bool> {
+    fn inspect(&self, name: &str) -> Result<ContainerInspect> {
+        self.base.inspect(name)
+    }
+
+    fn batch_running_states(&self, prefix: &str) -> Option<HashMap<String, bool>> {
         let output = self
             .base
             .command()
@@ -125,11 +133,11 @@ impl ContainerRuntimeInterface for Docker {
 
         let output = match output {
             Ok(o) if o.status.success() => o,
-            _ => return HashMap::new(),
+            _ => return None,
         };
 
         let stdout = String::from_utf8_lossy(&output.stdout);
-        stdout
+        let states = stdout
             .lines()
             .filter_map(|line| {
                 let mut parts = line.splitn(2, '\t');
@@ -142,7 +150,8 @@ impl ContainerRuntimeInterface for Docker {
                 }
                 Some((name.to_string(), state == "running"))
             })
-            .collect()
+            .collect();
+        Some(states)
     }
 }
 
@@ -22,6 +22,10 @@ pub enum DockerError {
     )]
     PermissionDenied,
 
+    /// The runtime binary or daemon is missing; carries a user-facing hint
+    #[error("{0}")]
+    RuntimeUnavailable(String),
+
     #[error("Container not found: {0}")]
     ContainerNotFound(String),
 
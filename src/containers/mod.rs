@@ -7,9 +7,12 @@ pub(crate) mod runtime_base;
 use std::collections::HashMap;
 
 use crate::cli::truncate_id;
-use crate::session::{Config, ContainerRuntimeName};
+use crate::session::{Config, ContainerNameScheme, ContainerRuntimeName};
 use apple_container::AppleContainer;
-pub use container_interface::{ContainerConfig, ContainerRuntimeInterface, VolumeMount};
+pub use container_interface::{
+    needs_recreate, parse_inspect, ContainerConfig, ContainerInspect, ContainerMount,
+    ContainerRuntimeInterface, ContainerState, VolumeMount,
+};
 use docker::Docker;
 use enum_dispatch::enum_dispatch;
 use error::Result;
@@ -49,10 +52,137 @@ pub fn get_container_runtime() -> ContainerRuntime {
     }
 }
 
+/// Prefix shared by every aoe sandbox container name.
+pub const CONTAINER_PREFIX: &str = "aoe-sandbox-";
+
+/// Longest profile or title fragment kept in a container name.
+const MAX_NAME_PART: usize = 24;
+
+/// Name for a new session container. Every scheme keeps the
+/// `aoe-sandbox-` prefix and ends with the short session id, so names stay
+/// unique across profiles and titles and remain valid container names
+/// (lowercase ASCII letters, digits and `-`).
+pub fn container_name_for(
+    scheme: ContainerNameScheme,
+    profile: &str,
+    id: &str,
+    title: &str,
+) -> String {
+    match scheme {
+        ContainerNameScheme::Id => DockerContainer::generate_name(id),
+        ContainerNameScheme::ProfileTitle => {
+            let mut name = CONTAINER_PREFIX.to_string();
+            for part in [profile, title] {
+                let part = sanitize_name_part(part);
+                if !part.is_empty() {
+                    name.push_str(&part);
+                    name.push('-');
+                }
+            }
+            name.push_str(&sanitize_name_part(truncate_id(id, 8)));
+            name
+        }
+    }
+}
+
+/// Lowercase `part`, replace runs of unsupported characters with a single
+/// `-` and trim dashes from both ends.
+fn sanitize_name_part(part: &str) -> String {
+    let mut out = String::new();
+    for c in part.chars() {
+        if c.is_ascii_alphanumeric() {
+            out.push(c.to_ascii_lowercase());
+        } else if !out.is_empty() && !out.ends_with('-') {
+            out.push('-');
+        }
+        if out.len() >= MAX_NAME_PART {
+            break;
+        }
+    }
+    out.trim_end_matches('-').to_string()
+}
+
+/// Which engine answers on the configured runtime binary. `docker` is often
+/// podman behind an alias or shim.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RuntimeKind {
+    Docker,
+    Podman,
+    AppleContainer,
+    Unknown,
+}
+
+impl std::fmt::Display for RuntimeKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            RuntimeKind::Docker => "docker",
+            RuntimeKind::Podman => "podman",
+            RuntimeKind::AppleContainer => "apple container",
+            RuntimeKind::Unknown => "unknown runtime",
+        })
+    }
+}
+
+/// The detected container runtime, for diagnostics and bug reports.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuntimeInfo {
+    /// Binary aoe invokes (`docker` or `container`)
+    pub binary: &'static str,
+    pub kind: RuntimeKind,
+    pub version: Option<String>,
+}
+
+impl std::fmt::Display for RuntimeInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.kind)?;
+        if let Some(version) = &self.version {
+            write!(f, " {}", version)?;
+        }
+        if self.kind == RuntimeKind::Podman && self.binary != "podman" {
+            write!(f, " (as {})", self.binary)?;
+        }
+        Ok(())
+    }
+}
+
+/// Parse the output of `<runtime> --version`, e.g. `Docker version 27.0.3,
+/// build 7d4bcd8` or `podman version 4.9.3`.
+pub fn parse_runtime_version(output: &str) -> (RuntimeKind, Option<String>) {
+    let lower = output.to_lowercase();
+    let kind = if lower.contains("podman") {
+        RuntimeKind::Podman
+    } else if lower.contains("docker") {
+        RuntimeKind::Docker
+    } else if lower.starts_with("container") {
+        RuntimeKind::AppleContainer
+    } else {
+        RuntimeKind::Unknown
+    };
+    let version = output
+        .split(|c: char| c.is_whitespace() || c == ',' || c == '(' || c == ')')
+        .map(|token| token.trim_start_matches('v'))
+        .find(|token| token.starts_with(|c: char| c.is_ascii_digit()) && token.contains('.'))
+        .map(String::from);
+    (kind, version)
+}
+
+/// Kind and version of the configured container runtime, or `None` when it
+/// is not installed.
+pub fn runtime_info() -> Option<RuntimeInfo> {
+    let output = get_container_runtime().get_version().ok()?;
+    let (kind, version) = parse_runtime_version(&output);
+    Some(RuntimeInfo {
+        binary: runtime_binary(),
+        kind,
+        version,
+    })
+}
+
 /// Check running state of all aoe sandbox containers in a single subprocess call.
-/// Returns a map of container name -> is_running.
-pub fn batch_container_health() -> HashMap<String, bool> {
-    get_container_runtime().batch_running_states("aoe-sandbox-")
+/// Returns a map of container name -> is_running, or None when the runtime
+/// cannot list containers.
+pub fn batch_container_health() -> Option<HashMap<String, bool>> {
+    get_container_runtime().batch_running_states(CONTAINER_PREFIX)
 }
 
 pub struct DockerContainer {
@@ -71,7 +201,16 @@ impl DockerContainer {
     }
 
     pub fn generate_name(session_id: &str) -> String {
-        format!("aoe-sandbox-{}", truncate_id(session_id, 8))
+        format!("{}{}", CONTAINER_PREFIX, truncate_id(session_id, 8))
+    }
+
+    /// Handle to an existing container by its recorded name.
+    pub fn from_name(name: &str, image: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            image: image.to_string(),
+            runtime: get_container_runtime(),
+        }
     }
 
     pub fn from_session_id(session_id: &str) -> Self {
@@ -119,12 +258,50 @@ impl DockerContainer {
     pub fn exec(&self, cmd: &[&str]) -> Result<std::process::Output> {
         self.runtime.exec(&self.name, cmd)
     }
+
+    pub fn inspect(&self) -> Result<ContainerInspect> {
+        self.runtime.inspect(&self.name)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_parse_runtime_version() {
+        assert_eq!(
+            parse_runtime_version("Docker version 27.0.3, build 7d4bcd8"),
+            (RuntimeKind::Docker, Some("27.0.3".to_string()))
+        );
+        assert_eq!(
+            parse_runtime_version("podman version 4.9.3"),
+            (RuntimeKind::Podman, Some("4.9.3".to_string()))
+        );
+        assert_eq!(
+            parse_runtime_version("container CLI version 0.1.0 (build: release, commit: 1a2b3c)"),
+            (RuntimeKind::AppleContainer, Some("0.1.0".to_string()))
+        );
+        assert_eq!(
+            parse_runtime_version("Docker version v28.1.1-rd, build 4d8c241"),
+            (RuntimeKind::Docker, Some("28.1.1-rd".to_string()))
+        );
+        assert_eq!(
+            parse_runtime_version("garbage"),
+            (RuntimeKind::Unknown, None)
+        );
+    }
+
+    #[test]
+    fn test_runtime_info_display() {
+        let info = RuntimeInfo {
+            binary: "docker",
+            kind: RuntimeKind::Podman,
+            version: Some("4.9.3".to_string()),
+        };
+        assert_eq!(info.to_string(), "podman 4.9.3 (as docker)");
+    }
+
     #[test]
     fn test_container_generate_name_short_id() {
         let name = DockerContainer::generate_name("abc");
@@ -156,6 +333,10 @@ mod tests {
             environment: vec![],
             cpu_limit: None,
             memory_limit: None,
+            labels: Default::default(),
+            read_only_root: false,
+            init: false,
+            extra_hosts: Vec::new(),
         };
 
         let args = container.build_create_args(&config);
@@ -184,6 +365,10 @@ mod tests {
             environment: vec![],
             cpu_limit: None,
             memory_limit: None,
+            labels: Default::default(),
+            read_only_root: false,
+            init: false,
+            extra_hosts: Vec::new(),
         };
 
         let args = container.build_create_args(&config);
@@ -191,4 +376,103 @@ mod tests {
         // No -v flags at all
         assert!(!args.contains(&"-v".to_string()));
     }
+
+    #[test]
+    fn test_container_name_for_id_scheme_matches_legacy_name() {
+        assert_eq!(
+            container_name_for(ContainerNameScheme::Id, "work", "abcdef123456", "API"),
+            DockerContainer::generate_name("abcdef123456")
+        );
+    }
+
+    #[test]
+    fn test_container_name_for_profile_title_avoids_collisions() {
+        let scheme = ContainerNameScheme::ProfileTitle;
+        let work = container_name_for(scheme, "work", "abcdef123456", "api");
+        let personal = container_name_for(scheme, "personal", "abcdef123456", "api");
+        assert_eq!(work, "aoe-sandbox-work-api-abcdef12");
+        assert_ne!(work, personal);
+        // Same profile and title still differ by session id
+        assert_ne!(
+            work,
+            container_name_for(scheme, "work", "99999999aaaa", "api")
+        );
+    }
+
+    #[test]
+    fn test_container_name_for_strips_invalid_chars() {
+        let name = container_name_for(
+            ContainerNameScheme::ProfileTitle,
+            "My Profile!",
+            "ABCDEF123456",
+            "  Fix: the/API bug 🚀 ",
+        );
+        assert_eq!(name, "aoe-sandbox-my-profile-fix-the-api-bug-abcdef12");
+        assert!(name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-'));
+
+        let name = container_name_for(ContainerNameScheme::ProfileTitle, "", "abc", "🚀");
+        assert_eq!(name, "aoe-sandbox-abc");
+        let long = container_name_for(
+            ContainerNameScheme::ProfileTitle,
+            "p",
+            "abc",
+            &"x".repeat(100),
+        );
+        assert_eq!(
+            long,
+            format!("aoe-sandbox-p-{}-abc", "x".repeat(MAX_NAME_PART))
+        );
+    }
+
+    fn limited_config(memory: &str) -> ContainerConfig {
+        ContainerConfig {
+            working_dir: "/workspace".to_string(),
+            volumes: vec![VolumeMount {
+                host_path: "/home/u/project".to_string(),
+                container_path: "/workspace".to_string(),
+                read_only: false,
+            }],
+            anonymous_volumes: vec![],
+            environment: vec![
+                ("TERM".to_string(), "xterm-256color".to_string()),
+                ("LANG".to_string(), "C.UTF-8".to_string()),
+            ],
+            cpu_limit: Some("2".to_string()),
+            memory_limit: Some(memory.to_string()),
+            labels: Default::default(),
+            read_only_root: false,
+            init: false,
+            extra_hosts: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_identical_config_reports_no_drift() {
+        let stored = limited_config("4g").content_hash("alpine:latest");
+        let mut reordered = limited_config("4g");
+        reordered.environment.reverse();
+        assert!(!needs_recreate(&reordered, "alpine:latest", Some(&stored)));
+        assert!(!needs_recreate(
+            &limited_config("8g"),
+            "alpine:latest",
+            None
+        ));
+    }
+
+    #[test]
+    fn test_changed_limit_or_image_reports_drift() {
+        let stored = limited_config("4g").content_hash("alpine:latest");
+        assert!(needs_recreate(
+            &limited_config("8g"),
+            "alpine:latest",
+            Some(&stored)
+        ));
+        assert!(needs_recreate(
+            &limited_config("4g"),
+            "ubuntu:latest",
+            Some(&stored)
+        ));
+    }
 }
@@ -1,4 +1,4 @@
-use super::container_interface::ContainerConfig;
+use super::container_interface::{parse_inspect, ContainerConfig, ContainerInspect};
 use super::error::{DockerError, Result};
 use std::process::Command;
 
@@ -81,13 +81,24 @@ impl RuntimeBase {
     }
 
     pub fn pull_image(&self, image: &str) -> Result<()> {
+        self.pull_image_with_progress(image, &mut |_| {})
+    }
+
+    /// Pull `image`, passing each line of the runtime's output to `on_line`
+    pub fn pull_image_with_progress(
+        &self,
+        image: &str,
+        on_line: &mut dyn FnMut(&str),
+    ) -> Result<()> {
         let mut cmd = self.command();
         cmd.args(self.pull_prefix);
         cmd.arg(image);
-        let output = cmd.output()?;
+        cmd.stdout(std::process::Stdio::piped())
+            .stderr(std::process::Stdio::piped());
+        let mut child = cmd.spawn()?;
+        let stderr = crate::process::stream_output(&mut child, on_line);
 
-        if !output.status.success() {
-            let stderr = String::from_utf8_lossy(&output.stderr);
+        if !child.wait()?.success() {
             return Err(DockerError::ImageNotFound(format!(
                 "{}: {}",
                 image,
@@ -99,13 +110,21 @@ impl RuntimeBase {
     }
 
     pub fn ensure_image(&self, image: &str) -> Result<()> {
+        self.ensure_image_with_progress(image, &mut |_| {})
+    }
+
+    pub fn ensure_image_with_progress(
+        &self,
+        image: &str,
+        on_line: &mut dyn FnMut(&str),
+    ) -> Result<()> {
         if self.image_exists_locally(image) {
             tracing::info!("Using local {} image '{}'", self.name, image);
             return Ok(());
         }
 
         tracing::info!("Pulling {} image '{}'", self.name, image);
-        self.pull_image(image)
+        self.pull_image_with_progress(image, on_line)
     }
 
     pub fn default_sandbox_image(&self) -> &'static str {
@@ -171,6 +190,28 @@ impl RuntimeBase {
             args.push(mem.clone());
         }
 
+        for (key, value) in &config.labels {
+            args.push("--label".to_string());
+            args.push(format!("{}={}", key, value));
+        }
+
+        if config.init {
+            args.push("--init".to_string());
+        }
+
+        for host in &config.extra_hosts {
+            args.push("--add-host".to_string());
+            args.push(host.clone());
+        }
+
+        if config.read_only_root {
+            // Tools still expect a scratch dir; the workspace is a bind mount
+            // and stays writable.
+            args.push("--read-only".to_string());
+            args.push("--tmpfs".to_string());
+            args.push("/tmp".to_string());
+        }
+
         args.push(image.to_string());
         args.push("sleep".to_string());
         args.push("infinity".to_string());
@@ -259,6 +300,23 @@ impl RuntimeBase {
         }
     }
 
+    pub fn inspect(&self, name: &str) -> Result<ContainerInspect> {
+        let output = self
+            .command()
+            .args(["container", "inspect", name])
+            .output()?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            if stderr.contains("No such container") || stderr.contains("no such container") {
+                return Err(DockerError::ContainerNotFound(name.to_string()));
+            }
+            return Err(DockerError::CommandFailed(stderr.to_string()));
+        }
+
+        parse_inspect(&String::from_utf8_lossy(&output.stdout))
+    }
+
     pub fn exec(&self, name: &str, cmd: &[&str]) -> Result<std::process::Output> {
         let mut args = vec!["exec", name];
         args.extend(cmd);
@@ -288,6 +346,10 @@ mod tests {
             environment: vec![],
             cpu_limit: None,
             memory_limit: None,
+            labels: Default::default(),
+            read_only_root: false,
+            init: false,
+            extra_hosts: Vec::new(),
         };
 
         let args = base.build_create_args("test-container", "alpine:latest", &config);
@@ -310,6 +372,10 @@ mod tests {
             environment: vec![],
             cpu_limit: None,
             memory_limit: None,
+            labels: Default::default(),
+            read_only_root: false,
+            init: false,
+            extra_hosts: Vec::new(),
         };
 
         let args = base.build_create_args("test-container", "alpine:latest", &config);
@@ -354,6 +420,10 @@ mod tests {
             environment: vec![("KEY".to_string(), "VALUE".to_string())],
             cpu_limit: Some("2".to_string()),
             memory_limit: Some("4g".to_string()),
+            labels: [("aoe.managed".to_string(), "true".to_string())].into(),
+            read_only_root: false,
+            init: false,
+            extra_hosts: Vec::new(),
         };
 
         let args = base.build_create_args("test", "ubuntu:latest", &config);
@@ -371,8 +441,108 @@ mod tests {
         assert!(args.contains(&"2".to_string()));
         assert!(args.contains(&"-m".to_string()));
         assert!(args.contains(&"4g".to_string()));
+        assert!(args.contains(&"--label".to_string()));
+        assert!(args.contains(&"aoe.managed=true".to_string()));
         assert!(args.contains(&"ubuntu:latest".to_string()));
         assert!(args.contains(&"sleep".to_string()));
         assert!(args.contains(&"infinity".to_string()));
+        assert!(!args.contains(&"--read-only".to_string()));
+        assert!(!args.contains(&"--init".to_string()));
+    }
+
+    #[test]
+    fn test_build_create_args_read_only_root() {
+        let base = RuntimeBase::DOCKER;
+        let mut config = ContainerConfig {
+            working_dir: "/workspace/project".to_string(),
+            volumes: vec![VolumeMount {
+                host_path: "/host/project".to_string(),
+                container_path: "/workspace/project".to_string(),
+                read_only: false,
+            }],
+            anonymous_volumes: vec![],
+            environment: vec![],
+            cpu_limit: None,
+            memory_limit: None,
+            labels: Default::default(),
+            read_only_root: true,
+            init: false,
+            extra_hosts: Vec::new(),
+        };
+
+        let args = base.build_create_args("test", "ubuntu:latest", &config);
+
+        assert!(args.contains(&"--read-only".to_string()));
+        let tmpfs = args.iter().position(|a| a == "--tmpfs").unwrap();
+        assert_eq!(args[tmpfs + 1], "/tmp");
+        assert!(args.contains(&"/host/project:/workspace/project".to_string()));
+
+        let hash = config.content_hash("ubuntu:latest");
+        config.read_only_root = false;
+        assert_ne!(hash, config.content_hash("ubuntu:latest"));
+    }
+
+    #[test]
+    fn test_build_create_args_init() {
+        let base = RuntimeBase::DOCKER;
+        let mut config = ContainerConfig {
+            working_dir: "/workspace/project".to_string(),
+            volumes: vec![],
+            anonymous_volumes: vec![],
+            environment: vec![],
+            cpu_limit: None,
+            memory_limit: None,
+            labels: Default::default(),
+            read_only_root: false,
+            init: true,
+            extra_hosts: Vec::new(),
+        };
+
+        let args = base.build_create_args("test", "ubuntu:latest", &config);
+        let init = args.iter().position(|a| a == "--init").unwrap();
+        let image = args.iter().position(|a| a == "ubuntu:latest").unwrap();
+        assert!(init < image);
+
+        let hash = config.content_hash("ubuntu:latest");
+        config.init = false;
+        assert_ne!(hash, config.content_hash("ubuntu:latest"));
+        let args = base.build_create_args("test", "ubuntu:latest", &config);
+        assert!(!args.contains(&"--init".to_string()));
+    }
+
+    #[test]
+    fn test_build_create_args_extra_hosts() {
+        let base = RuntimeBase::DOCKER;
+        let mut config = ContainerConfig {
+            working_dir: "/workspace/project".to_string(),
+            volumes: vec![],
+            anonymous_volumes: vec![],
+            environment: vec![],
+            cpu_limit: None,
+            memory_limit: None,
+            labels: Default::default(),
+            read_only_root: false,
+            init: false,
+            extra_hosts: vec![
+                "db.local:10.0.0.5".to_string(),
+                "host.docker.internal:host-gateway".to_string(),
+            ],
+        };
+
+        let args = base.build_create_args("test", "ubuntu:latest", &config);
+
+        let hosts: Vec<&str> = args
+            .windows(2)
+            .filter(|w| w[0] == "--add-host")
+            .map(|w| w[1].as_str())
+            .collect();
+        assert_eq!(
+            hosts,
+            vec!["db.local:10.0.0.5", "host.docker.internal:host-gateway"]
+        );
+
+        let hash = config.content_hash("ubuntu:latest");
+        config.extra_hosts.clear();
+        assert_ne!(hash, config.content_hash("ubuntu:latest"));
     }
 }
@@ -381,6 +381,69 @@ impl GitWorktree {
     }
 }
 
+/// Branch checked out in the repository containing `path`, read straight
+/// from `HEAD` so it is cheap enough to call on every render. A detached
+/// HEAD is shown as its abbreviated commit in parentheses. Returns None
+/// outside a git repository.
+pub fn current_branch(path: &Path) -> Option<String> {
+    let git_dir = find_git_dir(path)?;
+    let head = std::fs::read_to_string(git_dir.join("HEAD")).ok()?;
+    let head = head.trim();
+    match head.strip_prefix("ref:") {
+        Some(reference) => {
+            let reference = reference.trim();
+            Some(
+                reference
+                    .strip_prefix("refs/heads/")
+                    .unwrap_or(reference)
+                    .to_string(),
+            )
+        }
+        None => Some(format!("({})", head.get(..7)?)),
+    }
+}
+
+/// Number of files with uncommitted changes (modified, staged or untracked)
+/// in the repository containing `path`. Returns None outside a git
+/// repository or when git cannot be run.
+pub fn uncommitted_changes(path: &Path) -> Option<usize> {
+    let output = std::process::Command::new("git")
+        .args(["status", "--porcelain"])
+        .current_dir(path)
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    Some(count_porcelain_changes(&String::from_utf8_lossy(
+        &output.stdout,
+    )))
+}
+
+/// Count changed files in `git status --porcelain` (v1) output. Ignored
+/// files (`!!`) are not counted.
+fn count_porcelain_changes(porcelain: &str) -> usize {
+    porcelain
+        .lines()
+        .filter(|line| line.len() > 3 && !line.starts_with("!!"))
+        .count()
+}
+
+/// The `.git` directory for `path` or its nearest ancestor, following the
+/// `gitdir:` file used by linked worktrees.
+fn find_git_dir(path: &Path) -> Option<PathBuf> {
+    for dir in path.ancestors() {
+        let git_entry = dir.join(".git");
+        if git_entry.is_dir() {
+            return Some(git_entry);
+        }
+        if git_entry.is_file() {
+            return GitWorktree::read_gitdir_from_file(&git_entry);
+        }
+    }
+    None
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -410,6 +473,43 @@ mod tests {
         assert!(GitWorktree::is_git_repo(repo.path().parent().unwrap()));
     }
 
+    #[test]
+    fn test_current_branch_reads_head() {
+        let (dir, repo) = setup_test_repo();
+        let commit = repo.head().unwrap().peel_to_commit().unwrap();
+        repo.branch("feature/login", &commit, false).unwrap();
+        repo.set_head("refs/heads/feature/login").unwrap();
+
+        assert_eq!(current_branch(dir.path()).as_deref(), Some("feature/login"));
+        let nested = dir.path().join("src");
+        std::fs::create_dir(&nested).unwrap();
+        assert_eq!(current_branch(&nested).as_deref(), Some("feature/login"));
+    }
+
+    #[test]
+    fn test_current_branch_detached_head() {
+        let (dir, repo) = setup_test_repo();
+        let commit = repo.head().unwrap().peel_to_commit().unwrap();
+        repo.set_head_detached(commit.id()).unwrap();
+
+        let expected = format!("({})", &commit.id().to_string()[..7]);
+        assert_eq!(current_branch(dir.path()), Some(expected));
+    }
+
+    #[test]
+    fn test_count_porcelain_changes() {
+        let porcelain =
+            " M src/main.rs\nM  Cargo.toml\nR  old.rs -> new.rs\n?? notes.txt\n!! target/\n";
+        assert_eq!(count_porcelain_changes(porcelain), 4);
+        assert_eq!(count_porcelain_changes(""), 0);
+    }
+
+    #[test]
+    fn test_current_branch_outside_repo() {
+        let dir = TempDir::new().unwrap();
+        assert_eq!(current_branch(dir.path()), None);
+    }
+
     #[test]
     fn test_is_git_repo_returns_false_for_non_git_directory() {
         let dir = TempDir::new().unwrap();
@@ -1,6 +1,7 @@
 //! Agent of Empires library - Core functionality for the terminal session manager
 
 pub mod agents;
+pub mod audit;
 pub mod cli;
 pub mod containers;
 pub mod git;
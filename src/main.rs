@@ -1,6 +1,6 @@
 //! Agent of Empires - Terminal session manager for AI coding agents
 
-use agent_of_empires::cli::{self, Cli, Commands};
+use agent_of_empires::cli::{self, exit_code, Cli, Commands};
 use agent_of_empires::migrations;
 use agent_of_empires::tui;
 use anyhow::Result;
@@ -8,15 +8,44 @@ use clap::{CommandFactory, Parser};
 use clap_complete::generate;
 
 #[tokio::main]
-async fn main() -> Result<()> {
-    if std::env::var("AGENT_OF_EMPIRES_DEBUG").is_ok() {
-        tracing_subscriber::fmt()
-            .with_env_filter("agent_of_empires=debug")
-            .init();
+async fn main() {
+    let cli = match Cli::try_parse() {
+        Ok(cli) => cli,
+        Err(e) => {
+            let _ = e.print();
+            std::process::exit(exit_code::for_usage_error(&e));
+        }
+    };
+    cli::output::init(cli.no_color);
+    cli::output::set_quiet(cli.quiet);
+    if let Some(path) = &cli.config {
+        std::env::set_var(agent_of_empires::session::CONFIG_ENV, path);
+    }
+
+    let json_errors = cli.json_errors;
+    if let Err(e) = cli::logging::init(
+        cli.log_file.as_deref(),
+        cli.log_level.as_deref(),
+        cli.command.is_none(),
+    ) {
+        exit_with_error(&e, json_errors);
+    }
+
+    if let Err(e) = run(cli).await {
+        exit_with_error(&e, json_errors);
     }
+}
 
-    let cli = Cli::parse();
+fn exit_with_error(e: &anyhow::Error, json_errors: bool) -> ! {
+    if json_errors {
+        eprintln!("{}", exit_code::error_json(e));
+    } else {
+        eprintln!("Error: {:?}", e);
+    }
+    std::process::exit(exit_code::for_error(e));
+}
 
+async fn run(cli: Cli) -> Result<()> {
     // Handle commands that don't need app data or migrations.
     // These work in read-only/sandboxed environments (e.g. Nix builds).
     match cli.command {
@@ -33,6 +62,8 @@ async fn main() -> Result<()> {
         }
         Some(Commands::Sounds { command }) => return cli::sounds::run(command).await,
         Some(Commands::Uninstall(args)) => return cli::uninstall::run(args).await,
+        Some(Commands::Doctor) => return cli::doctor::run().await,
+        Some(Commands::PruneContainers(args)) => return cli::prune::run(args).await,
         _ => {}
     }
 
@@ -43,15 +74,32 @@ async fn main() -> Result<()> {
         migrations::run_migrations()?;
     }
 
+    if let Ok(config) = agent_of_empires::session::resolve_config(&profile) {
+        agent_of_empires::tmux::set_session_name_length(config.tmux.session_name_length);
+    }
+
     match cli.command {
-        Some(Commands::Add(args)) => cli::add::run(&profile, args).await,
+        Some(Commands::Add(args)) => cli::add::run(&profile, *args).await,
+        Some(Commands::Create(args)) => cli::create::run(&profile, *args).await,
         Some(Commands::List(args)) => cli::list::run(&profile, args).await,
         Some(Commands::Remove(args)) => cli::remove::run(&profile, args).await,
+        Some(Commands::ImportTmux(args)) => cli::import_tmux::run(&profile, args).await,
+        Some(Commands::Archive(args)) => cli::archive::run(&profile, args).await,
+        Some(Commands::Unarchive(args)) => cli::archive::run_unarchive(&profile, args).await,
         Some(Commands::Status(args)) => cli::status::run(&profile, args).await,
+        Some(Commands::Watch(args)) => cli::watch::run(&profile, args).await,
+        Some(Commands::Start(args)) => cli::session::start_session(&profile, args).await,
+        Some(Commands::Attach(args)) => cli::session::attach_session(&profile, args).await,
+        Some(Commands::Interrupt(args)) => cli::interrupt::run(&profile, args).await,
+        Some(Commands::Kill(args)) => cli::kill::run(&profile, args).await,
+        Some(Commands::Dump(args)) => cli::dump::run(&profile, args).await,
         Some(Commands::Session { command }) => cli::session::run(&profile, command).await,
         Some(Commands::Group { command }) => cli::group::run(&profile, command).await,
         Some(Commands::Profile { command }) => cli::profile::run(command).await,
+        Some(Commands::Config { command }) => cli::config::run(&profile, command).await,
         Some(Commands::Worktree { command }) => cli::worktree::run(&profile, command).await,
+        #[cfg(feature = "serve")]
+        Some(Commands::Serve(args)) => cli::serve::run(&profile, args).await,
         None => tui::run(&profile).await,
         _ => unreachable!(),
     }
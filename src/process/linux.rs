@@ -7,6 +7,9 @@ use nix::sys::signal::{kill, Signal};
 use nix::unistd::Pid;
 use tracing::debug;
 
+/// Command that opens a path in the desktop file manager
+pub const FILE_MANAGER: &str = "xdg-open";
+
 /// Kill a process and all its descendants
 /// Uses SIGTERM first, then SIGKILL after a short delay for stragglers
 pub fn kill_process_tree(pid: u32) {
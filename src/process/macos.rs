@@ -8,6 +8,9 @@ use nix::sys::signal::{kill, Signal};
 use nix::unistd::Pid;
 use tracing::debug;
 
+/// Command that opens a path in Finder
+pub const FILE_MANAGER: &str = "open";
+
 /// Kill a process and all its descendants
 /// Uses SIGTERM first, then SIGKILL after a short delay for stragglers
 pub fn kill_process_tree(pid: u32) {
@@ -1,6 +1,7 @@
 //! Process utilities for tmux session management
 
-use std::process::Command;
+use std::path::Path;
+use std::process::{Command, Stdio};
 
 #[cfg(target_os = "linux")]
 mod linux;
@@ -8,6 +9,53 @@ mod linux;
 #[cfg(target_os = "macos")]
 mod macos;
 
+/// Pass each line `child` writes to stdout or stderr to `on_line` as it
+/// arrives, and return everything it wrote to stderr. Both streams must be
+/// piped; lines from the two are interleaved in arrival order.
+pub fn stream_output(child: &mut std::process::Child, mut on_line: impl FnMut(&str)) -> String {
+    use std::io::BufRead;
+
+    fn forward(
+        reader: impl std::io::Read + Send + 'static,
+        is_stderr: bool,
+        tx: std::sync::mpsc::Sender<(bool, String)>,
+    ) -> std::thread::JoinHandle<()> {
+        std::thread::spawn(move || {
+            for line in std::io::BufReader::new(reader)
+                .lines()
+                .map_while(Result::ok)
+            {
+                if tx.send((is_stderr, line)).is_err() {
+                    break;
+                }
+            }
+        })
+    }
+
+    let (tx, rx) = std::sync::mpsc::channel();
+    let mut readers = Vec::new();
+    if let Some(stdout) = child.stdout.take() {
+        readers.push(forward(stdout, false, tx.clone()));
+    }
+    if let Some(stderr) = child.stderr.take() {
+        readers.push(forward(stderr, true, tx.clone()));
+    }
+    drop(tx);
+
+    let mut stderr = String::new();
+    for (is_stderr, line) in rx {
+        on_line(&line);
+        if is_stderr {
+            stderr.push_str(&line);
+            stderr.push('\n');
+        }
+    }
+    for reader in readers {
+        let _ = reader.join();
+    }
+    stderr
+}
+
 /// Get the PID of the shell process running in a tmux pane
 pub fn get_pane_pid(session_name: &str) -> Option<u32> {
     let output = Command::new("tmux")
@@ -61,3 +109,103 @@ pub fn kill_process_tree(pid: u32) {
         // No-op on unsupported platforms, fall back to tmux kill-session only
     }
 }
+
+fn file_manager() -> Option<&'static str> {
+    #[cfg(target_os = "linux")]
+    {
+        Some(linux::FILE_MANAGER)
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        Some(macos::FILE_MANAGER)
+    }
+
+    #[cfg(not(any(target_os = "linux", target_os = "macos")))]
+    {
+        None
+    }
+}
+
+/// Pick the program and arguments used to open `path`: `$VISUAL`, then
+/// `$EDITOR` (either may carry arguments, e.g. `code -n`), then the platform
+/// file manager.
+pub fn open_command(
+    visual: Option<&str>,
+    editor: Option<&str>,
+    file_manager: Option<&str>,
+    path: &Path,
+) -> Option<(String, Vec<String>)> {
+    let configured = [visual, editor]
+        .into_iter()
+        .flatten()
+        .find(|v| !v.trim().is_empty());
+    let mut parts = configured
+        .or(file_manager)?
+        .split_whitespace()
+        .map(str::to_string);
+    let program = parts.next()?;
+    let mut args: Vec<String> = parts.collect();
+    args.push(path.to_string_lossy().into_owned());
+    Some((program, args))
+}
+
+/// `$VISUAL` or `$EDITOR` with `path` appended, when either is set.
+pub fn editor_command(path: &Path) -> Option<(String, Vec<String>)> {
+    let visual = std::env::var("VISUAL").ok();
+    let editor = std::env::var("EDITOR").ok();
+    open_command(visual.as_deref(), editor.as_deref(), None, path)
+}
+
+/// Run the user's editor on `path` and wait for it. It inherits the
+/// terminal so terminal editors work; callers suspend any TUI first.
+pub fn open_in_editor(path: &Path) -> std::io::Result<std::process::ExitStatus> {
+    let (program, args) = editor_command(path)
+        .ok_or_else(|| std::io::Error::other("No editor configured; set $VISUAL or $EDITOR"))?;
+    Command::new(program).args(args).current_dir(path).status()
+}
+
+/// Open `path` in the platform file manager without waiting for it
+pub fn open_in_file_manager(path: &Path) -> std::io::Result<()> {
+    let (program, args) = open_command(None, None, file_manager(), path)
+        .ok_or_else(|| std::io::Error::other("No file manager available on this platform"))?;
+    Command::new(program)
+        .args(args)
+        .current_dir(path)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cmd(
+        visual: Option<&str>,
+        editor: Option<&str>,
+        file_manager: Option<&str>,
+    ) -> Option<(String, Vec<String>)> {
+        open_command(visual, editor, file_manager, Path::new("/work/proj"))
+    }
+
+    #[test]
+    fn test_open_command_selection() {
+        let path = "/work/proj".to_string();
+        assert_eq!(
+            cmd(Some("code"), Some("vim"), Some("xdg-open")),
+            Some(("code".to_string(), vec![path.clone()]))
+        );
+        assert_eq!(
+            cmd(None, Some("subl -n"), Some("open")),
+            Some(("subl".to_string(), vec!["-n".to_string(), path.clone()]))
+        );
+        assert_eq!(
+            cmd(Some("  "), None, Some("xdg-open")),
+            Some(("xdg-open".to_string(), vec![path]))
+        );
+        assert_eq!(cmd(None, None, None), None);
+    }
+}
@@ -9,10 +9,11 @@ use std::path::PathBuf;
 use anyhow::{bail, Result};
 use chrono::Utc;
 
+use crate::containers::error::DockerError;
 use crate::containers::{self, ContainerRuntimeInterface};
 use crate::git::GitWorktree;
 
-use super::{civilizations, Config, Instance, SandboxInfo, WorktreeInfo};
+use super::{civilizations, Config, Instance, SandboxInfo, SessionError, WorktreeInfo};
 
 /// Parameters for creating a new session instance.
 #[derive(Debug, Clone)]
@@ -31,6 +32,8 @@ pub struct InstanceParams {
     pub extra_env_keys: Vec<String>,
     /// Additional KEY=VALUE environment variables to inject into the container.
     pub extra_env_values: Vec<String>,
+    /// Profile the session is created in.
+    pub profile: String,
 }
 
 /// Result of building an instance, tracking what was created for cleanup purposes.
@@ -55,10 +58,16 @@ pub fn build_instance(params: InstanceParams, existing_titles: &[&str]) -> Resul
     if params.sandbox {
         let runtime = containers::get_container_runtime();
         if !runtime.is_available() {
-            bail!("Container runtime is not installed. Please install Docker or Apple Container to use sandbox mode.");
+            return Err(DockerError::RuntimeUnavailable(
+                "Container runtime is not installed. Please install Docker or Apple Container to use sandbox mode.".to_string(),
+            )
+            .into());
         }
         if !runtime.is_daemon_running() {
-            bail!("Container runtime daemon is not running. Please start Docker or Apple Container to use sandbox mode.");
+            return Err(DockerError::RuntimeUnavailable(
+                "Container runtime daemon is not running. Please start Docker or Apple Container to use sandbox mode.".to_string(),
+            )
+            .into());
         }
     }
 
@@ -106,7 +115,11 @@ pub fn build_instance(params: InstanceParams, existing_titles: &[&str]) -> Resul
                 });
             } else {
                 let session_id = uuid::Uuid::new_v4().to_string();
-                let worktree_path = git_wt.compute_path(branch, template, &session_id[..8])?;
+                let worktree_path = git_wt.compute_path(
+                    branch,
+                    template,
+                    crate::cli::truncate_id(&session_id, 8),
+                )?;
 
                 git_wt.create_worktree(branch, &worktree_path, false)?;
 
@@ -125,7 +138,8 @@ pub fn build_instance(params: InstanceParams, existing_titles: &[&str]) -> Resul
             }
         } else {
             let session_id = uuid::Uuid::new_v4().to_string();
-            let worktree_path = git_wt.compute_path(branch, template, &session_id[..8])?;
+            let worktree_path =
+                git_wt.compute_path(branch, template, crate::cli::truncate_id(&session_id, 8))?;
 
             if worktree_path.exists() {
                 bail!("Worktree already exists at {}", worktree_path.display());
@@ -153,10 +167,16 @@ pub fn build_instance(params: InstanceParams, existing_titles: &[&str]) -> Resul
     // without this check tmux silently falls back to the home directory.
     let final_path_buf = PathBuf::from(&final_path);
     if !final_path_buf.exists() {
-        bail!("Project path does not exist: {}", final_path);
+        return Err(
+            SessionError::Invalid(format!("Project path does not exist: {}", final_path)).into(),
+        );
     }
     if !final_path_buf.is_dir() {
-        bail!("Project path is not a directory: {}", final_path);
+        return Err(SessionError::Invalid(format!(
+            "Project path is not a directory: {}",
+            final_path
+        ))
+        .into());
     }
 
     let final_title = if params.title.is_empty() {
@@ -167,6 +187,7 @@ pub fn build_instance(params: InstanceParams, existing_titles: &[&str]) -> Resul
 
     let mut instance = Instance::new(&final_title, &final_path);
     instance.group_path = params.group;
+    instance.profile = params.profile;
     instance.tool = params.tool.clone();
     instance.command = crate::agents::get_agent(&params.tool)
         .filter(|a| a.set_default_command)
@@ -180,7 +201,14 @@ pub fn build_instance(params: InstanceParams, existing_titles: &[&str]) -> Resul
             enabled: true,
             container_id: None,
             image: params.sandbox_image.clone(),
-            container_name: containers::DockerContainer::generate_name(&instance.id),
+            container_name: containers::container_name_for(
+                super::resolve_config(&instance.profile)
+                    .map(|c| c.sandbox.container_name_scheme)
+                    .unwrap_or_default(),
+                &instance.profile,
+                &instance.id,
+                &instance.title,
+            ),
             created_at: None,
             extra_env_keys: if params.extra_env_keys.is_empty() {
                 None
@@ -206,6 +234,12 @@ pub fn build_instance(params: InstanceParams, existing_titles: &[&str]) -> Resul
             custom_instruction: Config::load()
                 .ok()
                 .and_then(|c| c.sandbox.custom_instruction),
+            cpu_limit: None,
+            memory_limit: None,
+            config_hash: None,
+            workdir: None,
+            volume_ignores: None,
+            extra_volumes: None,
         });
     }
 
@@ -232,7 +266,7 @@ pub fn cleanup_instance(instance: &Instance, created_worktree: Option<&CreatedWo
 
     if let Some(sandbox) = &instance.sandbox_info {
         if sandbox.enabled {
-            let container = containers::DockerContainer::from_session_id(&instance.id);
+            let container = instance.container();
             if container.exists().unwrap_or(false) {
                 if let Err(e) = container.remove(true) {
                     tracing::warn!("Failed to clean up container: {}", e);
@@ -40,6 +40,14 @@ pub struct Config {
     #[serde(default)]
     pub hooks: HooksConfig,
 
+    /// Launch command templates keyed by tool name (e.g. `claude = "~/bin/claude-wrapper {workdir}"`)
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub commands: HashMap<String, String>,
+
+    /// Named session defaults applied with `aoe add --template <name>`
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub templates: HashMap<String, SessionTemplate>,
+
     #[serde(default)]
     pub sound: crate::sound::SoundConfig,
 
@@ -47,6 +55,34 @@ pub struct Config {
     pub app_state: AppStateConfig,
 }
 
+/// Defaults for a new session, defined under `[templates.<name>]`. Flags
+/// passed to `aoe add` take precedence over every value here.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SessionTemplate {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tool: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group: Option<String>,
+
+    /// Overrides `sandbox.enabled_by_default` when set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sandbox: Option<bool>,
+
+    /// Sandbox image (implies `sandbox = true` unless that is set to false)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sandbox_image: Option<String>,
+
+    /// Environment variables set in the session
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub env: HashMap<String, String>,
+
+    /// Extra sandbox volume mounts (`host:container` or `host:container:ro`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub volumes: Vec<String>,
+}
+
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct AppStateConfig {
     #[serde(default)]
@@ -65,17 +101,87 @@ pub struct AppStateConfig {
     pub has_seen_custom_instruction_warning: bool,
 }
 
+/// Lower bound for the TUI status poll interval, to avoid hammering tmux/docker
+pub const MIN_POLL_INTERVAL_MS: u64 = 250;
+
 /// Session-related configuration defaults
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SessionConfig {
     /// Default coding tool for new sessions (claude, opencode, vibe, codex)
     /// If not set or tool is unavailable, falls back to first available tool
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::agents::deserialize_optional_tool_name"
+    )]
     pub default_tool: Option<String>,
 
     /// Enable YOLO mode by default for new sessions (skip permission prompts)
     #[serde(default)]
     pub yolo_mode_default: bool,
+
+    /// How often the TUI re-detects session statuses, in milliseconds
+    #[serde(default = "default_poll_interval_ms")]
+    pub poll_interval_ms: u64,
+
+    /// Ask for confirmation before quitting the TUI while any session is running
+    #[serde(default)]
+    pub confirm_quit_when_running: bool,
+
+    /// Send a single Enter to a session that has sat waiting with unchanged
+    /// output for this many seconds. Never sent to approval prompts. 0
+    /// disables it.
+    #[serde(default)]
+    pub auto_nudge_seconds: u64,
+
+    /// What to do when attaching to a host session whose agent has exited
+    #[serde(default)]
+    pub relaunch_on_attach: RelaunchOnAttach,
+}
+
+/// Behavior when attaching to a non-sandboxed session whose agent is no
+/// longer running in its pane.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum RelaunchOnAttach {
+    /// Ask before relaunching the agent
+    #[default]
+    Prompt,
+    /// Relaunch the agent without asking
+    Auto,
+    /// Attach to the pane as it is
+    Off,
+}
+
+impl Default for SessionConfig {
+    fn default() -> Self {
+        Self {
+            default_tool: None,
+            yolo_mode_default: false,
+            poll_interval_ms: default_poll_interval_ms(),
+            confirm_quit_when_running: false,
+            auto_nudge_seconds: 0,
+            relaunch_on_attach: RelaunchOnAttach::default(),
+        }
+    }
+}
+
+impl SessionConfig {
+    /// Status poll interval, clamped to `MIN_POLL_INTERVAL_MS`.
+    pub fn poll_interval(&self) -> std::time::Duration {
+        std::time::Duration::from_millis(self.poll_interval_ms.max(MIN_POLL_INTERVAL_MS))
+    }
+
+    /// How long a waiting session must stay unchanged before it is nudged,
+    /// if auto-nudge is enabled.
+    pub fn auto_nudge_threshold(&self) -> Option<chrono::Duration> {
+        (self.auto_nudge_seconds > 0)
+            .then(|| chrono::Duration::seconds(self.auto_nudge_seconds as i64))
+    }
+}
+
+fn default_poll_interval_ms() -> u64 {
+    500
 }
 
 /// Diff view configuration
@@ -208,6 +314,11 @@ pub struct SandboxConfig {
     #[serde(default = "default_sandbox_image")]
     pub default_image: String,
 
+    /// Default image per tool (e.g. `opencode = "me/opencode-sandbox"`),
+    /// used instead of `default_image` for sessions running that tool
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub tool_images: HashMap<String, String>,
+
     #[serde(default)]
     pub extra_volumes: Vec<String>,
 
@@ -220,9 +331,22 @@ pub struct SandboxConfig {
     #[serde(default, skip_serializing_if = "HashMap::is_empty")]
     pub environment_values: HashMap<String, String>,
 
+    /// Extra labels applied to sandbox containers. The `aoe.*` management
+    /// labels are always set and cannot be overridden.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub labels: HashMap<String, String>,
+
     #[serde(default = "default_true")]
     pub auto_cleanup: bool,
 
+    /// Stop sandbox containers whose agent has produced no output for
+    /// `idle_stop_minutes`. They are restarted on the next attach.
+    #[serde(default)]
+    pub auto_stop_idle: bool,
+
+    #[serde(default = "default_idle_stop_minutes")]
+    pub idle_stop_minutes: u64,
+
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub cpu_limit: Option<String>,
 
@@ -241,6 +365,63 @@ pub struct SandboxConfig {
     #[serde(default)]
     pub mount_ssh: bool,
 
+    /// Fail container creation when a bind mount's host path does not exist,
+    /// instead of only warning (default: false)
+    #[serde(default)]
+    pub strict_mounts: bool,
+
+    /// Fail container creation when an `environment_values` entry references
+    /// an undefined host variable, instead of expanding it to empty
+    /// (default: false)
+    #[serde(default)]
+    pub strict_env: bool,
+
+    /// Run the container with a read-only root filesystem. The agent can then
+    /// only write to bind-mounted paths (the project, extra volumes) and a
+    /// tmpfs at /tmp (default: false)
+    #[serde(default)]
+    pub read_only_root: bool,
+
+    /// Run an init process as PID 1 in the container (`docker run --init`) so
+    /// exited agent processes are reaped and signals are forwarded.
+    /// Recommended; off by default to keep existing containers unchanged
+    #[serde(default)]
+    pub init: bool,
+
+    /// Extra `/etc/hosts` entries for the container, as `hostname:ip`
+    /// (e.g. `db.local:10.0.0.5` or `api:host-gateway`)
+    #[serde(default)]
+    pub extra_hosts: Vec<String>,
+
+    /// Map `host.docker.internal` to the host gateway on Linux so the agent
+    /// can reach services on the host. Docker Desktop provides this name
+    /// already (default: false)
+    #[serde(default)]
+    pub host_gateway: bool,
+
+    /// Most sandbox containers allowed to run at once across all profiles.
+    /// Starting a session that needs another container is refused once the
+    /// cap is reached (0 = no limit)
+    #[serde(default)]
+    pub max_containers: u64,
+
+    /// Command run in the container with `sh -c` before the agent launches.
+    /// It is retried until it succeeds or `ready_timeout_secs` pass, so the
+    /// agent only starts once services it needs are up.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ready_probe: Option<String>,
+
+    /// Seconds to keep retrying `ready_probe` before giving up and failing
+    /// the session start
+    #[serde(default = "default_ready_timeout_secs")]
+    pub ready_timeout_secs: u64,
+
+    /// Host dotfiles or directories, relative to the home directory, mounted
+    /// read-only at the same path under the container home. Missing entries
+    /// are skipped.
+    #[serde(default)]
+    pub dotfiles: Vec<String>,
+
     /// Custom instruction text appended to the agent's system prompt in sandboxed sessions.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub custom_instruction: Option<String>,
@@ -248,6 +429,22 @@ pub struct SandboxConfig {
     /// Container runtime to use for sandboxing (docker or apple_container)
     #[serde(default)]
     pub container_runtime: ContainerRuntimeName,
+
+    /// How new sandbox containers are named (id or profile_title)
+    #[serde(default)]
+    pub container_name_scheme: ContainerNameScheme,
+}
+
+/// Naming scheme for new sandbox containers. Existing containers keep the
+/// name recorded when they were created.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ContainerNameScheme {
+    /// `aoe-sandbox-<id>`
+    #[default]
+    Id,
+    /// `aoe-sandbox-<profile>-<title>-<id>`
+    ProfileTitle,
 }
 
 /// Container runtime options for sandboxing
@@ -264,21 +461,62 @@ impl Default for SandboxConfig {
         Self {
             enabled_by_default: false,
             default_image: default_sandbox_image(),
+            tool_images: HashMap::new(),
             extra_volumes: Vec::new(),
             environment: default_sandbox_environment(),
             environment_values: HashMap::new(),
+            labels: HashMap::new(),
             auto_cleanup: true,
+            auto_stop_idle: false,
+            idle_stop_minutes: default_idle_stop_minutes(),
             cpu_limit: None,
             memory_limit: None,
             default_terminal_mode: DefaultTerminalMode::default(),
             volume_ignores: Vec::new(),
             mount_ssh: false,
+            strict_mounts: false,
+            strict_env: false,
+            read_only_root: false,
+            init: false,
+            extra_hosts: Vec::new(),
+            host_gateway: false,
+            max_containers: 0,
+            ready_probe: None,
+            ready_timeout_secs: default_ready_timeout_secs(),
+            dotfiles: Vec::new(),
             custom_instruction: None,
             container_runtime: ContainerRuntimeName::default(),
+            container_name_scheme: ContainerNameScheme::default(),
         }
     }
 }
 
+impl SandboxConfig {
+    /// The idle threshold after which containers are stopped, if enabled.
+    pub fn idle_stop_threshold(&self) -> Option<chrono::Duration> {
+        self.auto_stop_idle
+            .then(|| chrono::Duration::minutes(self.idle_stop_minutes.max(1) as i64))
+    }
+
+    /// Image for a new sandboxed session running `tool`: its `tool_images`
+    /// entry, else `default_image`.
+    pub fn default_image_for_tool(&self, tool: &str) -> &str {
+        self.tool_images
+            .get(tool)
+            .map(String::as_str)
+            .filter(|image| !image.trim().is_empty())
+            .unwrap_or(&self.default_image)
+    }
+}
+
+fn default_idle_stop_minutes() -> u64 {
+    60
+}
+
+fn default_ready_timeout_secs() -> u64 {
+    60
+}
+
 fn default_sandbox_image() -> String {
     "ghcr.io/njbrake/aoe-sandbox:latest".to_string()
 }
@@ -332,6 +570,20 @@ pub struct TmuxConfig {
     /// Mouse support mode (auto, enabled, disabled)
     #[serde(default)]
     pub mouse: TmuxMouseMode,
+
+    /// Number of title characters kept in generated tmux session names.
+    /// Only applies to sessions started afterwards; running ones keep their name.
+    #[serde(default = "default_session_name_length")]
+    pub session_name_length: usize,
+
+    /// Extra tmux options applied with `set-option` to every aoe session,
+    /// e.g. `history-limit = "50000"`. Per-session options take precedence.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub options: HashMap<String, String>,
+}
+
+fn default_session_name_length() -> usize {
+    crate::tmux::DEFAULT_SESSION_NAME_LENGTH
 }
 
 impl Default for TmuxConfig {
@@ -339,6 +591,8 @@ impl Default for TmuxConfig {
         Self {
             status_bar: TmuxStatusBarMode::Auto,
             mouse: TmuxMouseMode::Auto,
+            session_name_length: default_session_name_length(),
+            options: HashMap::new(),
         }
     }
 }
@@ -382,7 +636,24 @@ pub fn should_apply_tmux_mouse() -> Option<bool> {
     }
 }
 
-fn config_path() -> Result<PathBuf> {
+/// Render a `[commands]` launch template. `{workdir}` expands to the session's
+/// working directory and `{resume_id}` to the resume ID (empty when unknown).
+pub fn render_command_template(template: &str, workdir: &str, resume_id: Option<&str>) -> String {
+    template
+        .replace("{workdir}", workdir)
+        .replace("{resume_id}", resume_id.unwrap_or(""))
+        .trim()
+        .to_string()
+}
+
+/// Environment variable naming an explicit global config file, used instead
+/// of `config.toml` in the app directory. `--config` sets it for the process.
+pub const CONFIG_ENV: &str = "AOE_CONFIG";
+
+pub fn config_path() -> Result<PathBuf> {
+    if let Some(path) = std::env::var_os(CONFIG_ENV).filter(|p| !p.is_empty()) {
+        return Ok(PathBuf::from(path));
+    }
     Ok(get_app_dir()?.join("config.toml"))
 }
 
@@ -482,6 +753,34 @@ mod tests {
         assert_eq!(theme.name, "dark");
     }
 
+    #[test]
+    fn test_session_config_poll_interval_default() {
+        let session = SessionConfig::default();
+        assert_eq!(session.poll_interval_ms, 500);
+        assert_eq!(
+            session.poll_interval(),
+            std::time::Duration::from_millis(500)
+        );
+
+        let session: SessionConfig = toml::from_str("").unwrap();
+        assert_eq!(session.poll_interval_ms, 500);
+    }
+
+    #[test]
+    fn test_session_config_poll_interval_clamped_to_minimum() {
+        let session: SessionConfig = toml::from_str("poll_interval_ms = 10").unwrap();
+        assert_eq!(
+            session.poll_interval(),
+            std::time::Duration::from_millis(MIN_POLL_INTERVAL_MS)
+        );
+
+        let session: SessionConfig = toml::from_str("poll_interval_ms = 2000").unwrap();
+        assert_eq!(
+            session.poll_interval(),
+            std::time::Duration::from_millis(2000)
+        );
+    }
+
     // Tests for UpdatesConfig
     #[test]
     fn test_updates_config_default() {
@@ -864,4 +1163,122 @@ mod tests {
         assert_eq!(config.diff.default_branch, Some("main".to_string()));
         assert_eq!(config.diff.context_lines, 10);
     }
+
+    #[test]
+    fn test_templates_config_deserialize() {
+        let toml = r#"
+            [templates.backend]
+            tool = "codex"
+            group = "work/backend"
+            sandbox_image = "rust:latest"
+            env = { RUST_LOG = "debug" }
+            volumes = ["~/.cargo/registry:/root/.cargo/registry"]
+        "#;
+        let config: Config = toml::from_str(toml).unwrap();
+        let backend = &config.templates["backend"];
+        assert_eq!(backend.tool.as_deref(), Some("codex"));
+        assert_eq!(backend.group.as_deref(), Some("work/backend"));
+        assert_eq!(backend.sandbox, None);
+        assert_eq!(backend.sandbox_image.as_deref(), Some("rust:latest"));
+        assert_eq!(backend.env["RUST_LOG"], "debug");
+        assert_eq!(backend.volumes.len(), 1);
+        assert!(Config::default().templates.is_empty());
+    }
+
+    #[test]
+    fn test_commands_config_deserialize() {
+        let toml = r#"
+            [commands]
+            claude = "~/bin/claude-wrapper --cwd {workdir}"
+        "#;
+        let config: Config = toml::from_str(toml).unwrap();
+        assert_eq!(
+            config.commands.get("claude").map(String::as_str),
+            Some("~/bin/claude-wrapper --cwd {workdir}")
+        );
+        assert!(Config::default().commands.is_empty());
+    }
+
+    #[test]
+    fn test_render_command_template_placeholders() {
+        assert_eq!(
+            render_command_template(
+                "claude --cwd {workdir} --resume {resume_id}",
+                "/repo",
+                Some("abc")
+            ),
+            "claude --cwd /repo --resume abc"
+        );
+    }
+
+    #[test]
+    fn test_render_command_template_missing_placeholders() {
+        assert_eq!(
+            render_command_template("claude --verbose", "/repo", Some("abc")),
+            "claude --verbose"
+        );
+        assert_eq!(
+            render_command_template("claude --resume {resume_id}", "/repo", None),
+            "claude --resume"
+        );
+    }
+
+    #[test]
+    fn test_render_command_template_extra_text() {
+        assert_eq!(
+            render_command_template(
+                "  env FOO=1 wrapper.sh {workdir}/src -- --model opus  ",
+                "/w",
+                None
+            ),
+            "env FOO=1 wrapper.sh /w/src -- --model opus"
+        );
+        assert_eq!(
+            render_command_template("{unknown} {workdir}", "/w", None),
+            "{unknown} /w"
+        );
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_explicit_config_path_overrides_default_location() {
+        let temp = tempfile::tempdir().unwrap();
+        std::env::set_var("HOME", temp.path());
+        #[cfg(target_os = "linux")]
+        std::env::set_var("XDG_CONFIG_HOME", temp.path().join(".config"));
+
+        let mut default_config = Config::default();
+        default_config.session.default_tool = Some("codex".to_string());
+        save_config(&default_config).unwrap();
+
+        let explicit = temp.path().join("ci.toml");
+        fs::write(&explicit, "[session]\ndefault_tool = \"gemini\"\n").unwrap();
+
+        std::env::set_var(CONFIG_ENV, &explicit);
+        let loaded = Config::load();
+        std::env::remove_var(CONFIG_ENV);
+
+        assert_eq!(
+            config_path().unwrap(),
+            get_app_dir().unwrap().join("config.toml")
+        );
+        assert_eq!(
+            loaded.unwrap().session.default_tool.as_deref(),
+            Some("gemini")
+        );
+        assert_eq!(
+            Config::load().unwrap().session.default_tool.as_deref(),
+            Some("codex")
+        );
+    }
+
+    #[test]
+    fn test_default_image_for_tool() {
+        let mut sb = SandboxConfig::default();
+        sb.tool_images
+            .insert("opencode".to_string(), "me/opencode-sandbox".to_string());
+
+        assert_eq!(sb.default_image_for_tool("opencode"), "me/opencode-sandbox");
+        assert_eq!(sb.default_image_for_tool("claude"), sb.default_image);
+    }
 }
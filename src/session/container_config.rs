@@ -4,15 +4,16 @@
 //! `ContainerConfig` structs. Includes sandbox directory sync, agent config
 //! mounting, and credential extraction.
 
+use std::collections::{BTreeMap, HashMap};
 use std::path::Path;
 
-use anyhow::Result;
+use anyhow::{bail, Result};
 
 use crate::containers::{ContainerConfig, VolumeMount};
 use crate::git::GitWorktree;
 
-use super::environment::{collect_env_keys, collect_env_values};
-use super::instance::SandboxInfo;
+use super::environment::{apply_default_terminal_env, collect_env_keys, collect_env_values};
+use super::instance::{Instance, SandboxInfo};
 
 /// Subdirectory name inside each agent's config dir for the shared sandbox config.
 const SANDBOX_SUBDIR: &str = "sandbox";
@@ -435,6 +436,86 @@ pub(crate) fn compute_volume_paths(
     ))
 }
 
+/// Build and dependency directories worth keeping off the bind mount, keyed
+/// by a marker file that identifies the project type.
+const BUILD_DIR_MARKERS: &[(&str, &[&str])] = &[
+    ("package.json", &["node_modules"]),
+    ("Cargo.toml", &["target"]),
+    ("pyproject.toml", &[".venv"]),
+    ("requirements.txt", &[".venv"]),
+    ("pom.xml", &["target"]),
+    ("build.gradle", &["build", ".gradle"]),
+    ("build.gradle.kts", &["build", ".gradle"]),
+    ("mix.exs", &["_build", "deps"]),
+    ("composer.json", &["vendor"]),
+];
+
+/// Anonymous volume paths (relative to the workspace) suggested for the
+/// project in `dir`, from the marker files at its root. Heavy build output on
+/// an anonymous volume avoids slow bind-mount I/O, most of all on macOS.
+pub fn suggest_volume_ignores(dir: &Path) -> Vec<String> {
+    let mut suggested: Vec<String> = Vec::new();
+    for (marker, paths) in BUILD_DIR_MARKERS {
+        if !dir.join(marker).is_file() {
+            continue;
+        }
+        for path in *paths {
+            if !suggested.iter().any(|p| p == path) {
+                suggested.push(path.to_string());
+            }
+        }
+    }
+    suggested
+}
+
+/// Working directory inside the container. A session `workdir` replaces the
+/// default: absolute paths are used as-is, relative ones are joined onto the
+/// project mount, so the agent can run in a subdirectory of what is mounted.
+pub(crate) fn resolve_workdir(
+    mount_container_path: &str,
+    default_workdir: String,
+    workdir: Option<&str>,
+) -> String {
+    match workdir.map(|w| w.trim_end_matches('/')) {
+        Some(w) if w.starts_with('/') => w.to_string(),
+        Some(w) if !w.is_empty() => format!("{}/{}", mount_container_path, w),
+        _ => default_workdir,
+    }
+}
+
+/// How often the readiness probe is retried
+pub(crate) const READY_PROBE_INTERVAL: std::time::Duration = std::time::Duration::from_secs(1);
+
+/// Run `probe` until it succeeds, sleeping `interval` between attempts and
+/// calling `on_wait` with the number of failed attempts so far. Gives up once
+/// the time slept reaches `timeout`.
+pub(crate) fn wait_until_ready(
+    mut probe: impl FnMut() -> bool,
+    timeout: std::time::Duration,
+    interval: std::time::Duration,
+    mut sleep: impl FnMut(std::time::Duration),
+    mut on_wait: impl FnMut(u32),
+) -> Result<()> {
+    let mut waited = std::time::Duration::ZERO;
+    let mut failures = 0;
+    loop {
+        if probe() {
+            return Ok(());
+        }
+        failures += 1;
+        if waited >= timeout {
+            bail!(
+                "Sandbox readiness probe did not succeed within {}s ({} attempts)",
+                timeout.as_secs(),
+                failures
+            );
+        }
+        on_wait(failures);
+        sleep(interval);
+        waited += interval;
+    }
+}
+
 /// Re-sync shared sandbox directories from the host so the container picks up
 /// any credential changes (e.g. re-auth) since it was created.
 pub(crate) fn refresh_agent_configs() {
@@ -453,12 +534,142 @@ pub(crate) fn refresh_agent_configs() {
     }
 }
 
+/// Read-only mounts for configured dotfiles. Entries are relative to the host
+/// home (a leading `~/` is accepted) and land at the same path under
+/// `container_home`. Missing entries are skipped, as are absolute paths and
+/// paths that escape the home directory.
+pub(crate) fn dotfile_mounts(
+    home: &Path,
+    container_home: &str,
+    dotfiles: &[String],
+) -> Vec<VolumeMount> {
+    dotfiles
+        .iter()
+        .filter_map(|entry| {
+            let rel = entry.trim().trim_start_matches("~/").trim_end_matches('/');
+            let rel_path = Path::new(rel);
+            if rel.is_empty()
+                || rel_path.is_absolute()
+                || rel_path
+                    .components()
+                    .any(|c| matches!(c, std::path::Component::ParentDir))
+            {
+                tracing::warn!(
+                    "Ignoring dotfile entry outside the home directory: {}",
+                    entry
+                );
+                return None;
+            }
+            let host_path = home.join(rel_path);
+            if !host_path.exists() {
+                tracing::debug!("Skipping missing dotfile {}", host_path.display());
+                return None;
+            }
+            Some(VolumeMount {
+                host_path: host_path.to_string_lossy().to_string(),
+                container_path: format!("{}/{}", container_home, rel),
+                read_only: true,
+            })
+        })
+        .collect()
+}
+
+/// Labels identifying aoe-managed containers for external tooling. User labels
+/// are merged in first so the `aoe.*` management labels always win.
+pub(crate) fn container_labels(
+    session_id: &str,
+    profile: &str,
+    user_labels: &HashMap<String, String>,
+) -> BTreeMap<String, String> {
+    let mut labels: BTreeMap<String, String> = user_labels
+        .iter()
+        .map(|(k, v)| (k.clone(), v.clone()))
+        .collect();
+    labels.insert("aoe.managed".to_string(), "true".to_string());
+    labels.insert("aoe.session_id".to_string(), session_id.to_string());
+    if !profile.is_empty() {
+        labels.insert("aoe.profile".to_string(), profile.to_string());
+    }
+    labels
+}
+
+/// Titles of sandboxed sessions other than `exclude_id` whose container is
+/// running according to `is_running`.
+pub(crate) fn running_sandboxed_sessions(
+    instances: &[Instance],
+    exclude_id: &str,
+    is_running: impl Fn(&Instance) -> bool,
+) -> Vec<String> {
+    instances
+        .iter()
+        .filter(|i| i.id != exclude_id && i.is_sandboxed() && is_running(i))
+        .map(|i| i.title.clone())
+        .collect()
+}
+
+/// Refuse to start another container once `max_containers` are running
+/// (0 = no limit).
+pub(crate) fn check_container_cap(max_containers: u64, running: &[String]) -> Result<()> {
+    if max_containers == 0 || (running.len() as u64) < max_containers {
+        return Ok(());
+    }
+    bail!(
+        "{} sandbox containers are already running (sandbox.max_containers = {}). \
+         Stop one of these sessions first: {}",
+        running.len(),
+        max_containers,
+        running.join(", ")
+    );
+}
+
+const HOST_GATEWAY_ENTRY: &str = "host.docker.internal:host-gateway";
+
+/// `/etc/hosts` entries for a container: the configured `extra_hosts`, plus a
+/// `host.docker.internal` gateway mapping when `host_gateway` is set on Linux
+/// (Docker Desktop already resolves that name) and the user has not mapped
+/// it themselves. Entries without a `:` are dropped with a warning.
+pub(crate) fn resolve_extra_hosts(
+    configured: &[String],
+    host_gateway: bool,
+    is_linux: bool,
+) -> Vec<String> {
+    let mut hosts: Vec<String> = Vec::new();
+    for entry in configured {
+        if !entry.contains(':') {
+            tracing::warn!("Ignoring malformed extra_hosts entry: {}", entry);
+        } else if !hosts.contains(entry) {
+            hosts.push(entry.clone());
+        }
+    }
+    let mapped = hosts
+        .iter()
+        .any(|h| h.split(':').next() == Some("host.docker.internal"));
+    if host_gateway && is_linux && !mapped {
+        hosts.push(HOST_GATEWAY_ENTRY.to_string());
+    }
+    hosts
+}
+
+/// Host paths of bind mounts that do not exist. Docker creates a missing
+/// source as an empty root-owned directory, which silently hides a typo.
+/// Named volumes (sources not starting with `/` or `.`) are skipped.
+pub(crate) fn missing_mount_sources(volumes: &[VolumeMount]) -> Vec<&str> {
+    volumes
+        .iter()
+        .map(|v| v.host_path.as_str())
+        .filter(|p| p.starts_with('/') || p.starts_with('.'))
+        .filter(|p| !Path::new(p).exists())
+        .collect()
+}
+
 /// Build a full `ContainerConfig` for creating a sandboxed container.
 pub(crate) fn build_container_config(
     project_path_str: &str,
     sandbox_info: &SandboxInfo,
     tool: &str,
     is_yolo_mode: bool,
+    session_id: &str,
+    profile: &str,
 ) -> Result<ContainerConfig> {
     let home = dirs::home_dir().ok_or_else(|| anyhow::anyhow!("Could not find home directory"))?;
 
@@ -470,6 +681,12 @@ pub(crate) fn build_container_config(
     let (mount_host_path, container_base_path, workspace_path) =
         compute_volume_paths(project_path, project_path_str)?;
 
+    let working_dir = resolve_workdir(
+        &container_base_path,
+        workspace_path.clone(),
+        sandbox_info.workdir.as_deref(),
+    );
+
     let mut volumes = vec![VolumeMount {
         host_path: mount_host_path,
         container_path: container_base_path,
@@ -523,6 +740,12 @@ pub(crate) fn build_container_config(
         });
     }
 
+    volumes.extend(dotfile_mounts(
+        &home,
+        CONTAINER_HOME,
+        &sandbox_config.dotfiles,
+    ));
+
     // Sync host agent config into a shared sandbox directory per agent and
     // bind-mount it read-write. All containers share the same directory (1:N),
     // so in-container changes persist.
@@ -586,17 +809,24 @@ pub(crate) fn build_container_config(
         }
     }
 
-    environment.extend(collect_env_values(&sandbox_config, sandbox_info));
+    environment.extend(collect_env_values(
+        &sandbox_config,
+        sandbox_info,
+        sandbox_config.strict_env,
+    )?);
+    apply_default_terminal_env(&mut environment);
 
-    // Add extra_volumes from config (host:container format)
+    // Add extra_volumes from config and the session (host:container format)
     // Also collect container paths to filter conflicting volume_ignores later
     tracing::debug!(
-        "extra_volumes from config: {:?}",
-        sandbox_config.extra_volumes
+        "extra_volumes from config: {:?}, session: {:?}",
+        sandbox_config.extra_volumes,
+        sandbox_info.extra_volumes
     );
     let mut extra_volume_container_paths: std::collections::HashSet<String> =
         std::collections::HashSet::new();
-    for entry in &sandbox_config.extra_volumes {
+    let session_volumes = sandbox_info.extra_volumes.iter().flatten();
+    for entry in sandbox_config.extra_volumes.iter().chain(session_volumes) {
         let parts: Vec<&str> = entry.splitn(3, ':').collect();
         if parts.len() >= 2 {
             tracing::info!(
@@ -622,9 +852,13 @@ pub(crate) fn build_container_config(
     //   - Exact match: both point to same path
     //   - Anonymous volume is parent of extra_volume (would shadow the mount)
     //   - Anonymous volume is inside extra_volume (redundant/conflicting)
+    let session_ignores = sandbox_info.volume_ignores.iter().flatten();
+    let mut seen_ignores = std::collections::HashSet::new();
     let anonymous_volumes: Vec<String> = sandbox_config
         .volume_ignores
         .iter()
+        .chain(session_ignores)
+        .filter(|ignore| seen_ignores.insert(ignore.as_str()))
         .map(|ignore| format!("{}/{}", workspace_path, ignore))
         .filter(|anon_path| {
             !extra_volume_container_paths.iter().any(|extra_path| {
@@ -648,13 +882,39 @@ pub(crate) fn build_container_config(
     }
     deduped.reverse();
 
+    let missing = missing_mount_sources(&deduped);
+    if !missing.is_empty() {
+        if sandbox_config.strict_mounts {
+            bail!("Volume mount source does not exist: {}", missing.join(", "));
+        }
+        for path in missing {
+            tracing::warn!(
+                "Volume mount source {} does not exist; the container runtime will create it as an empty directory",
+                path
+            );
+        }
+    }
+
+    let labels = container_labels(session_id, profile, &sandbox_config.labels);
+
     Ok(ContainerConfig {
-        working_dir: workspace_path,
+        working_dir,
         volumes: deduped,
         anonymous_volumes,
         environment,
-        cpu_limit: sandbox_config.cpu_limit,
-        memory_limit: sandbox_config.memory_limit,
+        cpu_limit: sandbox_info.cpu_limit.clone().or(sandbox_config.cpu_limit),
+        memory_limit: sandbox_info
+            .memory_limit
+            .clone()
+            .or(sandbox_config.memory_limit),
+        labels,
+        read_only_root: sandbox_config.read_only_root,
+        init: sandbox_config.init,
+        extra_hosts: resolve_extra_hosts(
+            &sandbox_config.extra_hosts,
+            sandbox_config.host_gateway,
+            cfg!(target_os = "linux"),
+        ),
     })
 }
 
@@ -664,6 +924,157 @@ mod tests {
     use std::fs;
     use tempfile::TempDir;
 
+    // --- container cap tests ---
+
+    fn sandboxed(title: &str) -> Instance {
+        let mut inst = Instance::new(title, "/tmp/project");
+        inst.sandbox_info = Some(SandboxInfo {
+            enabled: true,
+            container_id: None,
+            image: "ubuntu:latest".to_string(),
+            container_name: format!("aoe-sandbox-{}", title),
+            created_at: None,
+            extra_env_keys: None,
+            extra_env_values: None,
+            custom_instruction: None,
+            cpu_limit: None,
+            memory_limit: None,
+            workdir: None,
+            config_hash: None,
+            volume_ignores: None,
+            extra_volumes: None,
+        });
+        inst
+    }
+
+    #[test]
+    fn test_container_cap_counts_running_sandboxed_sessions() {
+        let current = sandboxed("current");
+        let instances = vec![
+            sandboxed("api"),
+            sandboxed("idle"),
+            Instance::new("host", "/tmp/project"),
+            sandboxed("web"),
+            current.clone(),
+        ];
+        let running = running_sandboxed_sessions(&instances, &current.id, |i| i.title != "idle");
+        assert_eq!(running, vec!["api", "web"]);
+
+        assert!(check_container_cap(0, &running).is_ok());
+        assert!(check_container_cap(3, &running).is_ok());
+        let err = check_container_cap(2, &running).unwrap_err().to_string();
+        assert!(err.contains("max_containers = 2"));
+        assert!(err.contains("api, web"));
+    }
+
+    // --- resolve_extra_hosts tests ---
+
+    #[test]
+    fn test_resolve_extra_hosts_adds_gateway_on_linux() {
+        assert_eq!(
+            resolve_extra_hosts(&[], true, true),
+            vec!["host.docker.internal:host-gateway"]
+        );
+        assert!(resolve_extra_hosts(&[], true, false).is_empty());
+        assert!(resolve_extra_hosts(&[], false, true).is_empty());
+    }
+
+    #[test]
+    fn test_resolve_extra_hosts_keeps_user_entries() {
+        let configured = vec![
+            "db.local:10.0.0.5".to_string(),
+            "db.local:10.0.0.5".to_string(),
+            "no-address".to_string(),
+            "host.docker.internal:172.17.0.1".to_string(),
+        ];
+        assert_eq!(
+            resolve_extra_hosts(&configured, true, true),
+            vec!["db.local:10.0.0.5", "host.docker.internal:172.17.0.1"]
+        );
+    }
+
+    // --- container_labels tests ---
+
+    #[test]
+    fn test_container_labels_always_include_managed_labels() {
+        let labels = container_labels("abc123", "work", &HashMap::new());
+        assert_eq!(labels.get("aoe.managed").map(String::as_str), Some("true"));
+        assert_eq!(
+            labels.get("aoe.session_id").map(String::as_str),
+            Some("abc123")
+        );
+        assert_eq!(labels.get("aoe.profile").map(String::as_str), Some("work"));
+    }
+
+    #[test]
+    fn test_container_labels_merge_user_labels() {
+        let user = HashMap::from([
+            ("team".to_string(), "platform".to_string()),
+            ("aoe.managed".to_string(), "false".to_string()),
+        ]);
+        let labels = container_labels("abc123", "", &user);
+        assert_eq!(labels.get("team").map(String::as_str), Some("platform"));
+        assert_eq!(labels.get("aoe.managed").map(String::as_str), Some("true"));
+        assert!(!labels.contains_key("aoe.profile"));
+    }
+
+    // --- dotfile_mounts tests ---
+
+    #[test]
+    fn test_dotfile_mounts_read_only_and_skip_missing() {
+        let home = TempDir::new().unwrap();
+        fs::write(home.path().join(".gitconfig"), "[user]\n").unwrap();
+
+        let mounts = dotfile_mounts(
+            home.path(),
+            "/root",
+            &[".gitconfig".to_string(), ".config/missing".to_string()],
+        );
+        assert_eq!(mounts.len(), 1);
+        assert_eq!(
+            mounts[0].host_path,
+            home.path().join(".gitconfig").to_string_lossy()
+        );
+        assert_eq!(mounts[0].container_path, "/root/.gitconfig");
+        assert!(mounts[0].read_only);
+    }
+
+    #[test]
+    fn test_dotfile_mounts_reject_paths_outside_home() {
+        let home = TempDir::new().unwrap();
+        fs::create_dir_all(home.path().join(".config/gh")).unwrap();
+
+        let mounts = dotfile_mounts(
+            home.path(),
+            "/root",
+            &[
+                "~/.config/gh/".to_string(),
+                "/etc/passwd".to_string(),
+                "../outside".to_string(),
+            ],
+        );
+        assert_eq!(mounts.len(), 1);
+        assert_eq!(mounts[0].container_path, "/root/.config/gh");
+    }
+
+    // --- missing_mount_sources tests ---
+
+    #[test]
+    fn test_missing_mount_sources_reports_only_missing_bind_mounts() {
+        let dir = TempDir::new().unwrap();
+        let existing = dir.path().to_string_lossy().to_string();
+        let missing = dir.path().join("typo").to_string_lossy().to_string();
+        let mount = |host: &str| VolumeMount {
+            host_path: host.to_string(),
+            container_path: "/data".to_string(),
+            read_only: false,
+        };
+
+        let volumes = vec![mount(&existing), mount(&missing), mount("named-cache")];
+        assert_eq!(missing_mount_sources(&volumes), vec![missing.as_str()]);
+        assert!(missing_mount_sources(&volumes[..1]).is_empty());
+    }
+
     // --- compute_volume_paths tests ---
 
     fn setup_regular_repo() -> (TempDir, std::path::PathBuf) {
@@ -802,6 +1213,126 @@ mod tests {
         assert!(!working_dir.is_empty());
     }
 
+    #[test]
+    fn test_wait_until_ready_polls_until_probe_succeeds() {
+        let mut attempts = 0;
+        let mut slept = Vec::new();
+        let mut waits = Vec::new();
+        wait_until_ready(
+            || {
+                attempts += 1;
+                attempts == 3
+            },
+            std::time::Duration::from_secs(10),
+            std::time::Duration::from_secs(1),
+            |d| slept.push(d.as_secs()),
+            |n| waits.push(n),
+        )
+        .unwrap();
+
+        assert_eq!(attempts, 3);
+        assert_eq!(slept, vec![1, 1]);
+        assert_eq!(waits, vec![1, 2]);
+    }
+
+    #[test]
+    fn test_wait_until_ready_times_out() {
+        let mut attempts = 0;
+        let err = wait_until_ready(
+            || {
+                attempts += 1;
+                false
+            },
+            std::time::Duration::from_secs(3),
+            std::time::Duration::from_secs(1),
+            |_| {},
+            |_| {},
+        )
+        .unwrap_err();
+
+        assert_eq!(attempts, 4);
+        assert!(err.to_string().contains("within 3s"));
+    }
+
+    #[test]
+    fn test_suggest_volume_ignores_from_markers() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(suggest_volume_ignores(dir.path()).is_empty());
+
+        std::fs::write(dir.path().join("package.json"), "{}").unwrap();
+        assert_eq!(suggest_volume_ignores(dir.path()), vec!["node_modules"]);
+
+        std::fs::write(dir.path().join("Cargo.toml"), "").unwrap();
+        std::fs::write(dir.path().join("pyproject.toml"), "").unwrap();
+        std::fs::write(dir.path().join("requirements.txt"), "").unwrap();
+        assert_eq!(
+            suggest_volume_ignores(dir.path()),
+            vec!["node_modules", "target", ".venv"]
+        );
+
+        let gradle = tempfile::tempdir().unwrap();
+        std::fs::write(gradle.path().join("build.gradle.kts"), "").unwrap();
+        assert_eq!(
+            suggest_volume_ignores(gradle.path()),
+            vec!["build", ".gradle"]
+        );
+    }
+
+    #[test]
+    fn test_resolve_workdir_decoupled_from_mount() {
+        let default = || "/workspace/project".to_string();
+        assert_eq!(
+            resolve_workdir("/workspace/project", default(), None),
+            "/workspace/project"
+        );
+        assert_eq!(
+            resolve_workdir("/workspace/project", default(), Some("frontend/")),
+            "/workspace/project/frontend"
+        );
+        assert_eq!(
+            resolve_workdir("/workspace/project", default(), Some("/srv/app")),
+            "/srv/app"
+        );
+        assert_eq!(
+            resolve_workdir("/workspace/project", default(), Some("")),
+            "/workspace/project"
+        );
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_build_container_config_workdir_inside_mount() {
+        let temp = TempDir::new().unwrap();
+        std::env::set_var("HOME", temp.path());
+        #[cfg(target_os = "linux")]
+        std::env::set_var("XDG_CONFIG_HOME", temp.path().join(".config"));
+        let project = temp.path().join("project");
+        fs::create_dir_all(&project).unwrap();
+
+        let info = SandboxInfo {
+            enabled: true,
+            container_id: None,
+            image: "ubuntu:latest".to_string(),
+            container_name: "aoe-sandbox-test".to_string(),
+            created_at: None,
+            extra_env_keys: None,
+            extra_env_values: None,
+            custom_instruction: None,
+            cpu_limit: None,
+            memory_limit: None,
+            config_hash: None,
+            workdir: Some("frontend".to_string()),
+            volume_ignores: None,
+            extra_volumes: None,
+        };
+        let config =
+            build_container_config(project.to_str().unwrap(), &info, "claude", false, "id", "")
+                .unwrap();
+
+        assert_eq!(config.working_dir, "/workspace/project/frontend");
+        assert_eq!(config.volumes[0].container_path, "/workspace/project");
+    }
+
     // --- sandbox config tests ---
 
     fn setup_host_dir(dir: &TempDir) -> std::path::PathBuf {
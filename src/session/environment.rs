@@ -3,6 +3,8 @@
 //! Pure functions for building environment variable arguments used when
 //! launching tools inside Docker containers.
 
+use anyhow::{bail, Result};
+
 use super::config::SandboxConfig;
 use super::instance::SandboxInfo;
 
@@ -10,6 +12,28 @@ use super::instance::SandboxInfo;
 pub(crate) const DEFAULT_TERMINAL_ENV_VARS: &[&str] =
     &["TERM", "COLORTERM", "FORCE_COLOR", "NO_COLOR"];
 
+/// Fallback used when the host has no `TERM` and the config sets none
+pub(crate) const DEFAULT_CONTAINER_TERM: &str = "xterm-256color";
+
+/// Locale injected when neither `LANG` nor `LC_ALL` is set. `C.UTF-8` ships
+/// with practically every base image, unlike host locales such as `en_US.UTF-8`.
+pub(crate) const DEFAULT_CONTAINER_LOCALE: &str = "C.UTF-8";
+
+/// Fill in `TERM` and locale variables missing from the container environment,
+/// so agent TUIs render correctly. Existing entries (from the host or from
+/// `environment_values`) always win.
+pub(crate) fn apply_default_terminal_env(environment: &mut Vec<(String, String)>) {
+    let has = |env: &[(String, String)], key: &str| env.iter().any(|(k, _)| k == key);
+
+    if !has(environment, "TERM") {
+        environment.push(("TERM".to_string(), DEFAULT_CONTAINER_TERM.to_string()));
+    }
+    if !has(environment, "LANG") && !has(environment, "LC_ALL") {
+        environment.push(("LANG".to_string(), DEFAULT_CONTAINER_LOCALE.to_string()));
+        environment.push(("LC_ALL".to_string(), DEFAULT_CONTAINER_LOCALE.to_string()));
+    }
+}
+
 /// Shell-escape a value for safe interpolation into a shell command string.
 /// Uses double-quote escaping so values can be nested inside `bash -c '...'`
 /// (single quotes in the outer wrapper are literal, double quotes work inside).
@@ -24,17 +48,71 @@ pub(crate) fn shell_escape(val: &str) -> String {
     format!("\"{}\"", escaped)
 }
 
-/// Resolve an environment_values entry. If the value starts with `$`, read the
-/// named variable from the host environment (use `$$` to escape a literal `$`).
-/// Otherwise return the literal value.
-pub(crate) fn resolve_env_value(val: &str) -> Option<String> {
-    if let Some(rest) = val.strip_prefix("$$") {
-        Some(format!("${}", rest))
-    } else if let Some(var_name) = val.strip_prefix('$') {
-        std::env::var(var_name).ok()
-    } else {
-        Some(val.to_string())
+/// Expand `$VAR` and `${VAR}` references in `val` using `lookup`. `$$` is a
+/// literal `$`, as is a `$` not followed by a variable name. Undefined
+/// variables expand to empty and are returned alongside the result.
+pub(crate) fn interpolate_env(
+    val: &str,
+    lookup: impl Fn(&str) -> Option<String>,
+) -> (String, Vec<String>) {
+    let is_name_char = |c: char| c.is_ascii_alphanumeric() || c == '_';
+    let mut out = String::with_capacity(val.len());
+    let mut undefined = Vec::new();
+    let mut rest = val;
+
+    while let Some(pos) = rest.find('$') {
+        out.push_str(&rest[..pos]);
+        let after = &rest[pos + 1..];
+
+        let (name, consumed) = if let Some(stripped) = after.strip_prefix('$') {
+            out.push('$');
+            rest = stripped;
+            continue;
+        } else if let Some(braced) = after.strip_prefix('{') {
+            match braced.find('}') {
+                Some(end) => (&braced[..end], end + 2),
+                None => ("", 0),
+            }
+        } else {
+            let end = after.find(|c| !is_name_char(c)).unwrap_or(after.len());
+            (&after[..end], end)
+        };
+
+        if name.is_empty() {
+            out.push('$');
+            rest = after;
+            continue;
+        }
+        match lookup(name) {
+            Some(value) => out.push_str(&value),
+            None => undefined.push(name.to_string()),
+        }
+        rest = &after[consumed..];
+    }
+    out.push_str(rest);
+    (out, undefined)
+}
+
+/// Resolve an environment_values entry against the host environment. With
+/// `strict`, an undefined variable is an error; otherwise it is logged and
+/// expands to empty.
+pub(crate) fn resolve_env_value(key: &str, val: &str, strict: bool) -> Result<String> {
+    let (resolved, undefined) = interpolate_env(val, |name| std::env::var(name).ok());
+    if !undefined.is_empty() {
+        if strict {
+            bail!(
+                "environment value for {} references undefined host variable(s): {}",
+                key,
+                undefined.join(", ")
+            );
+        }
+        tracing::warn!(
+            "environment value for {} references undefined host variable(s) {}; expanding to empty",
+            key,
+            undefined.join(", ")
+        );
     }
+    Ok(resolved)
 }
 
 /// Collect all environment variable keys from defaults, global config, and per-session extras.
@@ -64,28 +142,20 @@ pub(crate) fn collect_env_keys(
     env_keys
 }
 
-/// Collect all key=value environment pairs from global config and per-session extras.
+/// Collect all key=value environment pairs from global config and per-session
+/// extras, interpolating host variables. `strict` follows `sandbox.strict_env`.
 pub(crate) fn collect_env_values(
     sandbox_config: &SandboxConfig,
     sandbox_info: &SandboxInfo,
-) -> Vec<(String, String)> {
-    let mut values = Vec::new();
-
-    for (key, val) in &sandbox_config.environment_values {
-        if let Some(resolved) = resolve_env_value(val) {
-            values.push((key.clone(), resolved));
-        }
-    }
-
-    if let Some(extra_vals) = &sandbox_info.extra_env_values {
-        for (key, val) in extra_vals {
-            if let Some(resolved) = resolve_env_value(val) {
-                values.push((key.clone(), resolved));
-            }
-        }
-    }
-
-    values
+    strict: bool,
+) -> Result<Vec<(String, String)>> {
+    let extra = sandbox_info.extra_env_values.iter().flatten();
+    sandbox_config
+        .environment_values
+        .iter()
+        .chain(extra)
+        .map(|(key, val)| Ok((key.clone(), resolve_env_value(key, val, strict)?)))
+        .collect()
 }
 
 /// Build docker exec environment flags from config and optional per-session extra keys.
@@ -105,7 +175,9 @@ pub(crate) fn build_docker_env_args(sandbox: &SandboxInfo) -> String {
         })
         .collect();
 
-    for (key, resolved) in collect_env_values(&config.sandbox, sandbox) {
+    // Strict mode is enforced when the container is created; exec only logs.
+    let values = collect_env_values(&config.sandbox, sandbox, false).unwrap_or_default();
+    for (key, resolved) in values {
         args.push(format!("-e {}={}", key, shell_escape(&resolved)));
     }
 
@@ -116,6 +188,84 @@ pub(crate) fn build_docker_env_args(sandbox: &SandboxInfo) -> String {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_apply_default_terminal_env_injects_missing() {
+        let mut env = vec![("COLORTERM".to_string(), "truecolor".to_string())];
+        apply_default_terminal_env(&mut env);
+        assert!(env.contains(&("TERM".to_string(), "xterm-256color".to_string())));
+        assert!(env.contains(&("LANG".to_string(), "C.UTF-8".to_string())));
+        assert!(env.contains(&("LC_ALL".to_string(), "C.UTF-8".to_string())));
+    }
+
+    #[test]
+    fn test_apply_default_terminal_env_keeps_existing() {
+        let mut env = vec![
+            ("TERM".to_string(), "screen-256color".to_string()),
+            ("LANG".to_string(), "de_DE.UTF-8".to_string()),
+        ];
+        apply_default_terminal_env(&mut env);
+        assert_eq!(
+            env,
+            vec![
+                ("TERM".to_string(), "screen-256color".to_string()),
+                ("LANG".to_string(), "de_DE.UTF-8".to_string()),
+            ]
+        );
+    }
+
+    fn lookup(name: &str) -> Option<String> {
+        match name {
+            "HOST_KEY" => Some("secret".to_string()),
+            "USER" => Some("dev".to_string()),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_interpolate_env_expands_both_forms() {
+        let (value, undefined) = interpolate_env("$HOST_KEY", lookup);
+        assert_eq!(value, "secret");
+        assert!(undefined.is_empty());
+
+        let (value, _) = interpolate_env("Bearer ${HOST_KEY}-${USER}", lookup);
+        assert_eq!(value, "Bearer secret-dev");
+
+        let (value, _) = interpolate_env("/home/$USER/bin", lookup);
+        assert_eq!(value, "/home/dev/bin");
+
+        let (value, _) = interpolate_env("plain", lookup);
+        assert_eq!(value, "plain");
+    }
+
+    #[test]
+    fn test_interpolate_env_escapes() {
+        let (value, undefined) = interpolate_env("$$LITERAL", lookup);
+        assert_eq!(value, "$LITERAL");
+        assert!(undefined.is_empty());
+
+        let (value, _) = interpolate_env("cost: 5$ or $$${USER}", lookup);
+        assert_eq!(value, "cost: 5$ or $dev");
+
+        let (value, _) = interpolate_env("${unterminated", lookup);
+        assert_eq!(value, "${unterminated");
+    }
+
+    #[test]
+    fn test_interpolate_env_undefined_expands_to_empty() {
+        let (value, undefined) = interpolate_env("a-${MISSING}-$ALSO_MISSING-b", lookup);
+        assert_eq!(value, "a---b");
+        assert_eq!(undefined, vec!["MISSING", "ALSO_MISSING"]);
+    }
+
+    #[test]
+    fn test_resolve_env_value_strict_rejects_undefined() {
+        let var = "AOE_TEST_SURELY_UNDEFINED_VAR";
+        let value = format!("${}", var);
+        assert_eq!(resolve_env_value("KEY", &value, false).unwrap(), "");
+        let err = resolve_env_value("KEY", &value, true).unwrap_err();
+        assert!(err.to_string().contains(var));
+    }
+
     #[test]
     fn test_shell_escape_simple() {
         assert_eq!(shell_escape("hello"), "\"hello\"");
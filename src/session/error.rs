@@ -0,0 +1,36 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum SessionError {
+    #[error("Session not found: {0}")]
+    NotFound(String),
+
+    #[error("Group not found: {0}")]
+    GroupNotFound(String),
+
+    #[error("Profile '{0}' does not exist")]
+    ProfileNotFound(String),
+
+    /// An identifier matched more than one session
+    #[error(
+        "'{identifier}' matches {} sessions; use a longer id prefix:\n  {}",
+        .candidates.len(),
+        .candidates.join("\n  ")
+    )]
+    Ambiguous {
+        identifier: String,
+        candidates: Vec<String>,
+    },
+
+    /// The session's project directory was deleted or moved
+    #[error(
+        "Working directory for '{title}' no longer exists: {path}\n\
+         Point the session at another directory with: aoe session rename {title} --path <DIR>\n\
+         Or remove it with: aoe remove {title}"
+    )]
+    MissingWorkingDir { title: String, path: String },
+
+    /// Bad user input such as a missing path or an unknown tool
+    #[error("{0}")]
+    Invalid(String),
+}
@@ -1,7 +1,7 @@
 //! Group tree management
 
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use super::Instance;
 
@@ -142,6 +142,37 @@ impl GroupTree {
         self.rebuild_tree();
     }
 
+    /// Rename a group and all its subgroups, keeping their collapsed state.
+    /// Missing parents of the new path are created.
+    pub fn rename_group(&mut self, old_path: &str, new_path: &str) {
+        let prefix = format!("{}/", old_path);
+        let to_move: Vec<String> = self
+            .groups_by_path
+            .keys()
+            .filter(|p| *p == old_path || p.starts_with(&prefix))
+            .cloned()
+            .collect();
+
+        for path in to_move {
+            if let Some(mut group) = self.groups_by_path.remove(&path) {
+                group.path = format!("{}{}", new_path, &path[old_path.len()..]);
+                group.name = group
+                    .path
+                    .rsplit('/')
+                    .next()
+                    .unwrap_or(&group.path)
+                    .to_string();
+                self.groups_by_path.insert(group.path.clone(), group);
+            }
+        }
+
+        if let Some((parent, _)) = new_path.rsplit_once('/') {
+            self.ensure_group_exists(parent);
+        }
+        self.ensure_group_exists(new_path);
+        self.rebuild_tree();
+    }
+
     pub fn group_exists(&self, path: &str) -> bool {
         self.groups_by_path.contains_key(path)
     }
@@ -162,6 +193,26 @@ impl GroupTree {
             self.rebuild_tree();
         }
     }
+
+    /// Paths of the collapsed groups, sorted.
+    pub fn collapsed_paths(&self) -> Vec<String> {
+        let mut paths: Vec<String> = self
+            .groups_by_path
+            .values()
+            .filter(|g| g.collapsed)
+            .map(|g| g.path.clone())
+            .collect();
+        paths.sort();
+        paths
+    }
+
+    /// Collapse exactly the groups in `paths` and expand every other group.
+    pub fn set_collapsed_paths(&mut self, paths: &HashSet<String>) {
+        for group in self.groups_by_path.values_mut() {
+            group.collapsed = paths.contains(&group.path);
+        }
+        self.rebuild_tree();
+    }
 }
 
 /// Item represents either a group or an instance in the flattened tree view
@@ -189,13 +240,76 @@ impl Item {
     }
 }
 
+/// Whether `group_path` is `path` itself or nested under it.
+pub fn is_in_group(group_path: &str, path: &str) -> bool {
+    group_path == path
+        || group_path
+            .strip_prefix(path)
+            .is_some_and(|rest| rest.starts_with('/'))
+}
+
+/// Normalize a user-entered group path such as `/work//backend/` into
+/// `work/backend`, dropping empty and whitespace-only segments.
+pub fn normalize_group_path(path: &str) -> String {
+    path.split('/')
+        .map(str::trim)
+        .filter(|segment| !segment.is_empty())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Indices of the unarchived sessions in `path` (and its subgroups) in the
+/// order they should start: ascending `start_order`, unordered sessions last,
+/// ties kept in storage order.
+pub fn group_start_order(instances: &[Instance], path: &str) -> Vec<usize> {
+    let mut indices: Vec<usize> = instances
+        .iter()
+        .enumerate()
+        .filter(|(_, i)| !i.archived && is_in_group(&i.group_path, path))
+        .map(|(idx, _)| idx)
+        .collect();
+    indices.sort_by_key(|&idx| match instances[idx].start_order {
+        Some(order) => (0, order),
+        None => (1, 0),
+    });
+    indices
+}
+
+/// Point sessions in `old_path` (and its subgroups) at `new_path`.
+/// Returns the number of sessions moved.
+pub fn rename_group_sessions(instances: &mut [Instance], old_path: &str, new_path: &str) -> usize {
+    let mut moved = 0;
+    for inst in instances
+        .iter_mut()
+        .filter(|i| is_in_group(&i.group_path, old_path))
+    {
+        inst.group_path = format!("{}{}", new_path, &inst.group_path[old_path.len()..]);
+        moved += 1;
+    }
+    moved
+}
+
+/// Move sessions in `path` (and its subgroups) to the top level.
+/// Returns the number of sessions moved.
+pub fn ungroup_sessions(instances: &mut [Instance], path: &str) -> usize {
+    let mut moved = 0;
+    for inst in instances
+        .iter_mut()
+        .filter(|i| is_in_group(&i.group_path, path))
+    {
+        inst.group_path = String::new();
+        moved += 1;
+    }
+    moved
+}
+
 pub fn flatten_tree(group_tree: &GroupTree, instances: &[Instance]) -> Vec<Item> {
     let mut items = Vec::new();
 
     // Add ungrouped sessions first
     let ungrouped: Vec<&Instance> = instances
         .iter()
-        .filter(|i| i.group_path.is_empty())
+        .filter(|i| i.group_path.is_empty() && !i.archived)
         .collect();
 
     for inst in ungrouped {
@@ -231,7 +345,7 @@ fn flatten_group(group: &Group, instances: &[Instance], items: &mut Vec<Item>, d
     // Add sessions in this group (direct children only)
     let group_sessions: Vec<&Instance> = instances
         .iter()
-        .filter(|i| i.group_path == group.path)
+        .filter(|i| i.group_path == group.path && !i.archived)
         .collect();
 
     for inst in group_sessions {
@@ -251,7 +365,7 @@ fn count_sessions_in_group(path: &str, instances: &[Instance]) -> usize {
     let prefix = format!("{}/", path);
     instances
         .iter()
-        .filter(|i| i.group_path == path || i.group_path.starts_with(&prefix))
+        .filter(|i| !i.archived && (i.group_path == path || i.group_path.starts_with(&prefix)))
         .count()
 }
 
@@ -295,6 +409,117 @@ mod tests {
         assert!(matches!(items[0], Item::Session { .. }));
     }
 
+    #[test]
+    fn test_flatten_tree_hides_archived_sessions() {
+        let active = Instance::new("active", "/tmp/a");
+        let mut archived = Instance::new("archived", "/tmp/b");
+        archived.group_path = "work".to_string();
+        archived.archived = true;
+
+        let instances = vec![active.clone(), archived];
+        let tree = GroupTree::new_with_groups(&instances, &[]);
+        let items = flatten_tree(&tree, &instances);
+
+        let session_ids: Vec<&str> = items
+            .iter()
+            .filter_map(|item| match item {
+                Item::Session { id, .. } => Some(id.as_str()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(session_ids, vec![active.id.as_str()]);
+        assert!(items.iter().any(|item| matches!(
+            item,
+            Item::Group { path, session_count: 0, .. } if path == "work"
+        )));
+    }
+
+    #[test]
+    fn test_rename_group_updates_members_and_subgroups() {
+        let mut inst1 = Instance::new("a", "/tmp/a");
+        inst1.group_path = "work".to_string();
+        let mut inst2 = Instance::new("b", "/tmp/b");
+        inst2.group_path = "work/frontend".to_string();
+        let mut other = Instance::new("c", "/tmp/c");
+        other.group_path = "workshop".to_string();
+        let mut instances = vec![inst1, inst2, other];
+
+        let mut tree = GroupTree::new_with_groups(&instances, &[]);
+        tree.toggle_collapsed("work/frontend");
+        tree.rename_group("work", "jobs/acme");
+        let moved = rename_group_sessions(&mut instances, "work", "jobs/acme");
+
+        assert_eq!(moved, 2);
+        assert_eq!(instances[0].group_path, "jobs/acme");
+        assert_eq!(instances[1].group_path, "jobs/acme/frontend");
+        assert_eq!(instances[2].group_path, "workshop");
+
+        assert!(!tree.group_exists("work"));
+        assert!(tree.group_exists("jobs"));
+        assert!(tree.group_exists("jobs/acme"));
+        let frontend = tree
+            .get_all_groups()
+            .into_iter()
+            .find(|g| g.path == "jobs/acme/frontend")
+            .unwrap();
+        assert_eq!(frontend.name, "frontend");
+        assert!(frontend.collapsed);
+    }
+
+    #[test]
+    fn test_ungroup_sessions_moves_members_to_top_level() {
+        let mut inst1 = Instance::new("a", "/tmp/a");
+        inst1.group_path = "work".to_string();
+        let mut inst2 = Instance::new("b", "/tmp/b");
+        inst2.group_path = "work/frontend".to_string();
+        let mut other = Instance::new("c", "/tmp/c");
+        other.group_path = "personal".to_string();
+        let mut instances = vec![inst1, inst2, other];
+
+        assert_eq!(ungroup_sessions(&mut instances, "work"), 2);
+        assert_eq!(instances[0].group_path, "");
+        assert_eq!(instances[1].group_path, "");
+        assert_eq!(instances[2].group_path, "personal");
+    }
+
+    #[test]
+    fn test_nested_group_path_builds_chain() {
+        let mut tree = GroupTree::new_with_groups(&[], &[]);
+        tree.create_group(&normalize_group_path("work/backend/api"));
+
+        assert!(tree.group_exists("work"));
+        assert!(tree.group_exists("work/backend"));
+        assert!(tree.group_exists("work/backend/api"));
+
+        let roots = tree.get_roots();
+        assert_eq!(roots.len(), 1);
+        assert_eq!(roots[0].name, "work");
+        assert_eq!(roots[0].children.len(), 1);
+        assert_eq!(roots[0].children[0].name, "backend");
+        assert_eq!(roots[0].children[0].children[0].path, "work/backend/api");
+    }
+
+    #[test]
+    fn test_normalize_group_path() {
+        assert_eq!(normalize_group_path("work/backend"), "work/backend");
+        assert_eq!(normalize_group_path("/work/backend/"), "work/backend");
+        assert_eq!(
+            normalize_group_path("work//backend///api"),
+            "work/backend/api"
+        );
+        assert_eq!(normalize_group_path(" work / backend "), "work/backend");
+        assert_eq!(normalize_group_path("///"), "");
+        assert_eq!(normalize_group_path(""), "");
+    }
+
+    #[test]
+    fn test_is_in_group() {
+        assert!(is_in_group("work", "work"));
+        assert!(is_in_group("work/frontend", "work"));
+        assert!(!is_in_group("workshop", "work"));
+        assert!(!is_in_group("", "work"));
+    }
+
     #[test]
     fn test_toggle_collapsed() {
         let mut inst = Instance::new("test", "/tmp/t");
@@ -548,4 +773,31 @@ mod tests {
         assert_eq!(roots[1].name, "mango");
         assert_eq!(roots[2].name, "zebra");
     }
+
+    #[test]
+    fn test_group_start_order_ascending_with_unordered_last() {
+        let mut server = Instance::new("server", "/tmp/s");
+        server.group_path = "app".to_string();
+        server.start_order = Some(1);
+        let mut loose = Instance::new("loose", "/tmp/l");
+        loose.group_path = "app/tools".to_string();
+        let mut client = Instance::new("client", "/tmp/c");
+        client.group_path = "app".to_string();
+        client.start_order = Some(2);
+        let mut db = Instance::new("db", "/tmp/d");
+        db.group_path = "app".to_string();
+        db.start_order = Some(-1);
+        let mut other = Instance::new("other", "/tmp/o");
+        other.group_path = "application".to_string();
+        let mut archived = Instance::new("archived", "/tmp/a");
+        archived.group_path = "app".to_string();
+        archived.archived = true;
+
+        let instances = vec![server, loose, client, db, other, archived];
+        let titles: Vec<&str> = group_start_order(&instances, "app")
+            .into_iter()
+            .map(|idx| instances[idx].title.as_str())
+            .collect();
+        assert_eq!(titles, vec!["db", "server", "client", "loose"]);
+    }
 }
@@ -1,8 +1,9 @@
 //! Session instance definition and operations
 
+use std::collections::HashMap;
 use std::path::Path;
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
@@ -38,6 +39,93 @@ pub enum Status {
     Deleting,
 }
 
+/// Number of sessions in each status, for at-a-glance summaries
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StatusCounts {
+    pub running: usize,
+    pub waiting: usize,
+    pub idle: usize,
+    pub stopped: usize,
+    pub error: usize,
+    pub starting: usize,
+    pub deleting: usize,
+}
+
+impl StatusCounts {
+    /// Non-zero counts of the statuses worth surfacing, in display order
+    pub fn highlights(&self) -> Vec<(Status, usize)> {
+        [
+            (Status::Running, self.running),
+            (Status::Waiting, self.waiting),
+            (Status::Idle, self.idle),
+            (Status::Error, self.error),
+        ]
+        .into_iter()
+        .filter(|(_, count)| *count > 0)
+        .collect()
+    }
+}
+
+/// Count sessions by status. Archived sessions are not counted.
+pub fn summarize_statuses(instances: &[Instance]) -> StatusCounts {
+    let mut counts = StatusCounts::default();
+    for inst in instances.iter().filter(|i| !i.archived) {
+        let slot = match inst.status {
+            Status::Running => &mut counts.running,
+            Status::Waiting => &mut counts.waiting,
+            Status::Idle => &mut counts.idle,
+            Status::Stopped => &mut counts.stopped,
+            Status::Error => &mut counts.error,
+            Status::Starting => &mut counts.starting,
+            Status::Deleting => &mut counts.deleting,
+        };
+        *slot += 1;
+    }
+    counts
+}
+
+/// Whether a session whose pane last changed at `last_activity_at` has been
+/// idle for at least `threshold`. Sessions with no observed activity are
+/// never considered idle, since there is no baseline to measure from.
+pub fn should_auto_stop(
+    last_activity_at: Option<DateTime<Utc>>,
+    now: DateTime<Utc>,
+    threshold: chrono::Duration,
+) -> bool {
+    last_activity_at.is_some_and(|at| now - at >= threshold)
+}
+
+/// Whether a session should get its one auto-nudge: it is waiting, its pane
+/// has not changed for `threshold`, and it was not already nudged during
+/// this wait (`nudged_at_activity` is the `last_activity_at` recorded at the
+/// previous nudge, so new output starts a fresh episode).
+pub fn should_nudge(
+    status: Status,
+    last_activity_at: Option<DateTime<Utc>>,
+    nudged_at_activity: Option<DateTime<Utc>>,
+    now: DateTime<Utc>,
+    threshold: chrono::Duration,
+) -> bool {
+    status == Status::Waiting
+        && last_activity_at.is_some()
+        && nudged_at_activity != last_activity_at
+        && should_auto_stop(last_activity_at, now, threshold)
+}
+
+/// Shells a pane is left running once an agent launched from it exits
+const SHELL_COMMANDS: &[&str] = &["bash", "zsh", "sh", "fish", "dash", "ksh", "tcsh", "csh"];
+
+/// Whether the agent is still running in its pane, judged from tmux's view of
+/// the pane: a dead pane or a bare shell in the foreground means it exited.
+pub fn agent_present(pane_dead: bool, current_command: Option<&str>) -> bool {
+    if pane_dead {
+        return false;
+    }
+    current_command.map_or(true, |cmd| {
+        !SHELL_COMMANDS.contains(&cmd.trim_start_matches('-'))
+    })
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WorktreeInfo {
     pub branch: String,
@@ -66,6 +154,27 @@ pub struct SandboxInfo {
     /// Custom instruction text to inject into agent launch command
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub custom_instruction: Option<String>,
+    /// CPU limit for this session's container (overrides sandbox.cpu_limit)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cpu_limit: Option<String>,
+    /// Memory limit for this session's container (overrides sandbox.memory_limit)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub memory_limit: Option<String>,
+    /// Hash of the effective container config at creation, used to detect drift
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub config_hash: Option<String>,
+    /// Directory the agent runs in inside the container, relative to the
+    /// project mount or absolute. Defaults to the mount's working directory.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub workdir: Option<String>,
+    /// Paths relative to the workspace kept off the bind mount as anonymous
+    /// volumes, on top of `sandbox.volume_ignores` (from `--auto-anon-volumes`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub volume_ignores: Option<Vec<String>>,
+    /// Volume mounts (`host:container[:ro]`) added on top of
+    /// `sandbox.extra_volumes` (from a session template)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extra_volumes: Option<Vec<String>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -79,7 +188,7 @@ pub struct Instance {
     pub parent_session_id: Option<String>,
     #[serde(default)]
     pub command: String,
-    #[serde(default)]
+    #[serde(default, deserialize_with = "crate::agents::deserialize_tool_name")]
     pub tool: String,
     #[serde(default)]
     pub yolo_mode: bool,
@@ -88,6 +197,9 @@ pub struct Instance {
     pub created_at: DateTime<Utc>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub last_accessed_at: Option<DateTime<Utc>>,
+    /// Archived sessions are hidden from the default list and have no tmux session
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub archived: bool,
 
     // Git worktree integration
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -101,6 +213,37 @@ pub struct Instance {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub terminal_info: Option<TerminalInfo>,
 
+    /// tmux options applied to this session after creation, on top of the
+    /// configured `[tmux] options`
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub tmux_options: HashMap<String, String>,
+
+    /// Command run once when the session is created, before the agent starts
+    /// (e.g. `npm install`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub setup_command: Option<String>,
+
+    /// Exit code of the agent the last time its process ended
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_exit_code: Option<i32>,
+
+    /// Position when its group is started with `session start --group`;
+    /// lower values start first and unset sessions start last
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_order: Option<i32>,
+
+    /// Environment captured from the creating shell with `aoe add
+    /// --inherit-env`, set for the agent on host launches (sandboxed sessions
+    /// keep it in `SandboxInfo::extra_env_values`)
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub env: HashMap<String, String>,
+
+    /// tmux session name recorded when the session was first started, so a
+    /// later `tmux.session_name_length` change does not lose it. Sessions
+    /// without one use the name derived from the current setting.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tmux_name: Option<String>,
+
     // Runtime state (not serialized)
     #[serde(skip)]
     pub last_error_check: Option<std::time::Instant>,
@@ -108,6 +251,21 @@ pub struct Instance {
     pub last_start_time: Option<std::time::Instant>,
     #[serde(skip)]
     pub last_error: Option<String>,
+    /// Informational notice from the pane, e.g. a switch to a fallback model
+    #[serde(skip)]
+    pub status_note: Option<String>,
+    /// Hash of the last captured pane content, used to detect new output
+    #[serde(skip)]
+    pub last_content_hash: Option<u64>,
+    /// When the pane content last changed while being polled
+    #[serde(skip)]
+    pub last_activity_at: Option<DateTime<Utc>>,
+    /// Per-pane statuses from the last poll, when the session has several panes
+    #[serde(skip)]
+    pub pane_statuses: Vec<tmux::PaneDetail>,
+    /// Profile this session belongs to, filled in by `Storage` on load
+    #[serde(skip)]
+    pub profile: String,
 }
 
 impl Instance {
@@ -124,12 +282,24 @@ impl Instance {
             status: Status::Idle,
             created_at: Utc::now(),
             last_accessed_at: None,
+            archived: false,
             worktree_info: None,
             sandbox_info: None,
             terminal_info: None,
+            tmux_options: HashMap::new(),
+            setup_command: None,
+            last_exit_code: None,
+            start_order: None,
+            env: HashMap::new(),
+            tmux_name: None,
             last_error_check: None,
             last_start_time: None,
             last_error: None,
+            status_note: None,
+            last_content_hash: None,
+            last_activity_at: None,
+            pane_statuses: Vec::new(),
+            profile: String::new(),
         }
     }
 
@@ -155,12 +325,99 @@ impl Instance {
         }
     }
 
+    /// Base launch command: the `[commands]` template for this tool when one is
+    /// configured and no custom command is set, otherwise `get_tool_command()`.
+    /// A command equal to the agent's own binary counts as no custom command.
+    fn launch_command(&self, workdir: &str) -> String {
+        let is_default_command = self.command.is_empty()
+            || crate::agents::get_agent(&self.tool).is_some_and(|a| self.command == a.binary);
+        if is_default_command {
+            if let Some(template) = self.effective_config().commands.remove(&self.tool) {
+                // Conversations of sandboxed agents live inside the container.
+                let resume_id = if self.is_sandboxed() {
+                    None
+                } else {
+                    crate::agents::latest_resume_id(&self.tool, Path::new(&self.project_path))
+                };
+                return super::config::render_command_template(
+                    &template,
+                    workdir,
+                    resume_id.as_deref(),
+                );
+            }
+        }
+        self.get_tool_command().to_string()
+    }
+
+    /// Command for a non-sandboxed launch, with the agent's YOLO flag or env var
+    /// applied. None when the tool cannot be launched directly on the host.
+    fn host_launch_command(&self) -> Option<String> {
+        if self.command.is_empty() {
+            crate::agents::get_agent(&self.tool)
+                .filter(|a| a.supports_host_launch)
+                .map(|a| {
+                    let mut cmd = self.launch_command(&self.project_path);
+                    if self.is_yolo_mode() {
+                        if let Some(ref yolo) = a.yolo {
+                            match yolo {
+                                crate::agents::YoloMode::CliFlag(flag) => {
+                                    cmd = format!("{} {}", cmd, flag);
+                                }
+                                crate::agents::YoloMode::EnvVar(key, value) => {
+                                    cmd = format!("{}={} {}", key, value, cmd);
+                                }
+                            }
+                        }
+                    }
+                    wrap_command_ignore_suspend(&self.with_session_env(cmd))
+                })
+        } else {
+            let mut cmd = self.launch_command(&self.project_path);
+            if self.is_yolo_mode() {
+                let agent = crate::agents::get_agent(&self.tool);
+                if let Some(ref yolo) = agent.and_then(|a| a.yolo.as_ref()) {
+                    match yolo {
+                        crate::agents::YoloMode::CliFlag(flag) => {
+                            cmd = format!("{} {}", cmd, flag);
+                        }
+                        crate::agents::YoloMode::EnvVar(key, value) => {
+                            cmd = format!("{}={} {}", key, value, cmd);
+                        }
+                    }
+                }
+            }
+            Some(wrap_command_ignore_suspend(&self.with_session_env(cmd)))
+        }
+    }
+
+    /// Prefix `cmd` with the session's captured environment via `env`.
+    fn with_session_env(&self, cmd: String) -> String {
+        if self.env.is_empty() {
+            return cmd;
+        }
+        let mut vars: Vec<_> = self.env.iter().collect();
+        vars.sort();
+        let assignments: Vec<String> = vars
+            .into_iter()
+            .map(|(key, val)| format!("{}={}", key, shell_escape(val)))
+            .collect();
+        format!("env {} {}", assignments.join(" "), cmd)
+    }
+
+    /// Name of this session's tmux session: the recorded one, or the name
+    /// derived from the id and title.
+    pub fn tmux_name(&self) -> String {
+        self.tmux_name
+            .clone()
+            .unwrap_or_else(|| tmux::Session::generate_name(&self.id, &self.title))
+    }
+
     pub fn tmux_session(&self) -> Result<tmux::Session> {
-        tmux::Session::new(&self.id, &self.title)
+        Ok(tmux::Session::from_name(&self.tmux_name()))
     }
 
     pub fn terminal_tmux_session(&self) -> Result<tmux::TerminalSession> {
-        tmux::TerminalSession::new(&self.id, &self.title)
+        Ok(tmux::TerminalSession::paired_with(&self.tmux_name()))
     }
 
     pub fn has_terminal(&self) -> bool {
@@ -204,7 +461,9 @@ impl Instance {
     }
 
     pub fn container_terminal_tmux_session(&self) -> Result<tmux::ContainerTerminalSession> {
-        tmux::ContainerTerminalSession::new(&self.id, &self.title)
+        Ok(tmux::ContainerTerminalSession::paired_with(
+            &self.tmux_name(),
+        ))
     }
 
     pub fn has_container_terminal(&self) -> bool {
@@ -266,6 +525,30 @@ impl Instance {
         })
     }
 
+    /// Global config merged with the overrides of this session's profile
+    /// (the default profile when none is recorded). Falls back to defaults
+    /// when the config cannot be read.
+    fn effective_config(&self) -> super::config::Config {
+        let profile = if self.profile.is_empty() {
+            super::config::Config::load()
+                .map(|c| c.default_profile)
+                .unwrap_or_else(|_| "default".to_string())
+        } else {
+            self.profile.clone()
+        };
+        super::profile_config::resolve_config(&profile).unwrap_or_else(|e| {
+            tracing::warn!("Failed to load config for profile '{}': {}", profile, e);
+            super::config::Config::default()
+        })
+    }
+
+    /// Configured `[tmux] options` overlaid with this session's own options.
+    fn effective_tmux_options(&self) -> HashMap<String, String> {
+        let mut options = self.effective_config().tmux.options;
+        options.extend(self.tmux_options.clone());
+        options
+    }
+
     /// Apply all configured tmux options to a session with the given name and title.
     fn apply_session_tmux_options(&self, session_name: &str, display_title: &str) {
         let branch = self.worktree_info.as_ref().map(|w| w.branch.as_str());
@@ -275,12 +558,13 @@ impl Instance {
             display_title,
             branch,
             sandbox.as_ref(),
+            &self.effective_tmux_options(),
         );
     }
 
     fn apply_container_terminal_tmux_options(&self) {
-        let name = tmux::ContainerTerminalSession::generate_name(&self.id, &self.title);
-        self.apply_session_tmux_options(&name, &format!("{} (container)", self.title));
+        let session = tmux::ContainerTerminalSession::paired_with(&self.tmux_name());
+        self.apply_session_tmux_options(session.name(), &format!("{} (container)", self.title));
     }
 
     pub fn start(&mut self) -> Result<()> {
@@ -301,8 +585,13 @@ impl Instance {
         let session = self.tmux_session()?;
 
         if session.exists() {
-            return Ok(());
+            if session.dead_pane().is_none() {
+                return Ok(());
+            }
+            session.kill()?;
         }
+        self.check_working_dir()?;
+        self.last_exit_code = None;
 
         // Resolve on_launch hooks from the full config chain (global > profile > repo).
         // Repo hooks go through trust verification; global/profile hooks are implicitly trusted.
@@ -336,6 +625,13 @@ impl Instance {
 
         let cmd = if self.is_sandboxed() {
             let container = self.get_container_for_instance()?;
+            self.wait_for_ready_probe(&container, |attempt| {
+                tracing::info!(
+                    "Waiting for '{}' to become ready (attempt {})",
+                    self.title,
+                    attempt
+                )
+            })?;
             // Run on_launch hooks inside the container
             if let Some(ref hook_cmds) = on_launch_hooks {
                 if let Some(ref sandbox) = self.sandbox_info {
@@ -352,19 +648,20 @@ impl Instance {
 
             let sandbox = self.sandbox_info.as_ref().unwrap();
             let agent = crate::agents::get_agent(&self.tool);
+            let base_cmd = self.launch_command(&self.container_workdir());
             let mut tool_cmd = if self.is_yolo_mode() {
                 if let Some(ref yolo) = agent.and_then(|a| a.yolo.as_ref()) {
                     match yolo {
                         crate::agents::YoloMode::CliFlag(flag) => {
-                            format!("{} {}", self.get_tool_command(), flag)
+                            format!("{} {}", base_cmd, flag)
                         }
-                        crate::agents::YoloMode::EnvVar(..) => self.get_tool_command().to_string(),
+                        crate::agents::YoloMode::EnvVar(..) => base_cmd,
                     }
                 } else {
-                    self.get_tool_command().to_string()
+                    base_cmd
                 }
             } else {
-                self.get_tool_command().to_string()
+                base_cmd
             };
             if let Some(ref instruction) = sandbox.custom_instruction {
                 if !instruction.is_empty() {
@@ -397,46 +694,12 @@ impl Instance {
                 }
             }
 
-            if self.command.is_empty() {
-                crate::agents::get_agent(&self.tool)
-                    .filter(|a| a.supports_host_launch)
-                    .map(|a| {
-                        let mut cmd = a.binary.to_string();
-                        if self.is_yolo_mode() {
-                            if let Some(ref yolo) = a.yolo {
-                                match yolo {
-                                    crate::agents::YoloMode::CliFlag(flag) => {
-                                        cmd = format!("{} {}", cmd, flag);
-                                    }
-                                    crate::agents::YoloMode::EnvVar(key, value) => {
-                                        cmd = format!("{}={} {}", key, value, cmd);
-                                    }
-                                }
-                            }
-                        }
-                        wrap_command_ignore_suspend(&cmd)
-                    })
-            } else {
-                let mut cmd = self.command.clone();
-                if self.is_yolo_mode() {
-                    let agent = crate::agents::get_agent(&self.tool);
-                    if let Some(ref yolo) = agent.and_then(|a| a.yolo.as_ref()) {
-                        match yolo {
-                            crate::agents::YoloMode::CliFlag(flag) => {
-                                cmd = format!("{} {}", cmd, flag);
-                            }
-                            crate::agents::YoloMode::EnvVar(key, value) => {
-                                cmd = format!("{}={} {}", key, value, cmd);
-                            }
-                        }
-                    }
-                }
-                Some(wrap_command_ignore_suspend(&cmd))
-            }
+            self.host_launch_command()
         };
 
         tracing::debug!("container cmd: {}", cmd.as_ref().map_or("none", |v| v));
         session.create_with_size(&self.project_path, cmd.as_deref(), size)?;
+        self.tmux_name = Some(session.name().to_string());
 
         // Apply all configured tmux options (status bar, mouse, etc.)
         self.apply_tmux_options();
@@ -448,38 +711,79 @@ impl Instance {
     }
 
     fn apply_tmux_options(&self) {
-        let name = tmux::Session::generate_name(&self.id, &self.title);
-        self.apply_session_tmux_options(&name, &self.title);
+        self.apply_session_tmux_options(&self.tmux_name(), &self.title);
     }
 
     fn apply_terminal_tmux_options(&self) {
-        let name = tmux::TerminalSession::generate_name(&self.id, &self.title);
-        self.apply_session_tmux_options(&name, &format!("{} (terminal)", self.title));
+        let session = tmux::TerminalSession::paired_with(&self.tmux_name());
+        self.apply_session_tmux_options(session.name(), &format!("{} (terminal)", self.title));
     }
 
     pub fn get_container_for_instance(&mut self) -> Result<containers::DockerContainer> {
+        self.ensure_container(None)
+    }
+
+    /// Like `get_container_for_instance`, streaming any image pull output
+    /// to `progress_tx` the same way hook output is.
+    pub fn get_container_reporting(
+        &mut self,
+        progress_tx: &std::sync::mpsc::Sender<super::repo_config::HookProgress>,
+    ) -> Result<containers::DockerContainer> {
+        self.ensure_container(Some(progress_tx))
+    }
+
+    fn ensure_container(
+        &mut self,
+        progress_tx: Option<&std::sync::mpsc::Sender<super::repo_config::HookProgress>>,
+    ) -> Result<containers::DockerContainer> {
         let sandbox = self
             .sandbox_info
             .as_ref()
             .ok_or_else(|| anyhow::anyhow!("Cannot ensure container for non-sandboxed session"))?;
 
-        let image = &sandbox.image;
-        let container = DockerContainer::new(&self.id, image);
+        let image = sandbox.image.clone();
+        let container = self.container();
 
-        if container.is_running()? {
-            container_config::refresh_agent_configs();
-            return Ok(container);
+        let running = container.is_running()?;
+        if !running {
+            self.check_container_cap()?;
         }
-
-        if container.exists()? {
-            container_config::refresh_agent_configs();
-            container.start()?;
-            return Ok(container);
+        let exists = running || container.exists()?;
+        let drifted = exists && self.config_drifted(&image);
+        match plan_container(exists, running, drifted) {
+            ContainerPlan::Reuse => {
+                container_config::refresh_agent_configs();
+                if drifted {
+                    tracing::warn!(
+                        "Sandbox config for '{}' changed since its container was created; \
+                         stop the session to recreate the container with it",
+                        self.title
+                    );
+                }
+                return Ok(container);
+            }
+            ContainerPlan::Start => {
+                container_config::refresh_agent_configs();
+                container.start()?;
+                return Ok(container);
+            }
+            ContainerPlan::Recreate => {
+                tracing::info!(
+                    "Sandbox config for '{}' changed; recreating its container",
+                    self.title
+                );
+                container.remove(true)?;
+            }
+            ContainerPlan::Create => {}
         }
 
         // Ensure image is available (always pulls to get latest)
         let runtime = containers::get_container_runtime();
-        runtime.ensure_image(image)?;
+        runtime.ensure_image_with_progress(&image, &mut |line| {
+            if let Some(tx) = progress_tx {
+                super::repo_config::send_output_line(tx, line);
+            }
+        })?;
 
         let config = self.build_container_config()?;
         let container_id = container.create(&config)?;
@@ -487,15 +791,209 @@ impl Instance {
         if let Some(ref mut sandbox) = self.sandbox_info {
             sandbox.container_id = Some(container_id);
             sandbox.created_at = Some(Utc::now());
+            sandbox.config_hash = Some(config.content_hash(&image));
         }
 
         Ok(container)
     }
 
+    /// Fail when starting this session's container would exceed
+    /// `sandbox.max_containers`, counting sessions in every profile.
+    fn check_container_cap(&self) -> Result<()> {
+        let max = super::resolve_config(&self.profile)
+            .context("Failed to read sandbox.max_containers")?
+            .sandbox
+            .max_containers;
+        if max == 0 {
+            return Ok(());
+        }
+        let mut instances = Vec::new();
+        for profile in super::list_profiles()? {
+            match super::Storage::new(&profile).and_then(|s| s.load()) {
+                Ok(loaded) => instances.extend(loaded),
+                Err(e) => tracing::warn!(
+                    "Skipping profile '{}' when counting sandbox containers: {:#}",
+                    profile,
+                    e
+                ),
+            }
+        }
+        let running = container_config::running_sandboxed_sessions(&instances, &self.id, |i| {
+            i.container().is_running().unwrap_or(false)
+        });
+        container_config::check_container_cap(max, &running)
+    }
+
+    /// Run the session's `setup_command`, if any, in the project directory or
+    /// inside the container for sandboxed sessions. Output is streamed to
+    /// `progress_tx`; a non-zero exit is an error.
+    pub fn run_setup_command(
+        &mut self,
+        progress_tx: &std::sync::mpsc::Sender<super::repo_config::HookProgress>,
+    ) -> Result<()> {
+        let Some(cmd) = self.setup_command.clone() else {
+            return Ok(());
+        };
+        let commands = [cmd];
+        if self.is_sandboxed() {
+            self.get_container_reporting(progress_tx)?;
+            let workdir = self.container_workdir();
+            let container_name = self.container().name;
+            super::repo_config::execute_hooks_in_container_streamed(
+                &commands,
+                &container_name,
+                &workdir,
+                progress_tx,
+            )
+        } else {
+            super::repo_config::execute_hooks_streamed(
+                &commands,
+                Path::new(&self.project_path),
+                progress_tx,
+            )
+        }
+    }
+
+    /// Handle to this session's container under the name recorded when the
+    /// sandbox was set up.
+    pub fn container(&self) -> DockerContainer {
+        match &self.sandbox_info {
+            Some(s) if !s.container_name.is_empty() => {
+                DockerContainer::from_name(&s.container_name, &s.image)
+            }
+            _ => DockerContainer::from_session_id(&self.id),
+        }
+    }
+
+    /// Whether the effective sandbox config no longer matches the one the
+    /// existing container was created with.
+    fn config_drifted(&self, image: &str) -> bool {
+        let stored = self
+            .sandbox_info
+            .as_ref()
+            .and_then(|s| s.config_hash.as_deref());
+        if stored.is_none() {
+            return false;
+        }
+        match self.build_container_config() {
+            Ok(config) => containers::needs_recreate(&config, image, stored),
+            Err(e) => {
+                tracing::debug!("Skipping config drift check for {}: {}", self.id, e);
+                false
+            }
+        }
+    }
+
+    /// Clear the recorded container ID when its container is missing from
+    /// `containers` (name -> running, from `batch_container_health`), e.g.
+    /// after a manual removal or a daemon restart. Returns true if the
+    /// instance changed.
+    pub fn reconcile_container(&mut self, containers: &HashMap<String, bool>) -> bool {
+        let has_id = self
+            .sandbox_info
+            .as_ref()
+            .is_some_and(|s| s.container_id.is_some());
+        if !has_id {
+            return false;
+        }
+        let exists = containers.contains_key(&self.container().name);
+        self.clear_stale_container(exists)
+    }
+
+    fn clear_stale_container(&mut self, exists: bool) -> bool {
+        if exists {
+            return false;
+        }
+        match self.sandbox_info.as_mut() {
+            Some(sandbox) if sandbox.container_id.is_some() => {
+                sandbox.container_id = None;
+                sandbox.created_at = None;
+                sandbox.config_hash = None;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// The `sandbox.ready_probe` command and its timeout, when this session is
+    /// sandboxed and a probe is configured.
+    pub fn ready_probe(&self) -> Option<(String, std::time::Duration)> {
+        if !self.is_sandboxed() {
+            return None;
+        }
+        let config = self.effective_config();
+        let probe = config
+            .sandbox
+            .ready_probe
+            .filter(|p| !p.trim().is_empty())?;
+        Some((
+            probe,
+            std::time::Duration::from_secs(config.sandbox.ready_timeout_secs),
+        ))
+    }
+
+    /// Start the container and block until the readiness probe succeeds,
+    /// calling `on_wait` with the number of failed attempts. Callers run this
+    /// ahead of starting the session so the wait can be shown (or moved off
+    /// the UI thread); the start then passes its own probe at once.
+    pub fn wait_until_sandbox_ready(&mut self, on_wait: impl FnMut(u32)) -> Result<()> {
+        if self.ready_probe().is_none() {
+            return Ok(());
+        }
+        let container = self.get_container_for_instance()?;
+        self.wait_for_ready_probe(&container, on_wait)
+    }
+
+    /// Block until the configured `sandbox.ready_probe` succeeds inside the
+    /// container. Does nothing when no probe is configured.
+    fn wait_for_ready_probe(
+        &self,
+        container: &containers::DockerContainer,
+        on_wait: impl FnMut(u32),
+    ) -> Result<()> {
+        let Some((probe, timeout)) = self.ready_probe() else {
+            return Ok(());
+        };
+
+        container_config::wait_until_ready(
+            || {
+                container
+                    .exec(&["sh", "-c", &probe])
+                    .is_ok_and(|o| o.status.success())
+            },
+            timeout,
+            container_config::READY_PROBE_INTERVAL,
+            std::thread::sleep,
+            on_wait,
+        )
+    }
+
+    /// Whether the project directory is still present on disk.
+    pub fn working_dir_exists(&self) -> bool {
+        Path::new(&self.project_path).is_dir()
+    }
+
+    /// Fail with `SessionError::MissingWorkingDir` when the project directory
+    /// is gone, instead of letting tmux or docker fail on it.
+    pub fn check_working_dir(&self) -> Result<()> {
+        if self.working_dir_exists() {
+            return Ok(());
+        }
+        Err(super::SessionError::MissingWorkingDir {
+            title: self.title.clone(),
+            path: self.project_path.clone(),
+        }
+        .into())
+    }
+
     /// Get the container working directory for this instance.
     pub fn container_workdir(&self) -> String {
+        let workdir_override = self
+            .sandbox_info
+            .as_ref()
+            .and_then(|s| s.workdir.as_deref());
         container_config::compute_volume_paths(Path::new(&self.project_path), &self.project_path)
-            .map(|(_, _, wd)| wd)
+            .map(|(_, mount, wd)| container_config::resolve_workdir(&mount, wd, workdir_override))
             .unwrap_or_else(|_| "/workspace".to_string())
     }
 
@@ -505,6 +1003,8 @@ impl Instance {
             self.sandbox_info.as_ref().unwrap(),
             &self.tool,
             self.is_yolo_mode(),
+            &self.id,
+            &self.profile,
         )
     }
 
@@ -533,21 +1033,30 @@ impl Instance {
         Ok(())
     }
 
-    /// Stop the session: kill the tmux session and stop the Docker container
-    /// (if sandboxed). The container is stopped but not removed, so it can be
-    /// restarted on re-attach.
+    /// Stop the session: kill its tmux sessions (agent and paired terminals)
+    /// and stop its container, keeping it for a later start.
     pub fn stop(&self) -> Result<()> {
-        self.kill()?;
-
-        if self.is_sandboxed() {
-            let container = containers::DockerContainer::from_session_id(&self.id);
-            if container.is_running().unwrap_or(false) {
-                container.stop()?;
-            }
+        let report =
+            super::teardown_session(self, super::TeardownMode::Stop, &mut super::SystemTeardown);
+        if !report.warnings.is_empty() {
+            anyhow::bail!("{}", report.warnings.join("; "));
         }
         Ok(())
     }
 
+    /// Archive the session: stop its tmux session (and container, if any) but
+    /// keep the record so it can be restored with `unarchive`.
+    pub fn archive(&mut self) -> Result<()> {
+        self.stop()?;
+        self.status = Status::Stopped;
+        self.archived = true;
+        Ok(())
+    }
+
+    pub fn unarchive(&mut self) {
+        self.archived = false;
+    }
+
     pub fn update_status(&mut self) {
         if self.status == Status::Stopped {
             return;
@@ -580,21 +1089,71 @@ impl Instance {
         };
 
         if !session.exists() {
+            // With no tmux server at all (e.g. after a reboot) nothing crashed;
+            // the session simply is not running.
+            if !crate::tmux::is_server_running() {
+                self.status = Status::Stopped;
+                return;
+            }
             self.status = Status::Error;
             self.last_error_check = Some(std::time::Instant::now());
             return;
         }
 
+        if let Some(dead) = session.dead_pane() {
+            self.status = dead.status();
+            self.status_note = None;
+            self.last_exit_code = dead.exit_status;
+            self.last_error_check = Some(std::time::Instant::now());
+            return;
+        }
+
         // Detect status from pane content
-        self.status = match session.detect_status(&self.tool) {
-            Ok(status) => status,
-            Err(_) => Status::Idle,
-        };
+        match session.poll_status(&self.tool) {
+            Ok(polled) => {
+                if polled.lossy {
+                    tracing::debug!("{}: pane output was not valid UTF-8", self.id);
+                }
+                self.status = polled.status;
+                self.status_note = polled.note;
+                self.pane_statuses = polled.panes;
+                self.observe_content_hash(polled.content_hash, Utc::now());
+            }
+            Err(_) => {
+                self.status = Status::Idle;
+                self.status_note = None;
+                self.pane_statuses.clear();
+            }
+        }
 
         // Clear stale error now that the session is healthy
         self.last_error = None;
     }
 
+    /// Record a pane content hash, bumping `last_activity_at` when the content
+    /// differs from the previous poll. The first observation only seeds the hash
+    /// since there is nothing to compare against.
+    pub fn observe_content_hash(&mut self, hash: u64, now: DateTime<Utc>) {
+        if let Some(prev) = self.last_content_hash {
+            if prev != hash {
+                self.last_activity_at = Some(now);
+            }
+        }
+        self.last_content_hash = Some(hash);
+    }
+
+    /// Forget observed pane activity, e.g. after the session was stopped.
+    pub fn clear_activity(&mut self) {
+        self.last_content_hash = None;
+        self.last_activity_at = None;
+    }
+
+    /// How long the pane has gone without new output, if known.
+    pub fn idle_duration(&self, now: DateTime<Utc>) -> Option<chrono::Duration> {
+        self.last_activity_at
+            .map(|at| (now - at).max(chrono::Duration::zero()))
+    }
+
     pub fn capture_output_with_size(
         &self,
         lines: usize,
@@ -604,6 +1163,64 @@ impl Instance {
         let session = self.tmux_session()?;
         session.capture_pane_with_size(lines, Some(width), Some(height))
     }
+
+    /// Press Enter in the agent pane unless it shows an approval prompt.
+    /// Returns whether Enter was sent.
+    pub fn nudge(&self) -> Result<bool> {
+        let session = self.tmux_session()?;
+        let content = session.capture_pane(50)?;
+        if tmux::status_detection::is_approval_prompt(&content) {
+            return Ok(false);
+        }
+        session.send_enter()?;
+        Ok(true)
+    }
+
+    /// A host session's tmux session is still there but its agent is not.
+    /// Sandboxed sessions always report false.
+    pub fn agent_exited(&self) -> bool {
+        if self.is_sandboxed() {
+            return false;
+        }
+        let Ok(session) = self.tmux_session() else {
+            return false;
+        };
+        session.exists()
+            && !agent_present(
+                session.dead_pane().is_some(),
+                session.pane_current_command().as_deref(),
+            )
+    }
+
+    /// Capture recent output with ANSI colors kept, for the TUI preview.
+    pub fn capture_output_ansi(&self, lines: usize) -> Result<String> {
+        self.tmux_session()?.capture_pane_ansi(lines)
+    }
+}
+
+/// What starting a sandboxed session does with its container.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ContainerPlan {
+    /// Keep using the running container
+    Reuse,
+    /// Start the stopped container, keeping its filesystem
+    Start,
+    /// Remove the stopped container and create a new one from current config
+    Recreate,
+    /// No container yet
+    Create,
+}
+
+/// Existing containers are kept so in-container state survives restarts,
+/// unless their config drifted. A running container is never recreated
+/// underneath attached panes; drift there is only reported.
+fn plan_container(exists: bool, running: bool, drifted: bool) -> ContainerPlan {
+    match (exists, running, drifted) {
+        (_, true, _) => ContainerPlan::Reuse,
+        (true, false, false) => ContainerPlan::Start,
+        (true, false, true) => ContainerPlan::Recreate,
+        (false, false, _) => ContainerPlan::Create,
+    }
 }
 
 fn generate_id() -> String {
@@ -635,6 +1252,148 @@ mod tests {
         assert_eq!(inst.id.len(), 16);
     }
 
+    #[test]
+    fn test_recorded_tmux_name_is_kept() {
+        let mut inst = Instance::new("My Project", "/tmp/test");
+        assert_eq!(
+            inst.tmux_name(),
+            tmux::Session::generate_name(&inst.id, &inst.title)
+        );
+
+        inst.tmux_name = Some("aoe_My_Pro_0a1b2c3d".to_string());
+        assert_eq!(inst.tmux_session().unwrap().name(), "aoe_My_Pro_0a1b2c3d");
+        assert_eq!(
+            inst.terminal_tmux_session().unwrap().name(),
+            "aoe_term_My_Pro_0a1b2c3d"
+        );
+    }
+
+    #[test]
+    fn test_observe_content_hash_bumps_only_on_change() {
+        use crate::tmux::status_detection::pane_content_hash;
+
+        let mut inst = Instance::new("test", "/tmp/test");
+        let t0 = Utc::now();
+        let t1 = t0 + chrono::Duration::seconds(30);
+        let t2 = t0 + chrono::Duration::seconds(60);
+
+        inst.observe_content_hash(pane_content_hash("> working"), t0);
+        assert_eq!(inst.last_activity_at, None);
+
+        inst.observe_content_hash(pane_content_hash("> working"), t1);
+        assert_eq!(inst.last_activity_at, None);
+
+        inst.observe_content_hash(pane_content_hash("> working\ndone"), t1);
+        assert_eq!(inst.last_activity_at, Some(t1));
+
+        inst.observe_content_hash(pane_content_hash("> working\ndone"), t2);
+        assert_eq!(inst.last_activity_at, Some(t1));
+        assert_eq!(inst.idle_duration(t2), Some(chrono::Duration::seconds(30)));
+    }
+
+    #[test]
+    fn test_agent_present_from_pane_state() {
+        assert!(agent_present(false, Some("node")));
+        assert!(agent_present(false, Some("claude")));
+        assert!(agent_present(false, None));
+        assert!(!agent_present(true, Some("node")));
+        assert!(!agent_present(false, Some("zsh")));
+        assert!(!agent_present(false, Some("-bash")));
+    }
+
+    #[test]
+    fn test_should_nudge_once_per_wait_episode() {
+        let t0 = Utc::now();
+        let threshold = chrono::Duration::seconds(60);
+        let later = t0 + chrono::Duration::seconds(90);
+
+        assert!(!should_nudge(
+            Status::Waiting,
+            Some(t0),
+            None,
+            t0 + chrono::Duration::seconds(30),
+            threshold
+        ));
+        assert!(should_nudge(
+            Status::Waiting,
+            Some(t0),
+            None,
+            later,
+            threshold
+        ));
+        // Already nudged in this episode
+        assert!(!should_nudge(
+            Status::Waiting,
+            Some(t0),
+            Some(t0),
+            later,
+            threshold
+        ));
+        // New output starts a new episode
+        let t1 = t0 + chrono::Duration::seconds(100);
+        assert!(should_nudge(
+            Status::Waiting,
+            Some(t1),
+            Some(t0),
+            t1 + threshold,
+            threshold
+        ));
+        // Only waiting sessions with observed activity qualify
+        assert!(!should_nudge(
+            Status::Running,
+            Some(t0),
+            None,
+            later,
+            threshold
+        ));
+        assert!(!should_nudge(
+            Status::Idle,
+            Some(t0),
+            None,
+            later,
+            threshold
+        ));
+        assert!(!should_nudge(Status::Waiting, None, None, later, threshold));
+    }
+
+    #[test]
+    fn test_plan_container_reuses_unless_drifted() {
+        assert_eq!(plan_container(true, false, false), ContainerPlan::Start);
+        assert_eq!(plan_container(true, false, true), ContainerPlan::Recreate);
+        assert_eq!(plan_container(true, true, false), ContainerPlan::Reuse);
+        assert_eq!(plan_container(true, true, true), ContainerPlan::Reuse);
+        assert_eq!(plan_container(false, false, false), ContainerPlan::Create);
+    }
+
+    #[test]
+    fn test_should_auto_stop() {
+        let now = Utc::now();
+        let threshold = chrono::Duration::minutes(30);
+
+        assert!(!should_auto_stop(None, now, threshold));
+        assert!(!should_auto_stop(
+            Some(now - chrono::Duration::minutes(29)),
+            now,
+            threshold
+        ));
+        assert!(should_auto_stop(
+            Some(now - chrono::Duration::minutes(30)),
+            now,
+            threshold
+        ));
+        assert!(should_auto_stop(
+            Some(now - chrono::Duration::hours(5)),
+            now,
+            threshold
+        ));
+        // Clock skew: activity in the future is not idle
+        assert!(!should_auto_stop(
+            Some(now + chrono::Duration::minutes(5)),
+            now,
+            threshold
+        ));
+    }
+
     #[test]
     fn test_is_sub_session() {
         let mut inst = Instance::new("test", "/tmp/test");
@@ -696,6 +1455,12 @@ mod tests {
             extra_env_keys: None,
             extra_env_values: None,
             custom_instruction: None,
+            cpu_limit: None,
+            memory_limit: None,
+            config_hash: None,
+            workdir: None,
+            volume_ignores: None,
+            extra_volumes: None,
         });
         assert!(!inst.is_sandboxed());
     }
@@ -712,6 +1477,12 @@ mod tests {
             extra_env_keys: None,
             extra_env_values: None,
             custom_instruction: None,
+            cpu_limit: None,
+            memory_limit: None,
+            config_hash: None,
+            workdir: None,
+            volume_ignores: None,
+            extra_volumes: None,
         });
         assert!(inst.is_sandboxed());
     }
@@ -745,6 +1516,148 @@ mod tests {
         assert_eq!(inst.get_tool_command(), "gemini");
     }
 
+    #[test]
+    fn test_summarize_statuses_mixed() {
+        let statuses = [
+            Status::Running,
+            Status::Running,
+            Status::Waiting,
+            Status::Error,
+            Status::Idle,
+            Status::Stopped,
+            Status::Running,
+        ];
+        let mut instances: Vec<Instance> = statuses
+            .iter()
+            .map(|status| {
+                let mut inst = Instance::new("test", "/tmp/test");
+                inst.status = *status;
+                inst
+            })
+            .collect();
+        let mut archived = Instance::new("old", "/tmp/old");
+        archived.status = Status::Running;
+        archived.archived = true;
+        instances.push(archived);
+
+        let counts = summarize_statuses(&instances);
+        assert_eq!(counts.running, 3);
+        assert_eq!(counts.waiting, 1);
+        assert_eq!(counts.error, 1);
+        assert_eq!(counts.idle, 1);
+        assert_eq!(counts.stopped, 1);
+        assert_eq!(
+            counts.highlights(),
+            vec![
+                (Status::Running, 3),
+                (Status::Waiting, 1),
+                (Status::Idle, 1),
+                (Status::Error, 1)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_summarize_statuses_empty() {
+        let counts = summarize_statuses(&[]);
+        assert_eq!(counts, StatusCounts::default());
+        assert!(counts.highlights().is_empty());
+    }
+
+    #[test]
+    fn test_get_tool_command_crush() {
+        let mut inst = Instance::new("test", "/tmp/test");
+        inst.tool = "crush".to_string();
+        assert_eq!(inst.get_tool_command(), "crush");
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_host_launch_command_crush_yolo() {
+        let temp = tempfile::TempDir::new().unwrap();
+        std::env::set_var("HOME", temp.path());
+        #[cfg(target_os = "linux")]
+        std::env::set_var("XDG_CONFIG_HOME", temp.path().join(".config"));
+
+        let mut inst = Instance::new("test", "/tmp/test");
+        inst.tool = "crush".to_string();
+        let cmd = inst.host_launch_command().unwrap();
+        assert!(cmd.contains("crush"));
+        assert!(!cmd.contains("--yolo"));
+
+        inst.yolo_mode = true;
+        let cmd = inst.host_launch_command().unwrap();
+        assert!(cmd.contains("crush --yolo"));
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_container_cap_skips_unreadable_profiles_but_not_bad_config() {
+        let temp = tempfile::TempDir::new().unwrap();
+        super::super::setup_test_home(temp.path());
+
+        let mut config = super::super::Config::default();
+        config.sandbox.max_containers = 1;
+        super::super::save_config(&config).unwrap();
+        let broken = super::super::get_profile_dir("broken").unwrap();
+        std::fs::write(broken.join("sessions.json"), "not json").unwrap();
+
+        let inst = Instance::new("test", "/tmp/test");
+        assert!(inst.check_container_cap().is_ok());
+
+        std::fs::write(super::super::config_path().unwrap(), "[sandbox\n").unwrap();
+        assert!(inst.check_container_cap().is_err());
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_tmux_options_use_the_sessions_profile() {
+        let temp = tempfile::TempDir::new().unwrap();
+        super::super::setup_test_home(temp.path());
+        let dir = super::super::get_profile_dir("work").unwrap();
+        std::fs::write(
+            dir.join("config.toml"),
+            "[tmux.options]\nhistory-limit = \"50000\"\n",
+        )
+        .unwrap();
+
+        let mut inst = Instance::new("test", "/tmp/test");
+        inst.profile = "work".to_string();
+        inst.tmux_options
+            .insert("mode-keys".to_string(), "vi".to_string());
+        let options = inst.effective_tmux_options();
+        assert_eq!(
+            options.get("history-limit").map(String::as_str),
+            Some("50000")
+        );
+        assert_eq!(options.get("mode-keys").map(String::as_str), Some("vi"));
+
+        inst.profile = "default".to_string();
+        assert!(!inst.effective_tmux_options().contains_key("history-limit"));
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_command_template_applies_to_default_binary_command() {
+        let temp = tempfile::TempDir::new().unwrap();
+        super::super::setup_test_home(temp.path());
+        let dir = super::super::get_profile_dir("work").unwrap();
+        std::fs::write(
+            dir.join("config.toml"),
+            "[commands]\nopencode = \"opencode --cwd {workdir} {resume_id}\"\n",
+        )
+        .unwrap();
+
+        let mut inst = Instance::new("test", "/tmp/test");
+        inst.profile = "work".to_string();
+        inst.tool = "opencode".to_string();
+        inst.command = "opencode".to_string();
+        assert_eq!(inst.launch_command("/w"), "opencode --cwd /w");
+
+        inst.command = "opencode --model x".to_string();
+        assert_eq!(inst.launch_command("/w"), "opencode --model x");
+    }
+
     #[test]
     fn test_get_tool_command_unknown_tool() {
         let mut inst = Instance::new("test", "/tmp/test");
@@ -752,6 +1665,17 @@ mod tests {
         assert_eq!(inst.get_tool_command(), "bash");
     }
 
+    #[test]
+    fn test_host_launch_command_applies_session_env() {
+        let mut inst = Instance::new("test", "/tmp/test");
+        inst.command = "my-agent".to_string();
+        inst.env
+            .insert("API_URL".to_string(), "http://localhost:8080".to_string());
+        inst.env.insert("DEBUG".to_string(), "1".to_string());
+        let cmd = inst.host_launch_command().unwrap();
+        assert!(cmd.contains("exec env API_URL=\"http://localhost:8080\" DEBUG=\"1\" my-agent"));
+    }
+
     #[test]
     fn test_get_tool_command_custom_command() {
         let mut inst = Instance::new("test", "/tmp/test");
@@ -826,6 +1750,12 @@ mod tests {
             extra_env_keys: Some(vec!["MY_VAR".to_string(), "OTHER_VAR".to_string()]),
             extra_env_values: None,
             custom_instruction: None,
+            cpu_limit: None,
+            memory_limit: None,
+            config_hash: None,
+            workdir: None,
+            volume_ignores: None,
+            extra_volumes: None,
         };
 
         let json = serde_json::to_string(&info).unwrap();
@@ -838,6 +1768,46 @@ mod tests {
         assert_eq!(info.extra_env_keys, deserialized.extra_env_keys);
     }
 
+    #[test]
+    fn test_clear_stale_container_id() {
+        let mut inst = Instance::new("test", "/tmp/test");
+        inst.sandbox_info = Some(SandboxInfo {
+            enabled: true,
+            container_id: Some("deadbeef".to_string()),
+            image: "test-image".to_string(),
+            container_name: "aoe-sandbox-test".to_string(),
+            created_at: Some(Utc::now()),
+            extra_env_keys: None,
+            extra_env_values: None,
+            custom_instruction: None,
+            cpu_limit: None,
+            memory_limit: None,
+            config_hash: None,
+            workdir: None,
+            volume_ignores: None,
+            extra_volumes: None,
+        });
+
+        assert!(!inst.clear_stale_container(true));
+        assert_eq!(
+            inst.sandbox_info.as_ref().unwrap().container_id.as_deref(),
+            Some("deadbeef")
+        );
+
+        let listed = HashMap::from([("aoe-sandbox-test".to_string(), false)]);
+        assert!(!inst.reconcile_container(&listed));
+        assert!(inst.reconcile_container(&HashMap::new()));
+        inst.sandbox_info.as_mut().unwrap().container_id = Some("deadbeef".to_string());
+
+        assert!(inst.clear_stale_container(false));
+        let sandbox = inst.sandbox_info.as_ref().unwrap();
+        assert!(sandbox.container_id.is_none());
+        assert!(sandbox.created_at.is_none());
+        assert!(sandbox.enabled);
+
+        assert!(!inst.clear_stale_container(false));
+    }
+
     #[test]
     fn test_sandbox_info_minimal_serialization() {
         // Required fields: enabled, image, container_name
@@ -953,4 +1923,51 @@ mod tests {
         });
         assert!(!inst.has_terminal());
     }
+
+    #[test]
+    fn test_setup_command_runs_in_project_directory() {
+        let dir = tempfile::tempdir().unwrap();
+        let project = dir.path().canonicalize().unwrap();
+        let mut inst = Instance::new("setup", project.to_str().unwrap());
+        inst.setup_command = Some("pwd > setup_pwd.txt".to_string());
+
+        let (tx, rx) = std::sync::mpsc::channel();
+        inst.run_setup_command(&tx).unwrap();
+
+        let pwd = std::fs::read_to_string(project.join("setup_pwd.txt")).unwrap();
+        assert_eq!(Path::new(pwd.trim()), project);
+        assert!(matches!(
+            rx.try_recv(),
+            Ok(super::super::repo_config::HookProgress::Started(cmd)) if cmd == "pwd > setup_pwd.txt"
+        ));
+    }
+
+    #[test]
+    fn test_working_dir_exists() {
+        let dir = tempfile::tempdir().unwrap();
+        let inst = Instance::new("wd", dir.path().to_str().unwrap());
+        assert!(inst.working_dir_exists());
+        assert!(inst.check_working_dir().is_ok());
+
+        let gone = dir.path().join("deleted");
+        let inst = Instance::new("wd", gone.to_str().unwrap());
+        assert!(!inst.working_dir_exists());
+        let err = inst.check_working_dir().unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<super::super::SessionError>(),
+            Some(super::super::SessionError::MissingWorkingDir { .. })
+        ));
+        assert!(err.to_string().contains("aoe session rename wd --path"));
+    }
+
+    #[test]
+    fn test_setup_command_failure_is_an_error() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut inst = Instance::new("setup", dir.path().to_str().unwrap());
+        let (tx, _rx) = std::sync::mpsc::channel();
+        assert!(inst.run_setup_command(&tx).is_ok());
+
+        inst.setup_command = Some("exit 3".to_string());
+        assert!(inst.run_setup_command(&tx).is_err());
+    }
 }
@@ -0,0 +1,413 @@
+//! High-level session management API for embedding aoe in other tools.
+//!
+//! `SessionManager` wraps `Storage`, tmux and container cleanup behind a small
+//! set of operations so callers do not need to reach into internal modules.
+//!
+//! ```
+//! # let home = tempfile::tempdir()?;
+//! # std::env::set_var("HOME", home.path());
+//! # std::env::set_var("XDG_CONFIG_HOME", home.path().join(".config"));
+//! use agent_of_empires::session::{Instance, SessionManager};
+//!
+//! let manager = SessionManager::new("doc-test")?;
+//! let added = manager.add(Instance::new("api", "/tmp/api"))?;
+//!
+//! let sessions = manager.list()?;
+//! assert_eq!(sessions.len(), 1);
+//! assert_eq!(sessions[0].title, "api");
+//!
+//! let removed = manager.remove("api")?;
+//! assert_eq!(removed.id, added.id);
+//! assert!(manager.list()?.is_empty());
+//! # anyhow::Ok(())
+//! ```
+
+use anyhow::{bail, Result};
+
+use super::{Config, GroupTree, Instance, SessionError, Status, Storage};
+use crate::audit::{self, AuditAction, AuditEvent};
+
+/// Find a session by exact id, id prefix, exact title, or project path.
+pub fn resolve_session<'a>(identifier: &str, instances: &'a [Instance]) -> Result<&'a Instance> {
+    if let Some(inst) = instances.iter().find(|i| i.id == identifier) {
+        return Ok(inst);
+    }
+    let strategies: [&dyn Fn(&Instance) -> bool; 3] = [
+        &|i| i.id.starts_with(identifier),
+        &|i| i.title == identifier,
+        &|i| i.project_path == identifier,
+    ];
+    for matches in strategies {
+        let found: Vec<&Instance> = instances.iter().filter(|i| matches(i)).collect();
+        match found.as_slice() {
+            [] => continue,
+            [inst] => return Ok(inst),
+            _ => {
+                return Err(SessionError::Ambiguous {
+                    identifier: identifier.to_string(),
+                    candidates: found
+                        .iter()
+                        .map(|i| format!("{}  {}", crate::cli::truncate_id(&i.id, 8), i.title))
+                        .collect(),
+                }
+                .into())
+            }
+        }
+    }
+    Err(SessionError::NotFound(identifier.to_string()).into())
+}
+
+/// How much of a session's runtime state a teardown discards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TeardownMode {
+    /// Stop the container but keep it, so its filesystem survives and a later
+    /// start does not have to recreate it.
+    Stop,
+    /// Remove the container along with everything written inside it.
+    Remove,
+}
+
+/// Side effects needed to tear a session down, separated so the ordering can
+/// be exercised without tmux or a container runtime.
+pub(crate) trait Teardown {
+    /// Kill the agent tmux session and any paired terminals.
+    fn kill_tmux(&mut self, inst: &Instance) -> Result<()>;
+    /// Stop the session's container, keeping it for a later restart.
+    fn stop_container(&mut self, inst: &Instance) -> Result<()>;
+    /// Remove the session's container. Returns false if there was none.
+    fn remove_container(&mut self, inst: &Instance) -> Result<bool>;
+}
+
+/// Teardown against the real tmux server and container runtime.
+pub(crate) struct SystemTeardown;
+
+impl Teardown for SystemTeardown {
+    fn kill_tmux(&mut self, inst: &Instance) -> Result<()> {
+        let agent = inst.kill();
+        let terminal = inst.kill_terminal();
+        let container_terminal = inst.kill_container_terminal();
+        agent.and(terminal).and(container_terminal)
+    }
+
+    fn stop_container(&mut self, inst: &Instance) -> Result<()> {
+        let container = inst.container();
+        if container.is_running().unwrap_or(false) {
+            container.stop()?;
+        }
+        Ok(())
+    }
+
+    fn remove_container(&mut self, inst: &Instance) -> Result<bool> {
+        let container = inst.container();
+        if !container.exists().unwrap_or(false) {
+            return Ok(false);
+        }
+        container.remove(true)?;
+        Ok(true)
+    }
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct TeardownReport {
+    pub container_removed: bool,
+    pub warnings: Vec<String>,
+}
+
+/// Tear down a session's runtime resources. tmux goes first so panes exec'd
+/// into the container exit before the container disappears under them.
+/// `mode` decides whether the container is only stopped or removed. Failures
+/// are collected as warnings rather than aborting the teardown.
+pub(crate) fn teardown_session(
+    inst: &Instance,
+    mode: TeardownMode,
+    ops: &mut impl Teardown,
+) -> TeardownReport {
+    let mut report = TeardownReport::default();
+
+    if let Err(e) = ops.kill_tmux(inst) {
+        report
+            .warnings
+            .push(format!("failed to kill tmux session: {}", e));
+    }
+
+    if !inst.is_sandboxed() {
+        return report;
+    }
+    match mode {
+        TeardownMode::Stop => {
+            if let Err(e) = ops.stop_container(inst) {
+                report
+                    .warnings
+                    .push(format!("failed to stop container: {}", e));
+            }
+        }
+        TeardownMode::Remove => match ops.remove_container(inst) {
+            Ok(removed) => report.container_removed = removed,
+            Err(e) => report
+                .warnings
+                .push(format!("failed to remove container: {}", e)),
+        },
+    }
+
+    report
+}
+
+/// Options for `SessionManager::remove_with`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RemoveOptions {
+    /// Only stop the container, even when `sandbox.auto_cleanup` is on.
+    pub keep_container: bool,
+}
+
+/// Outcome of `SessionManager::remove_with`.
+#[derive(Debug)]
+pub struct Removal {
+    pub instance: Instance,
+    pub container_removed: bool,
+    /// Teardown steps that failed; the session is removed regardless.
+    pub warnings: Vec<String>,
+}
+
+/// Session operations scoped to a single profile.
+pub struct SessionManager {
+    storage: Storage,
+}
+
+impl SessionManager {
+    pub fn new(profile: &str) -> Result<Self> {
+        Ok(Self {
+            storage: Storage::new(profile)?,
+        })
+    }
+
+    pub fn profile(&self) -> &str {
+        self.storage.profile()
+    }
+
+    /// All non-archived sessions in this profile.
+    pub fn list(&self) -> Result<Vec<Instance>> {
+        let mut instances = self.storage.load()?;
+        instances.retain(|i| !i.archived);
+        Ok(instances)
+    }
+
+    /// Look up a session by id, id prefix, title, or path.
+    pub fn get(&self, identifier: &str) -> Result<Instance> {
+        let instances = self.storage.load()?;
+        resolve_session(identifier, &instances).cloned()
+    }
+
+    /// Persist a new session. Its group chain is created if needed. The
+    /// session is not started; call `Instance::start` to launch it.
+    pub fn add(&self, instance: Instance) -> Result<Instance> {
+        let (mut instances, groups) = self.storage.load_with_groups()?;
+        if instances.iter().any(|i| i.id == instance.id) {
+            bail!("Session already exists: {}", instance.id);
+        }
+        instances.push(instance.clone());
+
+        let mut group_tree = GroupTree::new_with_groups(&instances, &groups);
+        if !instance.group_path.is_empty() {
+            group_tree.create_group(&instance.group_path);
+        }
+        self.storage.save_with_groups(&instances, &group_tree)?;
+        audit::record(AuditEvent::new(
+            self.profile(),
+            &instance,
+            AuditAction::Created,
+        ));
+        Ok(instance)
+    }
+
+    /// Remove a session: kill its tmux sessions, remove its container when
+    /// `sandbox.auto_cleanup` allows it, and drop it from storage. Worktrees
+    /// are left on disk.
+    pub fn remove(&self, identifier: &str) -> Result<Instance> {
+        let removal = self.remove_with(identifier, RemoveOptions::default())?;
+        for warning in &removal.warnings {
+            tracing::warn!("Removing {}: {}", removal.instance.id, warning);
+        }
+        Ok(removal.instance)
+    }
+
+    /// `remove` with options, returning teardown warnings to the caller
+    /// instead of logging them.
+    pub fn remove_with(&self, identifier: &str, options: RemoveOptions) -> Result<Removal> {
+        let (mut instances, groups) = self.storage.load_with_groups()?;
+        let id = resolve_session(identifier, &instances)?.id.clone();
+        let idx = instances
+            .iter()
+            .position(|i| i.id == id)
+            .expect("resolved session is present");
+        let removed = instances.remove(idx);
+
+        let auto_cleanup = Config::load().ok().unwrap_or_default().sandbox.auto_cleanup;
+        let mode = if auto_cleanup && !options.keep_container {
+            TeardownMode::Remove
+        } else {
+            TeardownMode::Stop
+        };
+        let report = teardown_session(&removed, mode, &mut SystemTeardown);
+
+        let group_tree = GroupTree::new_with_groups(&instances, &groups);
+        self.storage.save_with_groups(&instances, &group_tree)?;
+        audit::record(AuditEvent::new(
+            self.profile(),
+            &removed,
+            AuditAction::Removed,
+        ));
+        Ok(Removal {
+            instance: removed,
+            container_removed: report.container_removed,
+            warnings: report.warnings,
+        })
+    }
+
+    /// The command (program and arguments) that attaches a terminal to the
+    /// session's tmux session, for callers that spawn their own terminal.
+    /// `read_only` attaches a client whose keystrokes tmux ignores.
+    pub fn attach_command(&self, identifier: &str, read_only: bool) -> Result<Vec<String>> {
+        let inst = self.get(identifier)?;
+        Ok(inst.tmux_session()?.attach_command(read_only))
+    }
+
+    /// Poll the session's tmux pane and return its current status.
+    pub fn status(&self, identifier: &str) -> Result<Status> {
+        let mut inst = self.get(identifier)?;
+        inst.update_status();
+        Ok(inst.status)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default)]
+    struct RecordingTeardown {
+        calls: Vec<&'static str>,
+        fail_tmux: bool,
+    }
+
+    impl Teardown for RecordingTeardown {
+        fn kill_tmux(&mut self, _inst: &Instance) -> Result<()> {
+            self.calls.push("kill_tmux");
+            if self.fail_tmux {
+                bail!("no server running");
+            }
+            Ok(())
+        }
+
+        fn stop_container(&mut self, _inst: &Instance) -> Result<()> {
+            self.calls.push("stop_container");
+            Ok(())
+        }
+
+        fn remove_container(&mut self, _inst: &Instance) -> Result<bool> {
+            self.calls.push("remove_container");
+            Ok(true)
+        }
+    }
+
+    fn sandboxed() -> Instance {
+        let mut inst = Instance::new("boxed", "/tmp/boxed");
+        inst.sandbox_info = Some(crate::session::SandboxInfo {
+            enabled: true,
+            container_id: None,
+            image: "img".to_string(),
+            container_name: "aoe-sandbox-test".to_string(),
+            created_at: None,
+            extra_env_keys: None,
+            extra_env_values: None,
+            custom_instruction: None,
+            cpu_limit: None,
+            memory_limit: None,
+            config_hash: None,
+            workdir: None,
+            volume_ignores: None,
+            extra_volumes: None,
+        });
+        inst
+    }
+
+    #[test]
+    fn test_teardown_kills_tmux_before_removing_container() {
+        let mut ops = RecordingTeardown::default();
+        let report = teardown_session(&sandboxed(), TeardownMode::Remove, &mut ops);
+        assert_eq!(ops.calls, ["kill_tmux", "remove_container"]);
+        assert!(report.container_removed);
+        assert!(report.warnings.is_empty());
+    }
+
+    #[test]
+    fn test_teardown_continues_after_tmux_failure() {
+        let mut ops = RecordingTeardown {
+            fail_tmux: true,
+            ..Default::default()
+        };
+        let report = teardown_session(&sandboxed(), TeardownMode::Remove, &mut ops);
+        assert_eq!(ops.calls, ["kill_tmux", "remove_container"]);
+        assert_eq!(report.warnings.len(), 1);
+        assert!(report.container_removed);
+    }
+
+    #[test]
+    fn test_stop_mode_keeps_container() {
+        let mut ops = RecordingTeardown::default();
+        let report = teardown_session(&sandboxed(), TeardownMode::Stop, &mut ops);
+        assert_eq!(ops.calls, ["kill_tmux", "stop_container"]);
+        assert!(!report.container_removed);
+
+        let mut ops = RecordingTeardown::default();
+        let report = teardown_session(&sandboxed(), TeardownMode::Remove, &mut ops);
+        assert_eq!(ops.calls, ["kill_tmux", "remove_container"]);
+        assert!(report.container_removed);
+    }
+
+    #[test]
+    fn test_teardown_skips_container_for_host_sessions() {
+        for mode in [TeardownMode::Stop, TeardownMode::Remove] {
+            let mut ops = RecordingTeardown::default();
+            teardown_session(&Instance::new("host", "/tmp/host"), mode, &mut ops);
+            assert_eq!(ops.calls, ["kill_tmux"]);
+        }
+    }
+
+    #[test]
+    fn test_resolve_session_order() {
+        let mut a = Instance::new("alpha", "/tmp/a");
+        a.id = "abc123".to_string();
+        let mut b = Instance::new("abc", "/tmp/b");
+        b.id = "def456".to_string();
+        let instances = vec![a, b];
+
+        assert_eq!(
+            resolve_session("abc123", &instances).unwrap().title,
+            "alpha"
+        );
+        // Id prefix wins over an exact title match
+        assert_eq!(resolve_session("abc", &instances).unwrap().title, "alpha");
+        assert_eq!(resolve_session("/tmp/b", &instances).unwrap().id, "def456");
+        assert!(resolve_session("missing", &instances).is_err());
+    }
+
+    #[test]
+    fn test_resolve_session_refuses_ambiguous_prefix() {
+        let mut a = Instance::new("alpha", "/tmp/a");
+        a.id = "abc123".to_string();
+        let mut b = Instance::new("beta", "/tmp/b");
+        b.id = "abd456".to_string();
+        let instances = vec![a, b];
+
+        let err = resolve_session("ab", &instances).unwrap_err();
+        let msg = err.to_string();
+        assert!(matches!(
+            err.downcast_ref::<SessionError>(),
+            Some(SessionError::Ambiguous { .. })
+        ));
+        assert!(msg.contains("abc123  alpha"), "{msg}");
+        assert!(msg.contains("abd456  beta"), "{msg}");
+
+        assert_eq!(resolve_session("abd", &instances).unwrap().title, "beta");
+    }
+}
@@ -5,20 +5,33 @@ pub mod civilizations;
 pub mod config;
 mod container_config;
 mod environment;
+mod error;
 mod groups;
 mod instance;
+mod manager;
 pub mod profile_config;
 pub mod repo_config;
 mod storage;
 
 pub use crate::sound::{SoundConfig, SoundConfigOverride};
 pub use config::{
-    get_claude_config_dir, get_update_settings, load_config, save_config, ClaudeConfig, Config,
-    ContainerRuntimeName, DefaultTerminalMode, SandboxConfig, SessionConfig, ThemeConfig,
-    TmuxMouseMode, TmuxStatusBarMode, UpdatesConfig, WorktreeConfig,
+    config_path, get_claude_config_dir, get_update_settings, load_config, render_command_template,
+    save_config, ClaudeConfig, Config, ContainerNameScheme, ContainerRuntimeName,
+    DefaultTerminalMode, RelaunchOnAttach, SandboxConfig, SessionConfig, SessionTemplate,
+    ThemeConfig, TmuxMouseMode, TmuxStatusBarMode, UpdatesConfig, WorktreeConfig, CONFIG_ENV,
 };
-pub use groups::{flatten_tree, Group, GroupTree, Item};
-pub use instance::{Instance, SandboxInfo, Status, TerminalInfo, WorktreeInfo};
+pub use container_config::suggest_volume_ignores;
+pub use error::SessionError;
+pub use groups::{
+    flatten_tree, group_start_order, is_in_group, normalize_group_path, rename_group_sessions,
+    ungroup_sessions, Group, GroupTree, Item,
+};
+pub use instance::{
+    agent_present, should_auto_stop, should_nudge, summarize_statuses, Instance, SandboxInfo,
+    Status, StatusCounts, TerminalInfo, WorktreeInfo,
+};
+pub use manager::{resolve_session, Removal, RemoveOptions, SessionManager};
+pub(crate) use manager::{teardown_session, SystemTeardown, TeardownMode};
 pub use profile_config::{
     load_profile_config, merge_configs, resolve_config, save_profile_config,
     validate_check_interval, validate_memory_limit, validate_path_exists, validate_volume_format,
@@ -31,7 +44,9 @@ pub use repo_config::{
     merge_repo_config, profile_to_repo_config, repo_config_to_profile, resolve_config_with_repo,
     save_repo_config, trust_repo, HookTrustStatus, HooksConfig, RepoConfig,
 };
-pub use storage::Storage;
+#[cfg(test)]
+pub(crate) use storage::setup_test_home;
+pub use storage::{Storage, UiState};
 
 use anyhow::Result;
 use std::fs;
@@ -122,7 +137,7 @@ pub fn delete_profile(name: &str) -> Result<()> {
     let profile_dir = base.join("profiles").join(name);
 
     if !profile_dir.exists() {
-        anyhow::bail!("Profile '{}' does not exist", name);
+        return Err(SessionError::ProfileNotFound(name.to_string()).into());
     }
 
     fs::remove_dir_all(&profile_dir)?;
@@ -142,7 +157,7 @@ pub fn rename_profile(old_name: &str, new_name: &str) -> Result<()> {
     let new_dir = base.join("profiles").join(new_name);
 
     if !old_dir.exists() {
-        anyhow::bail!("Profile '{}' does not exist", old_name);
+        return Err(SessionError::ProfileNotFound(old_name.to_string()).into());
     }
     if new_dir.exists() {
         anyhow::bail!("Profile '{}' already exists", new_name);
@@ -9,7 +9,8 @@ use std::collections::HashMap;
 use std::fs;
 
 use super::config::{
-    Config, ContainerRuntimeName, DefaultTerminalMode, TmuxMouseMode, TmuxStatusBarMode,
+    Config, ContainerNameScheme, ContainerRuntimeName, DefaultTerminalMode, RelaunchOnAttach,
+    SessionTemplate, TmuxMouseMode, TmuxStatusBarMode,
 };
 use super::get_profile_dir;
 
@@ -42,6 +43,12 @@ pub struct ProfileConfig {
 
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub sound: Option<crate::sound::SoundConfigOverride>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commands: Option<HashMap<String, String>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub templates: Option<HashMap<String, SessionTemplate>>,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -109,9 +116,21 @@ pub struct SandboxConfigOverride {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub environment_values: Option<HashMap<String, String>>,
 
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<HashMap<String, String>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tool_images: Option<HashMap<String, String>>,
+
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub auto_cleanup: Option<bool>,
 
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_stop_idle: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub idle_stop_minutes: Option<u64>,
+
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub cpu_limit: Option<String>,
 
@@ -127,11 +146,44 @@ pub struct SandboxConfigOverride {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub mount_ssh: Option<bool>,
 
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub strict_mounts: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub strict_env: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub read_only_root: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub init: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extra_hosts: Option<Vec<String>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub host_gateway: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_containers: Option<u64>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ready_probe: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ready_timeout_secs: Option<u64>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dotfiles: Option<Vec<String>>,
+
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub custom_instruction: Option<String>,
 
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub container_runtime: Option<ContainerRuntimeName>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub container_name_scheme: Option<ContainerNameScheme>,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -141,15 +193,37 @@ pub struct TmuxConfigOverride {
 
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub mouse: Option<TmuxMouseMode>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub session_name_length: Option<usize>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub options: Option<HashMap<String, String>>,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct SessionConfigOverride {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::agents::deserialize_optional_tool_name"
+    )]
     pub default_tool: Option<String>,
 
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub yolo_mode_default: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub poll_interval_ms: Option<u64>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confirm_quit_when_running: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_nudge_seconds: Option<u64>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub relaunch_on_attach: Option<RelaunchOnAttach>,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -199,6 +273,8 @@ pub fn profile_has_overrides(config: &ProfileConfig) -> bool {
         || config.session.is_some()
         || config.hooks.is_some()
         || config.sound.is_some()
+        || config.commands.is_some()
+        || config.templates.is_some()
 }
 
 /// Load effective config for a profile (global + profile overrides merged)
@@ -228,9 +304,21 @@ pub fn apply_sandbox_overrides(
     if let Some(ref environment_values) = source.environment_values {
         target.environment_values = environment_values.clone();
     }
+    if let Some(ref labels) = source.labels {
+        target.labels = labels.clone();
+    }
+    if let Some(ref tool_images) = source.tool_images {
+        target.tool_images = tool_images.clone();
+    }
     if let Some(auto_cleanup) = source.auto_cleanup {
         target.auto_cleanup = auto_cleanup;
     }
+    if let Some(auto_stop_idle) = source.auto_stop_idle {
+        target.auto_stop_idle = auto_stop_idle;
+    }
+    if let Some(idle_stop_minutes) = source.idle_stop_minutes {
+        target.idle_stop_minutes = idle_stop_minutes;
+    }
     if let Some(ref cpu_limit) = source.cpu_limit {
         target.cpu_limit = Some(cpu_limit.clone());
     }
@@ -246,12 +334,45 @@ pub fn apply_sandbox_overrides(
     if let Some(mount_ssh) = source.mount_ssh {
         target.mount_ssh = mount_ssh;
     }
+    if let Some(strict_mounts) = source.strict_mounts {
+        target.strict_mounts = strict_mounts;
+    }
+    if let Some(strict_env) = source.strict_env {
+        target.strict_env = strict_env;
+    }
+    if let Some(read_only_root) = source.read_only_root {
+        target.read_only_root = read_only_root;
+    }
+    if let Some(init) = source.init {
+        target.init = init;
+    }
+    if let Some(ref extra_hosts) = source.extra_hosts {
+        target.extra_hosts = extra_hosts.clone();
+    }
+    if let Some(host_gateway) = source.host_gateway {
+        target.host_gateway = host_gateway;
+    }
+    if let Some(max_containers) = source.max_containers {
+        target.max_containers = max_containers;
+    }
+    if let Some(ref ready_probe) = source.ready_probe {
+        target.ready_probe = Some(ready_probe.clone());
+    }
+    if let Some(ready_timeout_secs) = source.ready_timeout_secs {
+        target.ready_timeout_secs = ready_timeout_secs;
+    }
+    if let Some(ref dotfiles) = source.dotfiles {
+        target.dotfiles = dotfiles.clone();
+    }
     if let Some(ref custom_instruction) = source.custom_instruction {
         target.custom_instruction = Some(custom_instruction.clone());
     }
     if let Some(container_runtime) = source.container_runtime {
         target.container_runtime = container_runtime;
     }
+    if let Some(scheme) = source.container_name_scheme {
+        target.container_name_scheme = scheme;
+    }
 }
 
 /// Apply worktree config overrides to a target config.
@@ -303,6 +424,18 @@ pub fn apply_session_overrides(
     if let Some(yolo_mode_default) = source.yolo_mode_default {
         target.yolo_mode_default = yolo_mode_default;
     }
+    if let Some(poll_interval_ms) = source.poll_interval_ms {
+        target.poll_interval_ms = poll_interval_ms;
+    }
+    if let Some(confirm_quit_when_running) = source.confirm_quit_when_running {
+        target.confirm_quit_when_running = confirm_quit_when_running;
+    }
+    if let Some(auto_nudge_seconds) = source.auto_nudge_seconds {
+        target.auto_nudge_seconds = auto_nudge_seconds;
+    }
+    if let Some(relaunch_on_attach) = source.relaunch_on_attach {
+        target.relaunch_on_attach = relaunch_on_attach;
+    }
 }
 
 /// Apply tmux config overrides to a target config.
@@ -313,6 +446,12 @@ pub fn apply_tmux_overrides(target: &mut super::config::TmuxConfig, source: &Tmu
     if let Some(mouse) = source.mouse {
         target.mouse = mouse;
     }
+    if let Some(len) = source.session_name_length {
+        target.session_name_length = len;
+    }
+    if let Some(ref options) = source.options {
+        target.options = options.clone();
+    }
 }
 
 /// Merge profile overrides into global config
@@ -368,6 +507,14 @@ pub fn merge_configs(mut global: Config, profile: &ProfileConfig) -> Config {
         crate::sound::apply_sound_overrides(&mut global.sound, sound_override);
     }
 
+    if let Some(ref commands_override) = profile.commands {
+        global.commands = commands_override.clone();
+    }
+
+    if let Some(ref templates_override) = profile.templates {
+        global.templates = templates_override.clone();
+    }
+
     global
 }
 
@@ -598,6 +745,7 @@ mod tests {
             tmux: Some(TmuxConfigOverride {
                 status_bar: Some(TmuxStatusBarMode::Enabled),
                 mouse: None,
+                ..Default::default()
             }),
             ..Default::default()
         };
@@ -689,6 +837,7 @@ mod tests {
             tmux: Some(TmuxConfigOverride {
                 status_bar: Some(TmuxStatusBarMode::Enabled),
                 mouse: Some(TmuxMouseMode::Enabled),
+                ..Default::default()
             }),
             ..Default::default()
         };
@@ -12,12 +12,73 @@ use std::path::{Path, PathBuf};
 use std::sync::mpsc;
 
 /// Progress messages streamed from hook execution.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "event", content = "line", rename_all = "snake_case")]
 pub enum HookProgress {
     /// A new hook command is starting.
     Started(String),
     /// A line of stdout/stderr output from the running hook.
     Output(String),
+    /// An image pull or build phase recognized in the hook's output.
+    Phase(String),
+}
+
+/// Recognize docker pull/build progress in a line of streamed output and
+/// return a short label for the phase it starts. Ordinary lines return None.
+pub fn classify_progress_line(line: &str) -> Option<String> {
+    let line = line.trim();
+
+    // Classic builder: "Step 3/7 : RUN apt-get update"
+    if let Some(rest) = line.strip_prefix("Step ") {
+        let (step, instruction) = rest.split_once(" : ")?;
+        let (n, m) = step.split_once('/')?;
+        if n.parse::<u32>().is_ok() && m.parse::<u32>().is_ok() {
+            return Some(format!("Build step {}: {}", step, instruction.trim()));
+        }
+        return None;
+    }
+
+    // BuildKit: "#5 [2/4] RUN apt-get update" or "#5 [builder 2/4] RUN ..."
+    if let Some(rest) = line.strip_prefix('#') {
+        let (_, rest) = rest.split_once(' ')?;
+        let rest = rest.strip_prefix('[')?;
+        let (step, instruction) = rest.split_once("] ")?;
+        let step = step.rsplit(' ').next()?;
+        let (n, m) = step.split_once('/')?;
+        if n.parse::<u32>().is_ok() && m.parse::<u32>().is_ok() {
+            return Some(format!("Build step {}: {}", step, instruction.trim()));
+        }
+        return None;
+    }
+
+    // "latest: Pulling from library/ubuntu"
+    if let Some((_, repo)) = line.split_once(": Pulling from ") {
+        return Some(format!("Pulling {}", repo.trim()));
+    }
+    // Compose: "Pulling web ..." / "Building web"
+    if let Some(name) = line
+        .strip_prefix("Pulling ")
+        .or_else(|| line.strip_prefix("Building "))
+    {
+        let verb = if line.starts_with("Pulling") {
+            "Pulling"
+        } else {
+            "Building"
+        };
+        let name = name.trim_end_matches("...").trim();
+        if !name.is_empty() && !name.contains(' ') {
+            return Some(format!("{} {}", verb, name));
+        }
+        return None;
+    }
+    if let Some(image) = line
+        .strip_prefix("Status: Downloaded newer image for ")
+        .or_else(|| line.strip_prefix("Status: Image is up to date for "))
+    {
+        return Some(format!("Pulled {}", image.trim()));
+    }
+
+    None
 }
 
 use super::config::Config;
@@ -499,14 +560,21 @@ fn run_hooks_captured(commands: &[String], target: &HookTarget) -> Result<()> {
     Ok(())
 }
 
+/// Report one line of streamed output, preceded by the phase it starts if
+/// it is recognizable docker pull/build progress.
+pub fn send_output_line(progress_tx: &mpsc::Sender<HookProgress>, line: &str) {
+    if let Some(phase) = classify_progress_line(line) {
+        let _ = progress_tx.send(HookProgress::Phase(phase));
+    }
+    let _ = progress_tx.send(HookProgress::Output(line.to_string()));
+}
+
 /// Run hook commands with streamed output sent through a progress channel.
 fn run_hooks_streamed(
     commands: &[String],
     target: &HookTarget,
     progress_tx: &mpsc::Sender<HookProgress>,
 ) -> Result<()> {
-    use std::io::BufRead;
-
     let in_container = matches!(target, HookTarget::Container { .. });
 
     for cmd in commands {
@@ -516,20 +584,16 @@ fn run_hooks_streamed(
         let mut command = build_hook_command(cmd, target, true);
         let mut child = command
             .stdout(std::process::Stdio::piped())
-            .stderr(std::process::Stdio::null())
+            .stderr(std::process::Stdio::piped())
             .spawn()
             .with_context(|| format!("Failed to execute hook: {}", cmd))?;
 
-        if let Some(stdout) = child.stdout.take() {
-            let reader = std::io::BufReader::new(stdout);
-            for line in reader.lines().map_while(Result::ok) {
-                let _ = progress_tx.send(HookProgress::Output(line));
-            }
-        }
+        let stderr =
+            crate::process::stream_output(&mut child, |line| send_output_line(progress_tx, line));
 
         let status = child.wait()?;
         if !status.success() {
-            let detail = format_hook_error(cmd, status.code(), "", "", in_container);
+            let detail = format_hook_error(cmd, status.code(), &stderr, "", in_container);
             let _ = progress_tx.send(HookProgress::Output(detail.clone()));
             anyhow::bail!(detail);
         }
@@ -624,6 +688,80 @@ pub const INIT_TEMPLATE: &str = r#"# Agent of Empires - Repository Configuration
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_classify_docker_pull_output() {
+        let phases: Vec<Option<String>> = [
+            "latest: Pulling from library/ubuntu",
+            "a1b2c3d4e5f6: Pulling fs layer",
+            "a1b2c3d4e5f6: Download complete",
+            "Digest: sha256:0123abcd",
+            "Status: Downloaded newer image for ubuntu:22.04",
+        ]
+        .iter()
+        .map(|l| classify_progress_line(l))
+        .collect();
+        assert_eq!(
+            phases,
+            vec![
+                Some("Pulling library/ubuntu".to_string()),
+                None,
+                None,
+                None,
+                Some("Pulled ubuntu:22.04".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_classify_docker_build_output() {
+        assert_eq!(
+            classify_progress_line("Step 3/7 : RUN apt-get update"),
+            Some("Build step 3/7: RUN apt-get update".to_string())
+        );
+        assert_eq!(classify_progress_line(" ---> Running in 4f3e2d1c"), None);
+        assert_eq!(
+            classify_progress_line("#5 [2/4] RUN npm ci"),
+            Some("Build step 2/4: RUN npm ci".to_string())
+        );
+        assert_eq!(
+            classify_progress_line("#7 [builder 3/5] COPY . ."),
+            Some("Build step 3/5: COPY . .".to_string())
+        );
+        assert_eq!(classify_progress_line("#5 sha256:abcd 1.2MB / 3.4MB"), None);
+        assert_eq!(
+            classify_progress_line("Building web"),
+            Some("Building web".to_string())
+        );
+        assert_eq!(
+            classify_progress_line("Building wheel for foo (setup.py)"),
+            None
+        );
+        assert_eq!(classify_progress_line("Step one of the plan"), None);
+    }
+
+    #[test]
+    fn test_streamed_hooks_report_stderr() {
+        let dir = tempfile::tempdir().unwrap();
+        let (tx, rx) = mpsc::channel();
+        let result = execute_hooks_streamed(
+            &["echo 'Step 1/2 : FROM alpine' >&2; echo done; exit 3".to_string()],
+            dir.path(),
+            &tx,
+        );
+        drop(tx);
+
+        let err = format!("{:#}", result.unwrap_err());
+        assert!(err.contains("Step 1/2 : FROM alpine"), "{}", err);
+        let events: Vec<HookProgress> = rx.iter().collect();
+        assert!(events.iter().any(|e| matches!(
+            e,
+            HookProgress::Phase(p) if p == "Build step 1/2: FROM alpine"
+        )));
+        assert!(events
+            .iter()
+            .any(|e| matches!(e, HookProgress::Output(l) if l == "done")));
+    }
+
     #[test]
     fn test_hooks_config_empty() {
         let hooks = HooksConfig::default();
@@ -722,6 +860,10 @@ mod tests {
             session: Some(SessionConfigOverride {
                 default_tool: Some("opencode".to_string()),
                 yolo_mode_default: None,
+                poll_interval_ms: None,
+                confirm_quit_when_running: None,
+                auto_nudge_seconds: None,
+                relaunch_on_attach: None,
             }),
             ..Default::default()
         };
@@ -1,12 +1,28 @@
 //! Session storage - JSON file persistence
 
 use anyhow::Result;
+use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::PathBuf;
 use tracing::warn;
 
 use super::{get_profile_dir, Group, GroupTree, Instance, DEFAULT_PROFILE};
 
+/// TUI view state restored on the next launch (per profile)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UiState {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub selected_session: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub selected_group: Option<String>,
+    #[serde(default)]
+    pub terminal_view: bool,
+    /// Paths of collapsed groups. `None` in files written before this was
+    /// tracked, in which case the flags in groups.json are kept.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub collapsed_groups: Option<Vec<String>>,
+}
+
 pub struct Storage {
     profile: String,
     sessions_path: PathBuf,
@@ -43,7 +59,10 @@ impl Storage {
             return Ok(Vec::new());
         }
 
-        let instances: Vec<Instance> = serde_json::from_str(&content)?;
+        let mut instances: Vec<Instance> = serde_json::from_str(&content)?;
+        for inst in &mut instances {
+            inst.profile = self.profile.clone();
+        }
         Ok(instances)
     }
 
@@ -91,6 +110,30 @@ impl Storage {
 
         Ok(())
     }
+
+    /// Load saved UI state, falling back to defaults if missing or unreadable.
+    pub fn load_ui_state(&self) -> UiState {
+        let path = self.sessions_path.with_file_name("ui_state.json");
+        fs::read_to_string(&path)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save_ui_state(&self, state: &UiState) -> Result<()> {
+        let path = self.sessions_path.with_file_name("ui_state.json");
+        let content = serde_json::to_string_pretty(state)?;
+        fs::write(&path, content)?;
+        Ok(())
+    }
+}
+
+/// Point the app directory at `temp` for tests.
+#[cfg(test)]
+pub(crate) fn setup_test_home(temp: &std::path::Path) {
+    std::env::set_var("HOME", temp);
+    #[cfg(target_os = "linux")]
+    std::env::set_var("XDG_CONFIG_HOME", temp.join(".config"));
 }
 
 #[cfg(test)]
@@ -99,12 +142,6 @@ mod tests {
     use serial_test::serial;
     use tempfile::tempdir;
 
-    fn setup_test_home(temp: &std::path::Path) {
-        std::env::set_var("HOME", temp);
-        #[cfg(target_os = "linux")]
-        std::env::set_var("XDG_CONFIG_HOME", temp.join(".config"));
-    }
-
     #[test]
     fn test_storage_roundtrip() -> Result<()> {
         let temp = tempdir()?;
@@ -127,6 +164,32 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    #[serial]
+    fn test_ui_state_roundtrip() -> Result<()> {
+        let temp = tempdir()?;
+        setup_test_home(temp.path());
+
+        let storage = Storage::new("test-ui-state")?;
+        assert_eq!(storage.load_ui_state(), UiState::default());
+
+        let state = UiState {
+            selected_session: Some("abc123".to_string()),
+            selected_group: None,
+            terminal_view: true,
+            collapsed_groups: Some(vec!["work".to_string()]),
+        };
+        storage.save_ui_state(&state)?;
+        assert_eq!(storage.load_ui_state(), state);
+
+        fs::write(
+            storage.sessions_path.with_file_name("ui_state.json"),
+            "{bad",
+        )?;
+        assert_eq!(storage.load_ui_state(), UiState::default());
+        Ok(())
+    }
+
     #[test]
     #[serial]
     fn test_storage_new_with_empty_profile() -> Result<()> {
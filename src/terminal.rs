@@ -1,6 +1,192 @@
 //! Terminal utilities and helpers
 
+use std::process::{Command, Stdio};
+
+use anyhow::{bail, Result};
+
 /// Returns the current terminal size as (width, height), or None if unavailable.
 pub fn get_size() -> Option<(u16, u16)> {
     crossterm::terminal::size().ok()
 }
+
+/// Platform family used to pick a fallback terminal emulator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Platform {
+    MacOs,
+    Linux,
+    Other,
+}
+
+impl Platform {
+    pub fn current() -> Self {
+        if cfg!(target_os = "macos") {
+            Self::MacOs
+        } else if cfg!(target_os = "linux") {
+            Self::Linux
+        } else {
+            Self::Other
+        }
+    }
+}
+
+/// Linux terminal emulators tried in order, with the flag that introduces the
+/// command to run (empty when the command follows directly).
+const LINUX_TERMINALS: &[(&str, &[&str])] = &[
+    ("x-terminal-emulator", &["-e"]),
+    ("gnome-terminal", &["--"]),
+    ("konsole", &["-e"]),
+    ("xfce4-terminal", &["-x"]),
+    ("alacritty", &["-e"]),
+    ("kitty", &[]),
+    ("wezterm", &["start", "--"]),
+    ("foot", &[]),
+    ("xterm", &["-e"]),
+];
+
+/// Build the command that opens a new terminal window running `command`.
+///
+/// `$TERMINAL` (passed as `terminal_var`) wins and is invoked with `-e`.
+/// Otherwise macOS uses Terminal.app via `osascript`, and Linux uses the first
+/// emulator from a known list for which `has_binary` returns true.
+pub fn window_command(
+    command: &[String],
+    terminal_var: Option<&str>,
+    platform: Platform,
+    has_binary: impl Fn(&str) -> bool,
+) -> Option<Vec<String>> {
+    if let Some(terminal) = terminal_var.map(str::trim).filter(|t| !t.is_empty()) {
+        let mut argv: Vec<String> = terminal.split_whitespace().map(String::from).collect();
+        argv.push("-e".to_string());
+        argv.extend(command.iter().cloned());
+        return Some(argv);
+    }
+
+    match platform {
+        Platform::MacOs => {
+            let script_cmd = command.join(" ").replace('\\', "\\\\").replace('"', "\\\"");
+            Some(vec![
+                "osascript".to_string(),
+                "-e".to_string(),
+                format!(
+                    "tell application \"Terminal\" to do script \"{}\"",
+                    script_cmd
+                ),
+                "-e".to_string(),
+                "tell application \"Terminal\" to activate".to_string(),
+            ])
+        }
+        Platform::Linux => LINUX_TERMINALS
+            .iter()
+            .find(|(binary, _)| has_binary(binary))
+            .map(|(binary, flags)| {
+                let mut argv = vec![binary.to_string()];
+                argv.extend(flags.iter().map(|f| f.to_string()));
+                argv.extend(command.iter().cloned());
+                argv
+            }),
+        Platform::Other => None,
+    }
+}
+
+/// Spawn a detached terminal window running `command`.
+pub fn open_in_new_window(command: &[String]) -> Result<()> {
+    let terminal_var = std::env::var("TERMINAL").ok();
+    let Some(argv) = window_command(
+        command,
+        terminal_var.as_deref(),
+        Platform::current(),
+        binary_on_path,
+    ) else {
+        bail!("No terminal emulator found. Set $TERMINAL to your terminal program.");
+    };
+
+    Command::new(&argv[0])
+        .args(&argv[1..])
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map_err(|e| anyhow::anyhow!("Failed to launch '{}': {}", argv[0], e))?;
+    Ok(())
+}
+
+pub(crate) fn binary_on_path(binary: &str) -> bool {
+    Command::new("which")
+        .arg(binary)
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .is_ok_and(|s| s.success())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attach() -> Vec<String> {
+        ["tmux", "attach-session", "-t", "aoe_demo_1234"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_terminal_env_var_wins() {
+        let argv = window_command(&attach(), Some("wezterm"), Platform::MacOs, |_| true).unwrap();
+        assert_eq!(
+            argv,
+            [
+                "wezterm",
+                "-e",
+                "tmux",
+                "attach-session",
+                "-t",
+                "aoe_demo_1234"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_terminal_env_var_with_args() {
+        let argv = window_command(
+            &attach(),
+            Some("kitty --single-instance"),
+            Platform::Linux,
+            |_| false,
+        )
+        .unwrap();
+        assert_eq!(&argv[..3], ["kitty", "--single-instance", "-e"]);
+    }
+
+    #[test]
+    fn test_blank_terminal_env_var_is_ignored() {
+        let argv =
+            window_command(&attach(), Some("  "), Platform::Linux, |b| b == "xterm").unwrap();
+        assert_eq!(argv[0], "xterm");
+    }
+
+    #[test]
+    fn test_macos_uses_terminal_app() {
+        let argv = window_command(&attach(), None, Platform::MacOs, |_| false).unwrap();
+        assert_eq!(argv[0], "osascript");
+        assert!(argv[2].contains("do script \"tmux attach-session -t aoe_demo_1234\""));
+    }
+
+    #[test]
+    fn test_linux_prefers_first_available_terminal() {
+        let argv = window_command(&attach(), None, Platform::Linux, |b| {
+            b == "gnome-terminal" || b == "xterm"
+        })
+        .unwrap();
+        assert_eq!(&argv[..2], ["gnome-terminal", "--"]);
+
+        let argv = window_command(&attach(), None, Platform::Linux, |b| b == "kitty").unwrap();
+        assert_eq!(argv[..2], ["kitty", "tmux"]);
+    }
+
+    #[test]
+    fn test_no_terminal_found() {
+        assert!(window_command(&attach(), None, Platform::Linux, |_| false).is_none());
+        assert!(window_command(&attach(), None, Platform::Other, |_| true).is_none());
+    }
+}
@@ -6,10 +6,11 @@ pub(crate) mod status_detection;
 mod terminal_session;
 mod utils;
 
-pub use session::Session;
+pub use session::{DeadPane, PaneDetail, PaneInfo, Session};
 pub use status_bar::{get_session_info_for_current, get_status_for_current_session};
 pub use status_detection::detect_status_from_content;
 pub use terminal_session::{ContainerTerminalSession, TerminalSession};
+pub use utils::{set_session_name_length, DEFAULT_SESSION_NAME_LENGTH};
 
 use std::collections::HashMap;
 use std::process::Command;
@@ -17,17 +18,97 @@ use std::sync::RwLock;
 use std::time::{Duration, Instant};
 
 pub const SESSION_PREFIX: &str = "aoe_";
+
 pub const TERMINAL_PREFIX: &str = "aoe_term_";
 pub const CONTAINER_TERMINAL_PREFIX: &str = "aoe_cterm_";
 
+/// The tmux binary could not be executed
+#[derive(Debug, thiserror::Error)]
+#[error("tmux is not installed or not in PATH")]
+pub struct TmuxUnavailable;
+
+/// Convert a failure to spawn tmux, reporting a missing binary as
+/// `TmuxUnavailable`.
+pub(crate) fn spawn_error(e: std::io::Error) -> anyhow::Error {
+    if e.kind() == std::io::ErrorKind::NotFound {
+        TmuxUnavailable.into()
+    } else {
+        e.into()
+    }
+}
+
+const CACHE_TTL: Duration = Duration::from_secs(2);
+
+/// Set to `1` to bypass the session cache and ask tmux on every lookup, for
+/// diagnosing stale-cache bugs.
+pub const NO_CACHE_ENV: &str = "AOE_NO_TMUX_CACHE";
+
+fn cache_disabled() -> bool {
+    std::env::var(NO_CACHE_ENV).is_ok_and(|v| !v.is_empty() && v != "0")
+}
+
 static SESSION_CACHE: RwLock<SessionCache> = RwLock::new(SessionCache {
     data: None,
     time: None,
+    server_running: None,
 });
 
 struct SessionCache {
     data: Option<HashMap<String, i64>>,
     time: Option<Instant>,
+    server_running: Option<bool>,
+}
+
+/// Whether a tmux server is reachable on the default socket.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ServerState {
+    Running,
+    NotRunning,
+}
+
+/// Whether tmux stderr means there is simply no server (or no sessions) yet,
+/// as opposed to a real failure. Happens on a fresh login before any session
+/// has been created.
+pub fn is_no_server_error(stderr: &str) -> bool {
+    let stderr = stderr.to_ascii_lowercase();
+    stderr.contains("no server running")
+        || stderr.contains("failed to connect to server")
+        || (stderr.contains("error connecting to") && stderr.contains("no such file or directory"))
+        || stderr.trim() == "no sessions"
+}
+
+/// Query the tmux server state. Errors other than "no server" are returned.
+pub fn server_state() -> anyhow::Result<ServerState> {
+    let output = Command::new("tmux")
+        .args(["list-sessions"])
+        .output()
+        .map_err(spawn_error)?;
+    if output.status.success() {
+        return Ok(ServerState::Running);
+    }
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    if is_no_server_error(&stderr) {
+        Ok(ServerState::NotRunning)
+    } else {
+        anyhow::bail!("tmux list-sessions failed: {}", stderr.trim())
+    }
+}
+
+/// Whether the tmux server is running, using the session cache when fresh.
+/// False when tmux is not installed; other query failures count as running.
+pub fn is_server_running() -> bool {
+    if let Some(running) = SESSION_CACHE
+        .read()
+        .ok()
+        .filter(|c| !cache_disabled() && c.time.is_some_and(|t| t.elapsed() <= CACHE_TTL))
+        .and_then(|c| c.server_running)
+    {
+        return running;
+    }
+    match server_state() {
+        Ok(state) => state == ServerState::Running,
+        Err(e) => !e.is::<TmuxUnavailable>(),
+    }
 }
 
 pub fn refresh_session_cache() {
@@ -39,7 +120,7 @@ pub fn refresh_session_cache() {
         ])
         .output();
 
-    let new_data = match output {
+    let (new_data, server_running) = match output {
         Ok(out) if out.status.success() => {
             let stdout = String::from_utf8_lossy(&out.stdout);
             let mut map = HashMap::new();
@@ -49,26 +130,32 @@ pub fn refresh_session_cache() {
                     map.insert(name.to_string(), activity);
                 }
             }
-            Some(map)
+            (Some(map), Some(true))
+        }
+        // No server means no sessions; cache that so existence checks do not
+        // fall back to one `has-session` call per session.
+        Ok(out) if is_no_server_error(&String::from_utf8_lossy(&out.stderr)) => {
+            (Some(HashMap::new()), Some(false))
         }
-        _ => None,
+        _ => (None, None),
     };
 
     if let Ok(mut cache) = SESSION_CACHE.write() {
         cache.data = new_data;
         cache.time = Some(Instant::now());
+        cache.server_running = server_running;
     }
 }
 
 pub fn session_exists_from_cache(name: &str) -> Option<bool> {
     let cache = SESSION_CACHE.read().ok()?;
+    cache_lookup(&cache, name, cache_disabled())
+}
 
-    // Cache valid for 2 seconds
-    if cache
-        .time
-        .map(|t| t.elapsed() > Duration::from_secs(2))
-        .unwrap_or(true)
-    {
+/// Answer from `cache` when it is fresh and not `disabled`; `None` sends the
+/// caller to tmux.
+fn cache_lookup(cache: &SessionCache, name: &str, disabled: bool) -> Option<bool> {
+    if disabled || cache.time.map(|t| t.elapsed() > CACHE_TTL).unwrap_or(true) {
         return None;
     }
 
@@ -90,6 +177,54 @@ pub fn get_current_session_name() -> Option<String> {
     None
 }
 
+/// Names of all sessions on the tmux server. No server means no sessions.
+pub fn list_session_names() -> anyhow::Result<Vec<String>> {
+    let output = Command::new("tmux")
+        .args(["list-sessions", "-F", "#{session_name}"])
+        .output()
+        .map_err(spawn_error)?;
+    if output.status.success() {
+        return Ok(parse_session_names(&String::from_utf8_lossy(
+            &output.stdout,
+        )));
+    }
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    if is_no_server_error(&stderr) {
+        Ok(Vec::new())
+    } else {
+        anyhow::bail!("tmux list-sessions failed: {}", stderr.trim())
+    }
+}
+
+fn parse_session_names(stdout: &str) -> Vec<String> {
+    stdout
+        .lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+/// Working directory and running command of a session's active pane.
+pub fn active_pane_details(session_name: &str) -> Option<(String, String)> {
+    let output = Command::new("tmux")
+        .args([
+            "display-message",
+            "-p",
+            "-t",
+            session_name,
+            "#{pane_current_path}\t#{pane_current_command}",
+        ])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let (path, command) = stdout.trim_end().split_once('\t')?;
+    Some((path.to_string(), command.to_string()))
+}
+
 pub fn is_tmux_available() -> bool {
     Command::new("tmux").arg("-V").output().is_ok()
 }
@@ -136,3 +271,46 @@ impl AvailableTools {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_no_server_error() {
+        assert!(is_no_server_error(
+            "no server running on /tmp/tmux-1000/default\n"
+        ));
+        assert!(is_no_server_error(
+            "error connecting to /tmp/tmux-501/default (No such file or directory)"
+        ));
+        assert!(is_no_server_error("no sessions\n"));
+        assert!(!is_no_server_error("can't find session: aoe_missing"));
+        assert!(!is_no_server_error(
+            "error connecting to /tmp/tmux-1000/default (Permission denied)"
+        ));
+        assert!(!is_no_server_error(""));
+    }
+
+    #[test]
+    fn test_parse_session_names() {
+        let stdout = "aoe_api_1a2b3c4d\nscratch\n\n  work  \n";
+        assert_eq!(
+            parse_session_names(stdout),
+            ["aoe_api_1a2b3c4d", "scratch", "work"]
+        );
+        assert!(parse_session_names("").is_empty());
+    }
+
+    #[test]
+    fn test_disabled_cache_is_skipped() {
+        let cache = SessionCache {
+            data: Some(HashMap::from([("aoe_test_cached".to_string(), 0)])),
+            time: Some(Instant::now()),
+            server_running: Some(true),
+        };
+        assert_eq!(cache_lookup(&cache, "aoe_test_cached", false), Some(true));
+        assert_eq!(cache_lookup(&cache, "aoe_test_missing", false), Some(false));
+        assert_eq!(cache_lookup(&cache, "aoe_test_cached", true), None);
+    }
+}
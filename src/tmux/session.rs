@@ -3,11 +3,18 @@
 use anyhow::{bail, Result};
 use std::process::Command;
 
+use super::utils::{
+    decode_capture, sanitize_session_name, session_name_length, tail_raw_bytes, Capture,
+};
 use super::{refresh_session_cache, session_exists_from_cache, SESSION_PREFIX};
 use crate::cli::truncate_id;
 use crate::process;
 use crate::session::Status;
 
+/// Upper bound on bytes kept from a single `capture-pane`, so a huge
+/// scrollback request on a busy pane cannot balloon memory.
+pub const MAX_CAPTURE_BYTES: usize = 1024 * 1024;
+
 pub struct Session {
     name: String,
 }
@@ -19,11 +26,23 @@ impl Session {
         })
     }
 
+    /// Handle to an existing tmux session under an arbitrary name, e.g. one
+    /// being imported.
+    pub fn from_name(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+        }
+    }
+
     pub fn generate_name(id: &str, title: &str) -> String {
-        let safe_title = sanitize_session_name(title);
+        let safe_title = sanitize_session_name(title, session_name_length());
         format!("{}{}_{}", SESSION_PREFIX, safe_title, truncate_id(id, 8))
     }
 
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
     pub fn exists(&self) -> bool {
         if let Some(exists) = session_exists_from_cache(&self.name) {
             return exists;
@@ -51,7 +70,10 @@ impl Session {
         }
 
         let args = build_create_args(&self.name, working_dir, command, size);
-        let output = Command::new("tmux").args(&args).output()?;
+        let output = Command::new("tmux")
+            .args(&args)
+            .output()
+            .map_err(super::spawn_error)?;
 
         // Note: With -d flag, tmux new-session returns 0 even if the shell command fails.
         // Log args at debug level for troubleshooting.
@@ -62,6 +84,14 @@ impl Session {
             bail!("Failed to create tmux session: {}", stderr);
         }
 
+        // Keep the pane around after the agent exits so its exit status can
+        // be read back with `dead_pane`.
+        if command.is_some() {
+            let _ = Command::new("tmux")
+                .args(["set-option", "-t", &self.name, "remain-on-exit", "on"])
+                .output();
+        }
+
         super::refresh_session_cache();
 
         Ok(())
@@ -98,6 +128,31 @@ impl Session {
         Ok(())
     }
 
+    /// Press Enter in the session's active pane.
+    pub fn send_enter(&self) -> Result<()> {
+        let output = Command::new("tmux")
+            .args(["send-keys", "-t", &self.name, "Enter"])
+            .output()?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            bail!("Failed to send keys to tmux session: {}", stderr);
+        }
+        Ok(())
+    }
+
+    /// Send Ctrl-C to the session's active pane, twice when `double` is set
+    /// (some agents only exit on a second interrupt).
+    pub fn interrupt(&self, double: bool) -> Result<()> {
+        let output = Command::new("tmux")
+            .args(build_interrupt_args(&self.name, double))
+            .output()?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            bail!("Failed to send keys to tmux session: {}", stderr);
+        }
+        Ok(())
+    }
+
     pub fn rename(&self, new_name: &str) -> Result<()> {
         if !self.exists() {
             return Ok(());
@@ -115,12 +170,24 @@ impl Session {
         Ok(())
     }
 
-    pub fn attach(&self) -> Result<()> {
+    /// The full `tmux attach-session` command line for this session, for
+    /// running in another terminal.
+    pub fn attach_command(&self, read_only: bool) -> Vec<String> {
+        let mut command = vec!["tmux".to_string()];
+        command.extend(build_attach_args(&self.name, read_only));
+        command
+    }
+
+    /// Attach to the session. A read-only client can watch the pane but its
+    /// keystrokes are ignored by tmux.
+    pub fn attach(&self, read_only: bool) -> Result<()> {
         if !self.exists() {
             bail!("Session does not exist: {}", self.name);
         }
 
-        if std::env::var("TMUX").is_ok() {
+        // switch-client has no per-switch read-only mode (its -r toggles the
+        // whole client), so read-only always goes through attach-session
+        if std::env::var("TMUX").is_ok() && !read_only {
             let status = Command::new("tmux")
                 .args(["switch-client", "-t", &self.name])
                 .status()?;
@@ -131,7 +198,7 @@ impl Session {
                 // not actually inside a tmux client (e.g., terminal spawned
                 // from within tmux via `open -a Terminal`).
                 let status = Command::new("tmux")
-                    .args(["attach-session", "-t", &self.name])
+                    .args(build_attach_args(&self.name, false))
                     .status()?;
 
                 if !status.success() {
@@ -140,10 +207,13 @@ impl Session {
             }
         } else {
             let status = Command::new("tmux")
-                .args(["attach-session", "-t", &self.name])
+                .args(build_attach_args(&self.name, read_only))
                 .status()?;
 
             if !status.success() {
+                if read_only && std::env::var("TMUX").is_ok() {
+                    bail!("Failed to attach read-only; run it from a terminal outside tmux");
+                }
                 bail!("Failed to attach to tmux session");
             }
         }
@@ -161,25 +231,43 @@ impl Session {
         _width: Option<u16>,
         _height: Option<u16>,
     ) -> Result<String> {
+        self.capture(lines, false).map(|c| c.content)
+    }
+
+    /// Capture the pane with its ANSI escape sequences (colors, bold, etc.)
+    /// kept, for display. Status detection uses the plain capture.
+    pub fn capture_pane_ansi(&self, lines: usize) -> Result<String> {
+        self.capture(lines, true).map(|c| c.content)
+    }
+
+    fn capture(&self, lines: usize, escapes: bool) -> Result<Capture> {
+        self.capture_target(&self.name, lines, escapes)
+    }
+
+    /// Capture `target`, the session itself (its active pane) or one pane id.
+    fn capture_target(&self, target: &str, lines: usize, escapes: bool) -> Result<Capture> {
+        let empty = Capture {
+            content: String::new(),
+            lossy: false,
+        };
         if !self.exists() {
-            return Ok(String::new());
+            return Ok(empty);
         }
 
-        let output = Command::new("tmux")
-            .args([
-                "capture-pane",
-                "-t",
-                &self.name,
-                "-p",
-                "-S",
-                &format!("-{}", lines),
-            ])
-            .output()?;
+        let start = format!("-{}", lines);
+        let mut args = vec!["capture-pane", "-t", target, "-p", "-S", &start];
+        if escapes {
+            args.push("-e");
+        }
+        let output = Command::new("tmux").args(&args).output()?;
 
         if output.status.success() {
-            Ok(String::from_utf8_lossy(&output.stdout).to_string())
+            Ok(decode_capture(tail_raw_bytes(
+                &output.stdout,
+                MAX_CAPTURE_BYTES,
+            )))
         } else {
-            Ok(String::new())
+            Ok(empty)
         }
     }
 
@@ -192,28 +280,235 @@ impl Session {
         process::get_foreground_pid(pane_pid).or(Some(pane_pid))
     }
 
+    /// The pane's process has exited (kept by `remain-on-exit`). Returns
+    /// `None` while it is still running or when tmux cannot be queried.
+    pub fn dead_pane(&self) -> Option<DeadPane> {
+        let output = Command::new("tmux")
+            .args([
+                "display-message",
+                "-t",
+                &self.name,
+                "-p",
+                "#{pane_dead} #{pane_dead_status}",
+            ])
+            .output()
+            .ok()?;
+        if !output.status.success() {
+            return None;
+        }
+        parse_dead_pane(&String::from_utf8_lossy(&output.stdout))
+    }
+
+    /// Name of the foreground command in the pane (tmux `pane_current_command`)
+    pub fn pane_current_command(&self) -> Option<String> {
+        let output = Command::new("tmux")
+            .args([
+                "display-message",
+                "-t",
+                &self.name,
+                "-p",
+                "#{pane_current_command}",
+            ])
+            .output()
+            .ok()?;
+        if !output.status.success() {
+            return None;
+        }
+        let command = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        (!command.is_empty()).then_some(command)
+    }
+
+    /// Every pane across the session's windows. Empty when tmux cannot be
+    /// queried.
+    pub fn list_panes(&self) -> Vec<PaneInfo> {
+        let output = Command::new("tmux")
+            .args([
+                "list-panes",
+                "-s",
+                "-t",
+                &self.name,
+                "-F",
+                "#{pane_id}\t#{window_index}.#{pane_index}\t#{pane_dead} #{pane_dead_status}\t#{pane_current_command}",
+            ])
+            .output();
+        match output {
+            Ok(output) if output.status.success() => {
+                parse_pane_list(&String::from_utf8_lossy(&output.stdout))
+            }
+            _ => Vec::new(),
+        }
+    }
+
     pub fn detect_status(&self, tool: &str) -> Result<Status> {
-        let content = self.capture_pane(50)?;
-        let fg_pid = self.get_foreground_pid();
-        Ok(super::status_detection::detect_status_from_content(
-            &content, tool, fg_pid,
-        ))
+        self.poll_status(tool).map(|p| p.status)
     }
-}
 
-fn sanitize_session_name(name: &str) -> String {
-    name.chars()
-        .map(|c| {
-            if c.is_alphanumeric() || c == '-' || c == '_' {
-                c
+    /// Capture the pane once and derive status, any informational note (e.g. a
+    /// fallback model notice) and a content hash used to track new output.
+    ///
+    /// A session split into several panes (e.g. one agent per pane) is polled
+    /// pane by pane and reported with its most urgent status; `panes` then
+    /// holds the per-pane detail.
+    pub fn poll_status(&self, tool: &str) -> Result<PaneStatus> {
+        let panes = self.list_panes();
+        if panes.len() <= 1 {
+            let Capture { content, lossy } = self.capture(50, false)?;
+            let fg_pid = self.get_foreground_pid();
+            return Ok(PaneStatus {
+                status: super::status_detection::detect_status_from_content(&content, tool, fg_pid),
+                note: super::status_detection::detect_status_note(&content),
+                content_hash: super::status_detection::pane_content_hash(&content),
+                lossy,
+                panes: Vec::new(),
+            });
+        }
+
+        let mut all_content = String::new();
+        let mut note = None;
+        let mut lossy = false;
+        let mut details = Vec::with_capacity(panes.len());
+        for pane in panes {
+            let status = if let Some(dead) = pane.dead {
+                dead.status()
             } else {
-                '_'
-            }
+                let capture = self.capture_target(&pane.id, 50, false)?;
+                lossy |= capture.lossy;
+                let pane_tool = crate::agents::resolve_tool_name(&pane.command).unwrap_or(tool);
+                if note.is_none() {
+                    note = super::status_detection::detect_status_note(&capture.content);
+                }
+                all_content.push_str(&capture.content);
+                super::status_detection::detect_status_from_content(
+                    &capture.content,
+                    pane_tool,
+                    None,
+                )
+            };
+            details.push(PaneDetail {
+                index: pane.index,
+                command: pane.command,
+                status,
+            });
+        }
+
+        Ok(PaneStatus {
+            status: super::status_detection::aggregate_status(details.iter().map(|p| p.status)),
+            note,
+            content_hash: super::status_detection::pane_content_hash(&all_content),
+            lossy,
+            panes: details,
+        })
+    }
+}
+
+/// One pane of a session as listed by `tmux list-panes`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PaneInfo {
+    /// tmux pane id, e.g. `%3`, usable as a target
+    pub id: String,
+    /// `window.pane` position within the session
+    pub index: String,
+    /// Set when the pane's process has exited
+    pub dead: Option<DeadPane>,
+    pub command: String,
+}
+
+/// Status of one pane in a multi-pane session.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PaneDetail {
+    pub index: String,
+    pub command: String,
+    pub status: Status,
+}
+
+/// Parse `list-panes` output in the tab-separated format used by
+/// `Session::list_panes`.
+pub(crate) fn parse_pane_list(output: &str) -> Vec<PaneInfo> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.splitn(4, '\t');
+            let id = fields.next()?.trim();
+            let index = fields.next()?.trim();
+            let dead = parse_dead_pane(fields.next()?);
+            let command = fields.next().unwrap_or("").trim();
+            (!id.is_empty()).then(|| PaneInfo {
+                id: id.to_string(),
+                index: index.to_string(),
+                dead,
+                command: command.to_string(),
+            })
         })
-        .take(20)
         .collect()
 }
 
+/// Result of a single pane poll.
+pub struct PaneStatus {
+    pub status: Status,
+    pub note: Option<String>,
+    pub content_hash: u64,
+    /// The capture contained invalid UTF-8 that was dropped before detection.
+    pub lossy: bool,
+    /// Per-pane statuses when the session has more than one pane
+    pub panes: Vec<PaneDetail>,
+}
+
+/// A pane whose process has exited.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeadPane {
+    /// Exit code, absent when the process was killed by a signal.
+    pub exit_status: Option<i32>,
+}
+
+impl DeadPane {
+    /// A clean exit means the agent is simply done; a failure or a signal is
+    /// an error.
+    pub fn status(&self) -> Status {
+        if self.exit_status == Some(0) {
+            Status::Stopped
+        } else {
+            Status::Error
+        }
+    }
+}
+
+/// Parse `#{pane_dead} #{pane_dead_status}` output.
+pub(crate) fn parse_dead_pane(output: &str) -> Option<DeadPane> {
+    let mut fields = output.split_whitespace();
+    if fields.next()? != "1" {
+        return None;
+    }
+    Some(DeadPane {
+        exit_status: fields.next().and_then(|s| s.parse().ok()),
+    })
+}
+
+/// Build the argument list for tmux attach-session, adding `-r` for a
+/// read-only client.
+pub(crate) fn build_attach_args(session_name: &str, read_only: bool) -> Vec<String> {
+    let mut args = vec!["attach-session".to_string()];
+    if read_only {
+        args.push("-r".to_string());
+    }
+    args.push("-t".to_string());
+    args.push(session_name.to_string());
+    args
+}
+
+/// Build the argument list for tmux send-keys delivering one or two Ctrl-C.
+fn build_interrupt_args(session_name: &str, double: bool) -> Vec<String> {
+    let mut args = vec![
+        "send-keys".to_string(),
+        "-t".to_string(),
+        session_name.to_string(),
+        "C-c".to_string(),
+    ];
+    if double {
+        args.push("C-c".to_string());
+    }
+    args
+}
+
 /// Build the argument list for tmux new-session command.
 /// Extracted for testability.
 fn build_create_args(
@@ -250,10 +545,95 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_sanitize_session_name() {
-        assert_eq!(sanitize_session_name("my-project"), "my-project");
-        assert_eq!(sanitize_session_name("my project"), "my_project");
-        assert_eq!(sanitize_session_name("a".repeat(30).as_str()).len(), 20);
+    fn test_build_attach_args() {
+        assert_eq!(
+            build_attach_args("aoe_demo_1234", false),
+            vec!["attach-session", "-t", "aoe_demo_1234"]
+        );
+        assert_eq!(
+            build_attach_args("aoe_demo_1234", true),
+            vec!["attach-session", "-r", "-t", "aoe_demo_1234"]
+        );
+        assert_eq!(
+            Session::from_name("aoe_demo_1234").attach_command(true),
+            vec!["tmux", "attach-session", "-r", "-t", "aoe_demo_1234"]
+        );
+    }
+
+    #[test]
+    fn test_build_interrupt_args() {
+        assert_eq!(
+            build_interrupt_args("aoe_demo_1234", false),
+            vec!["send-keys", "-t", "aoe_demo_1234", "C-c"]
+        );
+        assert_eq!(
+            build_interrupt_args("aoe_demo_1234", true),
+            vec!["send-keys", "-t", "aoe_demo_1234", "C-c", "C-c"]
+        );
+    }
+
+    #[test]
+    fn test_parse_pane_list() {
+        let output = "%0\t0.0\t0 \tclaude\n%4\t0.1\t0 \tcodex\n%7\t1.0\t1 2\tbash\n\n";
+        let panes = parse_pane_list(output);
+        assert_eq!(panes.len(), 3);
+        assert_eq!(
+            panes[1],
+            PaneInfo {
+                id: "%4".to_string(),
+                index: "0.1".to_string(),
+                dead: None,
+                command: "codex".to_string(),
+            }
+        );
+        assert_eq!(
+            panes[2].dead,
+            Some(DeadPane {
+                exit_status: Some(2)
+            })
+        );
+    }
+
+    #[test]
+    fn test_dead_pane_status() {
+        assert_eq!(
+            DeadPane {
+                exit_status: Some(0)
+            }
+            .status(),
+            Status::Stopped
+        );
+        assert_eq!(
+            DeadPane {
+                exit_status: Some(1)
+            }
+            .status(),
+            Status::Error
+        );
+        assert_eq!(DeadPane { exit_status: None }.status(), Status::Error);
+    }
+
+    #[test]
+    fn test_parse_dead_pane() {
+        assert_eq!(parse_dead_pane("0 \n"), None);
+        assert_eq!(parse_dead_pane(""), None);
+        assert_eq!(
+            parse_dead_pane("1 1\n"),
+            Some(DeadPane {
+                exit_status: Some(1)
+            })
+        );
+        assert_eq!(
+            parse_dead_pane("1 0"),
+            Some(DeadPane {
+                exit_status: Some(0)
+            })
+        );
+        // Killed by a signal: tmux leaves the status empty
+        assert_eq!(
+            parse_dead_pane("1 \n"),
+            Some(DeadPane { exit_status: None })
+        );
     }
 
     #[test]
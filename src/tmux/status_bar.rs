@@ -1,6 +1,7 @@
 //! tmux status bar configuration for aoe sessions
 
 use anyhow::Result;
+use std::collections::HashMap;
 use std::process::Command;
 
 /// Information about a sandboxed session for status bar display.
@@ -84,13 +85,52 @@ pub fn apply_mouse_option(session_name: &str, enabled: bool) -> Result<()> {
     set_session_option(session_name, "mouse", value)
 }
 
+/// Build the `set-option` argument lists for user-defined tmux options,
+/// ordered by option name so they are applied deterministically.
+pub fn custom_option_args(
+    session_name: &str,
+    options: &HashMap<String, String>,
+) -> Vec<Vec<String>> {
+    let mut names: Vec<&String> = options.keys().collect();
+    names.sort();
+    names
+        .into_iter()
+        .map(|name| {
+            vec![
+                "set-option".to_string(),
+                "-t".to_string(),
+                session_name.to_string(),
+                name.clone(),
+                options[name].clone(),
+            ]
+        })
+        .collect()
+}
+
+/// Apply user-defined tmux options (e.g. `history-limit`) to a session.
+pub fn apply_custom_options(session_name: &str, options: &HashMap<String, String>) {
+    for args in custom_option_args(session_name, options) {
+        match Command::new("tmux").args(&args).output() {
+            Ok(output) if !output.status.success() => tracing::warn!(
+                "Failed to set tmux option {}: {}",
+                args[3],
+                String::from_utf8_lossy(&output.stderr).trim()
+            ),
+            Err(e) => tracing::warn!("Failed to set tmux option {}: {}", args[3], e),
+            Ok(_) => {}
+        }
+    }
+}
+
 /// Apply all configured tmux options to a session.
-/// This is a unified entry point that applies status bar styling and mouse settings.
+/// This is a unified entry point that applies status bar styling, mouse
+/// settings and user-defined options. User options go last so they win.
 pub fn apply_all_tmux_options(
     session_name: &str,
     title: &str,
     branch: Option<&str>,
     sandbox: Option<&SandboxDisplay>,
+    options: &HashMap<String, String>,
 ) {
     use crate::session::config::{should_apply_tmux_mouse, should_apply_tmux_status_bar};
 
@@ -105,6 +145,8 @@ pub fn apply_all_tmux_options(
             tracing::debug!("Failed to apply tmux mouse option: {}", e);
         }
     }
+
+    apply_custom_options(session_name, options);
 }
 
 /// Session info retrieved from tmux user options.
@@ -196,4 +238,26 @@ mod tests {
         // This test just verifies the function doesn't panic
         let _ = get_status_for_current_session();
     }
+
+    #[test]
+    fn test_custom_option_args_in_name_order() {
+        let options = HashMap::from([
+            ("mouse".to_string(), "on".to_string()),
+            ("history-limit".to_string(), "50000".to_string()),
+        ]);
+        assert_eq!(
+            custom_option_args("aoe_demo_1234", &options),
+            vec![
+                vec![
+                    "set-option",
+                    "-t",
+                    "aoe_demo_1234",
+                    "history-limit",
+                    "50000"
+                ],
+                vec!["set-option", "-t", "aoe_demo_1234", "mouse", "on"],
+            ]
+        );
+        assert!(custom_option_args("aoe_demo_1234", &HashMap::new()).is_empty());
+    }
 }
@@ -2,16 +2,130 @@
 
 use crate::session::Status;
 
-use super::utils::strip_ansi;
+use super::utils::{contains_ignore_ascii_case, strip_ansi, tail_bytes};
 
 const SPINNER_CHARS: &[&str] = &["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
 
+/// Context-management messages (e.g. Claude compacting its conversation).
+/// The agent is busy but not working on the task, and not waiting either.
+const CONTEXT_MANAGEMENT_PATTERNS: &[&str] = &["compacting", "summarizing context", "condensing"];
+
+/// How many trailing non-empty lines may show a context-management message
+/// before it is considered stale.
+const CONTEXT_MANAGEMENT_LINES: usize = 5;
+
+/// Only the end of a capture matters for status, and detectors lowercase and
+/// scan their input, so cap what they see to keep a busy pane cheap.
+pub const DETECTION_TAIL_BYTES: usize = 16 * 1024;
+
 pub fn detect_status_from_content(content: &str, tool: &str, _fg_pid: Option<u32>) -> Status {
+    let content = tail_bytes(content, DETECTION_TAIL_BYTES);
     crate::agents::get_agent(tool)
         .map(|a| (a.detect_status)(content))
         .unwrap_or_else(|| detect_claude_status(content))
 }
 
+/// How urgently a status needs attention, for combining several panes.
+fn urgency(status: Status) -> u8 {
+    match status {
+        Status::Error => 5,
+        Status::Waiting => 4,
+        Status::Running => 3,
+        Status::Starting => 2,
+        Status::Idle => 1,
+        Status::Stopped | Status::Deleting => 0,
+    }
+}
+
+/// Combine per-pane statuses into one for the session: the most urgent wins
+/// (Error > Waiting > Running > Starting > Idle). No panes means Idle.
+pub fn aggregate_status(statuses: impl IntoIterator<Item = Status>) -> Status {
+    statuses
+        .into_iter()
+        .max_by_key(|s| urgency(*s))
+        .unwrap_or(Status::Idle)
+}
+
+/// Longest note kept from a pane line, in characters
+const MAX_NOTE_CHARS: usize = 120;
+
+/// Find an informational notice worth badging, such as an agent downgrading
+/// to a fallback model under load. Returns the most recent matching line.
+pub fn detect_status_note(content: &str) -> Option<String> {
+    let content = tail_bytes(content, DETECTION_TAIL_BYTES);
+    if let Some(line) = context_management_line(content) {
+        return Some(line);
+    }
+    content.lines().rev().take(50).find_map(|line| {
+        let clean = strip_ansi(line);
+        let is_fallback = contains_ignore_ascii_case(&clean, "falling back to")
+            || contains_ignore_ascii_case(&clean, "fallback model")
+            || (contains_ignore_ascii_case(&clean, "switched to")
+                && contains_ignore_ascii_case(&clean, "model"));
+        if !is_fallback {
+            return None;
+        }
+        Some(note_text(&clean))
+    })
+}
+
+fn note_text(line: &str) -> String {
+    let trimmed = line.trim().trim_start_matches(['⎿', '●', '*', '-', ' ']);
+    trimmed.chars().take(MAX_NOTE_CHARS).collect()
+}
+
+/// The context-management message near the bottom of the pane, if any.
+fn context_management_line(content: &str) -> Option<String> {
+    content
+        .lines()
+        .rev()
+        .filter(|l| !l.trim().is_empty())
+        .take(CONTEXT_MANAGEMENT_LINES)
+        .find_map(|line| {
+            let clean = strip_ansi(line);
+            CONTEXT_MANAGEMENT_PATTERNS
+                .iter()
+                .any(|p| contains_ignore_ascii_case(&clean, p))
+                .then(|| note_text(&clean))
+        })
+}
+
+/// Whether the bottom of the pane looks like a permission or approval
+/// prompt, where pressing Enter would accept the default answer.
+pub fn is_approval_prompt(content: &str) -> bool {
+    const MARKERS: &[&str] = &[
+        "allow once",
+        "allow always",
+        "approve",
+        "proceed?",
+        "do you want",
+        "do you trust",
+        "(y/n)",
+        "[y/n]",
+        "❯ yes",
+        "❯ no",
+    ];
+    let tail: Vec<&str> = content
+        .lines()
+        .rev()
+        .filter(|l| !l.trim().is_empty())
+        .take(15)
+        .collect();
+    let tail = tail.join("\n").to_lowercase();
+    MARKERS.iter().any(|m| tail.contains(m))
+}
+
+/// Hash of the visible pane text, ignoring ANSI styling and trailing blank
+/// lines so that cursor blinks and redraws without new output compare equal.
+pub fn pane_content_hash(content: &str) -> u64 {
+    use std::hash::{Hash, Hasher};
+
+    let stripped = strip_ansi(content);
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    stripped.trim_end().hash(&mut hasher);
+    hasher.finish()
+}
+
 pub fn detect_claude_status(content: &str) -> Status {
     let lines: Vec<&str> = content.lines().collect();
     let non_empty_lines: Vec<&str> = lines
@@ -44,6 +158,12 @@ pub fn detect_claude_status(content: &str) -> Status {
         }
     }
 
+    // Compacting shows the input box underneath, which would otherwise read
+    // as waiting for input
+    if context_management_line(content).is_some() {
+        return Status::Running;
+    }
+
     if last_lines_lower.contains("enter to select") || last_lines_lower.contains("esc to cancel") {
         return Status::Waiting;
     }
@@ -407,6 +527,42 @@ pub fn detect_cursor_status(content: &str) -> Status {
     detect_claude_status(content)
 }
 
+/// Conservative detection for agents without a dedicated detector. Only looks
+/// for interrupt hints, spinners and y/n prompts shared by most agent TUIs.
+pub fn detect_generic_status(raw_content: &str) -> Status {
+    let content = strip_ansi(raw_content).to_lowercase();
+    let last_lines: Vec<&str> = content
+        .lines()
+        .filter(|l| !l.trim().is_empty())
+        .rev()
+        .take(10)
+        .collect();
+
+    let running_hints = ["esc to interrupt", "ctrl+c to interrupt", "esc to cancel"];
+    if last_lines
+        .iter()
+        .any(|line| running_hints.iter().any(|hint| line.contains(hint)))
+    {
+        return Status::Running;
+    }
+    if last_lines
+        .iter()
+        .any(|line| SPINNER_CHARS.iter().any(|spinner| line.contains(spinner)))
+    {
+        return Status::Running;
+    }
+
+    let prompts = ["(y/n)", "[y/n]", "allow once", "continue?"];
+    if last_lines
+        .iter()
+        .any(|line| prompts.iter().any(|prompt| line.contains(prompt)))
+    {
+        return Status::Waiting;
+    }
+
+    Status::Idle
+}
+
 pub fn detect_gemini_status(raw_content: &str) -> Status {
     let content = raw_content.to_lowercase();
     let lines: Vec<&str> = content.lines().collect();
@@ -472,6 +628,25 @@ pub fn detect_gemini_status(raw_content: &str) -> Status {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_aggregate_status_most_urgent_wins() {
+        use Status::*;
+        assert_eq!(aggregate_status([Idle, Running, Idle]), Running);
+        assert_eq!(aggregate_status([Running, Waiting, Idle]), Waiting);
+        assert_eq!(aggregate_status([Waiting, Error, Running]), Error);
+        assert_eq!(aggregate_status([Starting, Idle]), Starting);
+        assert_eq!(aggregate_status([Idle]), Idle);
+        assert_eq!(aggregate_status([]), Idle);
+    }
+
+    #[test]
+    fn test_is_approval_prompt() {
+        assert!(is_approval_prompt("Run rm -rf build?\n❯ Yes\n  No"));
+        assert!(is_approval_prompt("Apply changes? (y/n)"));
+        assert!(is_approval_prompt("Do you want to proceed?\n 1. Yes"));
+        assert!(!is_approval_prompt("Press Enter to continue\n> "));
+    }
+
     #[test]
     fn test_detect_claude_status_running() {
         assert_eq!(
@@ -510,6 +685,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_detect_status_on_huge_capture_uses_tail() {
+        let filler = "compiling crate with lots of output\n".repeat(200_000);
+        let content = format!("{}Task complete.\n>", filler);
+        assert!(content.len() > 5 * 1024 * 1024);
+        assert_eq!(
+            detect_status_from_content(&content, "claude", None),
+            Status::Waiting
+        );
+
+        // A prompt far above the tail is out of scope for detection
+        let content = format!("Yes, allow once\n{}some random output", filler);
+        assert_eq!(
+            detect_status_from_content(&content, "claude", None),
+            Status::Idle
+        );
+    }
+
     #[test]
     fn test_detect_claude_status_idle() {
         assert_eq!(detect_claude_status("completed the task"), Status::Idle);
@@ -708,6 +901,95 @@ mod tests {
         assert_eq!(detect_codex_status("random output text"), Status::Idle);
     }
 
+    #[test]
+    fn test_detect_status_note_fallback() {
+        let content = "Working on it\n⎿ Opus is experiencing high load, falling back to Sonnet\n> ";
+        assert_eq!(
+            detect_status_note(content).as_deref(),
+            Some("Opus is experiencing high load, falling back to Sonnet")
+        );
+
+        let content = "\x1b[33mModel switched to gemini-2.5-flash due to quota\x1b[0m";
+        assert_eq!(
+            detect_status_note(content).as_deref(),
+            Some("Model switched to gemini-2.5-flash due to quota")
+        );
+    }
+
+    #[test]
+    fn test_detect_claude_status_compacting() {
+        let screen = "● Done with the refactor.\n\n✻ Compacting conversation…\n\n╭──────╮\n│ >    │\n╰──────╯\n  ? for shortcuts";
+        assert_eq!(detect_claude_status(screen), Status::Running);
+        assert_eq!(
+            detect_status_note(screen).as_deref(),
+            Some("✻ Compacting conversation…")
+        );
+        assert_eq!(
+            detect_claude_status("Summarizing context before continuing\n> "),
+            Status::Running
+        );
+
+        // Once compaction scrolls out of view the prompt wins again
+        let stale = format!("✻ Compacting conversation…\n{}>", "output\n".repeat(10));
+        assert_eq!(detect_claude_status(&stale), Status::Waiting);
+        assert_eq!(detect_status_note(&stale), None);
+    }
+
+    #[test]
+    fn test_detect_status_note_ignores_unrelated_lines() {
+        assert_eq!(detect_status_note("Switched to branch 'main'"), None);
+        assert_eq!(detect_status_note("all tests passed"), None);
+    }
+
+    #[test]
+    fn test_detect_generic_status() {
+        assert_eq!(
+            detect_generic_status("Generating... (esc to cancel)"),
+            Status::Running
+        );
+        assert_eq!(detect_generic_status("Working ⠋"), Status::Running);
+        assert_eq!(
+            detect_generic_status("Run this command? (y/n)"),
+            Status::Waiting
+        );
+        assert_eq!(detect_generic_status("done\n> "), Status::Idle);
+    }
+
+    #[test]
+    fn test_error_words_in_output_never_mean_error_status() {
+        // Error status comes from tmux (missing session, dead pane), never from
+        // what the agent prints.
+        for content in [
+            "Build finished: 0 errors found",
+            "Added error handling to parse_config\nerror: previously failed, now fixed",
+        ] {
+            for tool in [
+                "claude", "opencode", "vibe", "codex", "gemini", "cursor", "crush",
+            ] {
+                assert_ne!(
+                    detect_status_from_content(content, tool, None),
+                    Status::Error,
+                    "{tool}: {content}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_detect_status_from_content_routes_crush_to_generic() {
+        // Claude's detector treats a bare prompt as Waiting; the generic one does not
+        let content = "Task complete.\n>";
+        assert_eq!(detect_claude_status(content), Status::Waiting);
+        assert_eq!(
+            detect_status_from_content(content, "crush", None),
+            detect_generic_status(content)
+        );
+        assert_eq!(
+            detect_status_from_content(content, "crush", None),
+            Status::Idle
+        );
+    }
+
     #[test]
     fn test_detect_cursor_status_running() {
         assert_eq!(
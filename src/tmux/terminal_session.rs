@@ -3,13 +3,23 @@
 use anyhow::{bail, Result};
 use std::process::Command;
 
-use super::utils::sanitize_session_name;
+use super::utils::{sanitize_session_name, session_name_length};
 use super::{
-    refresh_session_cache, session_exists_from_cache, CONTAINER_TERMINAL_PREFIX, TERMINAL_PREFIX,
+    refresh_session_cache, session_exists_from_cache, CONTAINER_TERMINAL_PREFIX, SESSION_PREFIX,
+    TERMINAL_PREFIX,
 };
 use crate::cli::truncate_id;
 use crate::process;
 
+/// Name of a paired session: the agent session name with its prefix swapped,
+/// so it matches `generate_name` for the same id and title.
+fn paired_name(prefix: &str, agent_name: &str) -> String {
+    let rest = agent_name
+        .strip_prefix(SESSION_PREFIX)
+        .unwrap_or(agent_name);
+    format!("{}{}", prefix, rest)
+}
+
 pub struct TerminalSession {
     name: String,
 }
@@ -22,10 +32,21 @@ impl TerminalSession {
     }
 
     pub fn generate_name(id: &str, title: &str) -> String {
-        let safe_title = sanitize_session_name(title);
+        let safe_title = sanitize_session_name(title, session_name_length());
         format!("{}{}_{}", TERMINAL_PREFIX, safe_title, truncate_id(id, 8))
     }
 
+    /// Handle to the terminal paired with the agent session `agent_name`.
+    pub fn paired_with(agent_name: &str) -> Self {
+        Self {
+            name: paired_name(TERMINAL_PREFIX, agent_name),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
     pub fn exists(&self) -> bool {
         if let Some(exists) = session_exists_from_cache(&self.name) {
             return exists;
@@ -53,7 +74,10 @@ impl TerminalSession {
         }
 
         let args = build_terminal_create_args(&self.name, working_dir, command, size);
-        let output = Command::new("tmux").args(&args).output()?;
+        let output = Command::new("tmux")
+            .args(&args)
+            .output()
+            .map_err(super::spawn_error)?;
 
         if !output.status.success() {
             let stderr = String::from_utf8_lossy(&output.stderr);
@@ -142,7 +166,9 @@ impl TerminalSession {
             .output()?;
 
         if output.status.success() {
-            Ok(String::from_utf8_lossy(&output.stdout).to_string())
+            let tail =
+                super::utils::tail_raw_bytes(&output.stdout, super::session::MAX_CAPTURE_BYTES);
+            Ok(String::from_utf8_lossy(tail).into_owned())
         } else {
             Ok(String::new())
         }
@@ -163,7 +189,7 @@ impl ContainerTerminalSession {
     }
 
     pub fn generate_name(id: &str, title: &str) -> String {
-        let safe_title = sanitize_session_name(title);
+        let safe_title = sanitize_session_name(title, session_name_length());
         format!(
             "{}{}_{}",
             CONTAINER_TERMINAL_PREFIX,
@@ -172,6 +198,18 @@ impl ContainerTerminalSession {
         )
     }
 
+    /// Handle to the container terminal paired with the agent session
+    /// `agent_name`.
+    pub fn paired_with(agent_name: &str) -> Self {
+        Self {
+            name: paired_name(CONTAINER_TERMINAL_PREFIX, agent_name),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
     pub fn exists(&self) -> bool {
         if let Some(exists) = session_exists_from_cache(&self.name) {
             return exists;
@@ -284,7 +322,9 @@ impl ContainerTerminalSession {
             .output()?;
 
         if output.status.success() {
-            Ok(String::from_utf8_lossy(&output.stdout).to_string())
+            let tail =
+                super::utils::tail_raw_bytes(&output.stdout, super::session::MAX_CAPTURE_BYTES);
+            Ok(String::from_utf8_lossy(tail).into_owned())
         } else {
             Ok(String::new())
         }
@@ -352,6 +392,19 @@ mod tests {
         assert!(terminal_name.starts_with(TERMINAL_PREFIX));
     }
 
+    #[test]
+    fn test_paired_names_match_generated_names() {
+        let agent_name = Session::generate_name("abc123def456", "My Project");
+        assert_eq!(
+            TerminalSession::paired_with(&agent_name).name(),
+            TerminalSession::generate_name("abc123def456", "My Project")
+        );
+        assert_eq!(
+            ContainerTerminalSession::paired_with(&agent_name).name(),
+            ContainerTerminalSession::generate_name("abc123def456", "My Project")
+        );
+    }
+
     #[test]
     fn test_container_terminal_name_differs_from_host_terminal() {
         let host_name = TerminalSession::generate_name("abc123def456", "My Project");
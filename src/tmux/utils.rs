@@ -1,5 +1,22 @@
 //! tmux utility functions
 
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Default number of title characters kept in generated tmux session names.
+pub const DEFAULT_SESSION_NAME_LENGTH: usize = 20;
+
+static SESSION_NAME_LENGTH: AtomicUsize = AtomicUsize::new(DEFAULT_SESSION_NAME_LENGTH);
+
+/// Set the title length used when generating tmux session names for this
+/// process. Values below 1 are clamped to 1.
+pub fn set_session_name_length(len: usize) {
+    SESSION_NAME_LENGTH.store(len.max(1), Ordering::Relaxed);
+}
+
+pub fn session_name_length() -> usize {
+    SESSION_NAME_LENGTH.load(Ordering::Relaxed)
+}
+
 pub fn strip_ansi(content: &str) -> String {
     let mut result = content.to_string();
 
@@ -23,7 +40,78 @@ pub fn strip_ansi(content: &str) -> String {
     result
 }
 
-pub fn sanitize_session_name(name: &str) -> String {
+/// Pane text decoded from raw `capture-pane` output.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Capture {
+    pub content: String,
+    /// True when the output was not valid UTF-8 and invalid bytes were dropped.
+    pub lossy: bool,
+}
+
+/// Decode captured pane bytes. Invalid UTF-8 sequences are removed rather
+/// than replaced with U+FFFD so they cannot confuse status detection.
+pub fn decode_capture(bytes: &[u8]) -> Capture {
+    match std::str::from_utf8(bytes) {
+        Ok(content) => Capture {
+            content: content.to_string(),
+            lossy: false,
+        },
+        Err(_) => Capture {
+            content: String::from_utf8_lossy(bytes).replace(char::REPLACEMENT_CHARACTER, ""),
+            lossy: true,
+        },
+    }
+}
+
+/// Return at most the last `max_bytes` of `content`, starting on a line
+/// boundary when one is available so detectors never see a partial line.
+pub fn tail_bytes(content: &str, max_bytes: usize) -> &str {
+    if content.len() <= max_bytes {
+        return content;
+    }
+    let mut start = content.len() - max_bytes;
+    while !content.is_char_boundary(start) {
+        start += 1;
+    }
+    let tail = &content[start..];
+    match tail.find('\n') {
+        Some(nl) if nl + 1 < tail.len() => &tail[nl + 1..],
+        _ => tail,
+    }
+}
+
+/// `tail_bytes` for raw capture output, applied before decoding so an
+/// oversized capture is never converted whole. The result starts on a UTF-8
+/// character boundary.
+pub fn tail_raw_bytes(bytes: &[u8], max_bytes: usize) -> &[u8] {
+    if bytes.len() <= max_bytes {
+        return bytes;
+    }
+    let mut start = bytes.len() - max_bytes;
+    // Skip UTF-8 continuation bytes
+    while start < bytes.len() && bytes[start] & 0xC0 == 0x80 {
+        start += 1;
+    }
+    let tail = &bytes[start..];
+    match tail.iter().position(|&b| b == b'\n') {
+        Some(nl) if nl + 1 < tail.len() => &tail[nl + 1..],
+        _ => tail,
+    }
+}
+
+/// ASCII case-insensitive substring search that avoids allocating a
+/// lowercased copy of `haystack`. `needle` must already be lowercase.
+pub fn contains_ignore_ascii_case(haystack: &str, needle: &str) -> bool {
+    let (h, n) = (haystack.as_bytes(), needle.as_bytes());
+    if n.is_empty() {
+        return true;
+    }
+    h.windows(n.len()).any(|w| w.eq_ignore_ascii_case(n))
+}
+
+/// Replace characters tmux dislikes in session names and keep at most
+/// `max_len` characters. Uniqueness comes from the id suffix callers append.
+pub fn sanitize_session_name(name: &str, max_len: usize) -> String {
     name.chars()
         .map(|c| {
             if c.is_alphanumeric() || c == '-' || c == '_' {
@@ -32,7 +120,7 @@ pub fn sanitize_session_name(name: &str) -> String {
                 '_'
             }
         })
-        .take(20)
+        .take(max_len)
         .collect()
 }
 
@@ -40,11 +128,44 @@ pub fn sanitize_session_name(name: &str) -> String {
 mod tests {
     use super::*;
 
+    fn sanitize(name: &str) -> String {
+        sanitize_session_name(name, DEFAULT_SESSION_NAME_LENGTH)
+    }
+
     #[test]
     fn test_sanitize_session_name() {
-        assert_eq!(sanitize_session_name("my-project"), "my-project");
-        assert_eq!(sanitize_session_name("my project"), "my_project");
-        assert_eq!(sanitize_session_name("a".repeat(30).as_str()).len(), 20);
+        assert_eq!(sanitize("my-project"), "my-project");
+        assert_eq!(sanitize("my project"), "my_project");
+    }
+
+    #[test]
+    fn test_sanitize_session_name_default_truncates_at_20() {
+        assert_eq!(sanitize(&"a".repeat(30)).len(), 20);
+    }
+
+    #[test]
+    fn test_sanitize_session_name_configured_length() {
+        let title = "a".repeat(35);
+        assert_eq!(sanitize_session_name(&title, 40), title);
+        assert_eq!(sanitize_session_name(&"b".repeat(50), 40).len(), 40);
+    }
+
+    #[test]
+    fn test_decode_capture_valid_utf8() {
+        let capture = decode_capture("Done!\n> ".as_bytes());
+        assert_eq!(capture.content, "Done!\n> ");
+        assert!(!capture.lossy);
+    }
+
+    #[test]
+    fn test_decode_capture_drops_invalid_bytes() {
+        let capture = decode_capture(b"Task complete.\n> \xff\xfe");
+        assert!(capture.lossy);
+        assert!(!capture.content.contains(char::REPLACEMENT_CHARACTER));
+        assert_eq!(
+            crate::tmux::detect_status_from_content(&capture.content, "claude", None),
+            crate::session::Status::Waiting
+        );
     }
 
     #[test]
@@ -54,6 +175,45 @@ mod tests {
         assert_eq!(strip_ansi("\x1b[1;34mbold blue\x1b[0m"), "bold blue");
     }
 
+    #[test]
+    fn test_tail_bytes_bounds_and_aligns_to_lines() {
+        let content = format!("{}\nlast line", "x".repeat(10_000));
+        let tail = tail_bytes(&content, 100);
+        assert!(tail.len() <= 100);
+        assert_eq!(tail, "last line");
+
+        assert_eq!(tail_bytes("short", 100), "short");
+    }
+
+    #[test]
+    fn test_tail_bytes_respects_char_boundaries() {
+        let content = "é".repeat(100);
+        let tail = tail_bytes(&content, 51);
+        assert!(tail.len() <= 51);
+        assert!(tail.chars().all(|c| c == 'é'));
+    }
+
+    #[test]
+    fn test_tail_raw_bytes_starts_on_char_boundary() {
+        let content = "é".repeat(100);
+        let tail = tail_raw_bytes(content.as_bytes(), 51);
+        assert!(tail.len() <= 51);
+        assert_eq!(std::str::from_utf8(tail).unwrap(), "é".repeat(25));
+
+        let content = format!("{}\nlast line", "x".repeat(10_000));
+        assert_eq!(tail_raw_bytes(content.as_bytes(), 100), b"last line");
+    }
+
+    #[test]
+    fn test_contains_ignore_ascii_case() {
+        assert!(contains_ignore_ascii_case(
+            "Falling Back To Sonnet",
+            "falling back to"
+        ));
+        assert!(!contains_ignore_ascii_case("nothing here", "fallback"));
+        assert!(contains_ignore_ascii_case("anything", ""));
+    }
+
     #[test]
     fn test_strip_ansi_empty_string() {
         assert_eq!(strip_ansi(""), "");
@@ -87,25 +247,25 @@ mod tests {
 
     #[test]
     fn test_sanitize_session_name_special_chars() {
-        assert_eq!(sanitize_session_name("test/path"), "test_path");
-        assert_eq!(sanitize_session_name("test.name"), "test_name");
-        assert_eq!(sanitize_session_name("test@name"), "test_name");
-        assert_eq!(sanitize_session_name("test:name"), "test_name");
+        assert_eq!(sanitize("test/path"), "test_path");
+        assert_eq!(sanitize("test.name"), "test_name");
+        assert_eq!(sanitize("test@name"), "test_name");
+        assert_eq!(sanitize("test:name"), "test_name");
     }
 
     #[test]
     fn test_sanitize_session_name_preserves_valid_chars() {
-        assert_eq!(sanitize_session_name("test-name_123"), "test-name_123");
+        assert_eq!(sanitize("test-name_123"), "test-name_123");
     }
 
     #[test]
     fn test_sanitize_session_name_empty() {
-        assert_eq!(sanitize_session_name(""), "");
+        assert_eq!(sanitize(""), "");
     }
 
     #[test]
     fn test_sanitize_session_name_unicode() {
-        let result = sanitize_session_name("test😀emoji");
+        let result = sanitize("test😀emoji");
         assert!(result.starts_with("test"));
         assert!(result.contains('_'));
         assert!(!result.contains('😀'));
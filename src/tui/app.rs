@@ -9,7 +9,7 @@ use std::time::Duration;
 use super::home::{HomeView, TerminalMode};
 use super::styles::load_theme;
 use super::styles::Theme;
-use crate::session::{get_update_settings, load_config, save_config, Storage};
+use crate::session::{get_update_settings, load_config, save_config, RelaunchOnAttach, Storage};
 use crate::tmux::AvailableTools;
 use crate::update::{check_for_update, UpdateInfo};
 
@@ -143,7 +143,6 @@ impl App {
 
         let mut last_status_refresh = std::time::Instant::now();
         let mut last_disk_refresh = std::time::Instant::now();
-        const STATUS_REFRESH_INTERVAL: Duration = Duration::from_millis(500);
         const DISK_REFRESH_INTERVAL: Duration = Duration::from_secs(5);
 
         loop {
@@ -175,6 +174,12 @@ impl App {
 
                         continue;
                     }
+                    Event::Resize(_, _) => {
+                        // Dialog layouts depend on the area, so repaint from scratch
+                        terminal.clear()?;
+                        terminal.draw(|f| self.render(f))?;
+                        continue;
+                    }
                     _ => {}
                 }
             }
@@ -188,7 +193,7 @@ impl App {
             let mut refresh_needed = false;
 
             // Request status refresh every interval (non-blocking)
-            if last_status_refresh.elapsed() >= STATUS_REFRESH_INTERVAL {
+            if last_status_refresh.elapsed() >= self.home.poll_interval() {
                 self.home.request_status_refresh();
                 last_status_refresh = std::time::Instant::now();
             }
@@ -198,6 +203,11 @@ impl App {
                 refresh_needed = true;
             }
 
+            if self.home.auto_stop_idle_sessions() {
+                refresh_needed = true;
+            }
+            self.home.auto_nudge_waiting_sessions();
+
             // Check for and apply deletion results (non-blocking)
             if self.home.apply_deletion_results() {
                 refresh_needed = true;
@@ -210,6 +220,12 @@ impl App {
                 refresh_needed = true;
             }
 
+            // Attach sessions whose sandbox readiness probe has passed
+            if let Some(session_id) = self.home.apply_ready_results() {
+                self.attach_session(&session_id, terminal)?;
+                refresh_needed = true;
+            }
+
             // Tick dialog animations/timers (spinner, transient flashes)
             if self.home.tick_dialog() {
                 refresh_needed = true;
@@ -232,6 +248,7 @@ impl App {
             }
         }
 
+        self.home.save_ui_state();
         Ok(())
     }
 
@@ -291,7 +308,7 @@ impl App {
         match (key.code, key.modifiers) {
             (KeyCode::Char('c'), KeyModifiers::CONTROL) | (KeyCode::Char('q'), _) => {
                 if !self.home.has_dialog() {
-                    self.should_quit = true;
+                    self.should_quit = self.home.request_quit();
                     return Ok(());
                 }
             }
@@ -327,10 +344,16 @@ impl App {
             Action::AttachSession(id) => {
                 self.attach_session(&id, terminal)?;
             }
+            Action::RelaunchSession(id) => {
+                if self.relaunch_agent(&id)? {
+                    self.attach_session(&id, terminal)?;
+                }
+            }
             Action::AttachTerminal(id, mode) => {
                 self.attach_terminal(&id, mode, terminal)?;
             }
             Action::SwitchProfile(profile) => {
+                self.home.save_ui_state();
                 let storage = Storage::new(&profile)?;
                 let tools = self.home.available_tools();
                 self.home = HomeView::new(storage, tools)?;
@@ -338,6 +361,18 @@ impl App {
             Action::EditFile(path) => {
                 self.edit_file(&path, terminal)?;
             }
+            Action::OpenInEditor(path) => {
+                let status =
+                    with_raw_mode_disabled(terminal, || crate::process::open_in_editor(&path))?;
+                self.needs_redraw = true;
+                if let Err(e) = status {
+                    tracing::warn!("Failed to open {}: {}", path.display(), e);
+                    self.home.info_dialog = Some(crate::tui::dialogs::InfoDialog::new(
+                        "Error",
+                        &format!("Failed to open {}: {}", path.display(), e),
+                    ));
+                }
+            }
             Action::StopSession(id) => {
                 if let Some(inst) = self.home.get_instance(&id) {
                     let inst_clone = inst.clone();
@@ -348,6 +383,8 @@ impl App {
                         .set_instance_status(&id, crate::session::Status::Stopped);
                     match inst_clone.stop() {
                         Ok(()) => {
+                            self.home
+                                .record_audit(&inst_clone, crate::audit::AuditAction::Stopped);
                             crate::tmux::refresh_session_cache();
                             self.home.reload()?;
                             self.home
@@ -384,6 +421,17 @@ impl App {
         let tmux_session = instance.tmux_session()?;
 
         if !tmux_session.exists() {
+            if !instance.working_dir_exists() {
+                self.home.info_dialog = Some(crate::tui::dialogs::InfoDialog::new(
+                    "Working Directory Missing",
+                    &format!(
+                        "'{}' no longer exists. Press d to remove the session, or point it at another directory with: aoe session rename {} --path <DIR>",
+                        instance.project_path, instance.title
+                    ),
+                ));
+                return Ok(());
+            }
+
             // Show warning (once) if custom instruction is configured for an unsupported agent
             if instance.is_sandboxed() {
                 let has_instruction = instance
@@ -419,6 +467,13 @@ impl App {
                 }
             }
 
+            // A readiness probe can take a while; run it in the background and
+            // come back here once it passes
+            if instance.ready_probe().is_some() && !self.home.take_sandbox_ready(session_id) {
+                self.home.request_sandbox_ready(&instance);
+                return Ok(());
+            }
+
             // Get terminal size to pass to tmux session creation
             // This ensures the session starts at the correct size instead of 80x24 default
             let size = crate::terminal::get_size();
@@ -436,10 +491,25 @@ impl App {
                     .set_instance_status(session_id, crate::session::Status::Error);
                 return Ok(());
             }
+            self.home
+                .record_audit(&inst, crate::audit::AuditAction::Started);
             self.home.set_instance_error(session_id, None);
+        } else if instance.agent_exited() {
+            match self.home.relaunch_on_attach {
+                RelaunchOnAttach::Off => {}
+                RelaunchOnAttach::Prompt => {
+                    self.home.confirm_relaunch(&instance);
+                    return Ok(());
+                }
+                RelaunchOnAttach::Auto => {
+                    if !self.relaunch_agent(session_id)? {
+                        return Ok(());
+                    }
+                }
+            }
         }
 
-        let attach_result = with_raw_mode_disabled(terminal, || tmux_session.attach())?;
+        let attach_result = with_raw_mode_disabled(terminal, || tmux_session.attach(false))?;
 
         self.needs_redraw = true;
         crate::tmux::refresh_session_cache();
@@ -453,6 +523,25 @@ impl App {
         Ok(())
     }
 
+    /// Restart the agent in a session whose agent has exited. Returns false
+    /// (with the error recorded on the session) if it could not be started.
+    fn relaunch_agent(&mut self, session_id: &str) -> Result<bool> {
+        let Some(mut inst) = self.home.get_instance(session_id).cloned() else {
+            return Ok(false);
+        };
+        if let Err(e) = inst.restart_with_size(crate::terminal::get_size()) {
+            self.home
+                .set_instance_error(session_id, Some(e.to_string()));
+            self.home
+                .set_instance_status(session_id, crate::session::Status::Error);
+            return Ok(false);
+        }
+        self.home
+            .record_audit(&inst, crate::audit::AuditAction::Restarted);
+        self.home.set_instance_error(session_id, None);
+        Ok(true)
+    }
+
     fn attach_terminal(
         &mut self,
         session_id: &str,
@@ -575,9 +664,13 @@ impl App {
 pub enum Action {
     Quit,
     AttachSession(String),
+    /// Restart the exited agent in a session, then attach to it
+    RelaunchSession(String),
     AttachTerminal(String, TerminalMode),
     SwitchProfile(String),
     EditFile(PathBuf),
+    /// Open a project directory in `$VISUAL` / `$EDITOR`
+    OpenInEditor(PathBuf),
     StopSession(String),
     SetTheme(String),
 }
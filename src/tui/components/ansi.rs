@@ -0,0 +1,207 @@
+//! Convert pane output with ANSI escape sequences into styled ratatui lines.
+//!
+//! Only SGR sequences (`ESC [ ... m`) affect styling; every other CSI and OSC
+//! sequence is dropped. Styles carry over from one line to the next, as they
+//! do in the terminal.
+
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+
+/// Parse `content` into one line per input line.
+pub fn parse_ansi(content: &str) -> Vec<Line<'static>> {
+    let mut style = Style::default();
+    content
+        .lines()
+        .map(|line| parse_line(line, &mut style))
+        .collect()
+}
+
+fn parse_line(line: &str, style: &mut Style) -> Line<'static> {
+    let mut spans = Vec::new();
+    let mut text = String::new();
+    let mut chars = line.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '\x1b' {
+            text.push(c);
+            continue;
+        }
+        match chars.peek() {
+            Some('[') => {
+                chars.next();
+                let mut params = String::new();
+                let mut terminator = None;
+                for c in chars.by_ref() {
+                    if ('@'..='~').contains(&c) {
+                        terminator = Some(c);
+                        break;
+                    }
+                    params.push(c);
+                }
+                if terminator == Some('m') {
+                    let next = apply_sgr(*style, &params);
+                    if next != *style && !text.is_empty() {
+                        spans.push(Span::styled(std::mem::take(&mut text), *style));
+                    }
+                    *style = next;
+                }
+            }
+            Some(']') => {
+                chars.next();
+                while let Some(c) = chars.next() {
+                    if c == '\x07' {
+                        break;
+                    }
+                    if c == '\x1b' && chars.peek() == Some(&'\\') {
+                        chars.next();
+                        break;
+                    }
+                }
+            }
+            _ => {
+                chars.next();
+            }
+        }
+    }
+
+    if !text.is_empty() {
+        spans.push(Span::styled(text, *style));
+    }
+    Line::from(spans)
+}
+
+fn apply_sgr(mut style: Style, params: &str) -> Style {
+    let codes: Vec<u16> = params
+        .split([';', ':'])
+        .map(|p| p.parse().unwrap_or(0))
+        .collect();
+    let mut i = 0;
+    while i < codes.len() {
+        match codes[i] {
+            0 => style = Style::default(),
+            1 => style = style.add_modifier(Modifier::BOLD),
+            2 => style = style.add_modifier(Modifier::DIM),
+            3 => style = style.add_modifier(Modifier::ITALIC),
+            4 => style = style.add_modifier(Modifier::UNDERLINED),
+            5 => style = style.add_modifier(Modifier::SLOW_BLINK),
+            7 => style = style.add_modifier(Modifier::REVERSED),
+            9 => style = style.add_modifier(Modifier::CROSSED_OUT),
+            22 => style = style.remove_modifier(Modifier::BOLD | Modifier::DIM),
+            23 => style = style.remove_modifier(Modifier::ITALIC),
+            24 => style = style.remove_modifier(Modifier::UNDERLINED),
+            25 => style = style.remove_modifier(Modifier::SLOW_BLINK),
+            27 => style = style.remove_modifier(Modifier::REVERSED),
+            29 => style = style.remove_modifier(Modifier::CROSSED_OUT),
+            n @ 30..=37 => style.fg = Some(base_color(n - 30)),
+            n @ 40..=47 => style.bg = Some(base_color(n - 40)),
+            n @ 90..=97 => style.fg = Some(bright_color(n - 90)),
+            n @ 100..=107 => style.bg = Some(bright_color(n - 100)),
+            39 => style.fg = None,
+            49 => style.bg = None,
+            n @ (38 | 48) => {
+                let (color, used) = extended_color(&codes[i + 1..]);
+                if let Some(color) = color {
+                    if n == 38 {
+                        style.fg = Some(color);
+                    } else {
+                        style.bg = Some(color);
+                    }
+                }
+                i += used;
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    style
+}
+
+/// Parse the arguments after 38/48: `5;n` or `2;r;g;b`. Returns the color and
+/// how many arguments were consumed.
+fn extended_color(args: &[u16]) -> (Option<Color>, usize) {
+    match args {
+        [5, n, ..] => (Some(Color::Indexed(*n as u8)), 2),
+        [2, r, g, b, ..] => (Some(Color::Rgb(*r as u8, *g as u8, *b as u8)), 4),
+        _ => (None, args.len()),
+    }
+}
+
+fn base_color(n: u16) -> Color {
+    match n {
+        0 => Color::Black,
+        1 => Color::Red,
+        2 => Color::Green,
+        3 => Color::Yellow,
+        4 => Color::Blue,
+        5 => Color::Magenta,
+        6 => Color::Cyan,
+        _ => Color::Gray,
+    }
+}
+
+fn bright_color(n: u16) -> Color {
+    match n {
+        0 => Color::DarkGray,
+        1 => Color::LightRed,
+        2 => Color::LightGreen,
+        3 => Color::LightYellow,
+        4 => Color::LightBlue,
+        5 => Color::LightMagenta,
+        6 => Color::LightCyan,
+        _ => Color::White,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_red_then_reset() {
+        let lines = parse_ansi("\x1b[31mred\x1b[0m");
+        assert_eq!(
+            lines,
+            vec![Line::from(vec![Span::styled(
+                "red",
+                Style::default().fg(Color::Red)
+            )])]
+        );
+    }
+
+    #[test]
+    fn test_mixed_spans_and_carry_over() {
+        let lines = parse_ansi("a\x1b[1;38;5;208mb\x1b[22mc\nd\x1b[0me");
+        let orange = Style::default().fg(Color::Indexed(208));
+        assert_eq!(
+            lines[0].spans,
+            vec![
+                Span::raw("a"),
+                Span::styled("b", orange.add_modifier(Modifier::BOLD)),
+                Span::styled(
+                    "c",
+                    orange
+                        .add_modifier(Modifier::BOLD)
+                        .remove_modifier(Modifier::BOLD | Modifier::DIM)
+                ),
+            ]
+        );
+        // Color set on the previous line still applies until reset
+        assert_eq!(lines[1].spans[0].style.fg, Some(Color::Indexed(208)));
+        assert_eq!(lines[1].spans[1], Span::raw("e"));
+    }
+
+    #[test]
+    fn test_non_sgr_sequences_are_dropped() {
+        let lines = parse_ansi("\x1b[2K\x1b]0;title\x07\x1b[38;2;1;2;3mx");
+        assert_eq!(
+            lines[0].spans,
+            vec![Span::styled("x", Style::default().fg(Color::Rgb(1, 2, 3)))]
+        );
+    }
+
+    #[test]
+    fn test_plain_text_is_unstyled() {
+        let lines = parse_ansi("hello\nworld\n");
+        assert_eq!(lines, vec![Line::raw("hello"), Line::raw("world")]);
+    }
+}
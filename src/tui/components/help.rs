@@ -6,7 +6,7 @@ use ratatui::widgets::*;
 use crate::tui::styles::Theme;
 
 const DIALOG_WIDTH: u16 = 50;
-const DIALOG_HEIGHT: u16 = 31;
+const DIALOG_HEIGHT: u16 = 35;
 #[cfg(test)]
 const BORDER_HEIGHT: u16 = 2;
 #[cfg(test)]
@@ -24,6 +24,7 @@ fn shortcuts() -> Vec<(&'static str, Vec<(&'static str, &'static str)>)> {
                 ("h/←", "Collapse group"),
                 ("l/→", "Expand group"),
                 ("g/G", "Go to top / bottom"),
+                ("1-9", "Jump to Nth session"),
                 ("PgUp/Dn", "Move 10 items up / down"),
             ],
         ),
@@ -35,6 +36,8 @@ fn shortcuts() -> Vec<(&'static str, Vec<(&'static str, &'static str)>)> {
                 ("x", "Stop session"),
                 ("d", "Delete session/group"),
                 ("r", "Rename session"),
+                ("R", "Refresh status now"),
+                ("o", "Open working dir in editor"),
             ],
         ),
         (
@@ -44,6 +47,7 @@ fn shortcuts() -> Vec<(&'static str, Vec<(&'static str, &'static str)>)> {
                 ("c", "Toggle container/host (sandbox)"),
                 ("D", "Diff view (git changes)"),
                 ("H/L", "Resize list panel"),
+                ("w", "Toggle preview wrap (←/→ scroll)"),
             ],
         ),
         (
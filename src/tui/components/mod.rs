@@ -1,5 +1,6 @@
 //! TUI components
 
+mod ansi;
 mod dir_picker;
 mod help;
 mod list_picker;
@@ -9,5 +10,5 @@ mod text_input;
 pub use dir_picker::{DirPicker, DirPickerResult};
 pub use help::HelpOverlay;
 pub use list_picker::{ListPicker, ListPickerResult};
-pub use preview::Preview;
+pub use preview::{max_hscroll, LineMode, OutputLayout, Preview};
 pub use text_input::render_text_field;
@@ -2,10 +2,29 @@
 
 use ratatui::prelude::*;
 use ratatui::widgets::*;
+use unicode_width::UnicodeWidthChar;
 
+use super::ansi::parse_ansi;
 use crate::session::Instance;
 use crate::tui::styles::Theme;
 
+/// How output lines wider than the preview panel are displayed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LineMode {
+    /// Cut lines at the panel edge; Left/Right scroll horizontally
+    #[default]
+    Truncate,
+    /// Continue long lines on the following rows
+    Wrap,
+}
+
+/// Line mode plus horizontal scroll offset (in columns) for the output section
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct OutputLayout {
+    pub mode: LineMode,
+    pub hscroll: usize,
+}
+
 pub struct Preview;
 
 impl Preview {
@@ -15,6 +34,7 @@ impl Preview {
         instance: &Instance,
         terminal_running: bool,
         cached_output: &str,
+        layout: OutputLayout,
         theme: &Theme,
     ) {
         let info_height = if instance.sandbox_info.as_ref().is_some_and(|s| s.enabled) {
@@ -91,25 +111,7 @@ impl Preview {
                 .alignment(Alignment::Center);
             frame.render_widget(hint, inner);
         } else {
-            let output_lines: Vec<Line> = cached_output
-                .lines()
-                .map(|line| Line::from(Span::raw(line)))
-                .collect();
-
-            let line_count = output_lines.len();
-            let visible_height = inner.height as usize;
-
-            let scroll_offset = if line_count > visible_height {
-                (line_count - visible_height) as u16
-            } else {
-                0
-            };
-
-            let paragraph = Paragraph::new(output_lines)
-                .style(Style::default().fg(theme.text))
-                .scroll((scroll_offset, 0));
-
-            frame.render_widget(paragraph, inner);
+            Self::render_output_lines(frame, inner, cached_output, layout, theme);
         }
     }
 
@@ -118,6 +120,7 @@ impl Preview {
         area: Rect,
         instance: &Instance,
         cached_output: &str,
+        layout: OutputLayout,
         theme: &Theme,
     ) {
         // Adjust height based on whether worktree info is present
@@ -125,18 +128,30 @@ impl Preview {
             10 // Expanded to show worktree details
         } else {
             6 // Standard height
-        };
+        } + u16::from(instance.status_note.is_some())
+            + u16::from(instance.pane_statuses.len() > 1);
 
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
+                Constraint::Length(1),           // Header
                 Constraint::Length(info_height), // Info section
                 Constraint::Min(1),              // Output section
             ])
             .split(area);
 
-        Self::render_info(frame, chunks[0], instance, theme);
-        Self::render_output_cached(frame, chunks[1], instance, cached_output, theme);
+        Self::render_preview_header(frame, chunks[0], instance, theme);
+        Self::render_info(frame, chunks[1], instance, theme);
+        Self::render_output_cached(frame, chunks[2], instance, cached_output, layout, theme);
+    }
+
+    /// One-line summary of the session: tool, status, branch and age.
+    fn render_preview_header(frame: &mut Frame, area: Rect, instance: &Instance, theme: &Theme) {
+        let branch = crate::git::current_branch(std::path::Path::new(&instance.project_path))
+            .or_else(|| instance.worktree_info.as_ref().map(|wt| wt.branch.clone()));
+        let header = preview_header(instance, branch.as_deref(), chrono::Utc::now());
+        let paragraph = Paragraph::new(header).style(instance.status.style(theme).bold());
+        frame.render_widget(paragraph, area);
     }
 
     fn render_info(frame: &mut Frame, area: Rect, instance: &Instance, theme: &Theme) {
@@ -159,17 +174,10 @@ impl Preview {
             Line::from(vec![
                 Span::styled("Status:  ", Style::default().fg(theme.dimmed)),
                 Span::styled(
-                    format!("{:?}", instance.status),
-                    Style::default().fg(match instance.status {
-                        crate::session::Status::Running => theme.running,
-                        crate::session::Status::Waiting => theme.waiting,
-                        crate::session::Status::Idle => theme.idle,
-                        crate::session::Status::Stopped => theme.dimmed,
-                        crate::session::Status::Error => theme.error,
-                        crate::session::Status::Starting => theme.dimmed,
-                        crate::session::Status::Deleting => theme.waiting,
-                    }),
+                    format!("{} {:?}", instance.status.icon(), instance.status),
+                    instance.status.style(theme),
                 ),
+                Span::styled(idle_suffix(instance), Style::default().fg(theme.dimmed)),
             ]),
             Line::from(vec![
                 Span::styled("Group:   ", Style::default().fg(theme.dimmed)),
@@ -184,6 +192,27 @@ impl Preview {
             ]),
         ];
 
+        if let Some(note) = &instance.status_note {
+            info_lines.push(Line::from(vec![
+                Span::styled("Note:    ", Style::default().fg(theme.dimmed)),
+                Span::styled(note.as_str(), Style::default().fg(theme.waiting)),
+            ]));
+        }
+
+        if instance.pane_statuses.len() > 1 {
+            let mut spans = vec![Span::styled("Panes:   ", Style::default().fg(theme.dimmed))];
+            for (i, pane) in instance.pane_statuses.iter().enumerate() {
+                if i > 0 {
+                    spans.push(Span::styled("  ", Style::default().fg(theme.dimmed)));
+                }
+                spans.push(Span::styled(
+                    format!("{} {} {}", pane.status.icon(), pane.index, pane.command),
+                    pane.status.style(theme),
+                ));
+            }
+            info_lines.push(Line::from(spans));
+        }
+
         // Add worktree information if present
         if let Some(wt_info) = &instance.worktree_info {
             info_lines.push(Line::from(""));
@@ -231,6 +260,7 @@ impl Preview {
         area: Rect,
         instance: &Instance,
         cached_output: &str,
+        layout: OutputLayout,
         theme: &Theme,
     ) {
         let block = Block::default()
@@ -265,27 +295,155 @@ impl Preview {
                 .alignment(Alignment::Center);
             frame.render_widget(hint, inner);
         } else {
-            let output_lines: Vec<Line> = cached_output
-                .lines()
-                .map(|line| Line::from(Span::raw(line)))
-                .collect();
+            Self::render_output_lines(frame, inner, cached_output, layout, theme);
+        }
+    }
 
-            let line_count = output_lines.len();
-            let visible_height = inner.height as usize;
+    fn render_output_lines(
+        frame: &mut Frame,
+        area: Rect,
+        content: &str,
+        layout: OutputLayout,
+        theme: &Theme,
+    ) {
+        let rows = layout_output(content, area.width as usize, area.height as usize, layout);
+        let paragraph = Paragraph::new(rows).style(Style::default().fg(theme.text));
+        frame.render_widget(paragraph, area);
+    }
+}
 
-            // Scroll to show the bottom of the content
-            let scroll_offset = if line_count > visible_height {
-                (line_count - visible_height) as u16
-            } else {
-                0
-            };
+/// A single character of output with the style it is drawn in
+type Cell = (char, Style);
+
+/// Lay out output for a `width` x `height` panel, keeping the bottom of the
+/// content visible. ANSI colors in `content` become styled spans. Wrap mode
+/// splits long lines across rows; truncate mode skips `hscroll` columns and
+/// cuts each line at the panel edge.
+pub fn layout_output(
+    content: &str,
+    width: usize,
+    height: usize,
+    layout: OutputLayout,
+) -> Vec<Line<'static>> {
+    if width == 0 || height == 0 {
+        return Vec::new();
+    }
 
-            let paragraph = Paragraph::new(output_lines)
-                .style(Style::default().fg(theme.text))
-                .scroll((scroll_offset, 0));
+    let lines = parse_ansi(content);
+    let rows: Vec<Line<'static>> = match layout.mode {
+        LineMode::Wrap => lines
+            .iter()
+            .flat_map(|line| wrap_cells(&line_cells(line), width))
+            .map(|cells| cells_to_line(&cells))
+            .collect(),
+        LineMode::Truncate => lines
+            .iter()
+            .map(|line| cells_to_line(&slice_cells(&line_cells(line), layout.hscroll, width)))
+            .collect(),
+    };
+
+    let skip = rows.len().saturating_sub(height);
+    rows.into_iter().skip(skip).collect()
+}
 
-            frame.render_widget(paragraph, inner);
+/// Largest useful horizontal offset: past it, every line would be blank.
+pub fn max_hscroll(content: &str, width: usize) -> usize {
+    parse_ansi(content)
+        .iter()
+        .map(Line::width)
+        .max()
+        .unwrap_or(0)
+        .saturating_sub(width)
+}
+
+fn line_cells(line: &Line) -> Vec<Cell> {
+    line.spans
+        .iter()
+        .flat_map(|span| span.content.chars().map(move |c| (c, span.style)))
+        .collect()
+}
+
+fn cells_to_line(cells: &[Cell]) -> Line<'static> {
+    let mut spans: Vec<Span<'static>> = Vec::new();
+    for &(c, style) in cells {
+        match spans.last_mut() {
+            Some(last) if last.style == style => last.content.to_mut().push(c),
+            _ => spans.push(Span::styled(c.to_string(), style)),
+        }
+    }
+    Line::from(spans)
+}
+
+fn wrap_cells(cells: &[Cell], width: usize) -> Vec<Vec<Cell>> {
+    let mut rows = Vec::new();
+    let mut current = Vec::new();
+    let mut current_width = 0;
+    for &cell in cells {
+        let w = cell.0.width().unwrap_or(0);
+        if current_width + w > width && !current.is_empty() {
+            rows.push(std::mem::take(&mut current));
+            current_width = 0;
         }
+        current.push(cell);
+        current_width += w;
+    }
+    rows.push(current);
+    rows
+}
+
+fn slice_cells(cells: &[Cell], start: usize, width: usize) -> Vec<Cell> {
+    let mut out = Vec::new();
+    let mut col = 0;
+    let mut out_width = 0;
+    for &cell in cells {
+        let w = cell.0.width().unwrap_or(0);
+        if col < start {
+            col += w;
+            continue;
+        }
+        if out_width + w > width {
+            break;
+        }
+        out.push(cell);
+        out_width += w;
+    }
+    out
+}
+
+fn preview_header(
+    instance: &Instance,
+    branch: Option<&str>,
+    now: chrono::DateTime<chrono::Utc>,
+) -> String {
+    let mut parts = vec![
+        instance.tool.clone(),
+        format!("{} {:?}", instance.status.icon(), instance.status),
+    ];
+    parts.extend(branch.map(str::to_string));
+    let age = (now - instance.created_at).num_seconds();
+    parts.push(format!("{} old", format_idle_duration(age)));
+    parts.join(" · ")
+}
+
+fn idle_suffix(instance: &Instance) -> String {
+    use crate::session::Status;
+
+    if !matches!(instance.status, Status::Idle | Status::Waiting) {
+        return String::new();
+    }
+    instance
+        .idle_duration(chrono::Utc::now())
+        .map(|d| format!("  idle for {}", format_idle_duration(d.num_seconds())))
+        .unwrap_or_default()
+}
+
+/// Compact elapsed time such as `45s`, `5m`, `2h` or `3d`.
+fn format_idle_duration(secs: i64) -> String {
+    match secs.max(0) {
+        s if s < 60 => format!("{}s", s),
+        s if s < 3600 => format!("{}m", s / 60),
+        s if s < 86400 => format!("{}h", s / 3600),
+        s => format!("{}d", s / 86400),
     }
 }
 
@@ -318,6 +476,113 @@ fn shorten_path(path: &str) -> String {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_preview_header() {
+        let mut inst = Instance::new("demo", "/tmp/demo");
+        inst.tool = "codex".to_string();
+        inst.status = crate::session::Status::Waiting;
+        let now = inst.created_at + chrono::Duration::hours(3);
+
+        assert_eq!(
+            preview_header(&inst, Some("feature/x"), now),
+            "codex · ◐ Waiting · feature/x · 3h old"
+        );
+        assert_eq!(
+            preview_header(&inst, None, now),
+            "codex · ◐ Waiting · 3h old"
+        );
+    }
+
+    #[test]
+    fn test_format_idle_duration() {
+        assert_eq!(format_idle_duration(0), "0s");
+        assert_eq!(format_idle_duration(45), "45s");
+        assert_eq!(format_idle_duration(300), "5m");
+        assert_eq!(format_idle_duration(7200), "2h");
+        assert_eq!(format_idle_duration(3 * 86400 + 5), "3d");
+        assert_eq!(format_idle_duration(-5), "0s");
+    }
+
+    fn layout_lines(
+        content: &str,
+        width: usize,
+        height: usize,
+        layout: OutputLayout,
+    ) -> Vec<String> {
+        layout_output(content, width, height, layout)
+            .iter()
+            .map(|line| line.to_string())
+            .collect()
+    }
+
+    const LONG_LINE: &str = "abcdefghijklmnopqrstuvwxyz";
+
+    #[test]
+    fn test_layout_truncate_cuts_long_line() {
+        let rows = layout_lines(LONG_LINE, 10, 5, OutputLayout::default());
+        assert_eq!(rows, vec!["abcdefghij"]);
+    }
+
+    #[test]
+    fn test_layout_truncate_scrolls_horizontally() {
+        let layout = OutputLayout {
+            mode: LineMode::Truncate,
+            hscroll: 20,
+        };
+        let rows = layout_lines(LONG_LINE, 10, 5, layout);
+        assert_eq!(rows, vec!["uvwxyz"]);
+        assert_eq!(max_hscroll(LONG_LINE, 10), 16);
+    }
+
+    #[test]
+    fn test_layout_wrap_splits_long_line() {
+        let layout = OutputLayout {
+            mode: LineMode::Wrap,
+            hscroll: 20,
+        };
+        let rows = layout_lines(LONG_LINE, 10, 5, layout);
+        assert_eq!(rows, vec!["abcdefghij", "klmnopqrst", "uvwxyz"]);
+    }
+
+    #[test]
+    fn test_layout_wrap_keeps_bottom_rows() {
+        let layout = OutputLayout {
+            mode: LineMode::Wrap,
+            hscroll: 0,
+        };
+        let content = format!("first\n{}", LONG_LINE);
+        let rows = layout_lines(&content, 10, 2, layout);
+        assert_eq!(rows, vec!["klmnopqrst", "uvwxyz"]);
+    }
+
+    #[test]
+    fn test_layout_wrap_handles_wide_chars() {
+        let layout = OutputLayout {
+            mode: LineMode::Wrap,
+            hscroll: 0,
+        };
+        let rows = layout_lines("日本語テキスト", 5, 10, layout);
+        assert_eq!(rows, vec!["日本", "語テ", "キス", "ト"]);
+    }
+
+    #[test]
+    fn test_layout_keeps_ansi_styles_across_wrap() {
+        let layout = OutputLayout {
+            mode: LineMode::Wrap,
+            hscroll: 0,
+        };
+        let rows = layout_output("ab\x1b[32mcdef\x1b[0m", 3, 5, layout);
+        let green = Style::default().fg(Color::Green);
+        assert_eq!(
+            rows,
+            vec![
+                Line::from(vec![Span::raw("ab"), Span::styled("c", green)]),
+                Line::from(vec![Span::styled("def", green)]),
+            ]
+        );
+        assert_eq!(max_hscroll("\x1b[32mcdef\x1b[0m", 2), 2);
+    }
+
     #[test]
     fn test_shorten_path_with_home() {
         if let Some(home) = dirs::home_dir() {
@@ -17,6 +17,8 @@ pub struct CreationRequest {
     pub existing_instances: Vec<Instance>,
     /// Trusted hooks to execute after instance creation (already approved by user).
     pub hooks: Option<HooksConfig>,
+    /// Profile the session is created in.
+    pub profile: String,
 }
 
 #[derive(Debug)]
@@ -108,6 +110,7 @@ impl CreationPoller {
             yolo_mode: data.yolo_mode,
             extra_env_keys: data.extra_env_keys,
             extra_env_values: data.extra_env_values,
+            profile: request.profile,
         };
 
         let build_result = match builder::build_instance(params, &existing_titles) {
@@ -129,7 +132,7 @@ impl CreationPoller {
                 // Ensure the container is running so we can exec hooks inside it.
                 // Don't create the tmux session yet -- that happens at attach time
                 // where the terminal size is available.
-                if let Err(e) = instance.get_container_for_instance() {
+                if let Err(e) = instance.get_container_reporting(progress_tx) {
                     builder::cleanup_instance(&instance, created_worktree.as_ref());
                     return CreationResult::Error(format!("{:#}", e));
                 }
@@ -162,7 +165,7 @@ impl CreationPoller {
             let hooks = hooks.as_ref().unwrap();
             if data.sandbox {
                 if !container_started {
-                    if let Err(e) = instance.get_container_for_instance() {
+                    if let Err(e) = instance.get_container_reporting(progress_tx) {
                         let msg = format!("Container startup warning: {:#}", e);
                         tracing::warn!("{}", msg);
                         let _ = progress_tx.send(HookProgress::Output(msg));
@@ -196,7 +199,7 @@ impl CreationPoller {
             // Only ensure the container is running here if hooks didn't already
             // start it. Don't create the tmux session yet -- that happens at attach time
             // where the terminal size is available.
-            if let Err(e) = instance.get_container_for_instance() {
+            if let Err(e) = instance.get_container_reporting(progress_tx) {
                 builder::cleanup_instance(&instance, created_worktree.as_ref());
                 return CreationResult::Error(format!("{:#}", e));
             }
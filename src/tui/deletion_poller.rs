@@ -4,9 +4,8 @@ use std::path::PathBuf;
 use std::sync::mpsc;
 use std::thread;
 
-use crate::containers::DockerContainer;
 use crate::git::GitWorktree;
-use crate::session::Instance;
+use crate::session::{teardown_session, Instance, SystemTeardown, TeardownMode};
 
 pub struct DeletionRequest {
     pub session_id: String,
@@ -105,25 +104,15 @@ impl DeletionPoller {
             }
         }
 
-        // Container cleanup (if user opted to delete it)
-        if request.delete_sandbox {
-            if let Some(sandbox) = &request.instance.sandbox_info {
-                if sandbox.enabled {
-                    let container = DockerContainer::from_session_id(&request.instance.id);
-                    if container.exists().unwrap_or(false) {
-                        if let Err(e) = container.remove(true) {
-                            errors.push(format!("Container: {}", e));
-                        }
-                    }
-                }
-            }
-        }
-
-        // Tmux kill - non-fatal if session already gone
-        let _ = request.instance.kill();
-
-        // Kill paired terminal session if it exists
-        let _ = request.instance.kill_terminal();
+        // Kill tmux (agent and paired terminals) before removing the container
+        // so panes exec'd into it exit first. Missing sessions are not errors.
+        let mode = if request.delete_sandbox {
+            TeardownMode::Remove
+        } else {
+            TeardownMode::Stop
+        };
+        let report = teardown_session(&request.instance, mode, &mut SystemTeardown);
+        errors.extend(report.warnings);
 
         DeletionResult {
             session_id: request.session_id.clone(),
@@ -24,6 +24,13 @@ impl ConfirmDialog {
         }
     }
 
+    /// Start with Yes (`true`) or No selected, so Enter confirms or cancels.
+    /// Destructive actions should keep the default of No.
+    pub fn with_default(mut self, yes: bool) -> Self {
+        self.selected = yes;
+        self
+    }
+
     pub fn action(&self) -> &str {
         &self.action
     }
@@ -122,6 +129,23 @@ mod tests {
         assert!(!dialog.selected);
     }
 
+    #[test]
+    fn test_with_default_yes_submits_on_enter() {
+        let mut dialog = ConfirmDialog::new("Test", "Reload?", "reload").with_default(true);
+        assert!(dialog.selected);
+        assert!(matches!(
+            dialog.handle_key(key(KeyCode::Enter)),
+            DialogResult::Submit(())
+        ));
+
+        let mut dialog = ConfirmDialog::new("Test", "Delete?", "delete").with_default(false);
+        assert!(!dialog.selected);
+        assert!(matches!(
+            dialog.handle_key(key(KeyCode::Enter)),
+            DialogResult::Cancel
+        ));
+    }
+
     #[test]
     fn test_action_accessor() {
         let dialog = ConfirmDialog::new("Title", "Message", "delete");
@@ -18,7 +18,7 @@ pub use delete_options::{DeleteDialogConfig, DeleteOptions, UnifiedDeleteDialog}
 pub use group_delete_options::{GroupDeleteOptions, GroupDeleteOptionsDialog};
 pub use hook_trust::{HookTrustAction, HookTrustDialog};
 pub use info::InfoDialog;
-pub use new_session::{NewSessionData, NewSessionDialog};
+pub use new_session::{LastUsed, NewSessionData, NewSessionDialog};
 pub use rename::{RenameData, RenameDialog};
 pub use welcome::WelcomeDialog;
 
@@ -28,6 +28,49 @@ pub enum DialogResult<T> {
     Submit(T),
 }
 
+/// Smallest area any dialog is drawn in; below this a notice is shown instead
+pub const MIN_DIALOG_WIDTH: u16 = 40;
+pub const MIN_DIALOG_HEIGHT: u16 = 7;
+
+/// Whether a dialog of the given size fits within an area.
+pub fn fits(area: ratatui::layout::Rect, width: u16, height: u16) -> bool {
+    area.width >= width && area.height >= height
+}
+
+/// Draw a "terminal too small" notice in place of a dialog.
+pub fn render_too_small(
+    frame: &mut ratatui::Frame,
+    area: ratatui::layout::Rect,
+    theme: &crate::tui::styles::Theme,
+) {
+    use ratatui::prelude::*;
+    use ratatui::widgets::{Clear, Paragraph, Wrap};
+
+    let lines = vec![
+        Line::from(Span::styled(
+            "Terminal too small",
+            Style::default().fg(theme.error).bold(),
+        )),
+        Line::from(Span::styled(
+            "Enlarge the window",
+            Style::default().fg(theme.dimmed),
+        )),
+    ];
+    let height = (lines.len() as u16).min(area.height);
+    let notice = Rect {
+        y: area.y + area.height.saturating_sub(height) / 2,
+        height,
+        ..area
+    };
+    frame.render_widget(Clear, area);
+    frame.render_widget(
+        Paragraph::new(lines)
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true }),
+        notice,
+    );
+}
+
 /// Center a dialog of given size within an area, clamping to fit.
 pub fn centered_rect(
     area: ratatui::layout::Rect,
@@ -43,3 +86,91 @@ pub fn centered_rect(
         height: height.min(area.height),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tui::styles::load_theme;
+    use ratatui::backend::TestBackend;
+    use ratatui::layout::Rect;
+    use ratatui::Terminal;
+
+    fn draw(width: u16, height: u16, render: impl FnOnce(&mut ratatui::Frame)) -> String {
+        let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+        terminal.draw(render).unwrap();
+        terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect()
+    }
+
+    #[test]
+    fn test_centered_rect_clamps_to_area() {
+        let area = Rect::new(0, 0, 20, 5);
+        assert_eq!(centered_rect(area, 80, 14), area);
+        assert_eq!(centered_rect(area, 10, 3), Rect::new(5, 1, 10, 3));
+    }
+
+    #[test]
+    fn test_dialogs_render_in_tiny_area() {
+        let theme = load_theme("phosphor");
+        draw(20, 5, |f| {
+            NewSessionDialog::new_with_tools(vec!["claude", "opencode"], "/tmp".to_string()).render(
+                f,
+                f.area(),
+                &theme,
+            )
+        });
+        draw(20, 5, |f| {
+            ConfirmDialog::new("Delete", "Really delete?", "delete").render(f, f.area(), &theme)
+        });
+        draw(20, 5, |f| {
+            InfoDialog::new("Info", "Hello").render(f, f.area(), &theme)
+        });
+        draw(20, 5, |f| {
+            WelcomeDialog::new().render(f, f.area(), &theme);
+        });
+        draw(20, 5, |f| {
+            ChangelogDialog::new(None).render(f, f.area(), &theme);
+        });
+    }
+
+    #[test]
+    fn test_new_session_dialog_shows_too_small_notice() {
+        let theme = load_theme("phosphor");
+        let screen = draw(20, 5, |f| {
+            NewSessionDialog::new_with_tools(vec!["claude"], "/tmp".to_string()).render(
+                f,
+                f.area(),
+                &theme,
+            )
+        });
+        assert!(screen.contains("Terminal too small"));
+
+        let screen = draw(100, 40, |f| {
+            NewSessionDialog::new_with_tools(vec!["claude"], "/tmp".to_string()).render(
+                f,
+                f.area(),
+                &theme,
+            )
+        });
+        assert!(!screen.contains("Terminal too small"));
+        assert!(screen.contains("New Session"));
+    }
+
+    #[test]
+    fn test_new_session_dialog_with_sandbox_fits_80x24() {
+        let theme = load_theme("phosphor");
+        let mut dialog = NewSessionDialog::new_with_tools(vec!["claude"], "/tmp".to_string());
+        dialog.docker_available = true;
+        dialog.sandbox_enabled = true;
+        let screen = draw(80, 24, |f| dialog.render(f, f.area(), &theme));
+        assert!(!screen.contains("Terminal too small"));
+        assert!(screen.contains("New Session"));
+        assert!(screen.contains("Title:"));
+        assert!(screen.contains("Group:"));
+    }
+}
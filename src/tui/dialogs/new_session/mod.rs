@@ -7,6 +7,7 @@ mod render;
 mod tests;
 
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use std::collections::HashMap;
 use std::time::Instant;
 use tui_input::backend::crossterm::EventHandler;
 use tui_input::Input;
@@ -17,7 +18,7 @@ use crate::session::config::{DefaultTerminalMode, SandboxConfig};
 use crate::session::repo_config::HookProgress;
 #[cfg(test)]
 use crate::session::Config;
-use crate::session::{civilizations, resolve_config};
+use crate::session::{civilizations, normalize_group_path, resolve_config};
 use crate::tmux::AvailableTools;
 use crate::tui::components::{DirPicker, DirPickerResult, ListPicker, ListPickerResult};
 use path_input::PathGhostCompletion;
@@ -78,7 +79,7 @@ pub(super) const FIELD_HELP: &[FieldHelp] = &[
     },
     FieldHelp {
         name: "Group",
-        description: "Optional grouping for organization (Ctrl+P to browse existing groups)",
+        description: "Optional group; use / for nesting, e.g. work/backend (Ctrl+P to browse)",
     },
 ];
 
@@ -100,6 +101,14 @@ pub struct NewSessionData {
     pub extra_env_values: Vec<String>,
 }
 
+/// Group and tool of the last session created from the dialog, used to
+/// pre-fill the next one for the rest of the app run.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LastUsed {
+    pub group: String,
+    pub tool: String,
+}
+
 /// Spinner frames for loading animation
 pub(super) const SPINNER_FRAMES: &[&str] = &["◐", "◓", "◑", "◒"];
 const PATH_FIELD: usize = 1;
@@ -117,6 +126,10 @@ pub struct NewSessionDialog {
     pub(super) create_new_branch: bool,
     pub(super) sandbox_enabled: bool,
     pub(super) sandbox_image: Input,
+    /// Image used when the selected tool has no `sandbox.tool_images` entry
+    pub(super) default_image: String,
+    /// `sandbox.tool_images`: default image per tool
+    pub(super) tool_images: HashMap<String, String>,
     pub(super) docker_available: bool,
     pub(super) yolo_mode: bool,
     /// Extra environment variable keys (session-specific)
@@ -155,6 +168,8 @@ pub struct NewSessionDialog {
     pub(super) has_hooks: bool,
     /// The currently running hook command
     pub(super) current_hook: Option<String>,
+    /// The current pull/build phase reported by the running hook
+    pub(super) current_phase: Option<String>,
     /// Accumulated output lines from hook execution
     pub(super) hook_output: Vec<String>,
     /// Temporary highlight state for invalid path input.
@@ -302,6 +317,14 @@ impl NewSessionDialog {
             0
         };
 
+        let default_image = containers::get_container_runtime().effective_default_image();
+        let tool_images = config.sandbox.tool_images.clone();
+        let sandbox_image = available_tools
+            .get(tool_index)
+            .and_then(|tool| tool_images.get(*tool))
+            .cloned()
+            .unwrap_or_else(|| default_image.clone());
+
         // Apply sandbox defaults from config
         let sandbox_enabled = docker_available && config.sandbox.enabled_by_default;
         let yolo_mode = config.session.yolo_mode_default;
@@ -336,9 +359,9 @@ impl NewSessionDialog {
             worktree_branch: Input::default(),
             create_new_branch: true,
             sandbox_enabled,
-            sandbox_image: Input::new(
-                containers::get_container_runtime().effective_default_image(),
-            ),
+            sandbox_image: Input::new(sandbox_image),
+            default_image,
+            tool_images,
             docker_available,
             yolo_mode,
             extra_env_keys,
@@ -360,6 +383,7 @@ impl NewSessionDialog {
             needs_image_pull: false,
             has_hooks: false,
             current_hook: None,
+            current_phase: None,
             hook_output: Vec::new(),
             path_invalid_flash_until: None,
             path_ghost: None,
@@ -376,6 +400,10 @@ impl NewSessionDialog {
         match progress {
             HookProgress::Started(cmd) => {
                 self.current_hook = Some(cmd);
+                self.current_phase = None;
+            }
+            HookProgress::Phase(phase) => {
+                self.current_phase = Some(phase);
             }
             HookProgress::Output(line) => {
                 self.hook_output.push(line);
@@ -452,6 +480,8 @@ impl NewSessionDialog {
             sandbox_image: Input::new(
                 containers::get_container_runtime().effective_default_image(),
             ),
+            default_image: containers::get_container_runtime().effective_default_image(),
+            tool_images: config.sandbox.tool_images.clone(),
             docker_available: false,
             yolo_mode: false,
             extra_env_keys: Vec::new(),
@@ -473,6 +503,7 @@ impl NewSessionDialog {
             needs_image_pull: false,
             has_hooks: false,
             current_hook: None,
+            current_phase: None,
             hook_output: Vec::new(),
             path_invalid_flash_until: None,
             path_ghost: None,
@@ -500,6 +531,8 @@ impl NewSessionDialog {
             sandbox_image: Input::new(
                 containers::get_container_runtime().effective_default_image(),
             ),
+            default_image: containers::get_container_runtime().effective_default_image(),
+            tool_images: HashMap::new(),
             docker_available: false,
             yolo_mode: false,
             extra_env_keys: Vec::new(),
@@ -521,12 +554,45 @@ impl NewSessionDialog {
             needs_image_pull: false,
             has_hooks: false,
             current_hook: None,
+            current_phase: None,
             hook_output: Vec::new(),
             path_invalid_flash_until: None,
             path_ghost: None,
         }
     }
 
+    /// Pre-fill the group and tool from the previous session. A tool that is
+    /// no longer available keeps the configured default.
+    pub fn with_last_used(mut self, last: &LastUsed) -> Self {
+        self.group = Input::new(last.group.clone());
+        if let Some(index) = self
+            .available_tools
+            .iter()
+            .position(|&t| t == last.tool.as_str())
+        {
+            self.select_tool(index);
+        }
+        self
+    }
+
+    fn image_for_tool(&self, index: usize) -> String {
+        self.available_tools
+            .get(index)
+            .and_then(|tool| self.tool_images.get(*tool))
+            .cloned()
+            .unwrap_or_else(|| self.default_image.clone())
+    }
+
+    /// Switch the selected tool. An image the user has not edited follows
+    /// the tool's `sandbox.tool_images` default.
+    pub(super) fn select_tool(&mut self, index: usize) {
+        let untouched = self.sandbox_image.value().trim() == self.image_for_tool(self.tool_index);
+        self.tool_index = index;
+        if untouched {
+            self.sandbox_image = Input::new(self.image_for_tool(index));
+        }
+    }
+
     pub fn set_error(&mut self, error: String) {
         self.error_message = Some(error);
     }
@@ -705,7 +771,7 @@ impl NewSessionDialog {
                 DialogResult::Submit(NewSessionData {
                     title: final_title,
                     path: self.path.value().trim().to_string(),
-                    group: self.group.value().trim().to_string(),
+                    group: normalize_group_path(self.group.value()),
                     tool: self.available_tools[self.tool_index].to_string(),
                     worktree_branch,
                     create_new_branch: self.create_new_branch,
@@ -748,12 +814,13 @@ impl NewSessionDialog {
                 }
                 DialogResult::Continue
             }
-            KeyCode::Left | KeyCode::Right if self.focused_field == tool_field => {
-                self.tool_index = (self.tool_index + 1) % self.available_tools.len();
+            KeyCode::Left if self.focused_field == tool_field => {
+                let count = self.available_tools.len();
+                self.select_tool((self.tool_index + count - 1) % count);
                 DialogResult::Continue
             }
-            KeyCode::Char(' ') if self.focused_field == tool_field => {
-                self.tool_index = (self.tool_index + 1) % self.available_tools.len();
+            KeyCode::Right | KeyCode::Char(' ') if self.focused_field == tool_field => {
+                self.select_tool((self.tool_index + 1) % self.available_tools.len());
                 DialogResult::Continue
             }
             KeyCode::Left | KeyCode::Right | KeyCode::Char(' ')
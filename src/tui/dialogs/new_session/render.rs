@@ -7,6 +7,11 @@ use super::{NewSessionDialog, FIELD_HELP, HELP_DIALOG_WIDTH, PATH_FIELD, SPINNER
 use crate::tui::components::render_text_field;
 use crate::tui::styles::Theme;
 
+/// Height of the fields that are always shown (title, path, tool, YOLO,
+/// worktree, group and hints) plus border and margin. Below this the dialog
+/// is unusable; above it, taller optional sections are squeezed to fit.
+const MIN_HEIGHT: u16 = 6 * 2 + 1 + 4;
+
 impl NewSessionDialog {
     pub fn render(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
         // If loading, render the loading overlay instead
@@ -94,6 +99,10 @@ impl NewSessionDialog {
             })
             .sum();
         let dialog_height = fields_height + 4; // +2 border, +2 margin
+        if !crate::tui::dialogs::fits(area, crate::tui::dialogs::MIN_DIALOG_WIDTH, MIN_HEIGHT) {
+            crate::tui::dialogs::render_too_small(frame, area, theme);
+            return;
+        }
 
         let dialog_area = crate::tui::dialogs::centered_rect(area, dialog_width, dialog_height);
 
@@ -916,10 +925,18 @@ impl NewSessionDialog {
                 Span::styled(status_text, Style::default().fg(theme.text)),
             ]));
 
+            let inner_width = (dialog_width as usize).saturating_sub(6);
+            if let Some(ref phase) = self.current_phase {
+                let phase: String = phase.chars().take(inner_width).collect();
+                lines.push(Line::from(Span::styled(
+                    format!("   {}", phase),
+                    Style::default().fg(theme.accent),
+                )));
+            }
+
             // Show last N output lines
             let output_start = self.hook_output.len().saturating_sub(max_output_lines);
             let visible_lines = &self.hook_output[output_start..];
-            let inner_width = (dialog_width as usize).saturating_sub(6);
 
             for line in visible_lines {
                 let truncated = if line.len() > inner_width {
@@ -935,7 +952,7 @@ impl NewSessionDialog {
             }
 
             // Pad remaining lines so cancel hint stays at bottom
-            let used = 1 + visible_lines.len(); // status + output
+            let used = 1 + usize::from(self.current_phase.is_some()) + visible_lines.len();
             let available = dialog_height.saturating_sub(4) as usize; // borders + cancel line
             for _ in used..available {
                 lines.push(Line::from(""));
@@ -80,6 +80,19 @@ fn test_enter_preserves_custom_title() {
     }
 }
 
+#[test]
+fn test_enter_normalizes_nested_group_path() {
+    let mut dialog = single_tool_dialog();
+    dialog.group = Input::new("/work//backend/api/".to_string());
+    let result = dialog.handle_key(key(KeyCode::Enter));
+    match result {
+        DialogResult::Submit(data) => {
+            assert_eq!(data.group, "work/backend/api");
+        }
+        _ => panic!("Expected Submit"),
+    }
+}
+
 #[test]
 fn test_tab_cycles_fields_single_tool() {
     let mut dialog = single_tool_dialog();
@@ -499,6 +512,46 @@ fn test_submit_with_selected_tool() {
     }
 }
 
+#[test]
+fn test_submit_with_crush_selected() {
+    let mut dialog =
+        NewSessionDialog::new_with_tools(vec!["claude", "codex", "crush"], TEST_PATH.to_string());
+    dialog.focused_field = 2; // tool field
+    dialog.handle_key(key(KeyCode::Left));
+    dialog.title = Input::new("Test".to_string());
+
+    match dialog.handle_key(key(KeyCode::Enter)) {
+        DialogResult::Submit(data) => assert_eq!(data.tool, "crush"),
+        _ => panic!("Expected Submit"),
+    }
+}
+
+#[test]
+fn test_last_used_seeds_group_and_tool() {
+    let last = LastUsed {
+        group: "work/backend".to_string(),
+        tool: "opencode".to_string(),
+    };
+    let dialog = multi_tool_dialog().with_last_used(&last);
+
+    assert_eq!(dialog.group.value(), "work/backend");
+    assert_eq!(dialog.tool_index, 1);
+    assert_eq!(dialog.path.value(), TEST_PATH);
+    assert_eq!(dialog.title.value(), "");
+}
+
+#[test]
+fn test_last_used_unavailable_tool_keeps_default() {
+    let last = LastUsed {
+        group: String::new(),
+        tool: "codex".to_string(),
+    };
+    let dialog = multi_tool_dialog().with_last_used(&last);
+
+    assert_eq!(dialog.tool_index, 0);
+    assert_eq!(dialog.group.value(), "");
+}
+
 #[test]
 fn test_unknown_key_continues() {
     let mut dialog = single_tool_dialog();
@@ -827,6 +880,30 @@ fn help_content_fits_in_dialog() {
     }
 }
 
+#[test]
+fn test_tool_change_follows_tool_image_until_edited() {
+    let mut config = Config::default();
+    config
+        .sandbox
+        .tool_images
+        .insert("opencode".to_string(), "me/opencode-sandbox".to_string());
+    let mut dialog = NewSessionDialog::new_with_config(
+        vec!["claude", "opencode"],
+        "/tmp/project".to_string(),
+        config,
+    );
+    let default_image = dialog.sandbox_image.value().to_string();
+
+    dialog.select_tool(1);
+    assert_eq!(dialog.sandbox_image.value(), "me/opencode-sandbox");
+    dialog.select_tool(0);
+    assert_eq!(dialog.sandbox_image.value(), default_image);
+
+    dialog.sandbox_image = Input::new("custom:latest".to_string());
+    dialog.select_tool(1);
+    assert_eq!(dialog.sandbox_image.value(), "custom:latest");
+}
+
 #[test]
 fn test_profile_override_sets_default_tool() {
     let global = Config::default();
@@ -834,6 +911,10 @@ fn test_profile_override_sets_default_tool() {
         session: Some(SessionConfigOverride {
             default_tool: Some("opencode".to_string()),
             yolo_mode_default: None,
+            poll_interval_ms: None,
+            confirm_quit_when_running: None,
+            auto_nudge_seconds: None,
+            relaunch_on_attach: None,
         }),
         ..Default::default()
     };
@@ -861,6 +942,10 @@ fn test_profile_override_beats_global_default_tool() {
         session: Some(SessionConfigOverride {
             default_tool: Some("opencode".to_string()),
             yolo_mode_default: None,
+            poll_interval_ms: None,
+            confirm_quit_when_running: None,
+            auto_nudge_seconds: None,
+            relaunch_on_attach: None,
         }),
         ..Default::default()
     };
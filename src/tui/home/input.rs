@@ -7,6 +7,7 @@ use tui_input::Input;
 use super::{HomeView, TerminalMode, ViewMode};
 use crate::session::{flatten_tree, list_profiles, repo_config, resolve_config, Item, Status};
 use crate::tui::app::Action;
+use crate::tui::components::LineMode;
 use crate::tui::dialogs::{
     ConfirmDialog, DeleteDialogConfig, DialogResult, GroupDeleteOptionsDialog, HookTrustAction,
     InfoDialog, NewSessionData, NewSessionDialog, RenameDialog, UnifiedDeleteDialog,
@@ -14,6 +15,9 @@ use crate::tui::dialogs::{
 use crate::tui::diff::{DiffAction, DiffView};
 use crate::tui::settings::{SettingsAction, SettingsView};
 
+/// Columns moved per Left/Right press when the preview is truncating lines
+const PREVIEW_HSCROLL_STEP: isize = 8;
+
 impl HomeView {
     pub fn handle_key(&mut self, key: KeyEvent) -> Option<Action> {
         // Handle unsaved changes confirmation for settings (shown over settings view)
@@ -234,6 +238,7 @@ impl HomeView {
                 DialogResult::Cancel => {
                     self.confirm_dialog = None;
                     self.pending_stop_session = None;
+                    self.pending_relaunch_session = None;
                 }
                 DialogResult::Submit(_) => {
                     let action = dialog.action().to_string();
@@ -246,6 +251,12 @@ impl HomeView {
                         if let Some(session_id) = self.pending_stop_session.take() {
                             return Some(Action::StopSession(session_id));
                         }
+                    } else if action == "relaunch_session" {
+                        if let Some(session_id) = self.pending_relaunch_session.take() {
+                            return Some(Action::RelaunchSession(session_id));
+                        }
+                    } else if action == "quit" {
+                        return Some(Action::Quit);
                     }
                 }
             }
@@ -332,16 +343,34 @@ impl HomeView {
             return None;
         }
 
+        // Digits accumulate a count and jump to the Nth session as it is typed
+        if let KeyCode::Char(c @ '0'..='9') = key.code {
+            if let Some(count) = push_count_digit(self.pending_count, c) {
+                self.pending_count = Some(count);
+                self.select_nth(count);
+            }
+            return None;
+        }
+        // Any other key consumes the count. After one, `g`/`G` keep the
+        // session it selected instead of jumping to the top or bottom, like
+        // vim's `5G`.
+        if let (Some(n), KeyCode::Home | KeyCode::End | KeyCode::Char('g' | 'G')) =
+            (self.pending_count.take(), key.code)
+        {
+            self.select_nth(n);
+            return None;
+        }
+
         // Normal mode keybindings
         match key.code {
-            KeyCode::Esc => {
-                if !self.search_matches.is_empty() {
-                    self.search_matches.clear();
-                    self.search_match_index = 0;
-                    self.search_query = Input::default();
-                }
+            KeyCode::Esc if !self.search_matches.is_empty() => {
+                self.search_matches.clear();
+                self.search_match_index = 0;
+                self.search_query = Input::default();
+            }
+            KeyCode::Char('q') if self.request_quit() => {
+                return Some(Action::Quit);
             }
-            KeyCode::Char('q') => return Some(Action::Quit),
             KeyCode::Char('?') => {
                 self.show_help = true;
             }
@@ -356,20 +385,18 @@ impl HomeView {
                     ViewMode::Terminal => ViewMode::Agent,
                 };
             }
-            KeyCode::Char('c') => {
-                // Toggle container/host terminal mode (only in Terminal view for sandboxed sessions)
-                if self.view_mode == ViewMode::Terminal {
-                    if let Some(id) = &self.selected_session {
-                        if let Some(inst) = self.instance_map.get(id) {
-                            if inst.is_sandboxed() {
-                                let id = id.clone();
-                                self.toggle_terminal_mode(&id);
-                            } else {
-                                self.info_dialog = Some(InfoDialog::new(
-                                    "Not Available",
-                                    "Only sandboxed sessions support container terminals. This session runs directly on the host.",
-                                ));
-                            }
+            // Toggle container/host terminal mode (only in Terminal view for sandboxed sessions)
+            KeyCode::Char('c') if self.view_mode == ViewMode::Terminal => {
+                if let Some(id) = &self.selected_session {
+                    if let Some(inst) = self.instance_map.get(id) {
+                        if inst.is_sandboxed() {
+                            let id = id.clone();
+                            self.toggle_terminal_mode(&id);
+                        } else {
+                            self.info_dialog = Some(InfoDialog::new(
+                                "Not Available",
+                                "Only sandboxed sessions support container terminals. This session runs directly on the host.",
+                            ));
                         }
                     }
                 }
@@ -393,24 +420,26 @@ impl HomeView {
                         .iter()
                         .map(|g| g.path.clone())
                         .collect();
-                    self.new_dialog = Some(NewSessionDialog::new(
+                    let mut dialog = NewSessionDialog::new(
                         self.available_tools.clone(),
                         existing_titles,
                         existing_groups,
                         self.storage.profile(),
-                    ));
+                    );
+                    if let Some(last) = &self.last_new_session {
+                        dialog = dialog.with_last_used(last);
+                    }
+                    self.new_dialog = Some(dialog);
                 }
             }
-            KeyCode::Char('N') => {
-                if !self.search_matches.is_empty() {
-                    self.search_match_index = if self.search_match_index == 0 {
-                        self.search_matches.len() - 1
-                    } else {
-                        self.search_match_index - 1
-                    };
-                    self.cursor = self.search_matches[self.search_match_index];
-                    self.update_selected();
-                }
+            KeyCode::Char('N') if !self.search_matches.is_empty() => {
+                self.search_match_index = if self.search_match_index == 0 {
+                    self.search_matches.len() - 1
+                } else {
+                    self.search_match_index - 1
+                };
+                self.cursor = self.search_matches[self.search_match_index];
+                self.update_selected();
             }
             KeyCode::Char('s') => {
                 // Open settings view with selected session's project path (if any)
@@ -556,6 +585,9 @@ impl HomeView {
                     }
                 }
             }
+            KeyCode::Char('R') => {
+                self.refresh_selected_status();
+            }
             KeyCode::Up | KeyCode::Char('k') => {
                 self.move_cursor(-1);
             }
@@ -572,11 +604,9 @@ impl HomeView {
                 self.cursor = 0;
                 self.update_selected();
             }
-            KeyCode::End | KeyCode::Char('G') => {
-                if !self.flat_items.is_empty() {
-                    self.cursor = self.flat_items.len() - 1;
-                    self.update_selected();
-                }
+            KeyCode::End | KeyCode::Char('G') if !self.flat_items.is_empty() => {
+                self.cursor = self.flat_items.len() - 1;
+                self.update_selected();
             }
             KeyCode::Enter => {
                 if let Some(id) = &self.selected_session {
@@ -611,26 +641,58 @@ impl HomeView {
             KeyCode::Char('L') => {
                 self.grow_list();
             }
-            KeyCode::Left | KeyCode::Char('h') => {
-                if let Some(Item::Group {
-                    path, collapsed, ..
-                }) = self.flat_items.get(self.cursor)
+            KeyCode::Left | KeyCode::Char('h') => match self.flat_items.get(self.cursor) {
+                Some(Item::Group {
+                    path,
+                    collapsed: false,
+                    ..
+                }) => {
+                    let path = path.clone();
+                    self.toggle_group_collapsed(&path);
+                }
+                Some(Item::Session { .. }) if key.code == KeyCode::Left => {
+                    self.scroll_preview_horizontally(-PREVIEW_HSCROLL_STEP);
+                }
+                _ => {}
+            },
+            KeyCode::Right | KeyCode::Char('l') => match self.flat_items.get(self.cursor) {
+                Some(Item::Group {
+                    path,
+                    collapsed: true,
+                    ..
+                }) => {
+                    let path = path.clone();
+                    self.toggle_group_collapsed(&path);
+                }
+                Some(Item::Session { .. }) if key.code == KeyCode::Right => {
+                    self.scroll_preview_horizontally(PREVIEW_HSCROLL_STEP);
+                }
+                _ => {}
+            },
+            KeyCode::Char('o') => {
+                if let Some(inst) = self
+                    .selected_session
+                    .as_ref()
+                    .and_then(|id| self.instance_map.get(id))
                 {
-                    if !collapsed {
-                        let path = path.clone();
-                        self.toggle_group_collapsed(&path);
+                    let path = std::path::Path::new(&inst.project_path);
+                    // Terminal editors need the screen, so the app suspends
+                    // the TUI for them; the file manager runs detached
+                    if crate::process::editor_command(path).is_some() {
+                        return Some(Action::OpenInEditor(path.to_path_buf()));
+                    }
+                    if let Err(e) = crate::process::open_in_file_manager(path) {
+                        tracing::warn!("Failed to open {}: {}", path.display(), e);
+                        self.info_dialog = Some(InfoDialog::new(
+                            "Error",
+                            &format!("Failed to open {}: {}", path.display(), e),
+                        ));
                     }
                 }
             }
-            KeyCode::Right | KeyCode::Char('l') => {
-                if let Some(Item::Group {
-                    path, collapsed, ..
-                }) = self.flat_items.get(self.cursor)
-                {
-                    if *collapsed {
-                        let path = path.clone();
-                        self.toggle_group_collapsed(&path);
-                    }
+            KeyCode::Char('w') => {
+                if let Some(id) = self.selected_session.clone() {
+                    self.toggle_preview_line_mode(&id);
                 }
             }
             _ => {}
@@ -654,10 +716,41 @@ impl HomeView {
         self.update_selected();
     }
 
+    /// Select the Nth (1-based) visible session, clamping to the last one.
+    pub(super) fn select_nth(&mut self, n: usize) {
+        if n == 0 {
+            return;
+        }
+        let positions: Vec<usize> = self
+            .flat_items
+            .iter()
+            .enumerate()
+            .filter(|(_, item)| matches!(item, Item::Session { .. }))
+            .map(|(idx, _)| idx)
+            .collect();
+        if let Some(&idx) = positions.get(n - 1).or(positions.last()) {
+            self.cursor = idx;
+            self.update_selected();
+        }
+    }
+
+    /// Scroll the preview sideways; only meaningful in truncate mode.
+    pub(super) fn scroll_preview_horizontally(&mut self, delta: isize) {
+        let Some(id) = &self.selected_session else {
+            return;
+        };
+        if self.get_preview_line_mode(id) == LineMode::Truncate {
+            self.preview_hscroll = self.preview_hscroll.saturating_add_signed(delta);
+        }
+    }
+
     pub(super) fn update_selected(&mut self) {
         if let Some(item) = self.flat_items.get(self.cursor) {
             match item {
                 Item::Session { id, .. } => {
+                    if self.selected_session.as_ref() != Some(id) {
+                        self.preview_hscroll = 0;
+                    }
                     self.selected_session = Some(id.clone());
                     self.selected_group = None;
                 }
@@ -724,7 +817,7 @@ impl HomeView {
             }
         }
 
-        scored.sort_by(|a, b| b.1.cmp(&a.1));
+        scored.sort_by_key(|s| std::cmp::Reverse(s.1));
         self.search_matches = scored.into_iter().map(|(idx, _)| idx).collect();
         // Clamp match_index in case matches shrank
         if self.search_matches.is_empty() {
@@ -778,7 +871,7 @@ impl HomeView {
             }
         }
 
-        scored.sort_by(|a, b| b.1.cmp(&a.1));
+        scored.sort_by_key(|s| std::cmp::Reverse(s.1));
         self.search_matches = scored.into_iter().map(|(idx, _)| idx).collect();
 
         if let Some(&best) = self.search_matches.first() {
@@ -876,3 +969,14 @@ impl HomeView {
         }
     }
 }
+
+/// Append a typed digit to a pending count. Returns None when the digit should
+/// be ignored: a leading zero, or a count that would overflow.
+pub(super) fn push_count_digit(current: Option<usize>, digit: char) -> Option<usize> {
+    let value = digit.to_digit(10)? as usize;
+    match current {
+        None if value == 0 => None,
+        None => Some(value),
+        Some(count) => count.checked_mul(10)?.checked_add(value),
+    }
+}
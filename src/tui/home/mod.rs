@@ -8,23 +8,27 @@ mod render;
 mod tests;
 
 use std::collections::{HashMap, HashSet};
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 use tui_input::Input;
 
+use crate::audit::{AuditAction, AuditEvent};
 use crate::session::{
     config::{load_config, save_config},
-    flatten_tree, resolve_config, DefaultTerminalMode, Group, GroupTree, Instance, Item, Storage,
+    flatten_tree, resolve_config, DefaultTerminalMode, Group, GroupTree, Instance, Item,
+    RelaunchOnAttach, SessionConfig, Storage, UiState,
 };
 use crate::tmux::AvailableTools;
 
+use super::components::LineMode;
 use super::creation_poller::{CreationPoller, CreationRequest};
 use super::deletion_poller::DeletionPoller;
 use super::dialogs::{
     ChangelogDialog, ConfirmDialog, GroupDeleteOptionsDialog, HookTrustDialog, InfoDialog,
-    NewSessionData, NewSessionDialog, RenameDialog, UnifiedDeleteDialog, WelcomeDialog,
+    LastUsed, NewSessionData, NewSessionDialog, RenameDialog, UnifiedDeleteDialog, WelcomeDialog,
 };
 use super::diff::DiffView;
+use super::ready_poller::{ReadyEvent, ReadyPoller};
 use super::settings::SettingsView;
 use super::status_poller::StatusPoller;
 
@@ -80,13 +84,6 @@ pub(super) fn get_indent(depth: usize) -> &'static str {
     INDENTS.get(depth).copied().unwrap_or(INDENTS[9])
 }
 
-pub(super) const ICON_RUNNING: &str = "●";
-pub(super) const ICON_WAITING: &str = "◐";
-pub(super) const ICON_IDLE: &str = "○";
-pub(super) const ICON_ERROR: &str = "✕";
-pub(super) const ICON_STARTING: &str = "◌";
-pub(super) const ICON_STOPPED: &str = "■";
-pub(super) const ICON_DELETING: &str = "✗";
 pub(super) const ICON_COLLAPSED: &str = "▶";
 pub(super) const ICON_EXPANDED: &str = "▼";
 
@@ -107,6 +104,9 @@ pub struct HomeView {
     // Dialogs
     pub(super) show_help: bool,
     pub(super) new_dialog: Option<NewSessionDialog>,
+    /// Group and tool of the last session created this run, pre-filled into
+    /// the next new-session dialog
+    pub(super) last_new_session: Option<LastUsed>,
     pub(super) confirm_dialog: Option<ConfirmDialog>,
     pub(super) unified_delete_dialog: Option<UnifiedDeleteDialog>,
     pub(super) group_delete_options_dialog: Option<GroupDeleteOptionsDialog>,
@@ -121,6 +121,8 @@ pub struct HomeView {
     pub(super) pending_attach_after_warning: Option<String>,
     /// Session to stop after the confirmation dialog is accepted
     pub(super) pending_stop_session: Option<String>,
+    /// Session whose exited agent is relaunched if the confirmation is accepted
+    pub(super) pending_relaunch_session: Option<String>,
 
     // Search
     pub(super) search_active: bool,
@@ -145,6 +147,13 @@ pub struct HomeView {
     /// Sessions whose on_launch hooks already ran in the creation poller
     pub(super) on_launch_hooks_ran: HashSet<String>,
 
+    // Performance: background sandbox readiness probe
+    pub(super) ready_poller: ReadyPoller,
+    /// Sessions whose readiness probe is still running
+    pub(super) awaiting_ready: HashSet<String>,
+    /// Sessions whose readiness probe passed and can be started
+    pub(super) sandbox_ready: HashSet<String>,
+
     // Performance: preview caching
     pub(super) preview_cache: PreviewCache,
     pub(super) terminal_preview_cache: PreviewCache,
@@ -152,12 +161,33 @@ pub struct HomeView {
 
     // Terminal mode for sandboxed sessions (per-session, ephemeral)
     pub(super) terminal_modes: HashMap<String, TerminalMode>,
+    /// Per-session preview line mode (wrap vs truncate), defaults to truncate
+    pub(super) preview_line_modes: HashMap<String, LineMode>,
+    /// Horizontal scroll offset of the preview in truncate mode
+    pub(super) preview_hscroll: usize,
     // Default terminal mode from config
     pub(super) default_terminal_mode: TerminalMode,
 
     // Sound config for state transition sounds
     pub(super) sound_config: crate::sound::SoundConfig,
 
+    /// How often the event loop requests a status refresh
+    pub(super) poll_interval: Duration,
+
+    /// Whether quitting with running sessions needs confirmation
+    pub(super) confirm_quit_when_running: bool,
+
+    /// Idle period after which sandbox containers are stopped, if enabled
+    pub(super) idle_stop_threshold: Option<chrono::Duration>,
+
+    /// What attaching does when a host session's agent has exited
+    pub(super) relaunch_on_attach: RelaunchOnAttach,
+
+    /// How long a waiting session sits unchanged before it is nudged, if enabled
+    pub(super) auto_nudge_threshold: Option<chrono::Duration>,
+    /// `last_activity_at` of each session when it was last nudged
+    pub(super) nudged_at_activity: HashMap<String, chrono::DateTime<chrono::Utc>>,
+
     // Settings view
     pub(super) settings_view: Option<SettingsView>,
     /// Flag to indicate we're confirming settings close (unsaved changes)
@@ -168,11 +198,71 @@ pub struct HomeView {
 
     // Resizable list column width (percentage-like units)
     pub(super) list_width: u16,
+
+    /// Vim-style count being typed to jump to the Nth session
+    pub(super) pending_count: Option<usize>,
+}
+
+/// Find the row to select for a saved UI state. Prefers the saved session,
+/// then the saved group; returns None when neither is visible anymore.
+pub(super) fn reconcile_selection(flat_items: &[Item], state: &UiState) -> Option<usize> {
+    let by_session = state.selected_session.as_deref().and_then(|selected| {
+        flat_items
+            .iter()
+            .position(|item| matches!(item, Item::Session { id, .. } if id == selected))
+    });
+    by_session.or_else(|| {
+        state.selected_group.as_deref().and_then(|selected| {
+            flat_items
+                .iter()
+                .position(|item| matches!(item, Item::Group { path, .. } if path == selected))
+        })
+    })
+}
+
+/// Groups to collapse from a saved UI state: saved paths whose group still
+/// exists. Groups created since the state was saved stay expanded.
+pub(super) fn reconcile_collapsed(saved: &[String], tree: &GroupTree) -> HashSet<String> {
+    saved
+        .iter()
+        .filter(|path| tree.group_exists(path))
+        .cloned()
+        .collect()
+}
+
+/// Quitting needs confirmation only when enabled and some session is running.
+pub(super) fn should_confirm_quit(enabled: bool, instances: &[Instance]) -> bool {
+    enabled
+        && instances
+            .iter()
+            .any(|i| !i.archived && i.status == crate::session::Status::Running)
 }
 
 impl HomeView {
     pub fn new(storage: Storage, available_tools: AvailableTools) -> anyhow::Result<Self> {
-        let (instances, groups) = storage.load_with_groups()?;
+        let (mut instances, groups) = storage.load_with_groups()?;
+
+        // Drop container IDs whose containers disappeared while aoe was not
+        // running, listing containers once rather than per session
+        let mut reconciled = false;
+        let has_container_ids = instances.iter().any(|i| {
+            i.sandbox_info
+                .as_ref()
+                .is_some_and(|s| s.container_id.is_some())
+        });
+        if has_container_ids {
+            if let Some(containers) = crate::containers::batch_container_health() {
+                for inst in &mut instances {
+                    reconciled |= inst.reconcile_container(&containers);
+                }
+            }
+        }
+        if reconciled {
+            let group_tree = GroupTree::new_with_groups(&instances, &groups);
+            if let Err(e) = storage.save_with_groups(&instances, &group_tree) {
+                tracing::warn!("Failed to save reconciled sessions: {}", e);
+            }
+        }
 
         let instance_map: HashMap<String, Instance> = instances
             .iter()
@@ -194,6 +284,29 @@ impl HomeView {
             .as_ref()
             .map(|config| config.sound.clone())
             .unwrap_or_default();
+        let poll_interval = resolved
+            .as_ref()
+            .map(|config| config.session.poll_interval())
+            .unwrap_or_else(|_| SessionConfig::default().poll_interval());
+        let confirm_quit_when_running = resolved
+            .as_ref()
+            .map(|config| config.session.confirm_quit_when_running)
+            .unwrap_or(false);
+        let idle_stop_threshold = resolved
+            .as_ref()
+            .ok()
+            .and_then(|config| config.sandbox.idle_stop_threshold());
+        let auto_nudge_threshold = resolved
+            .as_ref()
+            .ok()
+            .and_then(|config| config.session.auto_nudge_threshold());
+        let relaunch_on_attach = resolved
+            .as_ref()
+            .map(|config| config.session.relaunch_on_attach)
+            .unwrap_or_default();
+        if let Ok(config) = &resolved {
+            crate::tmux::set_session_name_length(config.tmux.session_name_length);
+        }
 
         let mut view = Self {
             storage,
@@ -208,6 +321,7 @@ impl HomeView {
             view_mode: ViewMode::default(),
             show_help: false,
             new_dialog: None,
+            last_new_session: None,
             confirm_dialog: None,
             unified_delete_dialog: None,
             group_delete_options_dialog: None,
@@ -219,6 +333,7 @@ impl HomeView {
             info_dialog: None,
             pending_attach_after_warning: None,
             pending_stop_session: None,
+            pending_relaunch_session: None,
             search_active: false,
             search_query: Input::default(),
             search_matches: Vec::new(),
@@ -230,12 +345,23 @@ impl HomeView {
             creation_poller: CreationPoller::new(),
             creation_cancelled: false,
             on_launch_hooks_ran: HashSet::new(),
+            ready_poller: ReadyPoller::new(),
+            awaiting_ready: HashSet::new(),
+            sandbox_ready: HashSet::new(),
             preview_cache: PreviewCache::default(),
             terminal_preview_cache: PreviewCache::default(),
             container_terminal_preview_cache: PreviewCache::default(),
             terminal_modes: HashMap::new(),
+            preview_line_modes: HashMap::new(),
+            preview_hscroll: 0,
             default_terminal_mode,
             sound_config,
+            poll_interval,
+            confirm_quit_when_running,
+            idle_stop_threshold,
+            relaunch_on_attach,
+            auto_nudge_threshold,
+            nudged_at_activity: HashMap::new(),
             settings_view: None,
             settings_close_confirm: false,
             diff_view: None,
@@ -244,12 +370,40 @@ impl HomeView {
                 .flatten()
                 .and_then(|c| c.app_state.home_list_width)
                 .unwrap_or(35),
+            pending_count: None,
         };
 
-        view.update_selected();
+        view.restore_ui_state();
         Ok(view)
     }
 
+    /// Restore selection and view mode saved by a previous run.
+    fn restore_ui_state(&mut self) {
+        let state = self.storage.load_ui_state();
+        if state.terminal_view {
+            self.view_mode = ViewMode::Terminal;
+        }
+        if let Some(saved) = &state.collapsed_groups {
+            let collapsed = reconcile_collapsed(saved, &self.group_tree);
+            self.group_tree.set_collapsed_paths(&collapsed);
+            self.flat_items = flatten_tree(&self.group_tree, &self.instances);
+        }
+        self.cursor = reconcile_selection(&self.flat_items, &state).unwrap_or(0);
+        self.update_selected();
+    }
+
+    pub fn save_ui_state(&self) {
+        let state = UiState {
+            selected_session: self.selected_session.clone(),
+            selected_group: self.selected_group.clone(),
+            terminal_view: self.view_mode == ViewMode::Terminal,
+            collapsed_groups: Some(self.group_tree.collapsed_paths()),
+        };
+        if let Err(e) = self.storage.save_ui_state(&state) {
+            tracing::warn!("Failed to save UI state: {}", e);
+        }
+    }
+
     pub fn reload(&mut self) -> anyhow::Result<()> {
         let (mut instances, groups) = self.storage.load_with_groups()?;
 
@@ -257,6 +411,10 @@ impl HomeView {
             if let Some(prev) = self.instance_map.get(&inst.id) {
                 inst.status = prev.status;
                 inst.last_error = prev.last_error.clone();
+                inst.status_note = prev.status_note.clone();
+                inst.pane_statuses = prev.pane_statuses.clone();
+                inst.last_content_hash = prev.last_content_hash;
+                inst.last_activity_at = prev.last_activity_at;
                 inst.last_error_check = prev.last_error_check;
                 inst.last_start_time = prev.last_start_time;
             }
@@ -287,6 +445,22 @@ impl HomeView {
         Ok(())
     }
 
+    /// Ask whether to relaunch a session's exited agent before attaching.
+    pub fn confirm_relaunch(&mut self, instance: &Instance) {
+        let message = format!(
+            "The agent in '{}' has exited. Relaunch {}?",
+            instance.title, instance.tool
+        );
+        self.pending_relaunch_session = Some(instance.id.clone());
+        self.confirm_dialog = Some(
+            ConfirmDialog::new("Relaunch Agent", &message, "relaunch_session").with_default(true),
+        );
+    }
+
+    pub fn record_audit(&self, instance: &Instance, action: AuditAction) {
+        crate::audit::record(AuditEvent::new(self.storage.profile(), instance, action));
+    }
+
     /// Request a status refresh in the background (non-blocking).
     /// Call `apply_status_updates` to check for and apply results.
     pub fn request_status_refresh(&mut self) {
@@ -297,6 +471,113 @@ impl HomeView {
         }
     }
 
+    /// Re-detect the selected session's status now rather than at the next
+    /// poll tick. Detection runs on the poller thread so the UI never waits on
+    /// tmux; the row updates when `apply_status_updates` picks up the result.
+    pub fn refresh_selected_status(&mut self) {
+        use crate::session::Status;
+
+        let Some(inst) = self
+            .selected_session
+            .as_ref()
+            .and_then(|id| self.instance_map.get(id))
+        else {
+            return;
+        };
+        if matches!(inst.status, Status::Deleting | Status::Stopped) {
+            return;
+        }
+        let mut inst = inst.clone();
+        // Skip the back-off that delays rechecking errored sessions
+        inst.last_error_check = None;
+        self.status_poller.request_refresh(vec![inst]);
+    }
+
+    /// Stop sandboxed sessions that have been idle past the configured
+    /// threshold. Their containers are stopped in the background and restart
+    /// on the next attach. Returns true if any session was stopped.
+    pub fn auto_stop_idle_sessions(&mut self) -> bool {
+        use crate::session::Status;
+
+        let Some(threshold) = self.idle_stop_threshold else {
+            return false;
+        };
+        let now = chrono::Utc::now();
+        let idle: Vec<Instance> = self
+            .instances
+            .iter()
+            .filter(|i| {
+                i.is_sandboxed()
+                    && matches!(i.status, Status::Idle | Status::Waiting)
+                    && crate::session::should_auto_stop(i.last_activity_at, now, threshold)
+            })
+            .cloned()
+            .collect();
+        if idle.is_empty() {
+            return false;
+        }
+
+        for inst in idle {
+            tracing::info!("Auto-stopping idle sandboxed session {}", inst.title);
+            self.set_instance_status(&inst.id, Status::Stopped);
+            for target in self
+                .instances
+                .iter_mut()
+                .filter(|i| i.id == inst.id)
+                .chain(self.instance_map.get_mut(&inst.id))
+            {
+                target.clear_activity();
+            }
+            self.record_audit(&inst, AuditAction::Stopped);
+            std::thread::spawn(move || {
+                if let Err(e) = inst.stop() {
+                    tracing::warn!("Failed to auto-stop session {}: {}", inst.id, e);
+                }
+            });
+        }
+        if let Err(e) = self.save() {
+            tracing::warn!("Failed to save after auto-stop: {}", e);
+        }
+        true
+    }
+
+    /// Press Enter once in sessions that have sat waiting with unchanged
+    /// output past the configured threshold. Sessions showing an approval
+    /// prompt are left alone; the pane is checked again right before sending.
+    pub fn auto_nudge_waiting_sessions(&mut self) {
+        let Some(threshold) = self.auto_nudge_threshold else {
+            return;
+        };
+        let now = chrono::Utc::now();
+        let stuck: Vec<Instance> = self
+            .instances
+            .iter()
+            .filter(|i| {
+                crate::session::should_nudge(
+                    i.status,
+                    i.last_activity_at,
+                    self.nudged_at_activity.get(&i.id).copied(),
+                    now,
+                    threshold,
+                )
+            })
+            .cloned()
+            .collect();
+
+        for inst in stuck {
+            if let Some(at) = inst.last_activity_at {
+                self.nudged_at_activity.insert(inst.id.clone(), at);
+            }
+            std::thread::spawn(move || match inst.nudge() {
+                Ok(true) => tracing::info!("Auto-nudged waiting session {}", inst.title),
+                Ok(false) => {
+                    tracing::debug!("Not nudging {}: approval prompt", inst.title)
+                }
+                Err(e) => tracing::warn!("Failed to nudge session {}: {}", inst.id, e),
+            });
+        }
+    }
+
     /// Apply any pending status updates from the background poller.
     /// Returns true if updates were applied.
     pub fn apply_status_updates(&mut self) -> bool {
@@ -304,6 +585,10 @@ impl HomeView {
 
         if let Some(updates) = self.status_poller.try_recv_updates() {
             for update in updates {
+                // The session is not started until its readiness probe passes
+                if self.awaiting_ready.contains(&update.id) {
+                    continue;
+                }
                 if let Some(inst) = self.instances.iter_mut().find(|i| i.id == update.id) {
                     if inst.status != Status::Deleting
                         && inst.status != Status::Stopped
@@ -312,7 +597,20 @@ impl HomeView {
                         let old_status = inst.status;
                         inst.status = update.status;
                         inst.last_error = update.last_error.clone();
+                        inst.status_note = update.note.clone();
+                        inst.last_content_hash = update.content_hash;
+                        inst.last_activity_at = update.last_activity_at;
+                        inst.last_exit_code = update.last_exit_code;
+                        inst.pane_statuses = update.panes.clone();
                         if old_status != update.status {
+                            crate::audit::record(AuditEvent::new(
+                                self.storage.profile(),
+                                inst,
+                                AuditAction::StatusChanged {
+                                    from: old_status,
+                                    to: update.status,
+                                },
+                            ));
                             crate::sound::play_for_transition(
                                 old_status,
                                 update.status,
@@ -328,6 +626,11 @@ impl HomeView {
                     {
                         inst.status = update.status;
                         inst.last_error = update.last_error;
+                        inst.status_note = update.note;
+                        inst.last_content_hash = update.content_hash;
+                        inst.last_activity_at = update.last_activity_at;
+                        inst.last_exit_code = update.last_exit_code;
+                        inst.pane_statuses = update.panes;
                     }
                 }
             }
@@ -342,15 +645,20 @@ impl HomeView {
 
         if let Some(result) = self.deletion_poller.try_recv_result() {
             if result.success {
+                let removed = self.instance_map.remove(&result.session_id);
                 self.instances.retain(|i| i.id != result.session_id);
-                self.instance_map.remove(&result.session_id);
                 self.group_tree = GroupTree::new_with_groups(&self.instances, &self.groups);
 
-                if let Err(e) = self
+                match self
                     .storage
                     .save_with_groups(&self.instances, &self.group_tree)
                 {
-                    tracing::error!("Failed to save after deletion: {}", e);
+                    Ok(()) => {
+                        if let Some(inst) = &removed {
+                            self.record_audit(inst, AuditAction::Removed);
+                        }
+                    }
+                    Err(e) => tracing::error!("Failed to save after deletion: {}", e),
                 }
                 let _ = self.reload();
             } else {
@@ -391,6 +699,7 @@ impl HomeView {
             data,
             existing_instances: self.instances.clone(),
             hooks,
+            profile: self.storage.profile().to_string(),
         };
         self.creation_poller.request_creation(request);
     }
@@ -450,6 +759,8 @@ impl HomeView {
                 {
                     tracing::error!("Failed to save after creation: {}", e);
                 }
+                self.record_audit(&instance, AuditAction::Created);
+                self.remember_last_used(&instance);
 
                 if on_launch_hooks_ran {
                     self.on_launch_hooks_ran.insert(session_id.clone());
@@ -475,6 +786,52 @@ impl HomeView {
         self.on_launch_hooks_ran.remove(session_id)
     }
 
+    /// Start a sandboxed session's container and wait for its readiness probe
+    /// in the background. `apply_ready_results` reports when it can attach.
+    pub fn request_sandbox_ready(&mut self, instance: &Instance) {
+        if !self.awaiting_ready.insert(instance.id.clone()) {
+            return;
+        }
+        self.set_instance_status(&instance.id, crate::session::Status::Starting);
+        self.set_instance_note(&instance.id, Some("waiting for sandbox".to_string()));
+        self.ready_poller.request(instance.clone());
+    }
+
+    /// Check if the readiness probe passed for this session (and consume the flag).
+    pub fn take_sandbox_ready(&mut self, session_id: &str) -> bool {
+        self.sandbox_ready.remove(session_id)
+    }
+
+    /// Apply progress from the background readiness probe.
+    /// Returns Some(session_id) once a session is ready and we should attach.
+    pub fn apply_ready_results(&mut self) -> Option<String> {
+        use crate::session::Status;
+
+        while let Some(event) = self.ready_poller.try_recv() {
+            match event {
+                ReadyEvent::Waiting {
+                    session_id,
+                    attempt,
+                } => {
+                    let note = format!("waiting for sandbox (attempt {})", attempt);
+                    self.set_instance_note(&session_id, Some(note));
+                }
+                ReadyEvent::Done { session_id, error } => {
+                    self.awaiting_ready.remove(&session_id);
+                    self.set_instance_note(&session_id, None);
+                    if let Some(error) = error {
+                        self.set_instance_error(&session_id, Some(error));
+                        self.set_instance_status(&session_id, Status::Error);
+                        continue;
+                    }
+                    self.sandbox_ready.insert(session_id.clone());
+                    return Some(session_id);
+                }
+            }
+        }
+        None
+    }
+
     /// Check if there's a pending creation operation
     pub fn is_creation_pending(&self) -> bool {
         self.creation_poller.is_pending()
@@ -578,6 +935,15 @@ impl HomeView {
         Ok(())
     }
 
+    fn set_instance_note(&mut self, id: &str, note: Option<String>) {
+        if let Some(inst) = self.instance_map.get_mut(id) {
+            inst.status_note = note.clone();
+        }
+        if let Some(inst) = self.instances.iter_mut().find(|i| i.id == id) {
+            inst.status_note = note;
+        }
+    }
+
     pub fn set_instance_error(&mut self, id: &str, error: Option<String>) {
         if let Some(inst) = self.instance_map.get_mut(id) {
             inst.last_error = error.clone();
@@ -615,6 +981,25 @@ impl HomeView {
         }
     }
 
+    /// Handle a quit request. Returns true when the app should exit now, or
+    /// false after opening a confirmation dialog.
+    pub fn request_quit(&mut self) -> bool {
+        if should_confirm_quit(self.confirm_quit_when_running, &self.instances) {
+            self.confirm_dialog = Some(ConfirmDialog::new(
+                "Quit",
+                "Some sessions are still running. Quit anyway? (sessions keep running)",
+                "quit",
+            ));
+            false
+        } else {
+            true
+        }
+    }
+
+    pub fn poll_interval(&self) -> Duration {
+        self.poll_interval
+    }
+
     /// Get the terminal mode for a session (uses config default if not set)
     pub fn get_terminal_mode(&self, session_id: &str) -> TerminalMode {
         self.terminal_modes
@@ -623,6 +1008,24 @@ impl HomeView {
             .unwrap_or(self.default_terminal_mode)
     }
 
+    pub fn get_preview_line_mode(&self, session_id: &str) -> LineMode {
+        self.preview_line_modes
+            .get(session_id)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Toggle the preview between wrapping and truncating long lines
+    pub fn toggle_preview_line_mode(&mut self, session_id: &str) {
+        let new_mode = match self.get_preview_line_mode(session_id) {
+            LineMode::Truncate => LineMode::Wrap,
+            LineMode::Wrap => LineMode::Truncate,
+        };
+        self.preview_line_modes
+            .insert(session_id.to_string(), new_mode);
+        self.preview_hscroll = 0;
+    }
+
     /// Refresh all config-dependent state from the current profile's config.
     /// Call this after settings are saved to pick up any changes.
     pub fn refresh_from_config(&mut self) {
@@ -635,6 +1038,13 @@ impl HomeView {
 
             // Refresh sound config
             self.sound_config = config.sound.clone();
+
+            self.poll_interval = config.session.poll_interval();
+            self.confirm_quit_when_running = config.session.confirm_quit_when_running;
+            self.idle_stop_threshold = config.sandbox.idle_stop_threshold();
+            self.auto_nudge_threshold = config.session.auto_nudge_threshold();
+            self.relaunch_on_attach = config.session.relaunch_on_attach;
+            crate::tmux::set_session_name_length(config.tmux.session_name_length);
         }
     }
 
@@ -1,9 +1,10 @@
 //! Session operations for HomeView (create, delete, rename)
 
+use crate::audit::AuditAction;
 use crate::session::builder::{self, InstanceParams};
-use crate::session::{flatten_tree, list_profiles, GroupTree, Status, Storage};
+use crate::session::{flatten_tree, list_profiles, GroupTree, Instance, Status, Storage};
 use crate::tui::deletion_poller::DeletionRequest;
-use crate::tui::dialogs::{DeleteOptions, GroupDeleteOptions, NewSessionData};
+use crate::tui::dialogs::{DeleteOptions, GroupDeleteOptions, LastUsed, NewSessionData};
 
 use super::HomeView;
 
@@ -23,6 +24,7 @@ impl HomeView {
             yolo_mode: data.yolo_mode,
             extra_env_keys: data.extra_env_keys,
             extra_env_values: data.extra_env_values,
+            profile: self.storage.profile().to_string(),
         };
 
         let build_result = builder::build_instance(params, &existing_titles)?;
@@ -36,11 +38,20 @@ impl HomeView {
         }
         self.storage
             .save_with_groups(&self.instances, &self.group_tree)?;
+        self.record_audit(&instance, AuditAction::Created);
+        self.remember_last_used(&instance);
 
         self.reload()?;
         Ok(session_id)
     }
 
+    pub(super) fn remember_last_used(&mut self, instance: &Instance) {
+        self.last_new_session = Some(LastUsed {
+            group: instance.group_path.clone(),
+            tool: instance.tool.clone(),
+        });
+    }
+
     pub(super) fn delete_selected(&mut self, options: &DeleteOptions) -> anyhow::Result<()> {
         if let Some(id) = &self.selected_session {
             let id = id.clone();
@@ -221,8 +232,11 @@ impl HomeView {
                                 if let Err(e) = tmux_session.rename(&new_tmux_name) {
                                     tracing::warn!("Failed to rename tmux session: {}", e);
                                 } else {
+                                    instance.tmux_name = Some(new_tmux_name);
                                     crate::tmux::refresh_session_cache();
                                 }
+                            } else {
+                                instance.tmux_name = None;
                             }
                         }
                     }
@@ -253,28 +267,34 @@ impl HomeView {
                 }
             }
 
-            // No profile change - update in place
-            if let Some(inst) = self.instances.iter_mut().find(|i| i.id == id) {
-                inst.title = effective_title.clone();
-                inst.group_path = effective_group.clone();
-            }
-
             // Handle tmux rename if title changed
+            let mut new_tmux_name = None;
             if let Some(inst) = self.instance_map.get(&id) {
                 if inst.title != effective_title {
                     let tmux_session = inst.tmux_session()?;
                     if tmux_session.exists() {
-                        let new_tmux_name =
-                            crate::tmux::Session::generate_name(&id, &effective_title);
-                        if let Err(e) = tmux_session.rename(&new_tmux_name) {
+                        let name = crate::tmux::Session::generate_name(&id, &effective_title);
+                        if let Err(e) = tmux_session.rename(&name) {
                             tracing::warn!("Failed to rename tmux session: {}", e);
                         } else {
                             crate::tmux::refresh_session_cache();
+                            new_tmux_name = Some(Some(name));
                         }
+                    } else {
+                        new_tmux_name = Some(None);
                     }
                 }
             }
 
+            // No profile change - update in place
+            if let Some(inst) = self.instances.iter_mut().find(|i| i.id == id) {
+                inst.title = effective_title.clone();
+                inst.group_path = effective_group.clone();
+                if let Some(name) = new_tmux_name {
+                    inst.tmux_name = name;
+                }
+            }
+
             // Rebuild group tree and create group if needed
             self.group_tree = GroupTree::new_with_groups(&self.instances, &self.groups);
             if !effective_group.is_empty() {
@@ -4,12 +4,10 @@ use ratatui::prelude::*;
 use ratatui::widgets::*;
 use std::time::Instant;
 
-use super::{
-    get_indent, HomeView, TerminalMode, ViewMode, ICON_COLLAPSED, ICON_DELETING, ICON_ERROR,
-    ICON_EXPANDED, ICON_IDLE, ICON_RUNNING, ICON_STARTING, ICON_STOPPED, ICON_WAITING,
-};
-use crate::session::{Item, Status};
-use crate::tui::components::{HelpOverlay, Preview};
+use super::{get_indent, HomeView, TerminalMode, ViewMode, ICON_COLLAPSED, ICON_EXPANDED};
+use crate::session::{summarize_statuses, Item, Status};
+use crate::tui::components::{max_hscroll, HelpOverlay, LineMode, OutputLayout, Preview};
+use crate::tui::dialogs::{fits, render_too_small, MIN_DIALOG_HEIGHT, MIN_DIALOG_WIDTH};
 use crate::tui::styles::Theme;
 use crate::update::UpdateInfo;
 
@@ -72,6 +70,11 @@ impl HomeView {
         }
 
         // Render dialogs on top
+        if self.has_dialog() && !fits(area, MIN_DIALOG_WIDTH, MIN_DIALOG_HEIGHT) {
+            render_too_small(frame, area, theme);
+            return;
+        }
+
         if self.show_help {
             HelpOverlay::render(frame, area, theme);
         }
@@ -144,6 +147,17 @@ impl HomeView {
             return;
         }
 
+        // While a jump count is being typed, number sessions so the target is visible
+        let number_width = self.pending_count.map(|_| {
+            let sessions = self
+                .flat_items
+                .iter()
+                .filter(|item| matches!(item, Item::Session { .. }))
+                .count();
+            sessions.max(1).to_string().len()
+        });
+        let mut session_number = 0;
+
         let list_items: Vec<ListItem> = self
             .flat_items
             .iter()
@@ -152,7 +166,14 @@ impl HomeView {
                 let is_selected = idx == self.cursor;
                 let is_match =
                     !self.search_matches.is_empty() && self.search_matches.contains(&idx);
-                self.render_item(item, is_selected, is_match, theme)
+                let row_number = number_width.map(|width| match item {
+                    Item::Session { .. } => {
+                        session_number += 1;
+                        format!("{:>width$} ", session_number)
+                    }
+                    Item::Group { .. } => " ".repeat(width + 1),
+                });
+                self.render_item(item, is_selected, is_match, row_number, theme)
             })
             .collect();
 
@@ -213,6 +234,7 @@ impl HomeView {
         item: &Item,
         is_selected: bool,
         is_match: bool,
+        row_number: Option<String>,
         theme: &Theme,
     ) -> ListItem<'_> {
         let indent = get_indent(item.depth());
@@ -238,28 +260,11 @@ impl HomeView {
             Item::Session { id, .. } => {
                 if let Some(inst) = self.instance_map.get(id) {
                     match self.view_mode {
-                        ViewMode::Agent => {
-                            let icon = match inst.status {
-                                Status::Running => ICON_RUNNING,
-                                Status::Waiting => ICON_WAITING,
-                                Status::Idle => ICON_IDLE,
-                                Status::Stopped => ICON_STOPPED,
-                                Status::Error => ICON_ERROR,
-                                Status::Starting => ICON_STARTING,
-                                Status::Deleting => ICON_DELETING,
-                            };
-                            let color = match inst.status {
-                                Status::Running => theme.running,
-                                Status::Waiting => theme.waiting,
-                                Status::Idle => theme.idle,
-                                Status::Stopped => theme.dimmed,
-                                Status::Error => theme.error,
-                                Status::Starting => theme.dimmed,
-                                Status::Deleting => theme.waiting,
-                            };
-                            let style = Style::default().fg(color);
-                            (icon, Cow::Borrowed(&inst.title), style)
-                        }
+                        ViewMode::Agent => (
+                            inst.status.icon(),
+                            Cow::Borrowed(&inst.title),
+                            inst.status.style(theme),
+                        ),
                         ViewMode::Terminal => {
                             // For sandboxed sessions, check the appropriate terminal based on mode
                             let terminal_mode = if inst.is_sandboxed() {
@@ -278,9 +283,9 @@ impl HomeView {
                                     .unwrap_or(false),
                             };
                             let (icon, color) = if terminal_running {
-                                (ICON_RUNNING, theme.terminal_active)
+                                (Status::Running.icon(), theme.terminal_active)
                             } else {
-                                (ICON_IDLE, theme.dimmed)
+                                (Status::Idle.icon(), theme.dimmed)
                             };
                             let style = Style::default().fg(color);
                             (icon, Cow::Borrowed(&inst.title), style)
@@ -296,7 +301,10 @@ impl HomeView {
             }
         };
 
-        let mut line_spans = Vec::with_capacity(5);
+        let mut line_spans = Vec::with_capacity(6);
+        if let Some(number) = row_number {
+            line_spans.push(Span::styled(number, Style::default().fg(theme.hint)));
+        }
         line_spans.push(Span::raw(indent));
         let icon_style = if is_match {
             Style::default().fg(theme.search)
@@ -311,12 +319,23 @@ impl HomeView {
 
         if let Item::Session { id, .. } = item {
             if let Some(inst) = self.instance_map.get(id) {
-                if let Some(wt_info) = &inst.worktree_info {
+                let branch = crate::git::current_branch(std::path::Path::new(&inst.project_path))
+                    .or_else(|| inst.worktree_info.as_ref().map(|wt| wt.branch.clone()));
+                if let Some(branch) = branch {
                     line_spans.push(Span::styled(
-                        format!("  {}", wt_info.branch),
+                        format!("  {}", branch),
                         Style::default().fg(theme.branch),
                     ));
                 }
+                if let (Status::Error, Some(code)) = (inst.status, inst.last_exit_code) {
+                    line_spans.push(Span::styled(
+                        format!(" exited {}", code),
+                        Style::default().fg(theme.error),
+                    ));
+                }
+                if inst.status_note.is_some() && self.view_mode == ViewMode::Agent {
+                    line_spans.push(Span::styled(" [note]", Style::default().fg(theme.waiting)));
+                }
                 if inst.is_sandboxed() {
                     match self.view_mode {
                         ViewMode::Agent => {
@@ -365,7 +384,7 @@ impl HomeView {
             if let Some(id) = &self.selected_session {
                 if let Some(inst) = self.instance_map.get(id) {
                     self.preview_cache.content = inst
-                        .capture_output_with_size(height as usize, width, height)
+                        .capture_output_ansi(height as usize)
                         .unwrap_or_default();
                     self.preview_cache.session_id = Some(id.clone());
                     self.preview_cache.dimensions = (width, height);
@@ -441,10 +460,31 @@ impl HomeView {
         }
     }
 
+    /// Output layout for the selected session, clamping the horizontal
+    /// offset so it never scrolls past the longest line.
+    fn preview_output_layout(&mut self, max_offset: usize) -> OutputLayout {
+        self.preview_hscroll = self.preview_hscroll.min(max_offset);
+        let mode = self
+            .selected_session
+            .as_deref()
+            .map(|id| self.get_preview_line_mode(id))
+            .unwrap_or_default();
+        OutputLayout {
+            mode,
+            hscroll: self.preview_hscroll,
+        }
+    }
+
     fn render_preview(&mut self, frame: &mut Frame, area: Rect, theme: &Theme) {
-        let title = match self.view_mode {
-            ViewMode::Agent => " Preview ",
-            ViewMode::Terminal => " Terminal Preview ",
+        let wrapped = self
+            .selected_session
+            .as_deref()
+            .is_some_and(|id| self.get_preview_line_mode(id) == LineMode::Wrap);
+        let title = match (self.view_mode, wrapped) {
+            (ViewMode::Agent, false) => " Preview ",
+            (ViewMode::Agent, true) => " Preview (wrap) ",
+            (ViewMode::Terminal, false) => " Terminal Preview ",
+            (ViewMode::Terminal, true) => " Terminal Preview (wrap) ",
         };
         let (border_color, title_color) = match self.view_mode {
             ViewMode::Agent => (theme.border, theme.title),
@@ -463,6 +503,10 @@ impl HomeView {
             ViewMode::Agent => {
                 // Refresh cache before borrowing from instance_map to avoid borrow conflicts
                 self.refresh_preview_cache_if_needed(inner.width, inner.height);
+                let layout = self.preview_output_layout(max_hscroll(
+                    &self.preview_cache.content,
+                    inner.width as usize,
+                ));
 
                 if let Some(id) = &self.selected_session {
                     if let Some(inst) = self.instance_map.get(id) {
@@ -471,6 +515,7 @@ impl HomeView {
                             inner,
                             inst,
                             &self.preview_cache.content,
+                            layout,
                             theme,
                         );
                     }
@@ -512,6 +557,12 @@ impl HomeView {
                             );
                         }
                     }
+                    let content = match terminal_mode {
+                        TerminalMode::Container => &self.container_terminal_preview_cache.content,
+                        TerminalMode::Host => &self.terminal_preview_cache.content,
+                    };
+                    let layout =
+                        self.preview_output_layout(max_hscroll(content, inner.width as usize));
 
                     // Now borrow instance for rendering
                     if let Some(inst) = self.instance_map.get(&id) {
@@ -538,6 +589,7 @@ impl HomeView {
                             inst,
                             terminal_running,
                             preview_content,
+                            layout,
                             theme,
                         );
                     }
@@ -634,8 +686,40 @@ impl HomeView {
             Span::styled(" Quit", desc_style),
         ]);
 
-        let status = Paragraph::new(Line::from(spans)).style(Style::default().bg(theme.selection));
+        let hints = Line::from(spans);
+        let hints_width = hints.width();
+        let status = Paragraph::new(hints).style(Style::default().bg(theme.selection));
         frame.render_widget(status, area);
+
+        let summary = self.status_summary_line(theme);
+        if hints_width + summary.width() < area.width as usize {
+            frame.render_widget(Paragraph::new(summary).alignment(Alignment::Right), area);
+        }
+    }
+
+    /// Right-aligned "● 3 running · ✕ 1 error" summary, colored per status
+    fn status_summary_line(&self, theme: &Theme) -> Line<'static> {
+        let counts = summarize_statuses(&self.instances);
+        let mut spans = Vec::new();
+        for (status, count) in counts.highlights() {
+            if !spans.is_empty() {
+                spans.push(Span::styled(" · ", Style::default().fg(theme.dimmed)));
+            }
+            let label = match status {
+                Status::Running => "running",
+                Status::Waiting => "waiting",
+                Status::Error => "error",
+                _ => "idle",
+            };
+            spans.push(Span::styled(
+                format!("{} {} {}", status.icon(), count, label),
+                status.style(theme),
+            ));
+        }
+        if !spans.is_empty() {
+            spans.push(Span::raw(" "));
+        }
+        Line::from(spans)
     }
 
     fn render_update_bar(&self, frame: &mut Frame, area: Rect, theme: &Theme, info: &UpdateInfo) {
@@ -16,9 +16,7 @@ fn key(code: KeyCode) -> KeyEvent {
 }
 
 fn setup_test_home(temp: &TempDir) {
-    std::env::set_var("HOME", temp.path());
-    #[cfg(target_os = "linux")]
-    std::env::set_var("XDG_CONFIG_HOME", temp.path().join(".config"));
+    crate::session::setup_test_home(temp.path());
 }
 
 struct TestEnv {
@@ -865,6 +863,12 @@ fn create_test_env_with_group_sessions() -> TestEnv {
         extra_env_keys: None,
         extra_env_values: None,
         custom_instruction: None,
+        cpu_limit: None,
+        memory_limit: None,
+        config_hash: None,
+        workdir: None,
+        volume_ignores: None,
+        extra_volumes: None,
     });
     instances.push(inst3);
 
@@ -934,6 +938,12 @@ fn test_group_has_containers() {
         extra_env_keys: None,
         extra_env_values: None,
         custom_instruction: None,
+        cpu_limit: None,
+        memory_limit: None,
+        config_hash: None,
+        workdir: None,
+        volume_ignores: None,
+        extra_volumes: None,
     });
 
     let mut inst2 = Instance::new("other-session", "/tmp/other");
@@ -1101,6 +1111,12 @@ fn test_delete_group_with_sessions_respects_container_option() {
         extra_env_keys: None,
         extra_env_values: None,
         custom_instruction: None,
+        cpu_limit: None,
+        memory_limit: None,
+        config_hash: None,
+        workdir: None,
+        volume_ignores: None,
+        extra_volumes: None,
     });
 
     storage.save(&[inst1]).unwrap();
@@ -1355,3 +1371,332 @@ fn test_uppercase_l_grows_list() {
     env.view.handle_key(key(KeyCode::Char('L')));
     assert_eq!(env.view.list_width, 40);
 }
+
+#[test]
+fn test_push_count_digit() {
+    use super::input::push_count_digit;
+    assert_eq!(push_count_digit(None, '3'), Some(3));
+    assert_eq!(push_count_digit(Some(3), '0'), Some(30));
+    assert_eq!(push_count_digit(Some(1), '2'), Some(12));
+    assert_eq!(push_count_digit(None, '0'), None);
+    assert_eq!(push_count_digit(Some(usize::MAX), '9'), None);
+    assert_eq!(push_count_digit(None, 'x'), None);
+}
+
+#[test]
+#[serial]
+fn test_select_nth_selects_session() {
+    let mut env = create_test_env_with_sessions(5);
+    env.view.select_nth(3);
+    assert_eq!(env.view.cursor, 2);
+    assert!(env.view.selected_session.is_some());
+}
+
+#[test]
+#[serial]
+fn test_select_nth_clamps_to_last_session() {
+    let mut env = create_test_env_with_sessions(3);
+    env.view.select_nth(99);
+    assert_eq!(env.view.cursor, 2);
+    env.view.select_nth(0);
+    assert_eq!(env.view.cursor, 2);
+}
+
+#[test]
+#[serial]
+fn test_select_nth_skips_groups() {
+    let mut env = create_test_env_with_groups();
+    env.view.select_nth(2);
+    let Some(Item::Session { id, .. }) = env.view.flat_items.get(env.view.cursor) else {
+        panic!("expected a session to be selected");
+    };
+    assert_eq!(env.view.selected_session.as_deref(), Some(id.as_str()));
+    let sessions_before = env.view.flat_items[..env.view.cursor]
+        .iter()
+        .filter(|item| matches!(item, Item::Session { .. }))
+        .count();
+    assert_eq!(sessions_before, 1);
+}
+
+#[test]
+#[serial]
+fn test_digit_keys_accumulate_count() {
+    let mut env = create_test_env_with_sessions(15);
+    env.view.handle_key(key(KeyCode::Char('1')));
+    assert_eq!(env.view.pending_count, Some(1));
+    assert_eq!(env.view.cursor, 0);
+    env.view.handle_key(key(KeyCode::Char('2')));
+    assert_eq!(env.view.pending_count, Some(12));
+    assert_eq!(env.view.cursor, 11);
+    env.view.handle_key(key(KeyCode::Char('j')));
+    assert_eq!(env.view.pending_count, None);
+    assert_eq!(env.view.cursor, 12);
+}
+
+#[test]
+#[serial]
+fn test_g_and_shift_g_consume_count() {
+    let mut env = create_test_env_with_sessions(15);
+    env.view.handle_key(key(KeyCode::Char('5')));
+    env.view.handle_key(key(KeyCode::Char('G')));
+    assert_eq!(env.view.pending_count, None);
+    assert_eq!(env.view.cursor, 4);
+
+    env.view.handle_key(key(KeyCode::Char('G')));
+    assert_eq!(env.view.cursor, 14);
+
+    env.view.handle_key(key(KeyCode::Char('3')));
+    env.view.handle_key(key(KeyCode::Char('g')));
+    assert_eq!(env.view.pending_count, None);
+    assert_eq!(env.view.cursor, 2);
+
+    env.view.handle_key(key(KeyCode::Char('g')));
+    assert_eq!(env.view.cursor, 0);
+}
+
+#[test]
+#[serial]
+fn test_w_toggles_preview_line_mode_per_session() {
+    use crate::tui::components::LineMode;
+    let mut env = create_test_env_with_sessions(2);
+    let first = env.view.selected_session.clone().unwrap();
+    env.view.handle_key(key(KeyCode::Char('w')));
+    assert_eq!(env.view.get_preview_line_mode(&first), LineMode::Wrap);
+
+    env.view.handle_key(key(KeyCode::Down));
+    let second = env.view.selected_session.clone().unwrap();
+    assert_eq!(env.view.get_preview_line_mode(&second), LineMode::Truncate);
+
+    env.view.handle_key(key(KeyCode::Up));
+    assert_eq!(env.view.get_preview_line_mode(&first), LineMode::Wrap);
+}
+
+#[test]
+#[serial]
+fn test_arrow_keys_scroll_preview_only_in_truncate_mode() {
+    let mut env = create_test_env_with_sessions(1);
+    env.view.handle_key(key(KeyCode::Right));
+    env.view.handle_key(key(KeyCode::Right));
+    assert_eq!(env.view.preview_hscroll, 16);
+    env.view.handle_key(key(KeyCode::Left));
+    assert_eq!(env.view.preview_hscroll, 8);
+
+    env.view.handle_key(key(KeyCode::Char('w')));
+    assert_eq!(env.view.preview_hscroll, 0);
+    env.view.handle_key(key(KeyCode::Right));
+    assert_eq!(env.view.preview_hscroll, 0);
+}
+
+#[test]
+#[serial]
+fn test_reconcile_selection_prefers_saved_session() {
+    use super::reconcile_selection;
+    use crate::session::UiState;
+    let env = create_test_env_with_groups();
+    let (idx, id) = env
+        .view
+        .flat_items
+        .iter()
+        .enumerate()
+        .find_map(|(idx, item)| match item {
+            Item::Session { id, .. } => Some((idx, id.clone())),
+            _ => None,
+        })
+        .unwrap();
+    let state = UiState {
+        selected_session: Some(id),
+        selected_group: Some("work".to_string()),
+        terminal_view: false,
+        collapsed_groups: None,
+    };
+    assert_eq!(reconcile_selection(&env.view.flat_items, &state), Some(idx));
+}
+
+#[test]
+#[serial]
+fn test_reconcile_selection_falls_back_when_session_is_gone() {
+    use super::reconcile_selection;
+    use crate::session::UiState;
+    let env = create_test_env_with_groups();
+    let group_idx = env
+        .view
+        .flat_items
+        .iter()
+        .position(|item| matches!(item, Item::Group { path, .. } if path == "work"))
+        .unwrap();
+
+    let state = UiState {
+        selected_session: Some("deleted-session".to_string()),
+        selected_group: Some("work".to_string()),
+        terminal_view: false,
+        collapsed_groups: None,
+    };
+    assert_eq!(
+        reconcile_selection(&env.view.flat_items, &state),
+        Some(group_idx)
+    );
+
+    let state = UiState {
+        selected_session: Some("deleted-session".to_string()),
+        selected_group: None,
+        terminal_view: false,
+        collapsed_groups: None,
+    };
+    assert_eq!(reconcile_selection(&env.view.flat_items, &state), None);
+}
+
+#[test]
+fn test_reconcile_collapsed_drops_gone_groups_and_expands_new_ones() {
+    use super::reconcile_collapsed;
+    use crate::session::GroupTree;
+
+    let mut work = Instance::new("w", "/tmp/w");
+    work.group_path = "work".to_string();
+    let mut personal = Instance::new("p", "/tmp/p");
+    personal.group_path = "personal".to_string();
+    let mut tree = GroupTree::new_with_groups(&[work, personal], &[]);
+
+    let saved = vec!["work".to_string(), "deleted".to_string()];
+    let collapsed = reconcile_collapsed(&saved, &tree);
+    assert_eq!(
+        collapsed,
+        std::collections::HashSet::from(["work".to_string()])
+    );
+
+    tree.set_collapsed_paths(&collapsed);
+    assert_eq!(tree.collapsed_paths(), vec!["work".to_string()]);
+}
+
+#[test]
+#[serial]
+fn test_collapsed_groups_restored_from_ui_state() {
+    let temp = TempDir::new().unwrap();
+    setup_test_home(&temp);
+    let storage = Storage::new("test").unwrap();
+    let mut inst = Instance::new("w", "/tmp/w");
+    inst.group_path = "work".to_string();
+    storage.save(&[inst]).unwrap();
+    storage
+        .save_ui_state(&crate::session::UiState {
+            collapsed_groups: Some(vec!["work".to_string(), "gone".to_string()]),
+            ..Default::default()
+        })
+        .unwrap();
+
+    let tools = AvailableTools::with_tools(&["claude"]);
+    let view = HomeView::new(storage, tools).unwrap();
+    assert_eq!(view.group_tree.collapsed_paths(), vec!["work".to_string()]);
+    assert_eq!(view.flat_items.len(), 1);
+}
+
+#[test]
+#[serial]
+fn test_ui_state_restored_on_new() {
+    let temp = TempDir::new().unwrap();
+    setup_test_home(&temp);
+    let storage = Storage::new("test").unwrap();
+    let instances: Vec<Instance> = (0..3)
+        .map(|i| Instance::new(&format!("session{}", i), &format!("/tmp/{}", i)))
+        .collect();
+    storage.save(&instances).unwrap();
+
+    let tools = AvailableTools::with_tools(&["claude"]);
+    let mut view = HomeView::new(storage, tools.clone()).unwrap();
+    view.select_nth(3);
+    view.view_mode = ViewMode::Terminal;
+    let selected = view.selected_session.clone();
+    view.save_ui_state();
+
+    let view = HomeView::new(Storage::new("test").unwrap(), tools).unwrap();
+    assert_eq!(view.selected_session, selected);
+    assert_eq!(view.cursor, 2);
+    assert_eq!(view.view_mode, ViewMode::Terminal);
+}
+
+#[test]
+#[serial]
+fn test_ui_state_missing_session_selects_first_row() {
+    let temp = TempDir::new().unwrap();
+    setup_test_home(&temp);
+    let storage = Storage::new("test").unwrap();
+    storage.save(&[Instance::new("only", "/tmp/only")]).unwrap();
+    storage
+        .save_ui_state(&crate::session::UiState {
+            selected_session: Some("gone".to_string()),
+            ..Default::default()
+        })
+        .unwrap();
+
+    let tools = AvailableTools::with_tools(&["claude"]);
+    let view = HomeView::new(storage, tools).unwrap();
+    assert_eq!(view.cursor, 0);
+    assert!(view.selected_session.is_some());
+}
+
+#[test]
+fn test_should_confirm_quit() {
+    use super::should_confirm_quit;
+    use crate::session::Status;
+
+    let with_status = |status| {
+        let mut inst = Instance::new("s", "/tmp/s");
+        inst.status = status;
+        inst
+    };
+    let mixed = vec![
+        with_status(Status::Idle),
+        with_status(Status::Running),
+        with_status(Status::Waiting),
+    ];
+    let idle_only = vec![with_status(Status::Idle), with_status(Status::Waiting)];
+
+    assert!(should_confirm_quit(true, &mixed));
+    assert!(!should_confirm_quit(false, &mixed));
+    assert!(!should_confirm_quit(true, &idle_only));
+    assert!(!should_confirm_quit(true, &[]));
+
+    let mut archived = with_status(Status::Running);
+    archived.archived = true;
+    assert!(!should_confirm_quit(true, &[archived]));
+}
+
+#[test]
+#[serial]
+fn test_quit_shows_confirm_when_running() {
+    use crate::session::Status;
+    let mut env = create_test_env_with_sessions(2);
+    env.view.confirm_quit_when_running = true;
+    env.view.instances[0].status = Status::Running;
+
+    assert_eq!(env.view.handle_key(key(KeyCode::Char('q'))), None);
+    assert!(env.view.confirm_dialog.is_some());
+
+    let action = env.view.handle_key(key(KeyCode::Char('y')));
+    assert_eq!(action, Some(Action::Quit));
+}
+
+#[test]
+#[serial]
+fn test_manual_refresh_detects_selected_session_once() {
+    use std::time::Duration;
+
+    let mut env = create_test_env_with_sessions(3);
+    env.view.handle_key(key(KeyCode::Down));
+    let selected = env.view.selected_session.clone().unwrap();
+
+    let shift_r = KeyEvent::new(KeyCode::Char('R'), KeyModifiers::SHIFT);
+    assert_eq!(env.view.handle_key(shift_r), None);
+
+    let updates = env
+        .view
+        .status_poller
+        .recv_updates(Duration::from_secs(10))
+        .expect("refresh result");
+    assert_eq!(updates.len(), 1);
+    assert_eq!(updates[0].id, selected);
+    assert!(env
+        .view
+        .status_poller
+        .recv_updates(Duration::from_millis(200))
+        .is_none());
+}
@@ -7,8 +7,9 @@ mod deletion_poller;
 pub mod dialogs;
 pub mod diff;
 mod home;
+mod ready_poller;
 pub mod settings;
-mod status_poller;
+pub(crate) mod status_poller;
 mod styles;
 
 pub use app::*;
@@ -0,0 +1,75 @@
+//! Background sandbox readiness checks for TUI responsiveness
+//!
+//! A configured `sandbox.ready_probe` can take up to `ready_timeout_secs` to
+//! pass, so the container start and probe run on a background thread and the
+//! attach is retried once they finish.
+
+use std::sync::mpsc;
+use std::thread;
+
+use crate::session::Instance;
+
+#[derive(Debug)]
+pub enum ReadyEvent {
+    /// The probe has failed `attempt` times and is being retried
+    Waiting { session_id: String, attempt: u32 },
+    /// The probe passed, or gave up with an error
+    Done {
+        session_id: String,
+        error: Option<String>,
+    },
+}
+
+pub struct ReadyPoller {
+    request_tx: mpsc::Sender<Instance>,
+    event_rx: mpsc::Receiver<ReadyEvent>,
+    _handle: thread::JoinHandle<()>,
+}
+
+impl ReadyPoller {
+    pub fn new() -> Self {
+        let (request_tx, request_rx) = mpsc::channel::<Instance>();
+        let (event_tx, event_rx) = mpsc::channel::<ReadyEvent>();
+
+        let handle = thread::spawn(move || {
+            while let Ok(mut instance) = request_rx.recv() {
+                let session_id = instance.id.clone();
+                let result = instance.wait_until_sandbox_ready(|attempt| {
+                    let _ = event_tx.send(ReadyEvent::Waiting {
+                        session_id: session_id.clone(),
+                        attempt,
+                    });
+                });
+                let done = ReadyEvent::Done {
+                    session_id,
+                    error: result.err().map(|e| format!("{:#}", e)),
+                };
+                if event_tx.send(done).is_err() {
+                    break;
+                }
+            }
+        });
+
+        Self {
+            request_tx,
+            event_rx,
+            _handle: handle,
+        }
+    }
+
+    pub fn request(&self, instance: Instance) {
+        if self.request_tx.send(instance).is_err() {
+            tracing::error!("Failed to send readiness request: receiver thread died");
+        }
+    }
+
+    pub fn try_recv(&self) -> Option<ReadyEvent> {
+        self.event_rx.try_recv().ok()
+    }
+}
+
+impl Default for ReadyPoller {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -3,8 +3,8 @@
 use std::collections::HashMap;
 
 use crate::session::{
-    validate_check_interval, Config, ContainerRuntimeName, DefaultTerminalMode, ProfileConfig,
-    TmuxMouseMode, TmuxStatusBarMode,
+    validate_check_interval, Config, ContainerNameScheme, ContainerRuntimeName,
+    DefaultTerminalMode, ProfileConfig, RelaunchOnAttach, TmuxMouseMode, TmuxStatusBarMode,
 };
 use crate::sound::{validate_sound_exists, SoundMode};
 use crate::tui::styles::AVAILABLE_THEMES;
@@ -57,22 +57,44 @@ pub enum FieldKey {
     SandboxEnabledByDefault,
     YoloModeDefault,
     DefaultImage,
+    ToolImages,
     Environment,
     EnvironmentValues,
+    ContainerLabels,
     SandboxAutoCleanup,
+    SandboxAutoStopIdle,
+    SandboxIdleStopMinutes,
     CpuLimit,
     MemoryLimit,
     DefaultTerminalMode,
     ExtraVolumes,
     VolumeIgnores,
     MountSsh,
+    StrictMounts,
+    StrictEnv,
+    SandboxReadOnlyRoot,
+    SandboxInit,
+    ExtraHosts,
+    SandboxHostGateway,
+    SandboxMaxContainers,
+    SandboxReadyProbe,
+    SandboxReadyTimeout,
+    Dotfiles,
     CustomInstruction,
     ContainerRuntime,
+    ContainerNameScheme,
     // Tmux
     StatusBar,
     Mouse,
+    SessionNameLength,
+    TmuxOptions,
     // Session
     DefaultTool,
+    CommandTemplates,
+    PollIntervalMs,
+    ConfirmQuitWhenRunning,
+    AutoNudgeSeconds,
+    RelaunchOnAttach,
     // Sound
     SoundEnabled,
     SoundMode,
@@ -170,6 +192,9 @@ impl SettingField {
                 validate_check_interval(*n)?;
                 Ok(())
             }
+            (FieldKey::SessionNameLength, FieldValue::Number(0)) => {
+                Err("Session name length must be at least 1".to_string())
+            }
             (FieldKey::MemoryLimit, FieldValue::OptionalText(Some(v))) => {
                 crate::session::validate_memory_limit(v)?;
                 Ok(())
@@ -391,11 +416,44 @@ fn build_sandbox_fields(
         entries.sort();
         entries
     };
+    let (tool_images, o_tool_images) = resolve_value(
+        scope,
+        global.sandbox.tool_images.clone(),
+        sb.and_then(|s| s.tool_images.clone()),
+    );
+    let tool_images_list = {
+        let mut entries: Vec<String> = tool_images
+            .iter()
+            .map(|(k, v)| format!("{k}={v}"))
+            .collect();
+        entries.sort();
+        entries
+    };
+    let (labels, o_labels) = resolve_value(
+        scope,
+        global.sandbox.labels.clone(),
+        sb.and_then(|s| s.labels.clone()),
+    );
+    let labels_list = {
+        let mut entries: Vec<String> = labels.iter().map(|(k, v)| format!("{k}={v}")).collect();
+        entries.sort();
+        entries
+    };
     let (auto_cleanup, o5) = resolve_value(
         scope,
         global.sandbox.auto_cleanup,
         sb.and_then(|s| s.auto_cleanup),
     );
+    let (auto_stop_idle, o_asi) = resolve_value(
+        scope,
+        global.sandbox.auto_stop_idle,
+        sb.and_then(|s| s.auto_stop_idle),
+    );
+    let (idle_stop_minutes, o_ism) = resolve_value(
+        scope,
+        global.sandbox.idle_stop_minutes,
+        sb.and_then(|s| s.idle_stop_minutes),
+    );
     let (cpu_limit, o_cpu) = resolve_optional(
         scope,
         global.sandbox.cpu_limit.clone(),
@@ -428,6 +486,53 @@ fn build_sandbox_fields(
         global.sandbox.mount_ssh,
         sb.and_then(|s| s.mount_ssh),
     );
+    let (strict_mounts, o_sm) = resolve_value(
+        scope,
+        global.sandbox.strict_mounts,
+        sb.and_then(|s| s.strict_mounts),
+    );
+    let (strict_env, o_se) = resolve_value(
+        scope,
+        global.sandbox.strict_env,
+        sb.and_then(|s| s.strict_env),
+    );
+    let (read_only_root, o_ro) = resolve_value(
+        scope,
+        global.sandbox.read_only_root,
+        sb.and_then(|s| s.read_only_root),
+    );
+    let (init, o_init) = resolve_value(scope, global.sandbox.init, sb.and_then(|s| s.init));
+    let (extra_hosts, o_eh) = resolve_value(
+        scope,
+        global.sandbox.extra_hosts.clone(),
+        sb.and_then(|s| s.extra_hosts.clone()),
+    );
+    let (host_gateway, o_hg) = resolve_value(
+        scope,
+        global.sandbox.host_gateway,
+        sb.and_then(|s| s.host_gateway),
+    );
+    let (max_containers, o_mc) = resolve_value(
+        scope,
+        global.sandbox.max_containers,
+        sb.and_then(|s| s.max_containers),
+    );
+    let (ready_probe, o_rp) = resolve_optional(
+        scope,
+        global.sandbox.ready_probe.clone(),
+        sb.and_then(|s| s.ready_probe.clone()),
+        sb.map(|s| s.ready_probe.is_some()).unwrap_or(false),
+    );
+    let (ready_timeout_secs, o_rt) = resolve_value(
+        scope,
+        global.sandbox.ready_timeout_secs,
+        sb.and_then(|s| s.ready_timeout_secs),
+    );
+    let (dotfiles, o_dot) = resolve_value(
+        scope,
+        global.sandbox.dotfiles.clone(),
+        sb.and_then(|s| s.dotfiles.clone()),
+    );
     let (custom_instruction, o_ci) = resolve_optional(
         scope,
         global.sandbox.custom_instruction.clone(),
@@ -450,6 +555,16 @@ fn build_sandbox_fields(
         ContainerRuntimeName::AppleContainer => 1,
     };
 
+    let (name_scheme, o_ns) = resolve_value(
+        scope,
+        global.sandbox.container_name_scheme,
+        sb.and_then(|s| s.container_name_scheme),
+    );
+    let name_scheme_selected = match name_scheme {
+        ContainerNameScheme::Id => 0,
+        ContainerNameScheme::ProfileTitle => 1,
+    };
+
     vec![
         SettingField {
             key: FieldKey::SandboxEnabledByDefault,
@@ -467,6 +582,14 @@ fn build_sandbox_fields(
             category: SettingsCategory::Sandbox,
             has_override: o3,
         },
+        SettingField {
+            key: FieldKey::ToolImages,
+            label: "Tool Images",
+            description: "TOOL=IMAGE defaults that replace Default Image for that tool",
+            value: FieldValue::List(tool_images_list),
+            category: SettingsCategory::Sandbox,
+            has_override: o_tool_images,
+        },
         SettingField {
             key: FieldKey::Environment,
             label: "Environment Variables",
@@ -483,6 +606,14 @@ fn build_sandbox_fields(
             category: SettingsCategory::Sandbox,
             has_override: o_env_vals,
         },
+        SettingField {
+            key: FieldKey::ContainerLabels,
+            label: "Container Labels",
+            description: "Extra KEY=VALUE labels for sandbox containers (aoe.* are reserved)",
+            value: FieldValue::List(labels_list),
+            category: SettingsCategory::Sandbox,
+            has_override: o_labels,
+        },
         SettingField {
             key: FieldKey::SandboxAutoCleanup,
             label: "Auto Cleanup",
@@ -491,6 +622,22 @@ fn build_sandbox_fields(
             category: SettingsCategory::Sandbox,
             has_override: o5,
         },
+        SettingField {
+            key: FieldKey::SandboxAutoStopIdle,
+            label: "Auto-stop Idle Containers",
+            description: "Stop containers after the idle period; restarted on attach",
+            value: FieldValue::Bool(auto_stop_idle),
+            category: SettingsCategory::Sandbox,
+            has_override: o_asi,
+        },
+        SettingField {
+            key: FieldKey::SandboxIdleStopMinutes,
+            label: "Idle Stop (minutes)",
+            description: "Minutes without agent output before a container is stopped",
+            value: FieldValue::Number(idle_stop_minutes),
+            category: SettingsCategory::Sandbox,
+            has_override: o_ism,
+        },
         SettingField {
             key: FieldKey::CpuLimit,
             label: "CPU Limit",
@@ -542,6 +689,86 @@ fn build_sandbox_fields(
             category: SettingsCategory::Sandbox,
             has_override: o8,
         },
+        SettingField {
+            key: FieldKey::StrictMounts,
+            label: "Strict Mounts",
+            description: "Refuse to create a container when a bind mount's host path is missing",
+            value: FieldValue::Bool(strict_mounts),
+            category: SettingsCategory::Sandbox,
+            has_override: o_sm,
+        },
+        SettingField {
+            key: FieldKey::StrictEnv,
+            label: "Strict Env",
+            description: "Refuse to create a container when an env value references an unset host var",
+            value: FieldValue::Bool(strict_env),
+            category: SettingsCategory::Sandbox,
+            has_override: o_se,
+        },
+        SettingField {
+            key: FieldKey::SandboxReadOnlyRoot,
+            label: "Read-only Root",
+            description: "Read-only container root filesystem; only mounts and /tmp are writable",
+            value: FieldValue::Bool(read_only_root),
+            category: SettingsCategory::Sandbox,
+            has_override: o_ro,
+        },
+        SettingField {
+            key: FieldKey::SandboxInit,
+            label: "Init Process",
+            description: "Run an init as PID 1 to reap exited agent processes (recommended)",
+            value: FieldValue::Bool(init),
+            category: SettingsCategory::Sandbox,
+            has_override: o_init,
+        },
+        SettingField {
+            key: FieldKey::ExtraHosts,
+            label: "Extra Hosts",
+            description: "Container /etc/hosts entries as hostname:ip (ip may be host-gateway)",
+            value: FieldValue::List(extra_hosts),
+            category: SettingsCategory::Sandbox,
+            has_override: o_eh,
+        },
+        SettingField {
+            key: FieldKey::SandboxHostGateway,
+            label: "Host Gateway",
+            description: "Map host.docker.internal to the host on Linux",
+            value: FieldValue::Bool(host_gateway),
+            category: SettingsCategory::Sandbox,
+            has_override: o_hg,
+        },
+        SettingField {
+            key: FieldKey::SandboxMaxContainers,
+            label: "Max Running Containers",
+            description: "Refuse to start more sandbox containers than this (0 = no limit)",
+            value: FieldValue::Number(max_containers),
+            category: SettingsCategory::Sandbox,
+            has_override: o_mc,
+        },
+        SettingField {
+            key: FieldKey::SandboxReadyProbe,
+            label: "Readiness Probe",
+            description: "Command that must succeed in the container before the agent starts",
+            value: FieldValue::OptionalText(ready_probe),
+            category: SettingsCategory::Sandbox,
+            has_override: o_rp,
+        },
+        SettingField {
+            key: FieldKey::SandboxReadyTimeout,
+            label: "Readiness Timeout (secs)",
+            description: "How long to retry the readiness probe before giving up",
+            value: FieldValue::Number(ready_timeout_secs),
+            category: SettingsCategory::Sandbox,
+            has_override: o_rt,
+        },
+        SettingField {
+            key: FieldKey::Dotfiles,
+            label: "Dotfiles",
+            description: "Home-relative paths mounted read-only (e.g. .gitconfig, .config/gh)",
+            value: FieldValue::List(dotfiles),
+            category: SettingsCategory::Sandbox,
+            has_override: o_dot,
+        },
         SettingField {
             key: FieldKey::CustomInstruction,
             label: "Custom Instruction",
@@ -561,6 +788,17 @@ fn build_sandbox_fields(
             category: SettingsCategory::Sandbox,
             has_override: o_cr,
         },
+        SettingField {
+            key: FieldKey::ContainerNameScheme,
+            label: "Container Names",
+            description: "How new containers are named; existing containers keep their name",
+            value: FieldValue::Select {
+                selected: name_scheme_selected,
+                options: vec!["Session ID".into(), "Profile + Title".into()],
+            },
+            category: SettingsCategory::Sandbox,
+            has_override: o_ns,
+        },
     ]
 }
 
@@ -580,6 +818,23 @@ fn build_tmux_fields(
     let (mouse, mouse_override) =
         resolve_value(scope, global.tmux.mouse, tmux.and_then(|t| t.mouse));
 
+    let (name_length, name_length_override) = resolve_value(
+        scope,
+        global.tmux.session_name_length,
+        tmux.and_then(|t| t.session_name_length),
+    );
+
+    let (options, options_override) = resolve_value(
+        scope,
+        global.tmux.options.clone(),
+        tmux.and_then(|t| t.options.clone()),
+    );
+    let options_list = {
+        let mut entries: Vec<String> = options.iter().map(|(k, v)| format!("{k}={v}")).collect();
+        entries.sort();
+        entries
+    };
+
     let status_bar_selected = match status_bar {
         TmuxStatusBarMode::Auto => 0,
         TmuxStatusBarMode::Enabled => 1,
@@ -615,6 +870,24 @@ fn build_tmux_fields(
             category: SettingsCategory::Tmux,
             has_override: mouse_override,
         },
+        SettingField {
+            key: FieldKey::SessionNameLength,
+            label: "Session Name Length",
+            description:
+                "Title characters kept in tmux session names (changing it orphans running sessions)",
+            value: FieldValue::Number(name_length as u64),
+            category: SettingsCategory::Tmux,
+            has_override: name_length_override,
+        },
+        SettingField {
+            key: FieldKey::TmuxOptions,
+            label: "tmux Options",
+            description:
+                "Options set on each session after creation (NAME=VALUE, e.g. history-limit=50000)",
+            value: FieldValue::List(options_list),
+            category: SettingsCategory::Tmux,
+            has_override: options_override,
+        },
     ]
 }
 
@@ -643,6 +916,46 @@ fn build_session_fields(
         session.and_then(|s| s.yolo_mode_default),
     );
 
+    let (poll_interval_ms, poll_override) = resolve_value(
+        scope,
+        global.session.poll_interval_ms,
+        session.and_then(|s| s.poll_interval_ms),
+    );
+
+    let (confirm_quit, confirm_quit_override) = resolve_value(
+        scope,
+        global.session.confirm_quit_when_running,
+        session.and_then(|s| s.confirm_quit_when_running),
+    );
+
+    let (auto_nudge_seconds, auto_nudge_override) = resolve_value(
+        scope,
+        global.session.auto_nudge_seconds,
+        session.and_then(|s| s.auto_nudge_seconds),
+    );
+
+    let (relaunch, relaunch_override) = resolve_value(
+        scope,
+        global.session.relaunch_on_attach,
+        session.and_then(|s| s.relaunch_on_attach),
+    );
+    let relaunch_selected = match relaunch {
+        RelaunchOnAttach::Prompt => 0,
+        RelaunchOnAttach::Auto => 1,
+        RelaunchOnAttach::Off => 2,
+    };
+
+    let (commands, commands_override) =
+        resolve_value(scope, global.commands.clone(), profile.commands.clone());
+    let commands_list = {
+        let mut entries: Vec<String> = commands
+            .iter()
+            .map(|(tool, template)| format!("{tool}={template}"))
+            .collect();
+        entries.sort();
+        entries
+    };
+
     vec![
         SettingField {
             key: FieldKey::DefaultTool,
@@ -660,6 +973,49 @@ fn build_session_fields(
             category: SettingsCategory::Session,
             has_override: yolo_override,
         },
+        SettingField {
+            key: FieldKey::CommandTemplates,
+            label: "Launch Commands",
+            description: "Per-tool launch templates as tool=command ({workdir}, {resume_id})",
+            value: FieldValue::List(commands_list),
+            category: SettingsCategory::Session,
+            has_override: commands_override,
+        },
+        SettingField {
+            key: FieldKey::PollIntervalMs,
+            label: "Status Poll Interval (ms)",
+            description: "How often the TUI refreshes session statuses (minimum 250)",
+            value: FieldValue::Number(poll_interval_ms),
+            category: SettingsCategory::Session,
+            has_override: poll_override,
+        },
+        SettingField {
+            key: FieldKey::ConfirmQuitWhenRunning,
+            label: "Confirm Quit When Running",
+            description: "Ask before quitting the TUI while any session is running",
+            value: FieldValue::Bool(confirm_quit),
+            category: SettingsCategory::Session,
+            has_override: confirm_quit_override,
+        },
+        SettingField {
+            key: FieldKey::AutoNudgeSeconds,
+            label: "Auto-Nudge Waiting (seconds)",
+            description: "Press Enter once in sessions stuck waiting this long; never on approval prompts (0 = off)",
+            value: FieldValue::Number(auto_nudge_seconds),
+            category: SettingsCategory::Session,
+            has_override: auto_nudge_override,
+        },
+        SettingField {
+            key: FieldKey::RelaunchOnAttach,
+            label: "Relaunch Exited Agent",
+            description: "On attach to a host session whose agent exited: ask, relaunch, or do nothing",
+            value: FieldValue::Select {
+                selected: relaunch_selected,
+                options: vec!["Prompt".into(), "Auto".into(), "Off".into()],
+            },
+            category: SettingsCategory::Session,
+            has_override: relaunch_override,
+        },
     ]
 }
 
@@ -857,15 +1213,51 @@ fn apply_field_to_global(field: &SettingField, config: &mut Config) {
             config.sandbox.enabled_by_default = *v
         }
         (FieldKey::YoloModeDefault, FieldValue::Bool(v)) => config.session.yolo_mode_default = *v,
+        (FieldKey::PollIntervalMs, FieldValue::Number(v)) => config.session.poll_interval_ms = *v,
+        (FieldKey::ConfirmQuitWhenRunning, FieldValue::Bool(v)) => {
+            config.session.confirm_quit_when_running = *v
+        }
+        (FieldKey::AutoNudgeSeconds, FieldValue::Number(v)) => {
+            config.session.auto_nudge_seconds = *v
+        }
+        (FieldKey::RelaunchOnAttach, FieldValue::Select { selected, .. }) => {
+            config.session.relaunch_on_attach = relaunch_from_index(*selected)
+        }
         (FieldKey::DefaultImage, FieldValue::Text(v)) => config.sandbox.default_image = v.clone(),
+        (FieldKey::ToolImages, FieldValue::List(v)) => {
+            config.sandbox.tool_images = parse_key_value_list(v);
+        }
         (FieldKey::Environment, FieldValue::List(v)) => config.sandbox.environment = v.clone(),
         (FieldKey::EnvironmentValues, FieldValue::List(v)) => {
-            config.sandbox.environment_values = parse_env_values_list(v);
+            config.sandbox.environment_values = parse_key_value_list(v);
+        }
+        (FieldKey::ContainerLabels, FieldValue::List(v)) => {
+            config.sandbox.labels = parse_key_value_list(v);
         }
         (FieldKey::ExtraVolumes, FieldValue::List(v)) => config.sandbox.extra_volumes = v.clone(),
         (FieldKey::VolumeIgnores, FieldValue::List(v)) => config.sandbox.volume_ignores = v.clone(),
+        (FieldKey::Dotfiles, FieldValue::List(v)) => config.sandbox.dotfiles = v.clone(),
         (FieldKey::MountSsh, FieldValue::Bool(v)) => config.sandbox.mount_ssh = *v,
+        (FieldKey::StrictMounts, FieldValue::Bool(v)) => config.sandbox.strict_mounts = *v,
+        (FieldKey::StrictEnv, FieldValue::Bool(v)) => config.sandbox.strict_env = *v,
+        (FieldKey::SandboxReadOnlyRoot, FieldValue::Bool(v)) => config.sandbox.read_only_root = *v,
+        (FieldKey::SandboxInit, FieldValue::Bool(v)) => config.sandbox.init = *v,
+        (FieldKey::ExtraHosts, FieldValue::List(v)) => config.sandbox.extra_hosts = v.clone(),
+        (FieldKey::SandboxHostGateway, FieldValue::Bool(v)) => config.sandbox.host_gateway = *v,
+        (FieldKey::SandboxMaxContainers, FieldValue::Number(v)) => {
+            config.sandbox.max_containers = *v;
+        }
+        (FieldKey::SandboxReadyProbe, FieldValue::OptionalText(v)) => {
+            config.sandbox.ready_probe = v.clone();
+        }
+        (FieldKey::SandboxReadyTimeout, FieldValue::Number(v)) => {
+            config.sandbox.ready_timeout_secs = *v
+        }
         (FieldKey::SandboxAutoCleanup, FieldValue::Bool(v)) => config.sandbox.auto_cleanup = *v,
+        (FieldKey::SandboxAutoStopIdle, FieldValue::Bool(v)) => config.sandbox.auto_stop_idle = *v,
+        (FieldKey::SandboxIdleStopMinutes, FieldValue::Number(v)) => {
+            config.sandbox.idle_stop_minutes = *v
+        }
         (FieldKey::CpuLimit, FieldValue::OptionalText(v)) => {
             config.sandbox.cpu_limit = v.clone();
         }
@@ -887,6 +1279,12 @@ fn apply_field_to_global(field: &SettingField, config: &mut Config) {
                 _ => ContainerRuntimeName::AppleContainer,
             };
         }
+        (FieldKey::ContainerNameScheme, FieldValue::Select { selected, .. }) => {
+            config.sandbox.container_name_scheme = match selected {
+                0 => ContainerNameScheme::Id,
+                _ => ContainerNameScheme::ProfileTitle,
+            };
+        }
         // Tmux
         (FieldKey::StatusBar, FieldValue::Select { selected, .. }) => {
             config.tmux.status_bar = match selected {
@@ -902,11 +1300,20 @@ fn apply_field_to_global(field: &SettingField, config: &mut Config) {
                 _ => TmuxMouseMode::Disabled,
             };
         }
+        (FieldKey::SessionNameLength, FieldValue::Number(v)) => {
+            config.tmux.session_name_length = *v as usize
+        }
+        (FieldKey::TmuxOptions, FieldValue::List(v)) => {
+            config.tmux.options = parse_key_value_list(v);
+        }
         // Session
         (FieldKey::DefaultTool, FieldValue::Select { selected, .. }) => {
             config.session.default_tool =
                 crate::agents::name_from_settings_index(*selected).map(|s| s.to_string());
         }
+        (FieldKey::CommandTemplates, FieldValue::List(v)) => {
+            config.commands = parse_key_value_list(v);
+        }
         // Sound
         (FieldKey::SoundEnabled, FieldValue::Bool(v)) => config.sound.enabled = *v,
         (FieldKey::SoundMode, FieldValue::Select { selected, .. }) => {
@@ -1031,6 +1438,15 @@ fn apply_field_to_profile(field: &SettingField, global: &Config, config: &mut Pr
                 |s, val| s.default_image = val,
             );
         }
+        (FieldKey::ToolImages, FieldValue::List(v)) => {
+            let map = parse_key_value_list(v);
+            set_or_clear_override(
+                map,
+                &global.sandbox.tool_images,
+                &mut config.sandbox,
+                |s, val| s.tool_images = val,
+            );
+        }
         (FieldKey::Environment, FieldValue::List(v)) => {
             set_or_clear_override(
                 v.clone(),
@@ -1040,7 +1456,7 @@ fn apply_field_to_profile(field: &SettingField, global: &Config, config: &mut Pr
             );
         }
         (FieldKey::EnvironmentValues, FieldValue::List(v)) => {
-            let map = parse_env_values_list(v);
+            let map = parse_key_value_list(v);
             set_or_clear_override(
                 map,
                 &global.sandbox.environment_values,
@@ -1048,6 +1464,15 @@ fn apply_field_to_profile(field: &SettingField, global: &Config, config: &mut Pr
                 |s, val| s.environment_values = val,
             );
         }
+        (FieldKey::ContainerLabels, FieldValue::List(v)) => {
+            let map = parse_key_value_list(v);
+            set_or_clear_override(
+                map,
+                &global.sandbox.labels,
+                &mut config.sandbox,
+                |s, val| s.labels = val,
+            );
+        }
         (FieldKey::ExtraVolumes, FieldValue::List(v)) => {
             set_or_clear_override(
                 v.clone(),
@@ -1064,6 +1489,14 @@ fn apply_field_to_profile(field: &SettingField, global: &Config, config: &mut Pr
                 |s, val| s.volume_ignores = val,
             );
         }
+        (FieldKey::Dotfiles, FieldValue::List(v)) => {
+            set_or_clear_override(
+                v.clone(),
+                &global.sandbox.dotfiles,
+                &mut config.sandbox,
+                |s, val| s.dotfiles = val,
+            );
+        }
         (FieldKey::MountSsh, FieldValue::Bool(v)) => {
             set_or_clear_override(
                 *v,
@@ -1072,6 +1505,80 @@ fn apply_field_to_profile(field: &SettingField, global: &Config, config: &mut Pr
                 |s, val| s.mount_ssh = val,
             );
         }
+        (FieldKey::StrictMounts, FieldValue::Bool(v)) => {
+            set_or_clear_override(
+                *v,
+                &global.sandbox.strict_mounts,
+                &mut config.sandbox,
+                |s, val| s.strict_mounts = val,
+            );
+        }
+        (FieldKey::StrictEnv, FieldValue::Bool(v)) => {
+            set_or_clear_override(
+                *v,
+                &global.sandbox.strict_env,
+                &mut config.sandbox,
+                |s, val| s.strict_env = val,
+            );
+        }
+        (FieldKey::SandboxReadOnlyRoot, FieldValue::Bool(v)) => {
+            set_or_clear_override(
+                *v,
+                &global.sandbox.read_only_root,
+                &mut config.sandbox,
+                |s, val| s.read_only_root = val,
+            );
+        }
+        (FieldKey::SandboxInit, FieldValue::Bool(v)) => {
+            set_or_clear_override(*v, &global.sandbox.init, &mut config.sandbox, |s, val| {
+                s.init = val
+            });
+        }
+        (FieldKey::ExtraHosts, FieldValue::List(v)) => {
+            set_or_clear_override(
+                v.clone(),
+                &global.sandbox.extra_hosts,
+                &mut config.sandbox,
+                |s, val| s.extra_hosts = val,
+            );
+        }
+        (FieldKey::SandboxHostGateway, FieldValue::Bool(v)) => {
+            set_or_clear_override(
+                *v,
+                &global.sandbox.host_gateway,
+                &mut config.sandbox,
+                |s, val| s.host_gateway = val,
+            );
+        }
+        (FieldKey::SandboxMaxContainers, FieldValue::Number(v)) => {
+            set_or_clear_override(
+                *v,
+                &global.sandbox.max_containers,
+                &mut config.sandbox,
+                |s, val| s.max_containers = val,
+            );
+        }
+        (FieldKey::SandboxReadyProbe, FieldValue::OptionalText(v)) => {
+            if *v == global.sandbox.ready_probe {
+                if let Some(ref mut s) = config.sandbox {
+                    s.ready_probe = None;
+                }
+            } else {
+                use crate::session::SandboxConfigOverride;
+                let s = config
+                    .sandbox
+                    .get_or_insert_with(SandboxConfigOverride::default);
+                s.ready_probe = v.clone();
+            }
+        }
+        (FieldKey::SandboxReadyTimeout, FieldValue::Number(v)) => {
+            set_or_clear_override(
+                *v,
+                &global.sandbox.ready_timeout_secs,
+                &mut config.sandbox,
+                |s, val| s.ready_timeout_secs = val,
+            );
+        }
         (FieldKey::SandboxAutoCleanup, FieldValue::Bool(v)) => {
             set_or_clear_override(
                 *v,
@@ -1080,6 +1587,22 @@ fn apply_field_to_profile(field: &SettingField, global: &Config, config: &mut Pr
                 |s, val| s.auto_cleanup = val,
             );
         }
+        (FieldKey::SandboxAutoStopIdle, FieldValue::Bool(v)) => {
+            set_or_clear_override(
+                *v,
+                &global.sandbox.auto_stop_idle,
+                &mut config.sandbox,
+                |s, val| s.auto_stop_idle = val,
+            );
+        }
+        (FieldKey::SandboxIdleStopMinutes, FieldValue::Number(v)) => {
+            set_or_clear_override(
+                *v,
+                &global.sandbox.idle_stop_minutes,
+                &mut config.sandbox,
+                |s, val| s.idle_stop_minutes = val,
+            );
+        }
         (FieldKey::CpuLimit, FieldValue::OptionalText(v)) => {
             if *v == global.sandbox.cpu_limit {
                 if let Some(ref mut s) = config.sandbox {
@@ -1143,6 +1666,18 @@ fn apply_field_to_profile(field: &SettingField, global: &Config, config: &mut Pr
                 |s, val| s.container_runtime = val,
             );
         }
+        (FieldKey::ContainerNameScheme, FieldValue::Select { selected, .. }) => {
+            let scheme = match selected {
+                0 => ContainerNameScheme::Id,
+                _ => ContainerNameScheme::ProfileTitle,
+            };
+            set_or_clear_override(
+                scheme,
+                &global.sandbox.container_name_scheme,
+                &mut config.sandbox,
+                |s, val| s.container_name_scheme = val,
+            );
+        }
         // Tmux
         (FieldKey::StatusBar, FieldValue::Select { selected, .. }) => {
             let mode = match selected {
@@ -1164,6 +1699,20 @@ fn apply_field_to_profile(field: &SettingField, global: &Config, config: &mut Pr
                 s.mouse = val
             });
         }
+        (FieldKey::SessionNameLength, FieldValue::Number(v)) => {
+            set_or_clear_override(
+                *v as usize,
+                &global.tmux.session_name_length,
+                &mut config.tmux,
+                |s, val| s.session_name_length = val,
+            );
+        }
+        (FieldKey::TmuxOptions, FieldValue::List(v)) => {
+            let map = parse_key_value_list(v);
+            set_or_clear_override(map, &global.tmux.options, &mut config.tmux, |s, val| {
+                s.options = val
+            });
+        }
         // Session
         (FieldKey::DefaultTool, FieldValue::Select { selected, .. }) => {
             let tool = crate::agents::name_from_settings_index(*selected).map(|s| s.to_string());
@@ -1187,6 +1736,46 @@ fn apply_field_to_profile(field: &SettingField, global: &Config, config: &mut Pr
                 |s, val| s.yolo_mode_default = val,
             );
         }
+        (FieldKey::PollIntervalMs, FieldValue::Number(v)) => {
+            set_or_clear_override(
+                *v,
+                &global.session.poll_interval_ms,
+                &mut config.session,
+                |s, val| s.poll_interval_ms = val,
+            );
+        }
+        (FieldKey::ConfirmQuitWhenRunning, FieldValue::Bool(v)) => {
+            set_or_clear_override(
+                *v,
+                &global.session.confirm_quit_when_running,
+                &mut config.session,
+                |s, val| s.confirm_quit_when_running = val,
+            );
+        }
+        (FieldKey::AutoNudgeSeconds, FieldValue::Number(v)) => {
+            set_or_clear_override(
+                *v,
+                &global.session.auto_nudge_seconds,
+                &mut config.session,
+                |s, val| s.auto_nudge_seconds = val,
+            );
+        }
+        (FieldKey::RelaunchOnAttach, FieldValue::Select { selected, .. }) => {
+            set_or_clear_override(
+                relaunch_from_index(*selected),
+                &global.session.relaunch_on_attach,
+                &mut config.session,
+                |s, val| s.relaunch_on_attach = val,
+            );
+        }
+        (FieldKey::CommandTemplates, FieldValue::List(v)) => {
+            let map = parse_key_value_list(v);
+            config.commands = if map == global.commands {
+                None
+            } else {
+                Some(map)
+            };
+        }
         // Sound
         (FieldKey::SoundEnabled, FieldValue::Bool(v)) => {
             set_or_clear_override(*v, &global.sound.enabled, &mut config.sound, |s, val| {
@@ -1283,7 +1872,15 @@ fn apply_field_to_profile(field: &SettingField, global: &Config, config: &mut Pr
     }
 }
 
-fn parse_env_values_list(entries: &[String]) -> HashMap<String, String> {
+fn relaunch_from_index(selected: usize) -> RelaunchOnAttach {
+    match selected {
+        0 => RelaunchOnAttach::Prompt,
+        1 => RelaunchOnAttach::Auto,
+        _ => RelaunchOnAttach::Off,
+    }
+}
+
+fn parse_key_value_list(entries: &[String]) -> HashMap<String, String> {
     entries
         .iter()
         .filter_map(|entry| {
@@ -1291,7 +1888,7 @@ fn parse_env_values_list(entries: &[String]) -> HashMap<String, String> {
                 Some((key.to_string(), value.to_string()))
             } else {
                 tracing::warn!(
-                    "Ignoring malformed environment value (missing '='): {}",
+                    "Ignoring malformed KEY=VALUE entry (missing '='): {}",
                     entry
                 );
                 None
@@ -486,6 +486,11 @@ impl SettingsView {
                     s.default_image = None;
                 }
             }
+            FieldKey::ToolImages => {
+                if let Some(ref mut s) = config.sandbox {
+                    s.tool_images = None;
+                }
+            }
             FieldKey::Environment => {
                 if let Some(ref mut s) = config.sandbox {
                     s.environment = None;
@@ -496,11 +501,26 @@ impl SettingsView {
                     s.environment_values = None;
                 }
             }
+            FieldKey::ContainerLabels => {
+                if let Some(ref mut s) = config.sandbox {
+                    s.labels = None;
+                }
+            }
             FieldKey::SandboxAutoCleanup => {
                 if let Some(ref mut s) = config.sandbox {
                     s.auto_cleanup = None;
                 }
             }
+            FieldKey::SandboxAutoStopIdle => {
+                if let Some(ref mut s) = config.sandbox {
+                    s.auto_stop_idle = None;
+                }
+            }
+            FieldKey::SandboxIdleStopMinutes => {
+                if let Some(ref mut s) = config.sandbox {
+                    s.idle_stop_minutes = None;
+                }
+            }
             // Tmux
             FieldKey::StatusBar => {
                 if let Some(ref mut t) = config.tmux {
@@ -512,6 +532,16 @@ impl SettingsView {
                     t.mouse = None;
                 }
             }
+            FieldKey::SessionNameLength => {
+                if let Some(ref mut t) = config.tmux {
+                    t.session_name_length = None;
+                }
+            }
+            FieldKey::TmuxOptions => {
+                if let Some(ref mut t) = config.tmux {
+                    t.options = None;
+                }
+            }
             // Session
             FieldKey::DefaultTool => {
                 if let Some(ref mut s) = config.session {
@@ -528,6 +558,29 @@ impl SettingsView {
                     s.yolo_mode_default = None;
                 }
             }
+            FieldKey::CommandTemplates => {
+                config.commands = None;
+            }
+            FieldKey::PollIntervalMs => {
+                if let Some(ref mut s) = config.session {
+                    s.poll_interval_ms = None;
+                }
+            }
+            FieldKey::ConfirmQuitWhenRunning => {
+                if let Some(ref mut s) = config.session {
+                    s.confirm_quit_when_running = None;
+                }
+            }
+            FieldKey::AutoNudgeSeconds => {
+                if let Some(ref mut s) = config.session {
+                    s.auto_nudge_seconds = None;
+                }
+            }
+            FieldKey::RelaunchOnAttach => {
+                if let Some(ref mut s) = config.session {
+                    s.relaunch_on_attach = None;
+                }
+            }
             FieldKey::DefaultTerminalMode => {
                 if let Some(ref mut s) = config.sandbox {
                     s.default_terminal_mode = None;
@@ -543,11 +596,61 @@ impl SettingsView {
                     s.volume_ignores = None;
                 }
             }
+            FieldKey::Dotfiles => {
+                if let Some(ref mut s) = config.sandbox {
+                    s.dotfiles = None;
+                }
+            }
             FieldKey::MountSsh => {
                 if let Some(ref mut s) = config.sandbox {
                     s.mount_ssh = None;
                 }
             }
+            FieldKey::StrictMounts => {
+                if let Some(ref mut s) = config.sandbox {
+                    s.strict_mounts = None;
+                }
+            }
+            FieldKey::StrictEnv => {
+                if let Some(ref mut s) = config.sandbox {
+                    s.strict_env = None;
+                }
+            }
+            FieldKey::SandboxReadOnlyRoot => {
+                if let Some(ref mut s) = config.sandbox {
+                    s.read_only_root = None;
+                }
+            }
+            FieldKey::SandboxInit => {
+                if let Some(ref mut s) = config.sandbox {
+                    s.init = None;
+                }
+            }
+            FieldKey::ExtraHosts => {
+                if let Some(ref mut s) = config.sandbox {
+                    s.extra_hosts = None;
+                }
+            }
+            FieldKey::SandboxHostGateway => {
+                if let Some(ref mut s) = config.sandbox {
+                    s.host_gateway = None;
+                }
+            }
+            FieldKey::SandboxMaxContainers => {
+                if let Some(ref mut s) = config.sandbox {
+                    s.max_containers = None;
+                }
+            }
+            FieldKey::SandboxReadyProbe => {
+                if let Some(ref mut s) = config.sandbox {
+                    s.ready_probe = None;
+                }
+            }
+            FieldKey::SandboxReadyTimeout => {
+                if let Some(ref mut s) = config.sandbox {
+                    s.ready_timeout_secs = None;
+                }
+            }
             FieldKey::CpuLimit => {
                 if let Some(ref mut s) = config.sandbox {
                     s.cpu_limit = None;
@@ -568,6 +671,11 @@ impl SettingsView {
                     s.container_runtime = None;
                 }
             }
+            FieldKey::ContainerNameScheme => {
+                if let Some(ref mut s) = config.sandbox {
+                    s.container_name_scheme = None;
+                }
+            }
             // Sound
             FieldKey::SoundEnabled => {
                 if let Some(ref mut s) = config.sound {
@@ -8,6 +8,8 @@ use std::sync::mpsc;
 use std::thread;
 use std::time::{Duration, Instant};
 
+use chrono::{DateTime, Utc};
+
 use crate::session::{Instance, Status};
 
 /// Result of a status check for a single session
@@ -16,6 +18,11 @@ pub struct StatusUpdate {
     pub id: String,
     pub status: Status,
     pub last_error: Option<String>,
+    pub note: Option<String>,
+    pub content_hash: Option<u64>,
+    pub last_activity_at: Option<DateTime<Utc>>,
+    pub last_exit_code: Option<i32>,
+    pub panes: Vec<crate::tmux::PaneDetail>,
 }
 
 /// Background thread that polls session status without blocking the UI
@@ -56,7 +63,7 @@ impl StatusPoller {
             // Refresh container health if any sandboxed session exists and interval elapsed
             let has_sandboxed = instances.iter().any(|i| i.is_sandboxed());
             if has_sandboxed && last_container_check.elapsed() >= container_check_interval {
-                container_states = crate::containers::batch_container_health();
+                container_states = crate::containers::batch_container_health().unwrap_or_default();
                 last_container_check = Instant::now();
             }
 
@@ -78,6 +85,11 @@ impl StatusPoller {
                                         id: inst.id,
                                         status: Status::Error,
                                         last_error: Some("Container is not running".to_string()),
+                                        note: None,
+                                        content_hash: inst.last_content_hash,
+                                        last_activity_at: inst.last_activity_at,
+                                        last_exit_code: inst.last_exit_code,
+                                        panes: Vec::new(),
                                     };
                                 }
                             }
@@ -90,6 +102,11 @@ impl StatusPoller {
                         id: inst.id,
                         status: inst.status,
                         last_error: inst.last_error,
+                        note: inst.status_note,
+                        content_hash: inst.last_content_hash,
+                        last_activity_at: inst.last_activity_at,
+                        last_exit_code: inst.last_exit_code,
+                        panes: inst.pane_statuses,
                     }
                 })
                 .collect();
@@ -105,6 +122,11 @@ impl StatusPoller {
         let _ = self.request_tx.send(instances);
     }
 
+    /// Wait up to `timeout` for the next batch of status updates.
+    pub fn recv_updates(&self, timeout: Duration) -> Option<Vec<StatusUpdate>> {
+        self.result_rx.recv_timeout(timeout).ok()
+    }
+
     /// Try to receive status updates without blocking.
     /// Returns None if no updates are available yet.
     pub fn try_recv_updates(&self) -> Option<Vec<StatusUpdate>> {
@@ -1,8 +1,10 @@
 //! TUI theme and styling
 
-use ratatui::style::Color;
+use ratatui::style::{Color, Style};
 use tracing::warn;
 
+use crate::session::Status;
+
 pub const AVAILABLE_THEMES: &[&str] = &[
     "phosphor",
     "tokyo-night-storm",
@@ -226,10 +228,87 @@ impl Theme {
     }
 }
 
+impl Status {
+    /// Glyph shown next to a session wherever its status is rendered
+    pub fn icon(self) -> &'static str {
+        match self {
+            Status::Running => "●",
+            Status::Waiting => "◐",
+            Status::Idle => "○",
+            Status::Stopped => "■",
+            Status::Error => "✕",
+            Status::Starting => "◌",
+            Status::Deleting => "✗",
+        }
+    }
+
+    pub fn style(self, theme: &Theme) -> Style {
+        let color = match self {
+            Status::Running => theme.running,
+            Status::Waiting => theme.waiting,
+            Status::Idle => theme.idle,
+            Status::Stopped => theme.dimmed,
+            Status::Error => theme.error,
+            Status::Starting => theme.dimmed,
+            Status::Deleting => theme.waiting,
+        };
+        Style::default().fg(color)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    const ALL_STATUSES: [Status; 7] = [
+        Status::Running,
+        Status::Waiting,
+        Status::Idle,
+        Status::Stopped,
+        Status::Error,
+        Status::Starting,
+        Status::Deleting,
+    ];
+
+    // Adding a Status variant fails to compile here until ALL_STATUSES lists it
+    #[allow(dead_code)]
+    fn listed(status: Status) {
+        match status {
+            Status::Running
+            | Status::Waiting
+            | Status::Idle
+            | Status::Stopped
+            | Status::Error
+            | Status::Starting
+            | Status::Deleting => {}
+        }
+    }
+
+    #[test]
+    fn test_every_status_has_icon_and_style() {
+        for theme_name in AVAILABLE_THEMES {
+            let theme = load_theme(theme_name);
+            for status in ALL_STATUSES {
+                assert!(!status.icon().is_empty(), "{:?} has no icon", status);
+                assert!(
+                    status.style(&theme).fg.is_some(),
+                    "{:?} has no color in {}",
+                    status,
+                    theme_name
+                );
+            }
+        }
+
+        let mut icons: Vec<_> = ALL_STATUSES.iter().map(|s| s.icon()).collect();
+        icons.sort_unstable();
+        icons.dedup();
+        assert_eq!(
+            icons.len(),
+            ALL_STATUSES.len(),
+            "status icons must be distinct"
+        );
+    }
+
     #[test]
     fn test_load_phosphor() {
         let theme = load_theme("phosphor");
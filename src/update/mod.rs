@@ -6,6 +6,7 @@ use std::fs;
 use std::path::PathBuf;
 use tracing::warn;
 
+use crate::cli::output::errln;
 use crate::session::{get_app_dir, get_update_settings};
 
 const GITHUB_API_LATEST: &str =
@@ -209,7 +210,7 @@ fn is_newer_version(latest: &str, current: &str) -> bool {
 
 pub async fn print_update_notice() {
     let settings = get_update_settings();
-    if !settings.check_enabled || !settings.notify_in_cli {
+    if !settings.check_enabled || !settings.notify_in_cli || crate::cli::output::is_quiet() {
         return;
     }
 
@@ -217,9 +218,10 @@ pub async fn print_update_notice() {
 
     if let Ok(info) = check_for_update(version, false).await {
         if info.available {
-            eprintln!(
+            errln!(
                 "\n💡 Update available: v{} → v{} (run: brew update && brew upgrade aoe)",
-                info.current_version, info.latest_version
+                info.current_version,
+                info.latest_version
             );
         }
     }
@@ -30,6 +30,166 @@ fn test_cli_add_and_list() {
     );
 }
 
+#[test]
+#[serial]
+fn test_cli_remove_quiet_prints_nothing() {
+    let h = TuiTestHarness::new("cli_remove_quiet");
+    let project = h.project_path();
+
+    let add_output = h.run_cli(&["add", project.to_str().unwrap(), "-t", "Quiet Me", "-q"]);
+    assert!(add_output.status.success());
+    assert!(add_output.stdout.is_empty());
+
+    let remove_output = h.run_cli(&["remove", "Quiet Me", "--quiet"]);
+    assert!(
+        remove_output.status.success(),
+        "aoe remove failed: {}",
+        String::from_utf8_lossy(&remove_output.stderr)
+    );
+    assert!(
+        remove_output.stdout.is_empty(),
+        "quiet remove should print nothing.\nOutput:\n{}",
+        String::from_utf8_lossy(&remove_output.stdout)
+    );
+
+    let missing = h.run_cli(&["remove", "Quiet Me", "--quiet"]);
+    assert!(!missing.status.success());
+    assert!(!missing.stderr.is_empty());
+}
+
+#[test]
+#[serial]
+fn test_cli_remove_with_uncommitted_changes_needs_yes_without_tty() {
+    let h = TuiTestHarness::new("cli_remove_dirty");
+    let project = h.project_path();
+    let git = |args: &[&str]| {
+        std::process::Command::new("git")
+            .args(args)
+            .current_dir(&project)
+            .output()
+            .expect("run git")
+    };
+    git(&["init", "-q"]);
+    std::fs::write(project.join("notes.txt"), "draft").unwrap();
+
+    let add_output = h.run_cli(&["add", project.to_str().unwrap(), "-t", "Dirty", "-q"]);
+    assert!(add_output.status.success());
+
+    let refused = h.run_cli(&["remove", "Dirty"]);
+    assert!(!refused.status.success());
+    assert!(
+        String::from_utf8_lossy(&refused.stderr).contains("--yes"),
+        "expected a hint to pass --yes:\n{}",
+        String::from_utf8_lossy(&refused.stderr)
+    );
+
+    let removed = h.run_cli(&["remove", "Dirty", "--yes"]);
+    assert!(
+        removed.status.success(),
+        "aoe remove --yes failed: {}",
+        String::from_utf8_lossy(&removed.stderr)
+    );
+}
+
+#[test]
+#[serial]
+fn test_cli_list_quiet_still_prints_sessions() {
+    let h = TuiTestHarness::new("cli_list_quiet");
+    let project = h.project_path();
+
+    let add_output = h.run_cli(&["add", project.to_str().unwrap(), "-t", "Listed", "-q"]);
+    assert!(add_output.status.success());
+
+    let list_output = h.run_cli(&["list", "-q"]);
+    assert!(list_output.status.success());
+    let stdout = String::from_utf8_lossy(&list_output.stdout);
+    assert!(
+        stdout.contains("Listed"),
+        "quiet list lost its data:\n{}",
+        stdout
+    );
+    assert!(
+        !stdout.contains("Total:"),
+        "quiet list kept decoration:\n{}",
+        stdout
+    );
+}
+
+#[test]
+#[serial]
+fn test_cli_add_progress_json_requires_trust_hooks() {
+    let h = TuiTestHarness::new("cli_add_json_hooks");
+    let project = h.project_path();
+    std::fs::create_dir_all(project.join(".aoe")).unwrap();
+    std::fs::write(
+        project.join(".aoe/config.toml"),
+        "[hooks]\non_create = [\"touch created.txt\"]\n",
+    )
+    .unwrap();
+
+    let output = h.run_cli(&["add", project.to_str().unwrap(), "--progress-json"]);
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("--trust-hooks"),
+        "expected a hint to pass --trust-hooks:\n{}",
+        stderr
+    );
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout
+            .lines()
+            .all(|l| serde_json::from_str::<serde_json::Value>(l).is_ok()),
+        "stdout should only carry JSON lines:\n{}",
+        stdout
+    );
+    assert!(!project.join("created.txt").exists());
+}
+
+#[test]
+#[serial]
+fn test_cli_archive_and_unarchive() {
+    let h = TuiTestHarness::new("cli_archive");
+    let project = h.project_path();
+
+    let add_output = h.run_cli(&["add", project.to_str().unwrap(), "-t", "Archive Me"]);
+    assert!(add_output.status.success());
+
+    let archive_output = h.run_cli(&["archive", "Archive Me"]);
+    assert!(
+        archive_output.status.success(),
+        "aoe archive failed: {}",
+        String::from_utf8_lossy(&archive_output.stderr)
+    );
+
+    let list_output = h.run_cli(&["list"]);
+    let stdout = String::from_utf8_lossy(&list_output.stdout);
+    assert!(
+        !stdout.contains("Archive Me"),
+        "archived session should be hidden from the default list.\nOutput:\n{}",
+        stdout
+    );
+
+    let list_all_output = h.run_cli(&["list", "--all"]);
+    let stdout = String::from_utf8_lossy(&list_all_output.stdout);
+    assert!(
+        stdout.contains("Archive Me"),
+        "archived session should be shown with --all.\nOutput:\n{}",
+        stdout
+    );
+
+    let unarchive_output = h.run_cli(&["unarchive", "Archive Me"]);
+    assert!(unarchive_output.status.success());
+
+    let list_output = h.run_cli(&["list"]);
+    let stdout = String::from_utf8_lossy(&list_output.stdout);
+    assert!(
+        stdout.contains("Archive Me"),
+        "unarchived session should be listed again.\nOutput:\n{}",
+        stdout
+    );
+}
+
 #[test]
 #[serial]
 fn test_cli_add_invalid_path() {
@@ -56,3 +216,53 @@ fn test_cli_add_invalid_path() {
         stderr
     );
 }
+
+#[test]
+#[serial]
+fn test_cli_list_verbose_shows_container() {
+    let h = TuiTestHarness::new("cli_list_verbose");
+    let project = h.project_path();
+
+    let profile_dir = h
+        .home_path()
+        .join(".config/agent-of-empires/profiles/default");
+    std::fs::create_dir_all(&profile_dir).expect("create profile dir");
+    let sessions = serde_json::json!([{
+        "id": "abcdef0123456789",
+        "title": "Sandboxed Session",
+        "project_path": project.to_str().unwrap(),
+        "tool": "claude",
+        "created_at": "2025-01-01T00:00:00Z",
+        "sandbox_info": {
+            "enabled": true,
+            "container_id": "c0ffee",
+            "image": "ubuntu:latest",
+            "container_name": "aoe-sandbox-abcdef01"
+        }
+    }]);
+    std::fs::write(profile_dir.join("sessions.json"), sessions.to_string())
+        .expect("write sessions.json");
+
+    let plain = h.run_cli(&["list"]);
+    assert!(plain.status.success());
+    let stdout = String::from_utf8_lossy(&plain.stdout);
+    assert!(stdout.contains("Sandboxed Session"));
+    assert!(
+        !stdout.contains("aoe-sandbox-abcdef01"),
+        "container name should only appear with --verbose.\nOutput:\n{}",
+        stdout
+    );
+
+    let verbose = h.run_cli(&["list", "--verbose"]);
+    assert!(verbose.status.success());
+    let stdout = String::from_utf8_lossy(&verbose.stdout);
+    assert!(
+        stdout.contains("aoe-sandbox-abcdef01") && stdout.contains("c0ffee"),
+        "verbose list should show container name and id.\nOutput:\n{}",
+        stdout
+    );
+
+    let json = h.run_cli(&["list", "--json"]);
+    let stdout = String::from_utf8_lossy(&json.stdout);
+    assert!(stdout.contains("\"name\": \"aoe-sandbox-abcdef01\""));
+}
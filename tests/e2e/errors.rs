@@ -8,9 +8,10 @@ fn test_cli_remove_nonexistent() {
     let h = TuiTestHarness::new("cli_rm_noexist");
 
     let output = h.run_cli(&["remove", "nonexistent-session-id-12345"]);
-    assert!(
-        !output.status.success(),
-        "aoe remove should fail for nonexistent session"
+    assert_eq!(
+        output.status.code(),
+        Some(agent_of_empires::cli::exit_code::NOT_FOUND),
+        "aoe remove should exit with the not-found code"
     );
 
     let stderr = String::from_utf8_lossy(&output.stderr);
@@ -236,6 +236,7 @@ last_seen_version = "{}"
             .env("HOME", self.home_dir.path())
             .env("XDG_CONFIG_HOME", self.home_dir.path().join(".config"))
             .env("PATH", self.env_path())
+            .env_remove("AOE_CONFIG")
             .env("TERM", "xterm-256color")
             .output()
             .expect("failed to run tmux new-session");
@@ -366,6 +367,7 @@ last_seen_version = "{}"
             .env("HOME", self.home_dir.path())
             .env("XDG_CONFIG_HOME", self.home_dir.path().join(".config"))
             .env("PATH", self.env_path())
+            .env_remove("AOE_CONFIG")
             .output()
             .expect("failed to run aoe CLI")
     }
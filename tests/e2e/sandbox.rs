@@ -36,3 +36,41 @@ fn test_cli_add_with_sandbox() {
         stdout
     );
 }
+
+/// `aoe create --sandbox` provisions the container and starts the agent
+/// without attaching, printing only the session ID under `--quiet`.
+///
+/// Requires a running Docker daemon -- marked `#[ignore]` for CI.
+#[test]
+#[serial]
+#[ignore = "requires Docker daemon"]
+fn test_cli_create_with_sandbox_leaves_session_running() {
+    let h = TuiTestHarness::new("cli_create");
+    let project = h.project_path();
+
+    let output = h.run_cli(&[
+        "create",
+        project.to_str().unwrap(),
+        "-t",
+        "Create E2E",
+        "--sandbox",
+        "--quiet",
+    ]);
+    assert!(
+        output.status.success(),
+        "aoe create --sandbox failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let id = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    assert!(!id.is_empty(), "aoe create should print the session ID");
+
+    let tmux_name = agent_of_empires::tmux::Session::generate_name(&id, "Create E2E");
+    let alive = std::process::Command::new("tmux")
+        .args(["has-session", "-t", &tmux_name])
+        .output()
+        .map(|o| o.status.success())
+        .unwrap_or(false);
+
+    let _ = h.run_cli(&["remove", &id, "--yes", "--force"]);
+    assert!(alive, "tmux session {} should be running", tmux_name);
+}
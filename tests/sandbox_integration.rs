@@ -24,6 +24,12 @@ fn test_sandbox_info_serialization() {
         extra_env_keys: Some(vec!["MY_VAR".to_string()]),
         extra_env_values: None,
         custom_instruction: None,
+        cpu_limit: None,
+        memory_limit: None,
+        config_hash: None,
+        workdir: None,
+        volume_ignores: None,
+        extra_volumes: None,
     };
 
     let json = serde_json::to_string(&sandbox_info).unwrap();
@@ -53,6 +59,12 @@ fn test_instance_is_sandboxed() {
         extra_env_keys: None,
         extra_env_values: None,
         custom_instruction: None,
+        cpu_limit: None,
+        memory_limit: None,
+        config_hash: None,
+        workdir: None,
+        volume_ignores: None,
+        extra_volumes: None,
     });
     assert!(inst.is_sandboxed());
 
@@ -65,6 +77,12 @@ fn test_instance_is_sandboxed() {
         extra_env_keys: None,
         extra_env_values: None,
         custom_instruction: None,
+        cpu_limit: None,
+        memory_limit: None,
+        config_hash: None,
+        workdir: None,
+        volume_ignores: None,
+        extra_volumes: None,
     });
     assert!(!inst.is_sandboxed());
 }
@@ -86,6 +104,12 @@ fn test_sandbox_info_persists_across_save_load() {
         extra_env_keys: Some(vec!["API_KEY".to_string(), "SECRET".to_string()]),
         extra_env_values: None,
         custom_instruction: None,
+        cpu_limit: None,
+        memory_limit: None,
+        config_hash: None,
+        workdir: None,
+        volume_ignores: None,
+        extra_volumes: None,
     });
 
     storage.save(&[inst.clone()]).unwrap();
@@ -143,6 +167,10 @@ fn test_container_lifecycle() {
         environment: vec![],
         cpu_limit: None,
         memory_limit: None,
+        labels: Default::default(),
+        read_only_root: false,
+        init: false,
+        extra_hosts: Vec::new(),
     };
 
     let container_id = container.create(&config).unwrap();
@@ -184,6 +212,10 @@ fn test_container_force_remove() {
         environment: vec![],
         cpu_limit: None,
         memory_limit: None,
+        labels: Default::default(),
+        read_only_root: false,
+        init: false,
+        extra_hosts: Vec::new(),
     };
 
     container.create(&config).unwrap();
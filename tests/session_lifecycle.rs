@@ -1,6 +1,6 @@
 //! Integration tests for the core session lifecycle: create, persist, load, remove.
 
-use agent_of_empires::session::{GroupTree, Instance, Storage};
+use agent_of_empires::session::{GroupTree, Instance, Status, Storage};
 use anyhow::Result;
 use serial_test::serial;
 use std::fs;
@@ -150,3 +150,72 @@ fn test_storage_defaults_to_default_profile() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+#[serial]
+fn test_kill_keeps_session_record() -> Result<()> {
+    if std::process::Command::new("tmux")
+        .arg("-V")
+        .output()
+        .map(|o| !o.status.success())
+        .unwrap_or(true)
+    {
+        eprintln!("Skipping test: tmux not available");
+        return Ok(());
+    }
+    let temp = setup_temp_home();
+
+    let storage = Storage::new("default")?;
+    let instance = Instance::new("Kill Me", temp.path().to_str().unwrap());
+    storage.save(std::slice::from_ref(&instance))?;
+
+    let tmux = instance.tmux_session()?;
+    tmux.create(&instance.project_path, Some("sleep 60"))?;
+    assert!(tmux.exists());
+
+    let killed = agent_of_empires::cli::kill::kill_session(&storage, "Kill Me")?;
+    assert_eq!(killed.as_deref(), Some("Kill Me"));
+    assert!(!tmux.exists());
+
+    let loaded = storage.load()?;
+    assert_eq!(loaded.len(), 1);
+    assert_eq!(loaded[0].id, instance.id);
+    assert_eq!(loaded[0].status, Status::Stopped);
+
+    assert_eq!(
+        agent_of_empires::cli::kill::kill_session(&storage, "Kill Me")?,
+        None
+    );
+
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn test_manager_remove_with_keeps_group_and_other_sessions() -> Result<()> {
+    use agent_of_empires::session::{RemoveOptions, SessionManager};
+
+    let _temp = setup_temp_home();
+
+    let manager = SessionManager::new("default")?;
+    let mut api = Instance::new("api", "/tmp/api");
+    api.group_path = "work".to_string();
+    let api = manager.add(api)?;
+    manager.add(Instance::new("web", "/tmp/web"))?;
+
+    let removal = manager.remove_with(
+        &api.id,
+        RemoveOptions {
+            keep_container: true,
+        },
+    )?;
+    assert_eq!(removal.instance.id, api.id);
+    assert!(!removal.container_removed);
+
+    let (remaining, groups) = Storage::new("default")?.load_with_groups()?;
+    assert_eq!(remaining.len(), 1);
+    assert_eq!(remaining[0].title, "web");
+    assert!(groups.iter().any(|g| g.path == "work"));
+
+    Ok(())
+}